@@ -0,0 +1,466 @@
+//! User-defined command aliases and macros, expanded before clap parses argv.
+//!
+//! ```toml
+//! [aliases]
+//! standup = "i list --mine --since 1d"
+//! mine = "i list --assignee me --state {1}"
+//!
+//! [macros]
+//! ship = ["i update {1} -s Done", "g pr {1} --draft"]
+//! ```
+//!
+//! `{1}`, `{2}`, ... are replaced by the arguments given after the alias name and
+//! `{@}` by every argument not used by a numbered placeholder. Arguments an alias
+//! does not reference are appended, as with git aliases. Built-in commands always
+//! win over an alias of the same name.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::collections::{BTreeMap, HashSet};
+
+/// Nested aliases are expanded at most this many times
+const MAX_ALIAS_DEPTH: usize = 16;
+/// Macros running macros give up at this depth
+const MAX_MACRO_DEPTH: u32 = 8;
+const MACRO_DEPTH_ENV: &str = "LINEAR_CLI_MACRO_DEPTH";
+
+/// Result of alias expansion
+#[derive(Debug, PartialEq)]
+pub enum Expansion {
+    /// No alias matched; parse argv as given
+    None,
+    /// An alias matched; parse these arguments instead (including argv[0])
+    Args(Vec<String>),
+    /// A macro matched; run each step with the given global flags
+    Macro {
+        name: String,
+        globals: Vec<String>,
+        steps: Vec<Vec<String>>,
+    },
+}
+
+/// Expand `args` (argv including the program name) against the configured aliases and macros
+pub fn expand(
+    args: &[String],
+    aliases: &BTreeMap<String, String>,
+    macros: &BTreeMap<String, Vec<String>>,
+    cli: &clap::Command,
+) -> Result<Expansion> {
+    let builtins = builtin_names(cli);
+    let mut args = args.to_vec();
+    let mut chain: Vec<String> = Vec::new();
+
+    while let Some(pos) = command_position(&args, cli) {
+        let name = args[pos].clone();
+        if builtins.contains(name.as_str()) {
+            break;
+        }
+        let rest = &args[pos + 1..];
+
+        if let Some(steps) = macros.get(&name) {
+            let steps = steps
+                .iter()
+                .map(|step| {
+                    split_words(step).with_context(|| format!("Invalid step in macro '{}'", name))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let steps = substitute(&steps, rest, &name, false)?;
+            return Ok(Expansion::Macro {
+                name,
+                globals: args[1..pos].to_vec(),
+                steps,
+            });
+        }
+
+        let Some(alias) = aliases.get(&name) else {
+            break;
+        };
+        if chain.contains(&name) || chain.len() >= MAX_ALIAS_DEPTH {
+            chain.push(name);
+            anyhow::bail!("Alias loop: {}", chain.join(" -> "));
+        }
+        chain.push(name.clone());
+
+        let words = split_words(alias).with_context(|| format!("Invalid alias '{}'", name))?;
+        if words.is_empty() {
+            anyhow::bail!("Alias '{}' is empty", name);
+        }
+        let mut expanded = args[..pos].to_vec();
+        expanded.extend(substitute(&[words], rest, &name, true)?.concat());
+        args = expanded;
+    }
+
+    if chain.is_empty() {
+        Ok(Expansion::None)
+    } else {
+        Ok(Expansion::Args(args))
+    }
+}
+
+/// Run the steps of a macro by re-invoking this binary; stops at the first failing step
+pub fn run_macro(name: &str, globals: &[String], steps: &[Vec<String>]) -> Result<i32> {
+    let depth: u32 = std::env::var(MACRO_DEPTH_ENV)
+        .ok()
+        .and_then(|d| d.parse().ok())
+        .unwrap_or(0);
+    if depth >= MAX_MACRO_DEPTH {
+        anyhow::bail!(
+            "Macro '{}' nests too deeply (limit {})",
+            name,
+            MAX_MACRO_DEPTH
+        );
+    }
+    let exe = std::env::current_exe().context("Failed to locate linear-cli executable")?;
+
+    for (i, step) in steps.iter().enumerate() {
+        if !crate::output::is_quiet() {
+            eprintln!(
+                "{} {}",
+                format!("[{}/{}]", i + 1, steps.len()).dimmed(),
+                format!("linear {}", step.join(" ")).cyan()
+            );
+        }
+        let status = std::process::Command::new(&exe)
+            .args(globals)
+            .args(step)
+            .env(MACRO_DEPTH_ENV, (depth + 1).to_string())
+            .status()
+            .with_context(|| format!("Failed to run step {} of macro '{}'", i + 1, name))?;
+        if !status.success() {
+            let code = status.code().unwrap_or(1);
+            eprintln!(
+                "{} Macro '{}' stopped at step {} (exit code {})",
+                "!".red(),
+                name,
+                i + 1,
+                code
+            );
+            return Ok(code);
+        }
+    }
+    Ok(0)
+}
+
+fn builtin_names(cli: &clap::Command) -> HashSet<String> {
+    let mut names: HashSet<String> = HashSet::from(["help".to_string()]);
+    for sub in cli.get_subcommands() {
+        names.insert(sub.get_name().to_string());
+        names.extend(sub.get_all_aliases().map(str::to_string));
+    }
+    names
+}
+
+/// Index of the first positional argument (the subcommand), skipping global flags and their values
+fn command_position(args: &[String], cli: &clap::Command) -> Option<usize> {
+    let takes_value = |arg: &clap::Arg| arg.get_action().takes_values();
+    let mut i = 1;
+    while i < args.len() {
+        let arg = &args[i];
+        if arg == "--" {
+            return None;
+        }
+        if let Some(long) = arg.strip_prefix("--") {
+            if !long.contains('=')
+                && cli
+                    .get_arguments()
+                    .any(|a| a.get_long() == Some(long) && takes_value(a))
+            {
+                i += 1;
+            }
+        } else if let Some(short) = arg.strip_prefix('-').filter(|s| !s.is_empty()) {
+            let mut chars = short.chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                if cli
+                    .get_arguments()
+                    .any(|a| a.get_short() == Some(c) && takes_value(a))
+                {
+                    i += 1;
+                }
+            }
+        } else {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Replace `{N}` and `{@}` placeholders in each command of `commands` with `args`.
+///
+/// Arguments not referenced by a placeholder in any command are appended to the
+/// last one with `append_rest`, and are an error otherwise.
+fn substitute(
+    commands: &[Vec<String>],
+    args: &[String],
+    name: &str,
+    append_rest: bool,
+) -> Result<Vec<Vec<String>>> {
+    let mut used = vec![false; args.len()];
+    let mut has_rest = false;
+
+    for word in commands.iter().flatten() {
+        let mut rest = word.as_str();
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}') else {
+                break;
+            };
+            let key = &rest[start + 1..start + end];
+            if key == "@" {
+                has_rest = true;
+            } else if let Ok(n) = key.parse::<usize>() {
+                if n == 0 || n > args.len() {
+                    anyhow::bail!(
+                        "'{}' needs argument {{{}}} but got {} argument(s)",
+                        name,
+                        n,
+                        args.len()
+                    );
+                }
+                used[n - 1] = true;
+            }
+            rest = &rest[start + end + 1..];
+        }
+    }
+
+    let unused: Vec<String> = args
+        .iter()
+        .zip(&used)
+        .filter(|(_, used)| !**used)
+        .map(|(a, _)| a.clone())
+        .collect();
+
+    let mut out: Vec<Vec<String>> = commands
+        .iter()
+        .map(|words| {
+            let mut command = Vec::new();
+            for word in words {
+                if word == "{@}" {
+                    command.extend(unused.iter().cloned());
+                } else {
+                    command.push(render(word, args, &unused));
+                }
+            }
+            command
+        })
+        .collect();
+
+    if !has_rest && !unused.is_empty() {
+        if !append_rest {
+            anyhow::bail!(
+                "Macro '{}' does not use argument(s): {}",
+                name,
+                unused.join(" ")
+            );
+        }
+        if let Some(last) = out.last_mut() {
+            last.extend(unused);
+        }
+    }
+    Ok(out)
+}
+
+/// Replace placeholders in one word in a single pass, so argument text is never re-expanded
+fn render(word: &str, args: &[String], unused: &[String]) -> String {
+    let mut out = String::new();
+    let mut rest = word;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let Some(end) = tail.find('}') else {
+            out.push_str(tail);
+            return out;
+        };
+        let key = &tail[1..end];
+        match key.parse::<usize>() {
+            _ if key == "@" => out.push_str(&unused.join(" ")),
+            Ok(n) if (1..=args.len()).contains(&n) => out.push_str(&args[n - 1]),
+            _ => out.push_str(&tail[..=end]),
+        }
+        rest = &tail[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Split a command string into words, honouring single quotes, double quotes and backslashes
+fn split_words(input: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => anyhow::bail!("Unterminated ' quote"),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c) => current.push(c),
+                            None => anyhow::bail!("Unterminated \" quote"),
+                        },
+                        Some(c) => current.push(c),
+                        None => anyhow::bail!("Unterminated \" quote"),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                if let Some(c) = chars.next() {
+                    current.push(c);
+                }
+            }
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            c => {
+                in_word = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(current);
+    }
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Arg, ArgAction, Command};
+
+    fn cli() -> Command {
+        Command::new("linear")
+            .arg(Arg::new("output").short('o').long("output").global(true))
+            .arg(
+                Arg::new("quiet")
+                    .short('q')
+                    .long("quiet")
+                    .action(ArgAction::SetTrue),
+            )
+            .subcommand(Command::new("issues").alias("i"))
+            .subcommand(Command::new("git").alias("g"))
+    }
+
+    fn argv(s: &str) -> Vec<String> {
+        std::iter::once("linear".to_string())
+            .chain(s.split_whitespace().map(str::to_string))
+            .collect()
+    }
+
+    fn aliases(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_alias_expands_after_global_flags_and_appends_rest() {
+        let aliases = aliases(&[("standup", "i list --mine --since 1d")]);
+        let result = expand(
+            &argv("-o json -q standup --limit 5"),
+            &aliases,
+            &BTreeMap::new(),
+            &cli(),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            Expansion::Args(argv("-o json -q i list --mine --since 1d --limit 5"))
+        );
+    }
+
+    #[test]
+    fn test_alias_placeholders_nesting_and_builtins() {
+        let aliases = aliases(&[
+            ("state", "i list --state '{1}' {@}"),
+            ("todo", "state Todo"),
+            ("issues", "g pr"),
+        ]);
+        let result = expand(&argv("todo --limit 3"), &aliases, &BTreeMap::new(), &cli()).unwrap();
+        assert_eq!(
+            result,
+            Expansion::Args(argv("i list --state Todo --limit 3"))
+        );
+
+        // Built-in commands cannot be shadowed
+        let result = expand(&argv("issues list"), &aliases, &BTreeMap::new(), &cli()).unwrap();
+        assert_eq!(result, Expansion::None);
+
+        let err = expand(&argv("state"), &aliases, &BTreeMap::new(), &cli()).unwrap_err();
+        assert!(err.to_string().contains("needs argument {1}"));
+    }
+
+    #[test]
+    fn test_alias_loop_is_reported() {
+        let aliases = aliases(&[("a", "b"), ("b", "a")]);
+        let err = expand(&argv("a"), &aliases, &BTreeMap::new(), &cli()).unwrap_err();
+        assert_eq!(err.to_string(), "Alias loop: a -> b -> a");
+    }
+
+    #[test]
+    fn test_macro_steps_substitute_arguments() {
+        let mut macros = BTreeMap::new();
+        macros.insert(
+            "ship".to_string(),
+            vec![
+                "i update {1} -s Done".to_string(),
+                "g pr {1} --title \"Ship {1}\"".to_string(),
+            ],
+        );
+        let result = expand(&argv("-q ship ENG-1"), &BTreeMap::new(), &macros, &cli()).unwrap();
+        assert_eq!(
+            result,
+            Expansion::Macro {
+                name: "ship".to_string(),
+                globals: vec!["-q".to_string()],
+                steps: vec![
+                    vec!["i", "update", "ENG-1", "-s", "Done"]
+                        .into_iter()
+                        .map(String::from)
+                        .collect(),
+                    vec!["g", "pr", "ENG-1", "--title", "Ship ENG-1"]
+                        .into_iter()
+                        .map(String::from)
+                        .collect(),
+                ],
+            }
+        );
+
+        let err = expand(&argv("ship ENG-1 extra"), &BTreeMap::new(), &macros, &cli()).unwrap_err();
+        assert!(err.to_string().contains("does not use argument(s): extra"));
+    }
+
+    #[test]
+    fn test_split_words_quotes_and_escapes() {
+        assert_eq!(
+            split_words(r#"i list --filter 'state.name=In Progress' "a \"b\"" c\ d"#).unwrap(),
+            vec![
+                "i",
+                "list",
+                "--filter",
+                "state.name=In Progress",
+                "a \"b\"",
+                "c d"
+            ]
+        );
+        assert!(split_words("'open").is_err());
+        assert_eq!(split_words("  ").unwrap(), Vec::<String>::new());
+    }
+}
@@ -3,8 +3,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::fs;
 use std::io::Write;
-use std::path::PathBuf;
-use std::sync::OnceLock;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::config;
@@ -16,6 +15,8 @@ const DEFAULT_TTL_SECONDS: u64 = 3600;
 pub struct CacheOptions {
     pub ttl_seconds: Option<u64>,
     pub no_cache: bool,
+    /// Serve expired entries and refresh them in the background
+    pub stale_while_revalidate: bool,
 }
 
 impl CacheOptions {
@@ -61,7 +62,7 @@ impl CacheEntry {
 }
 
 /// Cache types supported by the CLI
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CacheType {
     Teams,
     Users,
@@ -69,6 +70,8 @@ pub enum CacheType {
     Labels,
     Projects,
     Views,
+    /// Issue identifier (ENG-123) to UUID lookups, keyed by identifier
+    Issues,
 }
 
 impl CacheType {
@@ -81,6 +84,7 @@ impl CacheType {
             CacheType::Labels => "labels.json",
             CacheType::Projects => "projects.json",
             CacheType::Views => "views.json",
+            CacheType::Issues => "issues.json",
         }
     }
 
@@ -93,6 +97,7 @@ impl CacheType {
             CacheType::Labels => "Labels",
             CacheType::Projects => "Projects",
             CacheType::Views => "Views",
+            CacheType::Issues => "Issues",
         }
     }
 
@@ -105,8 +110,42 @@ impl CacheType {
             CacheType::Labels,
             CacheType::Projects,
             CacheType::Views,
+            CacheType::Issues,
         ]
     }
+
+    /// The cache a mutation root field (e.g. `issueLabelUpdate`) can make stale.
+    /// Names are matched exactly: `projectUpdateCreate` posts a project update and
+    /// `teamMembershipCreate` adds a member, neither touches the cached lists.
+    pub fn invalidated_by(field: &str) -> Option<CacheType> {
+        match field {
+            // Creating an issue cannot invalidate an existing identifier
+            "issueUpdate" | "issueDelete" | "issueArchive" | "issueUnarchive"
+            | "issueBatchUpdate" => Some(CacheType::Issues),
+            "issueLabelCreate"
+            | "issueLabelUpdate"
+            | "issueLabelDelete"
+            | "issueLabelArchive"
+            | "issueLabelUnarchive"
+            | "projectLabelCreate"
+            | "projectLabelUpdate"
+            | "projectLabelDelete" => Some(CacheType::Labels),
+            "workflowStateCreate" | "workflowStateUpdate" | "workflowStateArchive" => {
+                Some(CacheType::Statuses)
+            }
+            "customViewCreate" | "customViewUpdate" | "customViewDelete" => Some(CacheType::Views),
+            "projectCreate" | "projectUpdate" | "projectDelete" | "projectArchive"
+            | "projectUnarchive" => Some(CacheType::Projects),
+            "teamCreate" | "teamUpdate" | "teamDelete" | "teamArchive" | "teamUnarchive" => {
+                Some(CacheType::Teams)
+            }
+            "userUpdate" | "userSuspend" | "userUnsuspend" | "userDemoteAdmin"
+            | "userPromoteAdmin" | "userDemoteMember" | "userPromoteMember" => {
+                Some(CacheType::Users)
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Cache manager for Linear CLI
@@ -131,24 +170,17 @@ impl Cache {
         })
     }
 
-    /// Get the cache directory path, scoped by workspace/profile
+    /// Get the cache directory path, scoped by workspace/profile.
+    ///
+    /// Resolved on every call so fan-out tasks (`config::with_profile`) each
+    /// get their own profile's directory.
     pub(crate) fn cache_dir() -> Result<PathBuf> {
-        static CACHE_DIR: OnceLock<PathBuf> = OnceLock::new();
-
-        if let Some(cached) = CACHE_DIR.get() {
-            return Ok(cached.clone());
-        }
-
         let profile = config::current_profile().unwrap_or_else(|_| "default".to_string());
-        let config_dir = dirs::config_dir()
+        Ok(dirs::config_dir()
             .context("Could not find config directory")?
             .join("linear-cli")
             .join("cache")
-            .join(profile);
-
-        // Store for future calls (ignore if another thread beat us)
-        let _ = CACHE_DIR.set(config_dir.clone());
-        Ok(config_dir)
+            .join(profile))
     }
 
     /// Get the path for a specific cache type
@@ -175,6 +207,14 @@ impl Cache {
         }
     }
 
+    /// Get cached data even when expired, with whether it is stale.
+    /// Unlike `get`, expired entries are kept for stale-while-revalidate reads.
+    pub fn get_stale(&self, cache_type: CacheType) -> Option<(Value, bool)> {
+        let entry = self.get_entry(cache_type)?;
+        let stale = !entry.is_valid_with_ttl(self.ttl_seconds);
+        Some((entry.data, stale))
+    }
+
     /// Get cache entry with metadata
     pub fn get_entry(&self, cache_type: CacheType) -> Option<CacheEntry> {
         let path = self.cache_path(cache_type);
@@ -202,38 +242,7 @@ impl Cache {
 
         let content = serde_json::to_string_pretty(&entry)?;
 
-        // Atomic write: write to temp file, sync, then rename
-        // Use secure permissions on Unix (0600)
-        let temp_path = path.with_extension("tmp");
-
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::OpenOptionsExt;
-            let mut file = fs::OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .mode(0o600)
-                .open(&temp_path)?;
-            file.write_all(content.as_bytes())?;
-            file.sync_all()?;
-        }
-
-        #[cfg(not(unix))]
-        {
-            let mut file = fs::File::create(&temp_path)?;
-            file.write_all(content.as_bytes())?;
-            file.sync_all()?;
-        }
-
-        // Atomic rename
-        // On Windows, fs::rename fails if the destination exists, so remove it first
-        #[cfg(windows)]
-        {
-            let _ = fs::remove_file(&path);
-        }
-        fs::rename(&temp_path, &path)?;
-        Ok(())
+        write_atomic(&path, &content)
     }
 
     /// Clear cache for a specific type
@@ -256,6 +265,14 @@ impl Cache {
     /// Get cached data for a specific key within a cache type (e.g., statuses for a specific team).
     /// Uses per-key timestamps to check validity, so updating one key doesn't refresh others.
     pub fn get_keyed(&self, cache_type: CacheType, key: &str) -> Option<Value> {
+        match self.get_keyed_stale(cache_type, key)? {
+            (data, false) => Some(data),
+            (_, true) => None,
+        }
+    }
+
+    /// Get cached data for a key even when expired, with whether it is stale
+    pub fn get_keyed_stale(&self, cache_type: CacheType, key: &str) -> Option<(Value, bool)> {
         let path = self.cache_path(cache_type);
         if !path.exists() {
             return None;
@@ -272,10 +289,7 @@ impl Cache {
                 .duration_since(UNIX_EPOCH)
                 .unwrap_or(Duration::ZERO)
                 .as_secs();
-            if now < timestamp + self.ttl_seconds {
-                return Some(data.clone());
-            }
-            return None;
+            return Some((data.clone(), now >= timestamp + self.ttl_seconds));
         }
 
         // Old format (no per-key timestamp): treat as expired for backwards compatibility
@@ -315,6 +329,27 @@ impl Cache {
         self.set(cache_type, data)
     }
 
+    /// Remove keyed entries whose key or cached value matches one of `ids`
+    /// (case-insensitively), leaving the other keys and their timestamps intact.
+    pub fn remove_keyed(&self, cache_type: CacheType, ids: &[String]) -> Result<()> {
+        let Some(mut entry) = self.get_entry(cache_type) else {
+            return Ok(());
+        };
+        let Some(obj) = entry.data.as_object_mut() else {
+            return Ok(());
+        };
+        let matches = |value: &str| ids.iter().any(|id| id.eq_ignore_ascii_case(value));
+        let before = obj.len();
+        obj.retain(|key, wrapper| {
+            let value = wrapper.get("data").and_then(|d| d.as_str()).unwrap_or("");
+            !matches(key) && !matches(value)
+        });
+        if obj.len() == before {
+            return Ok(());
+        }
+        self.set(cache_type, entry.data)
+    }
+
     /// Get cache status for all types
     pub fn status(&self) -> Vec<CacheStatus> {
         CacheType::all()
@@ -336,6 +371,8 @@ impl Cache {
                                     .and_then(|n| n.as_array())
                                     .map(|a| a.len())
                             })
+                            // Keyed caches hold one entry per key
+                            .or_else(|| entry.data.as_object().map(|o| o.len()))
                             .unwrap_or(1);
                         (
                             entry.is_valid(),
@@ -398,10 +435,99 @@ impl CacheStatus {
     }
 }
 
+/// Write a file atomically: write a temp file next to it, sync, then rename.
+/// The file is created with owner-only permissions (0600) on Unix.
+pub(crate) fn write_atomic(path: &Path, content: &str) -> Result<()> {
+    let temp_path = unique_temp_path(path);
+    if let Err(e) = write_temp(&temp_path, content) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e.into());
+    }
+
+    // On Windows, fs::rename fails if the destination exists, so remove it first
+    #[cfg(windows)]
+    {
+        let _ = fs::remove_file(path);
+    }
+    if let Err(e) = fs::rename(&temp_path, path) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// Temp file in the destination's directory, unique per process and call so
+/// concurrent writers of the same file never share one.
+fn unique_temp_path(path: &Path) -> PathBuf {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(
+        ".{}.tmp-{}-{}",
+        name,
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+fn write_temp(temp_path: &Path, content: &str) -> std::io::Result<()> {
+    #[cfg(unix)]
+    let mut file = {
+        use std::os::unix::fs::OpenOptionsExt;
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(temp_path)?
+    };
+
+    #[cfg(not(unix))]
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(temp_path)?;
+
+    file.write_all(content.as_bytes())?;
+    file.sync_all()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// A cache in a fresh temporary directory, removed when dropped
+    struct TempCache(Cache);
+
+    impl TempCache {
+        fn new() -> Self {
+            let cache_dir = std::env::temp_dir()
+                .join(format!("linear-cli-cache-test-{}", crate::text::new_uuid()));
+            fs::create_dir_all(&cache_dir).unwrap();
+            TempCache(Cache {
+                cache_dir,
+                ttl_seconds: 3600,
+            })
+        }
+    }
+
+    impl std::ops::Deref for TempCache {
+        type Target = Cache;
+
+        fn deref(&self) -> &Cache {
+            &self.0
+        }
+    }
+
+    impl Drop for TempCache {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0.cache_dir);
+        }
+    }
+
     #[test]
     fn test_cache_entry_validity() {
         let entry = CacheEntry {
@@ -448,6 +574,7 @@ mod tests {
         assert_eq!(CacheType::Labels.filename(), "labels.json");
         assert_eq!(CacheType::Projects.filename(), "projects.json");
         assert_eq!(CacheType::Views.filename(), "views.json");
+        assert_eq!(CacheType::Issues.filename(), "issues.json");
     }
 
     #[test]
@@ -459,7 +586,114 @@ mod tests {
     #[test]
     fn test_cache_type_all() {
         let all = CacheType::all();
-        assert_eq!(all.len(), 6);
+        assert_eq!(all.len(), 7);
+    }
+
+    #[test]
+    fn test_cache_type_invalidated_by() {
+        let cases = [
+            ("issueLabelUpdate", Some(CacheType::Labels)),
+            ("projectLabelCreate", Some(CacheType::Labels)),
+            ("workflowStateUpdate", Some(CacheType::Statuses)),
+            ("projectArchive", Some(CacheType::Projects)),
+            ("projectUnarchive", Some(CacheType::Projects)),
+            ("teamDelete", Some(CacheType::Teams)),
+            ("customViewCreate", Some(CacheType::Views)),
+            ("issueUpdate", Some(CacheType::Issues)),
+            ("issueBatchUpdate", Some(CacheType::Issues)),
+            ("issueCreate", None),
+            ("commentCreate", None),
+            ("issueRelationCreate", None),
+            ("teamMemberships", None),
+            ("projectUpdateCreate", None),
+            ("projectMilestoneUpdate", None),
+            ("teamMembershipCreate", None),
+            ("userSettingsUpdate", None),
+        ];
+        for (field, expected) in cases {
+            assert_eq!(CacheType::invalidated_by(field), expected, "{}", field);
+        }
+    }
+
+    #[test]
+    fn test_get_stale_keeps_expired_entries() {
+        let cache = TempCache::new();
+        let expired = CacheEntry {
+            timestamp: 1,
+            ttl_seconds: 3600,
+            data: json!([{ "id": "team-1" }]),
+        };
+        fs::write(
+            cache.cache_dir.join(CacheType::Teams.filename()),
+            serde_json::to_string(&expired).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            cache.get_stale(CacheType::Teams),
+            Some((json!([{ "id": "team-1" }]), true))
+        );
+        // `get` still treats it as a miss (and drops it)
+        assert_eq!(cache.get(CacheType::Teams), None);
+        assert_eq!(cache.get_stale(CacheType::Teams), None);
+
+        cache.set(CacheType::Teams, json!([])).unwrap();
+        assert_eq!(cache.get_stale(CacheType::Teams), Some((json!([]), false)));
+    }
+
+    #[test]
+    fn test_get_keyed_stale_keeps_expired_keys() {
+        let cache = TempCache::new();
+        let entry = CacheEntry {
+            timestamp: 1,
+            ttl_seconds: 3600,
+            data: json!({ "team-1": { "data": ["old"], "timestamp": 1 } }),
+        };
+        fs::write(
+            cache.cache_dir.join(CacheType::Statuses.filename()),
+            serde_json::to_string(&entry).unwrap(),
+        )
+        .unwrap();
+        cache
+            .set_keyed(CacheType::Statuses, "team-2", json!(["new"]))
+            .unwrap();
+
+        assert_eq!(cache.get_keyed(CacheType::Statuses, "team-1"), None);
+        assert_eq!(
+            cache.get_keyed_stale(CacheType::Statuses, "team-1"),
+            Some((json!(["old"]), true))
+        );
+        assert_eq!(
+            cache.get_keyed_stale(CacheType::Statuses, "team-2"),
+            Some((json!(["new"]), false))
+        );
+    }
+
+    #[test]
+    fn test_remove_keyed_drops_matching_keys_and_values() {
+        let cache = TempCache::new();
+        cache
+            .set_keyed(CacheType::Issues, "ENG-1", json!("uuid-1"))
+            .unwrap();
+        cache
+            .set_keyed(CacheType::Issues, "ENG-2", json!("uuid-2"))
+            .unwrap();
+        cache
+            .set_keyed(CacheType::Issues, "ENG-3", json!("uuid-3"))
+            .unwrap();
+
+        cache
+            .remove_keyed(
+                CacheType::Issues,
+                &["eng-1".to_string(), "uuid-3".to_string()],
+            )
+            .unwrap();
+        assert_eq!(cache.get_keyed(CacheType::Issues, "ENG-1"), None);
+        assert_eq!(
+            cache.get_keyed(CacheType::Issues, "ENG-2"),
+            Some(json!("uuid-2"))
+        );
+        assert_eq!(cache.get_keyed(CacheType::Issues, "ENG-3"), None);
     }
 
     #[test]
@@ -470,6 +704,7 @@ mod tests {
         let opts_custom = CacheOptions {
             ttl_seconds: Some(7200),
             no_cache: false,
+            stale_while_revalidate: false,
         };
         assert_eq!(opts_custom.effective_ttl_seconds(), 7200);
     }
@@ -533,4 +768,12 @@ mod tests {
         };
         assert_eq!(status_mb.size_display(), "1.0 MB");
     }
+
+    #[tokio::test]
+    async fn test_cache_dir_follows_scoped_profile() {
+        let a = config::with_profile("a".to_string(), async { Cache::cache_dir().unwrap() }).await;
+        let b = config::with_profile("b".to_string(), async { Cache::cache_dir().unwrap() }).await;
+        assert!(a.ends_with("cache/a"), "{}", a.display());
+        assert!(b.ends_with("cache/b"), "{}", b.display());
+    }
 }
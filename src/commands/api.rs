@@ -1,11 +1,47 @@
-use anyhow::Result;
-use clap::Subcommand;
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
 use serde_json::{json, Map, Value};
 use std::io::{self, BufRead, IsTerminal};
+use std::path::PathBuf;
 
 use crate::api::LinearClient;
+use crate::error::CliError;
+use crate::graphql;
 use crate::output::{print_json_owned, OutputOptions};
 use crate::pagination::{paginate_nodes, PaginationOptions};
+use crate::schema::{self, Schema};
+
+/// Where a raw operation comes from and how its variables are supplied
+#[derive(Args, Debug, Clone, Default)]
+pub struct OperationArgs {
+    /// GraphQL document string. Use "-" to read from stdin, or omit when piping.
+    #[arg(conflicts_with = "file")]
+    pub query: Option<String>,
+
+    /// Read the document from a file (e.g. a saved ops.graphql)
+    #[arg(short = 'f', long, value_name = "PATH")]
+    pub file: Option<PathBuf>,
+
+    /// Operation to run when the document defines several
+    #[arg(long, value_name = "NAME")]
+    pub operation: Option<String>,
+
+    /// Variables as key=value pairs (e.g. -v id=abc123 -v name=test)
+    #[arg(short = 'v', long = "variable", value_name = "KEY=VALUE")]
+    pub variables: Vec<String>,
+
+    /// Variables with JSON values (e.g. -V first:=10 -V 'filter:={"state":{"type":{"eq":"started"}}}')
+    #[arg(short = 'V', long = "json-variable", value_name = "KEY:=JSON")]
+    pub json_variables: Vec<String>,
+
+    /// Variables from a JSON file, or an inline JSON object
+    #[arg(long = "variables", value_name = "FILE|JSON")]
+    pub variables_json: Option<String>,
+
+    /// Skip checking the document against the cached schema
+    #[arg(long)]
+    pub no_validate: bool,
+}
 
 #[derive(Subcommand)]
 pub enum ApiCommands {
@@ -14,15 +50,16 @@ pub enum ApiCommands {
     linear api query '{ viewer { id name email } }'
     linear api query '{ teams { nodes { id key name } } }'
     linear api query -v teamId=abc123 'query($teamId: String!) { team(id: $teamId) { name } }'
+    linear api query -f ops.graphql --operation OpenIssues --variables vars.json
+    linear api query -f ops.graphql --operation OpenIssues -V first:=100 --paginate \
+        --nodes-path data.issues.nodes --page-info-path data.issues.pageInfo
     echo '{ viewer { id } }' | linear api query
-    echo '{ viewer { id } }' | linear api query -"#)]
-    Query {
-        /// GraphQL query string. Use "-" to read from stdin, or omit when piping.
-        query: Option<String>,
+    echo '{ viewer { id } }' | linear api query -
 
-        /// Variables as key=value pairs (e.g. -v id=abc123 -v name=test)
-        #[arg(short = 'v', long = "variable", value_name = "KEY=VALUE")]
-        variables: Vec<String>,
+Variable sources are merged in order: --variables, then -V, then -v (later wins)."#)]
+    Query {
+        #[command(flatten)]
+        op: OperationArgs,
 
         /// Auto-paginate through all results (requires nodes + pageInfo in query)
         #[arg(long)]
@@ -40,42 +77,62 @@ pub enum ApiCommands {
     #[command(after_help = r#"EXAMPLES:
     linear api mutate -v title="New Issue" -v teamId=abc123 \
         'mutation($title: String!, $teamId: String!) { issueCreate(input: { title: $title, teamId: $teamId }) { issue { id identifier } } }'
+    linear api mutate -f ops.graphql --operation CloseIssue -V 'input:={"stateId":"abc"}' -v id=ENG-1
     cat mutation.graphql | linear api mutate -
     echo '...' | linear api mutate"#)]
     Mutate {
-        /// GraphQL mutation string. Use "-" to read from stdin, or omit when piping.
-        query: Option<String>,
+        #[command(flatten)]
+        op: OperationArgs,
+    },
+    /// Cache and inspect the GraphQL schema used to check raw queries
+    #[command(after_help = r#"EXAMPLES:
+    linear api schema pull                  # Fetch and cache the schema
+    linear api schema show                  # Schema summary
+    linear api schema show Issue            # Fields of a type
+    linear api schema show Query.issues     # Arguments and type of a field
+
+Once pulled, `api query` and `api mutate` reject unknown fields, arguments and
+types before sending, and `-v` values are converted to the declared variable
+types (Int, Float, Boolean, enums, JSON, input objects, lists)."#)]
+    Schema {
+        #[command(subcommand)]
+        action: SchemaCommands,
+    },
+    /// Show the remaining request and query-complexity budget
+    #[command(after_help = r#"EXAMPLES:
+    linear api rate-limit
+    linear api rate-limit --output json
+
+Bulk, import and batch commands slow down automatically when either budget
+runs low."#)]
+    RateLimit,
+}
 
-        /// Variables as key=value pairs (e.g. -v id=abc123 -v name=test)
-        #[arg(short = 'v', long = "variable", value_name = "KEY=VALUE")]
-        variables: Vec<String>,
+#[derive(Subcommand)]
+pub enum SchemaCommands {
+    /// Fetch the schema by introspection and cache it for this profile
+    Pull,
+    /// Show a schema summary, a type's fields, or a single field
+    Show {
+        /// Type name or Type.field
+        name: Option<String>,
     },
 }
 
 pub async fn handle(cmd: ApiCommands, output: &OutputOptions) -> Result<()> {
     match cmd {
         ApiCommands::Query {
-            query,
-            variables,
+            op,
             paginate,
             nodes_path,
             page_info_path,
-        } => {
-            let resolved = resolve_query_source(query)?;
-            run_query(
-                &resolved,
-                &variables,
-                paginate,
-                &nodes_path,
-                &page_info_path,
-                output,
-            )
-            .await
-        }
-        ApiCommands::Mutate { query, variables } => {
-            let resolved = resolve_query_source(query)?;
-            run_mutate(&resolved, &variables, output).await
-        }
+        } => run_query(&op, paginate, &nodes_path, &page_info_path, output).await,
+        ApiCommands::Mutate { op } => run_mutate(&op, output).await,
+        ApiCommands::Schema { action } => match action {
+            SchemaCommands::Pull => pull_schema(output).await,
+            SchemaCommands::Show { name } => show_schema(name.as_deref(), output),
+        },
+        ApiCommands::RateLimit => show_rate_limit(output).await,
     }
 }
 
@@ -116,6 +173,46 @@ fn read_query(input: &str) -> Result<String> {
     }
 }
 
+/// Load the document from `--file` or the query argument/stdin, narrowed to the
+/// operation chosen with `--operation` and the fragments it uses.
+fn load_document(op: &OperationArgs) -> Result<String> {
+    let source = match &op.file {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?,
+        None => read_query(&resolve_query_source(op.query.clone())?)?,
+    };
+    graphql::extract_operation(&source, op.operation.as_deref())
+}
+
+/// Collect JSON-typed variables: the `--variables` object first, then each `-V key:=json`.
+fn json_variables(op: &OperationArgs) -> Result<Map<String, Value>> {
+    let mut map = Map::new();
+    if let Some(source) = &op.variables_json {
+        let (content, origin) = if source.trim_start().starts_with('{') {
+            (source.clone(), "--variables".to_string())
+        } else {
+            let content = std::fs::read_to_string(source)
+                .with_context(|| format!("Failed to read variables file: {}", source))?;
+            (content, source.clone())
+        };
+        let value: Value = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid JSON in {}", origin))?;
+        match value {
+            Value::Object(vars) => map.extend(vars),
+            _ => anyhow::bail!("Variables in {} must be a JSON object", origin),
+        }
+    }
+    for var in &op.json_variables {
+        let (key, raw) = var
+            .split_once(":=")
+            .ok_or_else(|| anyhow::anyhow!("Invalid variable format '{}'. Use key:=json.", var))?;
+        let value = serde_json::from_str(raw)
+            .map_err(|e| anyhow::anyhow!("Variable '{}' is not valid JSON: {}", key, e))?;
+        map.insert(key.to_string(), value);
+    }
+    Ok(map)
+}
+
 fn parse_variables(vars: &[String]) -> Result<Option<Value>> {
     if vars.is_empty() {
         return Ok(None);
@@ -136,16 +233,122 @@ fn parse_variables(vars: &[String]) -> Result<Option<Value>> {
     Ok(Some(Value::Object(map)))
 }
 
+/// Check a document against the cached schema (unless `no_validate`) and build
+/// its variables: `json` values are taken as given, then each `-v` value is
+/// coerced to the type the operation declares and overrides them.
+/// `implicit` names variables the command fills in itself (pagination cursors).
+fn prepare_variables(
+    query: &str,
+    json: Map<String, Value>,
+    vars: &[String],
+    no_validate: bool,
+    implicit: &[&str],
+) -> Result<Option<Value>> {
+    let schema = if no_validate { None } else { schema::load()? };
+    let document = match graphql::parse(query) {
+        Ok(document) => document,
+        Err(err) if schema.is_some() => {
+            return Err(CliError::general(format!(
+                "Invalid GraphQL document: {} (skip the check with --no-validate)",
+                err
+            ))
+            .into())
+        }
+        // Without a schema, leave unparseable documents for the server to reject
+        Err(_) => {
+            let mut map = json;
+            if let Some(Value::Object(parsed)) = parse_variables(vars)? {
+                map.extend(parsed);
+            }
+            return Ok((!map.is_empty()).then_some(Value::Object(map)));
+        }
+    };
+    if let Some(schema) = &schema {
+        validate_document(schema, &document)?;
+    }
+    let operation = &document.operations[0];
+    let declared = |key: &str| -> Result<&graphql::VariableDefinition> {
+        operation
+            .variables
+            .iter()
+            .find(|v| v.name == key)
+            .ok_or_else(|| {
+                let names: Vec<String> = operation
+                    .variables
+                    .iter()
+                    .map(|v| format!("${}: {}", v.name, v.ty))
+                    .collect();
+                anyhow::anyhow!(
+                    "Variable '{}' is not declared by the operation (declared: {})",
+                    key,
+                    if names.is_empty() {
+                        "none".to_string()
+                    } else {
+                        names.join(", ")
+                    }
+                )
+            })
+    };
+
+    let mut map = Map::new();
+    for (key, value) in json {
+        declared(&key)?;
+        map.insert(key, value);
+    }
+    for var in vars {
+        let (key, value) = var
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid variable format '{}'. Use key=value.", var))?;
+        let def = declared(key)?;
+        let value = schema::coerce(value, &def.ty, schema.as_ref())
+            .map_err(|e| anyhow::anyhow!("Variable '${}' {}", key, e))?;
+        map.insert(key.to_string(), value);
+    }
+
+    for def in &operation.variables {
+        let required = def.ty.ends_with('!') && !def.has_default;
+        if required && !map.contains_key(&def.name) && !implicit.contains(&def.name.as_str()) {
+            anyhow::bail!(
+                "Variable '${}' ({}) is required; pass -v {}=...",
+                def.name,
+                def.ty,
+                def.name
+            );
+        }
+    }
+
+    Ok((!map.is_empty()).then_some(Value::Object(map)))
+}
+
+fn validate_document(schema: &Schema, document: &graphql::Document) -> Result<()> {
+    let errors = schema.validate(document);
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let details: Vec<Value> = errors.iter().map(|m| json!({ "message": m })).collect();
+    Err(CliError::general(
+        "Query does not match the cached schema (refresh with `api schema pull` or skip with --no-validate)",
+    )
+    .with_details(json!(details))
+    .into())
+}
+
 async fn run_query(
-    query_str: &str,
-    variables: &[String],
+    op: &OperationArgs,
     paginate: bool,
     nodes_path: &str,
     page_info_path: &str,
     output: &OutputOptions,
 ) -> Result<()> {
-    let query = read_query(query_str)?;
-    let vars = parse_variables(variables)?;
+    let query = load_document(op)?;
+    let implicit: &[&str] = if paginate { &["first", "after"] } else { &[] };
+    let vars = prepare_variables(
+        &query,
+        json_variables(op)?,
+        &op.variables,
+        op.no_validate,
+        implicit,
+    )?;
     let client = LinearClient::new()?;
 
     if paginate {
@@ -204,9 +407,15 @@ async fn run_query(
     Ok(())
 }
 
-async fn run_mutate(query_str: &str, variables: &[String], output: &OutputOptions) -> Result<()> {
-    let query = read_query(query_str)?;
-    let vars = parse_variables(variables)?;
+async fn run_mutate(op: &OperationArgs, output: &OutputOptions) -> Result<()> {
+    let query = load_document(op)?;
+    let vars = prepare_variables(
+        &query,
+        json_variables(op)?,
+        &op.variables,
+        op.no_validate,
+        &[],
+    )?;
     let client = LinearClient::new()?;
 
     let result = client.mutate(&query, vars).await?;
@@ -215,6 +424,161 @@ async fn run_mutate(query_str: &str, variables: &[String], output: &OutputOption
     Ok(())
 }
 
+async fn show_rate_limit(output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    // Any request returns the budget headers; this is about the cheapest one
+    client
+        .query("query RateLimitProbe { viewer { id } }", None)
+        .await?;
+    let status = client.rate_limit();
+
+    if output.is_json() || output.has_template() {
+        return print_json_owned(status.map(|s| s.to_json()).unwrap_or(Value::Null), output);
+    }
+
+    let Some(status) = status else {
+        println!("The API returned no rate-limit headers.");
+        return Ok(());
+    };
+    print_budget(
+        "Requests",
+        status.requests_remaining,
+        status.requests_limit,
+        status.requests_reset,
+    );
+    print_budget(
+        "Complexity",
+        status.complexity_remaining,
+        status.complexity_limit,
+        status.complexity_reset,
+    );
+    if let Some(cost) = status.last_complexity {
+        println!("Last query complexity: {}", cost);
+    }
+    Ok(())
+}
+
+async fn pull_schema(output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    let result = client.query(schema::INTROSPECTION_QUERY, None).await?;
+    let schema = Schema::from_introspection(&result)?;
+    let path = schema::save(&schema)?;
+
+    let root_fields = |name: Option<&str>| {
+        name.and_then(|n| schema.types.get(n))
+            .map(|t| t.fields.len())
+            .unwrap_or(0)
+    };
+    let summary = json!({
+        "path": path.display().to_string(),
+        "types": schema.types.len(),
+        "queries": root_fields(Some(&schema.query_type)),
+        "mutations": root_fields(schema.mutation_type.as_deref()),
+    });
+    if output.is_json() || output.has_template() {
+        return print_json_owned(summary, output);
+    }
+    println!(
+        "Cached schema: {} types, {} queries, {} mutations",
+        summary["types"], summary["queries"], summary["mutations"]
+    );
+    println!("  Path: {}", path.display());
+    Ok(())
+}
+
+fn show_schema(name: Option<&str>, output: &OutputOptions) -> Result<()> {
+    let schema = schema::load()?.ok_or_else(|| {
+        anyhow::anyhow!("No cached schema. Run `linear-cli api schema pull` first.")
+    })?;
+
+    let Some(name) = name else {
+        let summary = json!({
+            "pulled_at": schema.pulled_at,
+            "path": schema::path()?.display().to_string(),
+            "query_type": schema.query_type,
+            "mutation_type": schema.mutation_type,
+            "types": schema.types.len(),
+        });
+        if output.is_json() || output.has_template() {
+            return print_json_owned(summary, output);
+        }
+        println!("Schema pulled at {}", schema.pulled_at);
+        println!("  Types: {}", schema.types.len());
+        println!("  Path:  {}", summary["path"].as_str().unwrap_or(""));
+        return Ok(());
+    };
+
+    let (type_name, field_name) = match name.split_once('.') {
+        Some((t, f)) => (t, Some(f)),
+        None => (name, None),
+    };
+    let ty = schema
+        .types
+        .get(type_name)
+        .ok_or_else(|| CliError::not_found(format!("Type '{}' not found in schema", type_name)))?;
+
+    if let Some(field_name) = field_name {
+        let field = ty
+            .fields
+            .get(field_name)
+            .ok_or_else(|| CliError::not_found(format!("Field '{}' not found in schema", name)))?;
+        if output.is_json() || output.has_template() {
+            return print_json_owned(serde_json::to_value(field)?, output);
+        }
+        println!("{}: {}", name, field.ty);
+        for (arg, def) in &field.args {
+            println!("  {}: {}", arg, def.ty);
+        }
+        return Ok(());
+    }
+
+    if output.is_json() || output.has_template() {
+        return print_json_owned(serde_json::to_value(ty)?, output);
+    }
+    println!("{} ({})", type_name, ty.kind.to_lowercase());
+    for (field, def) in &ty.fields {
+        let args: Vec<String> = def
+            .args
+            .iter()
+            .map(|(arg, v)| format!("{}: {}", arg, v.ty))
+            .collect();
+        if args.is_empty() {
+            println!("  {}: {}", field, def.ty);
+        } else {
+            println!("  {}({}): {}", field, args.join(", "), def.ty);
+        }
+    }
+    for (field, def) in &ty.input_fields {
+        println!("  {}: {}", field, def.ty);
+    }
+    for value in &ty.enum_values {
+        println!("  {}", value);
+    }
+    for member in &ty.possible_types {
+        println!("  ... on {}", member);
+    }
+    Ok(())
+}
+
+fn print_budget(label: &str, remaining: Option<u64>, limit: Option<u64>, reset: Option<i64>) {
+    let (Some(remaining), Some(limit)) = (remaining, limit) else {
+        return;
+    };
+    let reset = reset
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .map(|at| {
+            let secs = (at - chrono::Utc::now()).num_seconds().max(0);
+            format!(
+                " (resets {} UTC, in {}m {}s)",
+                at.format("%H:%M:%S"),
+                secs / 60,
+                secs % 60
+            )
+        })
+        .unwrap_or_default();
+    println!("{}: {} / {} remaining{}", label, remaining, limit, reset);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,4 +656,45 @@ mod tests {
         let result = resolve_query_source(None);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_json_variables_merge_in_order() {
+        let op = OperationArgs {
+            variables_json: Some(r#"{"first": 5, "filter": {"title": {"eq": "a"}}}"#.to_string()),
+            json_variables: vec!["first:=10".to_string(), "ids:=[\"a\",\"b\"]".to_string()],
+            ..Default::default()
+        };
+        let map = json_variables(&op).unwrap();
+        assert_eq!(map["first"], json!(10));
+        assert_eq!(map["ids"], json!(["a", "b"]));
+        assert_eq!(map["filter"]["title"]["eq"], json!("a"));
+
+        let bad = OperationArgs {
+            json_variables: vec!["first=10".to_string()],
+            ..Default::default()
+        };
+        assert!(json_variables(&bad).is_err());
+        let not_object = OperationArgs {
+            variables_json: Some("[1, 2]".to_string()),
+            ..Default::default()
+        };
+        assert!(json_variables(&not_object).is_err());
+    }
+
+    #[test]
+    fn test_prepare_variables_merges_json_and_strings() {
+        let query = "query Q($first: Int, $name: String!) { viewer { id } }";
+        let mut json_vars = Map::new();
+        json_vars.insert("first".to_string(), json!(3));
+        json_vars.insert("name".to_string(), json!("json"));
+        let vars = prepare_variables(query, json_vars, &["name=flag".to_string()], true, &[])
+            .unwrap()
+            .unwrap();
+        assert_eq!(vars, json!({ "first": 3, "name": "flag" }));
+
+        let mut undeclared = Map::new();
+        undeclared.insert("nope".to_string(), json!(1));
+        let err = prepare_variables(query, undeclared, &[], true, &[]).unwrap_err();
+        assert!(err.to_string().contains("not declared"));
+    }
 }
@@ -4,7 +4,7 @@ use colored::Colorize;
 use serde_json::json;
 use tabled::{Table, Tabled};
 
-use crate::api::LinearClient;
+use crate::api::{resolve_issue_id, LinearClient};
 use crate::display_options;
 use crate::output::{
     ensure_non_empty, filter_values, print_json, print_json_owned, sort_values, OutputOptions,
@@ -113,22 +113,6 @@ pub async fn handle(cmd: AttachmentCommands, output: &OutputOptions) -> Result<(
     }
 }
 
-async fn resolve_issue_uuid(client: &LinearClient, issue: &str) -> Result<String> {
-    let query = r#"
-        query($id: String!) {
-            issue(id: $id) {
-                id
-            }
-        }
-    "#;
-
-    let result = client.query(query, Some(json!({ "id": issue }))).await?;
-    let id = result["data"]["issue"]["id"]
-        .as_str()
-        .ok_or_else(|| anyhow::anyhow!("Issue not found: {}", issue))?;
-    Ok(id.to_string())
-}
-
 async fn list_attachments(issue: &str, output: &OutputOptions) -> Result<()> {
     let client = LinearClient::new()?;
 
@@ -289,7 +273,7 @@ async fn create_attachment(
     output: &OutputOptions,
 ) -> Result<()> {
     let client = LinearClient::new()?;
-    let issue_id = resolve_issue_uuid(&client, issue).await?;
+    let issue_id = resolve_issue_id(&client, issue, &output.cache).await?;
 
     let mut input = json!({
         "issueId": issue_id,
@@ -428,7 +412,7 @@ async fn link_url(
     output: &OutputOptions,
 ) -> Result<()> {
     let client = LinearClient::new()?;
-    let issue_id = resolve_issue_uuid(&client, issue).await?;
+    let issue_id = resolve_issue_id(&client, issue, &output.cache).await?;
 
     let mut vars = json!({
         "issueId": issue_id,
@@ -210,6 +210,15 @@ async fn status(validate: bool, output: &OutputOptions) -> Result<()> {
         .map(|w| !w.api_key.is_empty())
         .unwrap_or(false);
 
+    // Run the credential helper so a broken one shows up here rather than mid-command
+    let api_key_command = profile
+        .as_ref()
+        .and_then(|p| config_data.workspaces.get(p))
+        .and_then(|w| w.api_key_command.clone());
+    let helper_result = api_key_command
+        .as_deref()
+        .map(|c| config::run_api_key_command(c).map_err(|e| e.to_string()));
+
     // Check keyring storage
     #[cfg(feature = "secure-storage")]
     let api_key_keyring_configured = profile
@@ -247,7 +256,10 @@ async fn status(validate: bool, output: &OutputOptions) -> Result<()> {
         .and_then(|p| config::get_oauth_config(p).ok())
         .flatten();
     let auth_type = if oauth_configured { "oauth" } else { "api_key" };
-    let configured = config_file_configured || keyring_configured || oauth_configured;
+    let configured = config_file_configured
+        || keyring_configured
+        || oauth_configured
+        || matches!(helper_result, Some(Ok(_)));
 
     let mut validated = None;
     if validate {
@@ -271,8 +283,12 @@ async fn status(validate: bool, output: &OutputOptions) -> Result<()> {
                 validated = Some(false);
             }
         } else {
-            // Validate API key using the priority: env > keyring > config
-            let key = env_key.clone().or_else(|| {
+            // Validate API key using the priority: env > command > keyring > config
+            let helper_key = helper_result
+                .as_ref()
+                .and_then(|r| r.as_ref().ok())
+                .cloned();
+            let key = env_key.clone().or(helper_key).or_else(|| {
                 #[cfg(feature = "secure-storage")]
                 if let Some(ref p) = profile {
                     if let Ok(Some(k)) = crate::keyring::get_key(p) {
@@ -305,6 +321,13 @@ async fn status(validate: bool, output: &OutputOptions) -> Result<()> {
                 "oauth_expires_at": oauth_metadata.as_ref().and_then(|o| o.expires_at),
                 "env_api_key": env_key.is_some(),
                 "env_profile": env_profile,
+                "api_key_command": api_key_command,
+                "api_key_command_ok": helper_result.as_ref().map(|r| r.is_ok()),
+                "api_key_command_key": helper_result
+                    .as_ref()
+                    .and_then(|r| r.as_ref().ok())
+                    .map(|k| config::mask_api_key_for_display(k)),
+                "api_key_command_error": helper_result.as_ref().and_then(|r| r.as_ref().err()),
                 "validated": validated,
             }),
             output,
@@ -330,6 +353,16 @@ async fn status(validate: bool, output: &OutputOptions) -> Result<()> {
             "no"
         }
     );
+    if let (Some(command), Some(result)) = (&api_key_command, &helper_result) {
+        match result {
+            Ok(key) => println!(
+                "API key command: {} ({})",
+                command,
+                config::mask_api_key_for_display(key)
+            ),
+            Err(err) => println!("API key command: {} (failed: {})", command, err),
+        }
+    }
     println!(
         "Env API key override: {}",
         if env_key.is_some() { "yes" } else { "no" }
@@ -8,8 +8,13 @@ use futures::stream::{self, StreamExt};
 use serde_json::json;
 use tokio::sync::Mutex;
 
-use crate::api::{resolve_label_id, resolve_state_id, resolve_user_id, LinearClient};
+use crate::api::{
+    remember_issue_id, resolve_issue_id, resolve_label_id, resolve_state_id, resolve_user_id,
+    Idempotency, LinearClient,
+};
+use crate::cache::CacheOptions;
 use crate::display_options;
+use crate::outbox::{is_network_error, IssueChange, Outbox, OutboxAction};
 use crate::output::{print_json_owned, OutputOptions};
 use crate::text::truncate;
 
@@ -60,18 +65,24 @@ pub enum BulkCommands {
 }
 
 /// Result of a single bulk operation
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct BulkResult {
     issue_id: String,
     success: bool,
     identifier: Option<String>,
     error: Option<String>,
+    /// The request never reached the server
+    unreachable: bool,
+    /// The update was journaled to the outbox instead
+    queued: bool,
 }
 
-/// Get issue details including UUID and team ID from identifier (e.g., "LIN-123")
+/// Get issue details including UUID and team ID from identifier (e.g., "LIN-123").
+/// The UUID is remembered for `resolve_issue_id` like any other lookup.
 async fn get_issue_info(
     client: &LinearClient,
     issue_id: &str,
+    cache_opts: &CacheOptions,
 ) -> Result<(String, String, Option<String>)> {
     let query = r#"
         query($id: String!) {
@@ -103,6 +114,7 @@ async fn get_issue_info(
         .to_string();
 
     let identifier = issue["identifier"].as_str().map(|s| s.to_string());
+    remember_issue_id(issue_id, &uuid, cache_opts);
 
     Ok((uuid, team_id, identifier))
 }
@@ -140,6 +152,19 @@ async fn bulk_update_state(state: &str, issues: Vec<String>, output: &OutputOpti
         );
     }
 
+    let change = IssueChange {
+        state: Some(state.to_string()),
+        ..Default::default()
+    };
+    if crate::mirror::is_offline() {
+        return finish(
+            unreachable_results(&issues),
+            &change,
+            "state updated",
+            output,
+        );
+    }
+
     let client = LinearClient::new()?;
     let state_owned = state.to_string();
     let state_cache: Arc<Mutex<HashMap<String, String>>> = Arc::new(Mutex::new(HashMap::new()));
@@ -150,14 +175,12 @@ async fn bulk_update_state(state: &str, issues: Vec<String>, output: &OutputOpti
             let state = &state_owned;
             let cache = Arc::clone(&state_cache);
             let id = issue_id.clone();
-            async move { update_issue_state(client, &id, state, &cache).await }
+            async move { update_issue_state(client, &id, state, &cache, &output.cache).await }
         })
-        .buffer_unordered(10)
+        .buffer_unordered(client.fanout_limit(10))
         .collect()
         .await;
-    print_summary(&results, "state updated", output);
-
-    Ok(())
+    finish(results, &change, "state updated", output)
 }
 
 async fn bulk_assign(user: &str, issues: Vec<String>, output: &OutputOptions) -> Result<()> {
@@ -182,11 +205,22 @@ async fn bulk_assign(user: &str, issues: Vec<String>, output: &OutputOptions) ->
         );
     }
 
+    let change = IssueChange {
+        assignee: Some(user.to_string()),
+        ..Default::default()
+    };
+    if crate::mirror::is_offline() {
+        return finish(unreachable_results(&issues), &change, "assigned", output);
+    }
+
     let client = LinearClient::new()?;
 
     // Resolve the user ID once upfront
     let user_id = match resolve_user_id(&client, user, &output.cache).await {
         Ok(id) => id,
+        Err(e) if is_network_error(&e) => {
+            return finish(unreachable_results(&issues), &change, "assigned", output);
+        }
         Err(e) => {
             if output.is_json() || output.has_template() {
                 print_json_owned(
@@ -205,14 +239,12 @@ async fn bulk_assign(user: &str, issues: Vec<String>, output: &OutputOptions) ->
             let client = &client;
             let user_id = &user_id;
             let id = issue_id.clone();
-            async move { update_issue_assignee(client, &id, Some(user_id)).await }
+            async move { update_issue_assignee(client, &id, Some(user_id), &output.cache).await }
         })
-        .buffer_unordered(10)
+        .buffer_unordered(client.fanout_limit(10))
         .collect()
         .await;
-    print_summary(&results, "assigned", output);
-
-    Ok(())
+    finish(results, &change, "assigned", output)
 }
 
 async fn bulk_label(label: &str, issues: Vec<String>, output: &OutputOptions) -> Result<()> {
@@ -237,11 +269,22 @@ async fn bulk_label(label: &str, issues: Vec<String>, output: &OutputOptions) ->
         );
     }
 
+    let change = IssueChange {
+        add_labels: vec![label.to_string()],
+        ..Default::default()
+    };
+    if crate::mirror::is_offline() {
+        return finish(unreachable_results(&issues), &change, "labeled", output);
+    }
+
     let client = LinearClient::new()?;
 
     // Resolve the label ID once upfront
     let label_id = match resolve_label_id(&client, label, &output.cache).await {
         Ok(id) => id,
+        Err(e) if is_network_error(&e) => {
+            return finish(unreachable_results(&issues), &change, "labeled", output);
+        }
         Err(e) => {
             if output.is_json() || output.has_template() {
                 print_json_owned(
@@ -262,12 +305,10 @@ async fn bulk_label(label: &str, issues: Vec<String>, output: &OutputOptions) ->
             let id = issue_id.clone();
             async move { add_label_to_issue(client, &id, label_id).await }
         })
-        .buffer_unordered(10)
+        .buffer_unordered(client.fanout_limit(10))
         .collect()
         .await;
-    print_summary(&results, "labeled", output);
-
-    Ok(())
+    finish(results, &change, "labeled", output)
 }
 
 async fn bulk_unassign(issues: Vec<String>, output: &OutputOptions) -> Result<()> {
@@ -287,20 +328,24 @@ async fn bulk_unassign(issues: Vec<String>, output: &OutputOptions) -> Result<()
         println!("{} Unassigning {} issues...", ">>".cyan(), issues.len());
     }
 
+    let mut change = IssueChange::default();
+    change.input.insert("assigneeId".to_string(), json!(null));
+    if crate::mirror::is_offline() {
+        return finish(unreachable_results(&issues), &change, "unassigned", output);
+    }
+
     let client = LinearClient::new()?;
 
     let results: Vec<_> = stream::iter(issues.iter())
         .map(|issue_id| {
             let client = &client;
             let id = issue_id.clone();
-            async move { update_issue_assignee(client, &id, None).await }
+            async move { update_issue_assignee(client, &id, None, &output.cache).await }
         })
-        .buffer_unordered(10)
+        .buffer_unordered(client.fanout_limit(10))
         .collect()
         .await;
-    print_summary(&results, "unassigned", output);
-
-    Ok(())
+    finish(results, &change, "unassigned", output)
 }
 
 async fn update_issue_state(
@@ -308,9 +353,10 @@ async fn update_issue_state(
     issue_id: &str,
     state: &str,
     state_cache: &Arc<Mutex<HashMap<String, String>>>,
+    cache_opts: &CacheOptions,
 ) -> BulkResult {
     // First, get issue UUID and team ID
-    let (uuid, team_id, identifier) = match get_issue_info(client, issue_id).await {
+    let (uuid, team_id, identifier) = match get_issue_info(client, issue_id, cache_opts).await {
         Ok(info) => info,
         Err(e) => {
             return BulkResult {
@@ -318,6 +364,8 @@ async fn update_issue_state(
                 success: false,
                 identifier: None,
                 error: Some(e.to_string()),
+                unreachable: is_network_error(&e),
+                ..Default::default()
             };
         }
     };
@@ -345,6 +393,8 @@ async fn update_issue_state(
                         success: false,
                         identifier,
                         error: Some(e.to_string()),
+                        unreachable: is_network_error(&e),
+                        ..Default::default()
                     };
                 }
             }
@@ -366,7 +416,11 @@ async fn update_issue_state(
     let input = json!({ "stateId": state_id });
 
     match client
-        .mutate(mutation, Some(json!({ "id": uuid, "input": input })))
+        .mutate_with(
+            mutation,
+            Some(json!({ "id": uuid, "input": input })),
+            Idempotency::Repeatable,
+        )
         .await
     {
         Ok(result) => {
@@ -379,6 +433,7 @@ async fn update_issue_state(
                     success: true,
                     identifier,
                     error: None,
+                    ..Default::default()
                 }
             } else {
                 BulkResult {
@@ -386,6 +441,7 @@ async fn update_issue_state(
                     success: false,
                     identifier: None,
                     error: Some("Update failed".to_string()),
+                    ..Default::default()
                 }
             }
         }
@@ -394,6 +450,8 @@ async fn update_issue_state(
             success: false,
             identifier: None,
             error: Some(e.to_string()),
+            unreachable: is_network_error(&e),
+            ..Default::default()
         },
     }
 }
@@ -402,16 +460,19 @@ async fn update_issue_assignee(
     client: &LinearClient,
     issue_id: &str,
     assignee_id: Option<&str>,
+    cache_opts: &CacheOptions,
 ) -> BulkResult {
     // First, get issue UUID
-    let (uuid, _team_id, identifier) = match get_issue_info(client, issue_id).await {
-        Ok(info) => info,
+    let uuid = match resolve_issue_id(client, issue_id, cache_opts).await {
+        Ok(uuid) => uuid,
         Err(e) => {
             return BulkResult {
                 issue_id: issue_id.to_string(),
                 success: false,
                 identifier: None,
                 error: Some(e.to_string()),
+                unreachable: is_network_error(&e),
+                ..Default::default()
             };
         }
     };
@@ -434,35 +495,42 @@ async fn update_issue_assignee(
     };
 
     match client
-        .mutate(mutation, Some(json!({ "id": uuid, "input": input })))
+        .mutate_with(
+            mutation,
+            Some(json!({ "id": uuid, "input": input })),
+            Idempotency::Repeatable,
+        )
         .await
     {
         Ok(result) => {
             if result["data"]["issueUpdate"]["success"].as_bool() == Some(true) {
                 let identifier = result["data"]["issueUpdate"]["issue"]["identifier"]
                     .as_str()
-                    .map(|s| s.to_string())
-                    .or(identifier);
+                    .map(|s| s.to_string());
                 BulkResult {
                     issue_id: issue_id.to_string(),
                     success: true,
                     identifier,
                     error: None,
+                    ..Default::default()
                 }
             } else {
                 BulkResult {
                     issue_id: issue_id.to_string(),
                     success: false,
-                    identifier,
+                    identifier: None,
                     error: Some("Update failed".to_string()),
+                    ..Default::default()
                 }
             }
         }
         Err(e) => BulkResult {
             issue_id: issue_id.to_string(),
             success: false,
-            identifier,
+            identifier: None,
             error: Some(e.to_string()),
+            unreachable: is_network_error(&e),
+            ..Default::default()
         },
     }
 }
@@ -492,6 +560,7 @@ async fn add_label_to_issue(client: &LinearClient, issue_id: &str, label_id: &st
                         success: false,
                         identifier: None,
                         error: Some("Issue not found".to_string()),
+                        ..Default::default()
                     };
                 }
 
@@ -519,6 +588,8 @@ async fn add_label_to_issue(client: &LinearClient, issue_id: &str, label_id: &st
                     success: false,
                     identifier: None,
                     error: Some(e.to_string()),
+                    unreachable: is_network_error(&e),
+                    ..Default::default()
                 };
             }
         };
@@ -545,7 +616,11 @@ async fn add_label_to_issue(client: &LinearClient, issue_id: &str, label_id: &st
     let input = json!({ "labelIds": label_ids });
 
     match client
-        .mutate(mutation, Some(json!({ "id": uuid, "input": input })))
+        .mutate_with(
+            mutation,
+            Some(json!({ "id": uuid, "input": input })),
+            Idempotency::Repeatable,
+        )
         .await
     {
         Ok(result) => {
@@ -559,6 +634,7 @@ async fn add_label_to_issue(client: &LinearClient, issue_id: &str, label_id: &st
                     success: true,
                     identifier,
                     error: None,
+                    ..Default::default()
                 }
             } else {
                 BulkResult {
@@ -566,6 +642,7 @@ async fn add_label_to_issue(client: &LinearClient, issue_id: &str, label_id: &st
                     success: false,
                     identifier,
                     error: Some("Update failed".to_string()),
+                    ..Default::default()
                 }
             }
         }
@@ -574,13 +651,50 @@ async fn add_label_to_issue(client: &LinearClient, issue_id: &str, label_id: &st
             success: false,
             identifier,
             error: Some(e.to_string()),
+            unreachable: is_network_error(&e),
+            ..Default::default()
         },
     }
 }
 
+/// Results for issues that could not be sent because the API is unreachable
+fn unreachable_results(issues: &[String]) -> Vec<BulkResult> {
+    issues
+        .iter()
+        .map(|id| BulkResult {
+            issue_id: id.clone(),
+            error: Some("network unavailable".to_string()),
+            unreachable: true,
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Queue the change for every unreachable issue in the outbox, then print the summary
+fn finish(
+    mut results: Vec<BulkResult>,
+    change: &IssueChange,
+    action: &str,
+    output: &OutputOptions,
+) -> Result<()> {
+    if results.iter().any(|r| r.unreachable) {
+        let outbox = Outbox::open()?;
+        for result in results.iter_mut().filter(|r| r.unreachable) {
+            let action = OutboxAction::IssueUpdate {
+                change: change.clone(),
+            };
+            outbox.enqueue(&result.issue_id, None, action)?;
+            result.queued = true;
+        }
+    }
+    print_summary(&results, action, output);
+    Ok(())
+}
+
 fn print_summary(results: &[BulkResult], action: &str, output: &OutputOptions) {
     let success_count = results.iter().filter(|r| r.success).count();
-    let failure_count = results.len() - success_count;
+    let queued_count = results.iter().filter(|r| r.queued).count();
+    let failure_count = results.len() - success_count - queued_count;
     let id_width = display_options().max_width(30);
     let err_width = display_options().max_width(60);
 
@@ -592,6 +706,7 @@ fn print_summary(results: &[BulkResult], action: &str, output: &OutputOptions) {
                     "issue_id": r.issue_id,
                     "identifier": r.identifier,
                     "success": r.success,
+                    "queued": r.queued,
                     "error": r.error,
                 })
             })
@@ -603,6 +718,7 @@ fn print_summary(results: &[BulkResult], action: &str, output: &OutputOptions) {
             "summary": {
                 "total": results.len(),
                 "succeeded": success_count,
+                "queued": queued_count,
                 "failed": failure_count,
             }
         });
@@ -620,6 +736,12 @@ fn print_summary(results: &[BulkResult], action: &str, output: &OutputOptions) {
             let display_id = result.identifier.as_deref().unwrap_or(&result.issue_id);
             let display_id = truncate(display_id, id_width);
             println!("  {} {} {}", "+".green(), display_id.cyan(), action);
+        } else if result.queued {
+            println!(
+                "  {} {} queued in outbox",
+                "~".yellow(),
+                result.issue_id.cyan()
+            );
         } else {
             let error_msg = result.error.as_deref().unwrap_or("Unknown error");
             let error_msg = truncate(error_msg, err_width);
@@ -634,6 +756,17 @@ fn print_summary(results: &[BulkResult], action: &str, output: &OutputOptions) {
 
     // Print summary
     println!();
+    if queued_count > 0 {
+        println!(
+            "{} Summary: {} succeeded, {} queued, {} failed",
+            ">>".cyan(),
+            success_count.to_string().green(),
+            queued_count.to_string().yellow(),
+            failure_count
+        );
+        println!("   Run `linear-cli outbox push` once you are back online.");
+        return;
+    }
     println!(
         "{} Summary: {} succeeded, {} failed",
         ">>".cyan(),
@@ -1,20 +1,37 @@
 use anyhow::Result;
 use clap::Subcommand;
 use colored::Colorize;
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
 use tabled::{Table, Tabled};
 
-use crate::cache::{Cache, CacheType};
+use crate::api::{refresh_lookup, refresh_statuses, resolve_team_id, LinearClient};
+use crate::cache::{Cache, CacheOptions, CacheType};
+use crate::output::{print_json_owned, OutputOptions};
 
 #[derive(Subcommand)]
 pub enum CacheCommands {
     /// Clear all cached data
     Clear {
-        /// Only clear a specific cache type (teams, users, statuses, labels, projects)
+        /// Only clear a specific cache type (teams, users, statuses, labels, projects, views, issues)
         #[arg(short, long)]
         r#type: Option<String>,
     },
     /// Show cache status and sizes
     Status,
+    /// Pre-populate the lookup caches used for name resolution and completions
+    #[command(after_help = r#"EXAMPLES:
+    linear cache warm                       # Teams, users, labels, projects, views, all teams' statuses
+    linear cache warm --team ENG            # Statuses for ENG only
+    linear cache warm --output json
+
+Run it from a shell startup file or a cron job, together with
+--stale-while-revalidate, so completions and prompts never wait on the API."#)]
+    Warm {
+        /// Only warm workflow states for these teams (key, name or ID; default: every team)
+        #[arg(short, long)]
+        team: Vec<String>,
+    },
 }
 
 #[derive(Tabled)]
@@ -31,10 +48,21 @@ struct CacheStatusRow {
     items: String,
 }
 
-pub async fn handle(cmd: CacheCommands) -> Result<()> {
+#[derive(Tabled)]
+struct WarmRow {
+    #[tabled(rename = "Cache")]
+    cache: String,
+    #[tabled(rename = "Items")]
+    items: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+pub async fn handle(cmd: CacheCommands, output: &OutputOptions) -> Result<()> {
     match cmd {
         CacheCommands::Clear { r#type } => clear_cache(r#type).await,
         CacheCommands::Status => show_status().await,
+        CacheCommands::Warm { team } => warm_cache(&team, output).await,
     }
 }
 
@@ -48,9 +76,11 @@ async fn clear_cache(cache_type: Option<String>) -> Result<()> {
             "statuses" | "states" => CacheType::Statuses,
             "labels" => CacheType::Labels,
             "projects" => CacheType::Projects,
+            "views" => CacheType::Views,
+            "issues" => CacheType::Issues,
             _ => {
                 anyhow::bail!(
-                    "Unknown cache type: '{}'. Valid types: teams, users, statuses, labels, projects",
+                    "Unknown cache type: '{}'. Valid types: teams, users, statuses, labels, projects, views, issues",
                     type_str
                 );
             }
@@ -116,3 +146,121 @@ async fn show_status() -> Result<()> {
 
     Ok(())
 }
+
+/// Lookup caches that hold one flat list each
+const WARM_LISTS: &[CacheType] = &[
+    CacheType::Teams,
+    CacheType::Users,
+    CacheType::Labels,
+    CacheType::Projects,
+    CacheType::Views,
+];
+
+async fn warm_cache(teams: &[String], output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    // Warming always writes, even under --no-cache
+    let opts = CacheOptions {
+        no_cache: false,
+        ..output.cache
+    };
+
+    let lists = futures::future::join_all(WARM_LISTS.iter().map(|cache_type| {
+        let client = &client;
+        async move {
+            let result = refresh_lookup(client, *cache_type, &opts).await;
+            (cache_type.display_name().to_string(), result)
+        }
+    }))
+    .await;
+
+    let team_ids: Vec<Result<(String, String)>> = if teams.is_empty() {
+        lists
+            .iter()
+            .find(|(name, _)| name == CacheType::Teams.display_name())
+            .and_then(|(_, result)| result.as_ref().ok())
+            .map(|all| {
+                all.iter()
+                    .filter_map(|t| {
+                        Some((
+                            t["key"].as_str()?.to_string(),
+                            t["id"].as_str()?.to_string(),
+                        ))
+                    })
+                    .map(Ok)
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        let mut ids = Vec::new();
+        for team in teams {
+            ids.push(
+                resolve_team_id(&client, team, &opts)
+                    .await
+                    .map(|id| (team.clone(), id))
+                    .map_err(|e| anyhow::anyhow!("{}: {}", team, e)),
+            );
+        }
+        ids
+    };
+
+    let limit = client.fanout_limit(5);
+    let statuses: Vec<(String, Result<usize>)> = stream::iter(team_ids)
+        .map(|team| {
+            let client = &client;
+            async move {
+                match team {
+                    Ok((key, id)) => {
+                        let result = refresh_statuses(client, &id, &opts)
+                            .await
+                            .map(|data| data["states"].as_array().map_or(0, Vec::len));
+                        (format!("Statuses ({})", key), result)
+                    }
+                    Err(e) => ("Statuses".to_string(), Err(e)),
+                }
+            }
+        })
+        .buffer_unordered(limit)
+        .collect()
+        .await;
+
+    let mut results: Vec<(String, Result<usize>)> = lists
+        .into_iter()
+        .map(|(name, result)| (name, result.map(|items| items.len())))
+        .collect();
+    let mut statuses = statuses;
+    statuses.sort_by(|a, b| a.0.cmp(&b.0));
+    results.extend(statuses);
+    let failed = results.iter().filter(|(_, r)| r.is_err()).count();
+
+    if output.is_json() || output.has_template() {
+        let entries: Vec<Value> = results
+            .iter()
+            .map(|(cache, result)| match result {
+                Ok(items) => json!({ "cache": cache, "items": items, "error": null }),
+                Err(e) => json!({ "cache": cache, "items": null, "error": e.to_string() }),
+            })
+            .collect();
+        print_json_owned(json!(entries), output)?;
+    } else {
+        let rows: Vec<WarmRow> = results
+            .iter()
+            .map(|(cache, result)| WarmRow {
+                cache: cache.clone(),
+                items: result
+                    .as_ref()
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|_| "-".to_string()),
+                result: match result {
+                    Ok(_) => "ok".green().to_string(),
+                    Err(e) => e.to_string().red().to_string(),
+                },
+            })
+            .collect();
+        println!("{}", Table::new(rows));
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} caches failed to warm", failed, results.len());
+    }
+    Ok(())
+}
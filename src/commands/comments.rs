@@ -5,7 +5,8 @@ use futures::stream::{self, StreamExt};
 use serde_json::json;
 use tabled::{Table, Tabled};
 
-use crate::api::LinearClient;
+use crate::api::{remember_issue_id, resolve_issue_id, Idempotency, LinearClient};
+use crate::cache::CacheOptions;
 use crate::display_options;
 use crate::input::read_ids_from_stdin;
 use crate::output::{
@@ -75,7 +76,7 @@ pub async fn handle(cmd: CommentCommands, output: &OutputOptions) -> Result<()>
             issue_id,
             body,
             parent_id,
-        } => create_comment(&issue_id, &body, parent_id).await,
+        } => create_comment(&issue_id, &body, parent_id, &output.cache).await,
         CommentCommands::Update { id, body } => update_comment(&id, &body, output).await,
         CommentCommands::Delete { id, force } => delete_comment(&id, force).await,
     }
@@ -96,7 +97,7 @@ async fn list_comments(issue_ids: &[String], output: &OutputOptions) -> Result<(
             let pagination = &pagination;
             let id = id.clone();
             async move {
-                let issue = fetch_issue_meta(client, &id).await;
+                let issue = fetch_issue_meta(client, &id, &output.cache).await;
                 match issue {
                     Ok(mut issue_val) if !issue_val.is_null() => {
                         match fetch_issue_comments(client, &id, pagination).await {
@@ -203,7 +204,11 @@ async fn list_comments(issue_ids: &[String], output: &OutputOptions) -> Result<(
     Ok(())
 }
 
-async fn fetch_issue_meta(client: &LinearClient, issue_id: &str) -> Result<serde_json::Value> {
+async fn fetch_issue_meta(
+    client: &LinearClient,
+    issue_id: &str,
+    cache_opts: &CacheOptions,
+) -> Result<serde_json::Value> {
     let query = r#"
         query($issueId: String!) {
             issue(id: $issueId) {
@@ -217,6 +222,9 @@ async fn fetch_issue_meta(client: &LinearClient, issue_id: &str) -> Result<serde
     let result = client
         .query(query, Some(json!({ "issueId": issue_id })))
         .await?;
+    if let Some(id) = result["data"]["issue"]["id"].as_str() {
+        remember_issue_id(issue_id, id, cache_opts);
+    }
     Ok(result["data"]["issue"].clone())
 }
 
@@ -262,8 +270,14 @@ async fn fetch_issue_comments(
     .await
 }
 
-async fn create_comment(issue_id: &str, body: &str, parent_id: Option<String>) -> Result<()> {
+async fn create_comment(
+    issue_id: &str,
+    body: &str,
+    parent_id: Option<String>,
+    cache_opts: &CacheOptions,
+) -> Result<()> {
     let client = LinearClient::new()?;
+    let issue_id = resolve_issue_id(&client, issue_id, cache_opts).await?;
 
     let mut input = json!({
         "issueId": issue_id,
@@ -289,8 +303,15 @@ async fn create_comment(issue_id: &str, body: &str, parent_id: Option<String>) -
         }
     "#;
 
+    let recover = r#"
+        query($id: String!) {
+            comment(id: $id) { id body createdAt user { name } issue { identifier title } }
+        }
+    "#;
+
+    let idempotency = Idempotency::client_id(&mut input, recover);
     let result = client
-        .mutate(mutation, Some(json!({ "input": input })))
+        .mutate_with(mutation, Some(json!({ "input": input })), idempotency)
         .await?;
 
     if result["data"]["commentCreate"]["success"].as_bool() == Some(true) {
@@ -323,6 +344,7 @@ async fn create_comment(issue_id: &str, body: &str, parent_id: Option<String>) -
 }
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod tests {
     use super::*;
 
@@ -20,7 +20,7 @@ pub enum CycleCommands {
     #[command(alias = "ls")]
     List {
         /// Team ID or name
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Include completed cycles
         #[arg(short, long)]
@@ -34,13 +34,13 @@ pub enum CycleCommands {
     /// Show the current active cycle
     Current {
         /// Team ID or name
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
     },
     /// Create a new cycle
     Create {
         /// Team key, name, or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Cycle name
         #[arg(short, long)]
@@ -0,0 +1,407 @@
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use colored::Colorize;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use crate::graphql;
+
+const MAX_MOCK_BODY_BYTES: usize = 4 * 1024 * 1024;
+
+#[derive(Subcommand)]
+pub enum DevCommands {
+    /// Serve recorded GraphQL fixtures on a local endpoint
+    #[command(after_help = r#"EXAMPLES:
+    linear dev mock-server                           # Serve tests/fixtures on :4000
+    linear dev mock-server -f ./fixtures -p 0        # Pick a free port
+
+FIXTURES:
+    Each *.json file holds one fixture object or an array of them:
+    {
+      "operation": "issue",               # operation name or root field
+      "variables": { "id": "ENG-1" },     # optional, subset match
+      "status": 200,                      # optional HTTP status
+      "headers": { "retry-after": "1" },  # optional response headers
+      "disconnect": false,                # optional, close without responding
+      "response": { "data": { "issue": { ... } } }
+    }
+    The fixture with the most matching variables wins.
+
+Point the CLI at the server with LINEAR_API_URL or `config set api-url`."#)]
+    MockServer {
+        /// Directory containing JSON fixture files
+        #[arg(short, long, default_value = "tests/fixtures")]
+        fixtures: PathBuf,
+        /// Port to listen on (0 picks a free port)
+        #[arg(short, long, default_value = "4000")]
+        port: u16,
+        /// Address to bind to
+        #[arg(long, default_value = "127.0.0.1")]
+        bind: String,
+    },
+}
+
+pub async fn handle(cmd: DevCommands) -> Result<()> {
+    match cmd {
+        DevCommands::MockServer {
+            fixtures,
+            port,
+            bind,
+        } => mock_server(&fixtures, port, &bind).await,
+    }
+}
+
+/// A recorded GraphQL response served by the mock server
+#[derive(Debug, Clone, Deserialize)]
+pub struct Fixture {
+    pub operation: String,
+    #[serde(default)]
+    pub variables: Option<Map<String, Value>>,
+    #[serde(default = "default_status")]
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    pub response: Value,
+    /// Serve this fixture only for the first N matching requests (for retry tests)
+    #[serde(default)]
+    pub times: Option<u32>,
+    /// Close the connection without responding (a lost response)
+    #[serde(default)]
+    pub disconnect: bool,
+    #[serde(skip)]
+    served: Arc<AtomicU32>,
+}
+
+impl Fixture {
+    fn is_exhausted(&self) -> bool {
+        self.times
+            .is_some_and(|times| self.served.load(Ordering::SeqCst) >= times)
+    }
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+/// Load every *.json fixture file in a directory (sorted by file name)
+pub fn load_fixtures(dir: &Path) -> Result<Vec<Fixture>> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read fixtures directory {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("json"))
+        .collect();
+    paths.sort();
+
+    let mut fixtures = Vec::new();
+    for path in paths {
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let value: Value = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+        let items = match value {
+            Value::Array(items) => items,
+            other => vec![other],
+        };
+        for item in items {
+            let fixture: Fixture = serde_json::from_value(item)
+                .with_context(|| format!("Invalid fixture in {}", path.display()))?;
+            fixtures.push(fixture);
+        }
+    }
+    Ok(fixtures)
+}
+
+/// True when every key in `expected` is present in `actual` with a matching value.
+/// Objects are compared recursively so fixtures can pin only part of an input.
+fn json_subset(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::Object(exp), Value::Object(act)) => exp
+            .iter()
+            .all(|(k, v)| act.get(k).is_some_and(|a| json_subset(v, a))),
+        _ => expected == actual,
+    }
+}
+
+/// Pick the most specific fixture for a GraphQL request
+pub fn find_fixture<'a>(
+    fixtures: &'a [Fixture],
+    query: &str,
+    operation_name: Option<&str>,
+    variables: &Value,
+) -> Option<&'a Fixture> {
+    let name = operation_name
+        .map(|s| s.to_string())
+        .or_else(|| graphql::operation_name(query));
+    let fields = graphql::root_fields(query);
+
+    let mut best: Option<(&Fixture, usize)> = None;
+    for fixture in fixtures {
+        let op_matches = name.as_deref() == Some(fixture.operation.as_str())
+            || fields.iter().any(|f| f == &fixture.operation);
+        if !op_matches || fixture.is_exhausted() {
+            continue;
+        }
+        let specificity = match &fixture.variables {
+            Some(vars) => {
+                if !json_subset(&Value::Object(vars.clone()), variables) {
+                    continue;
+                }
+                vars.len()
+            }
+            None => 0,
+        };
+        if best.is_none_or(|(_, s)| specificity > s) {
+            best = Some((fixture, specificity));
+        }
+    }
+    best.map(|(f, _)| f)
+}
+
+async fn mock_server(fixtures_dir: &Path, port: u16, bind: &str) -> Result<()> {
+    let fixtures = Arc::new(load_fixtures(fixtures_dir)?);
+
+    let listener_addr = format!("{}:{}", bind, port);
+    let listener = tokio::net::TcpListener::bind(&listener_addr)
+        .await
+        .with_context(|| format!("Failed to bind to {}", listener_addr))?;
+    let local_addr = listener.local_addr()?;
+
+    println!(
+        "{} Mock GraphQL server listening on http://{}/graphql",
+        "+".green(),
+        local_addr
+    );
+    println!(
+        "  Serving {} fixture(s) from {}",
+        fixtures.len(),
+        fixtures_dir.display()
+    );
+    println!("  Press Ctrl+C to stop.\n");
+
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            accept = listener.accept() => {
+                match accept {
+                    Ok((stream, addr)) => {
+                        let fixtures = fixtures.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = handle_connection(stream, &fixtures).await {
+                                eprintln!("Error handling connection from {}: {}", addr, e);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        eprintln!("Accept error: {}", e);
+                    }
+                }
+            }
+            _ = &mut shutdown => {
+                println!("\n{} Shutting down...", "!".yellow());
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_connection(mut stream: tokio::net::TcpStream, fixtures: &[Fixture]) -> Result<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut buf = Vec::with_capacity(8192);
+    let mut chunk = [0u8; 8192];
+    let header_end = loop {
+        let n = tokio::time::timeout(std::time::Duration::from_secs(10), stream.read(&mut chunk))
+            .await
+            .context("Read timeout")?
+            .context("Read error")?;
+        if n == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+        if buf.len() > 64 * 1024 {
+            return write_response(&mut stream, 431, &BTreeMap::new(), "").await;
+        }
+    };
+
+    let headers_str = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let content_length: usize = headers_str
+        .lines()
+        .find(|l| l.to_lowercase().starts_with("content-length:"))
+        .and_then(|l| l.split_once(':'))
+        .and_then(|(_, v)| v.trim().parse().ok())
+        .unwrap_or(0);
+    if content_length > MAX_MOCK_BODY_BYTES {
+        return write_response(&mut stream, 413, &BTreeMap::new(), "").await;
+    }
+
+    let mut body = buf[header_end + 4..].to_vec();
+    while body.len() < content_length {
+        let n = tokio::time::timeout(std::time::Duration::from_secs(10), stream.read(&mut chunk))
+            .await
+            .context("Body read timeout")?
+            .context("Body read error")?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+
+    let method = headers_str.split_whitespace().next().unwrap_or("");
+    if method != "POST" {
+        return write_response(&mut stream, 405, &BTreeMap::new(), "").await;
+    }
+
+    let request: Value = match serde_json::from_slice(&body) {
+        Ok(v) => v,
+        Err(_) => return write_response(&mut stream, 400, &BTreeMap::new(), "").await,
+    };
+    let query = request["query"].as_str().unwrap_or("");
+    let variables = request.get("variables").cloned().unwrap_or(json!({}));
+    let operation_name = request["operationName"].as_str();
+    let label = operation_name
+        .map(|s| s.to_string())
+        .or_else(|| graphql::root_fields(query).into_iter().next())
+        .unwrap_or_else(|| "?".to_string());
+    let kind = graphql::operation_type(query).unwrap_or_else(|| "query".to_string());
+
+    match find_fixture(fixtures, query, operation_name, &variables) {
+        Some(fixture) => {
+            fixture.served.fetch_add(1, Ordering::SeqCst);
+            if fixture.disconnect {
+                eprintln!("{} {} {} -> disconnect", "<".green(), kind, label);
+                return Ok(());
+            }
+            eprintln!("{} {} {} -> {}", "<".green(), kind, label, fixture.status);
+            let body = serde_json::to_string(&fixture.response)?;
+            write_response(&mut stream, fixture.status, &fixture.headers, &body).await
+        }
+        None => {
+            eprintln!("{} {} {} -> no fixture", "!".yellow(), kind, label);
+            let body = json!({
+                "errors": [{
+                    "message": format!("No mock fixture matches operation '{}'", label),
+                    "extensions": { "variables": variables },
+                }]
+            });
+            write_response(&mut stream, 200, &BTreeMap::new(), &body.to_string()).await
+        }
+    }?;
+
+    stream.shutdown().await.ok();
+    Ok(())
+}
+
+async fn write_response(
+    stream: &mut tokio::net::TcpStream,
+    status: u16,
+    headers: &BTreeMap<String, String>,
+    body: &str,
+) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let reason = reqwest::StatusCode::from_u16(status)
+        .ok()
+        .and_then(|s| s.canonical_reason())
+        .unwrap_or("Unknown");
+    let mut response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        status,
+        reason,
+        body.len()
+    );
+    for (name, value) in headers {
+        response.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    response.push_str("\r\n");
+    response.push_str(body);
+    stream.write_all(response.as_bytes()).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(operation: &str, variables: Option<Value>, marker: &str) -> Fixture {
+        Fixture {
+            operation: operation.to_string(),
+            variables: variables.and_then(|v| v.as_object().cloned()),
+            status: 200,
+            headers: BTreeMap::new(),
+            response: json!({ "marker": marker }),
+            times: None,
+            disconnect: false,
+            served: Arc::default(),
+        }
+    }
+
+    #[test]
+    fn test_find_fixture_by_root_field() {
+        let fixtures = vec![fixture("viewer", None, "viewer")];
+        let found = find_fixture(&fixtures, "query { viewer { id } }", None, &json!({}));
+        assert_eq!(found.unwrap().response["marker"], "viewer");
+        assert!(find_fixture(
+            &fixtures,
+            "query { teams { nodes { id } } }",
+            None,
+            &json!({})
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_find_fixture_prefers_most_specific_variables() {
+        let fixtures = vec![
+            fixture("issue", None, "any"),
+            fixture("issue", Some(json!({ "id": "ENG-1" })), "eng-1"),
+        ];
+        let query = "query($id: String!) { issue(id: $id) { id } }";
+        let found = find_fixture(&fixtures, query, None, &json!({ "id": "ENG-1" }));
+        assert_eq!(found.unwrap().response["marker"], "eng-1");
+        let found = find_fixture(&fixtures, query, None, &json!({ "id": "ENG-2" }));
+        assert_eq!(found.unwrap().response["marker"], "any");
+    }
+
+    #[test]
+    fn test_find_fixture_by_operation_name() {
+        let fixtures = vec![fixture("Me", None, "me")];
+        let found = find_fixture(&fixtures, "query Me { viewer { id } }", None, &json!({}));
+        assert!(found.is_some());
+    }
+
+    #[test]
+    fn test_find_fixture_skips_exhausted_times() {
+        let mut flaky = fixture("viewer", None, "flaky");
+        flaky.times = Some(1);
+        let fixtures = vec![flaky, fixture("viewer", None, "ok")];
+        let query = "query { viewer { id } }";
+        let found = find_fixture(&fixtures, query, None, &json!({})).unwrap();
+        assert_eq!(found.response["marker"], "flaky");
+        found.served.fetch_add(1, Ordering::SeqCst);
+        let found = find_fixture(&fixtures, query, None, &json!({})).unwrap();
+        assert_eq!(found.response["marker"], "ok");
+    }
+
+    #[test]
+    fn test_json_subset_nested() {
+        assert!(json_subset(
+            &json!({ "input": { "title": "x" } }),
+            &json!({ "input": { "title": "x", "teamId": "t" } })
+        ));
+        assert!(!json_subset(
+            &json!({ "input": { "title": "y" } }),
+            &json!({ "input": { "title": "x" } })
+        ));
+    }
+}
@@ -24,6 +24,14 @@ pub async fn run(output: &OutputOptions, check_api: bool, fix: bool) -> Result<(
         .map(|w| !w.api_key.is_empty())
         .unwrap_or(false);
 
+    let api_key_command = profile
+        .as_ref()
+        .and_then(|p| config_data.workspaces.get(p))
+        .and_then(|w| w.api_key_command.clone());
+    let helper_result = api_key_command
+        .as_deref()
+        .map(|c| config::run_api_key_command(c).map_err(|e| e.to_string()));
+
     #[cfg(feature = "secure-storage")]
     let keyring_available = crate::keyring::is_available();
     #[cfg(not(feature = "secure-storage"))]
@@ -44,7 +52,10 @@ pub async fn run(output: &OutputOptions, check_api: bool, fix: bool) -> Result<(
         .flatten()
         .is_some();
 
-    let configured = config_file_configured || oauth_configured || oauth_keyring_configured;
+    let configured = config_file_configured
+        || oauth_configured
+        || oauth_keyring_configured
+        || matches!(helper_result, Some(Ok(_)));
 
     let mut api_ok = None;
     let mut api_error = None;
@@ -168,6 +179,9 @@ pub async fn run(output: &OutputOptions, check_api: bool, fix: bool) -> Result<(
                 "oauth_keyring_configured": oauth_keyring_configured,
                 "env_api_key": env_key.is_some(),
                 "env_profile": env_profile,
+                "api_key_command": api_key_command,
+                "api_key_command_ok": helper_result.as_ref().map(|r| r.is_ok()),
+                "api_key_command_error": helper_result.as_ref().and_then(|r| r.as_ref().err()),
                 "cache_dir": cache_dir.to_string_lossy(),
                 "cache_ttl_seconds": output.cache.effective_ttl_seconds(),
                 "api_ok": api_ok,
@@ -197,6 +211,16 @@ pub async fn run(output: &OutputOptions, check_api: bool, fix: bool) -> Result<(
             "no"
         }
     );
+    if let (Some(command), Some(result)) = (&api_key_command, &helper_result) {
+        match result {
+            Ok(key) => println!(
+                "API key command: {} (ok, {})",
+                command,
+                config::mask_api_key_for_display(key)
+            ),
+            Err(err) => println!("API key command: {} (failed: {})", command, err),
+        }
+    }
     println!(
         "Env API key override: {}",
         if env_key.is_some() { "yes" } else { "no" }
@@ -4,7 +4,7 @@ use colored::Colorize;
 use serde_json::json;
 use tabled::{Table, Tabled};
 
-use crate::api::{resolve_project_id, LinearClient};
+use crate::api::{resolve_project_id, Idempotency, LinearClient};
 use crate::display_options;
 use crate::input::read_ids_from_stdin;
 use crate::output::{
@@ -36,7 +36,7 @@ pub enum DocumentCommands {
         /// Document title
         title: String,
         /// Project name or ID to associate the document with
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_PROJECT")]
         project: String,
         /// Document content (Markdown)
         #[arg(short, long)]
@@ -389,8 +389,15 @@ async fn create_document(
         }
     "#;
 
+    let recover = r#"
+        query($id: String!) {
+            document(id: $id) { id title url }
+        }
+    "#;
+
+    let idempotency = Idempotency::client_id(&mut input, recover);
     let result = client
-        .mutate(mutation, Some(json!({ "input": input })))
+        .mutate_with(mutation, Some(json!({ "input": input })), idempotency)
         .await?;
 
     if result["data"]["documentCreate"]["success"].as_bool() == Some(true) {
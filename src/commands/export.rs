@@ -7,6 +7,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use crate::api::LinearClient;
+use crate::mirror::IssueQuery;
 use crate::output::OutputOptions;
 use crate::pagination::{paginate_nodes, stream_nodes, PaginationOptions};
 use colored::Colorize;
@@ -256,8 +257,6 @@ async fn export_csv(
     limit: Option<usize>,
     all: bool,
 ) -> Result<()> {
-    let client = LinearClient::new()?;
-
     let query = r#"
         query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
             issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
@@ -339,58 +338,39 @@ async fn export_csv(
         "Updated",
     ])?;
 
-    // Stream pages and write rows as they arrive
-    let wtr_clone = Rc::clone(&wtr);
-    let total = stream_nodes(
-        &client,
-        query,
-        vars,
-        &["data", "issues", "nodes"],
-        &["data", "issues", "pageInfo"],
-        &pagination,
-        250,
-        |batch| {
-            let wtr = Rc::clone(&wtr_clone);
-            async move {
-                let mut writer = wtr.borrow_mut();
-                for issue in &batch {
-                    let labels: Vec<&str> = issue["labels"]["nodes"]
-                        .as_array()
-                        .map(|a| a.iter().filter_map(|l| l["name"].as_str()).collect())
-                        .unwrap_or_default();
-
-                    writer.write_record([
-                        sanitize_csv_cell(issue["identifier"].as_str().unwrap_or("")).as_ref(),
-                        sanitize_csv_cell(issue["title"].as_str().unwrap_or("")).as_ref(),
-                        sanitize_csv_cell(issue["state"]["name"].as_str().unwrap_or("")).as_ref(),
-                        &issue["priority"].as_i64().unwrap_or(0).to_string(),
-                        &issue["estimate"].as_f64().unwrap_or(0.0).to_string(),
-                        sanitize_csv_cell(issue["dueDate"].as_str().unwrap_or("")).as_ref(),
-                        sanitize_csv_cell(issue["assignee"]["name"].as_str().unwrap_or(""))
-                            .as_ref(),
-                        sanitize_csv_cell(issue["team"]["key"].as_str().unwrap_or("")).as_ref(),
-                        sanitize_csv_cell(issue["project"]["name"].as_str().unwrap_or("")).as_ref(),
-                        sanitize_csv_cell(issue["cycle"]["name"].as_str().unwrap_or("")).as_ref(),
-                        sanitize_csv_cell(&labels.join("; ")).as_ref(),
-                        &issue["createdAt"]
-                            .as_str()
-                            .unwrap_or("")
-                            .chars()
-                            .take(10)
-                            .collect::<String>(),
-                        &issue["updatedAt"]
-                            .as_str()
-                            .unwrap_or("")
-                            .chars()
-                            .take(10)
-                            .collect::<String>(),
-                    ])?;
+    let total = if crate::mirror::is_offline() {
+        let issues = offline_issues(team.as_deref(), include_completed, &pagination)?;
+        let mut writer = wtr.borrow_mut();
+        for issue in &issues {
+            write_csv_issue(&mut writer, issue)?;
+        }
+        issues.len()
+    } else {
+        let client = LinearClient::new()?;
+
+        // Stream pages and write rows as they arrive
+        let wtr_clone = Rc::clone(&wtr);
+        stream_nodes(
+            &client,
+            query,
+            vars,
+            &["data", "issues", "nodes"],
+            &["data", "issues", "pageInfo"],
+            &pagination,
+            250,
+            |batch| {
+                let wtr = Rc::clone(&wtr_clone);
+                async move {
+                    let mut writer = wtr.borrow_mut();
+                    for issue in &batch {
+                        write_csv_issue(&mut writer, issue)?;
+                    }
+                    Ok(())
                 }
-                Ok(())
-            }
-        },
-    )
-    .await?;
+            },
+        )
+        .await?
+    };
 
     wtr.borrow_mut().flush()?;
     let writer = std::rc::Rc::try_unwrap(wtr)
@@ -408,14 +388,63 @@ async fn export_csv(
     Ok(())
 }
 
+fn write_csv_issue(
+    writer: &mut Writer<ExportDestination>,
+    issue: &serde_json::Value,
+) -> Result<()> {
+    let labels: Vec<&str> = issue["labels"]["nodes"]
+        .as_array()
+        .map(|a| a.iter().filter_map(|l| l["name"].as_str()).collect())
+        .unwrap_or_default();
+
+    writer.write_record([
+        sanitize_csv_cell(issue["identifier"].as_str().unwrap_or("")).as_ref(),
+        sanitize_csv_cell(issue["title"].as_str().unwrap_or("")).as_ref(),
+        sanitize_csv_cell(issue["state"]["name"].as_str().unwrap_or("")).as_ref(),
+        &issue["priority"].as_i64().unwrap_or(0).to_string(),
+        &issue["estimate"].as_f64().unwrap_or(0.0).to_string(),
+        sanitize_csv_cell(issue["dueDate"].as_str().unwrap_or("")).as_ref(),
+        sanitize_csv_cell(issue["assignee"]["name"].as_str().unwrap_or("")).as_ref(),
+        sanitize_csv_cell(issue["team"]["key"].as_str().unwrap_or("")).as_ref(),
+        sanitize_csv_cell(issue["project"]["name"].as_str().unwrap_or("")).as_ref(),
+        sanitize_csv_cell(issue["cycle"]["name"].as_str().unwrap_or("")).as_ref(),
+        sanitize_csv_cell(&labels.join("; ")).as_ref(),
+        &issue["createdAt"]
+            .as_str()
+            .unwrap_or("")
+            .chars()
+            .take(10)
+            .collect::<String>(),
+        &issue["updatedAt"]
+            .as_str()
+            .unwrap_or("")
+            .chars()
+            .take(10)
+            .collect::<String>(),
+    ])?;
+    Ok(())
+}
+
+/// Issues from the offline mirror matching an export's team/completed filters
+fn offline_issues(
+    team: Option<&str>,
+    include_completed: bool,
+    pagination: &PaginationOptions,
+) -> Result<Vec<serde_json::Value>> {
+    let query = IssueQuery {
+        team: team.map(|t| t.to_string()),
+        exclude_completed: !include_completed,
+        ..Default::default()
+    };
+    crate::mirror::query_issues(&query, pagination)
+}
+
 async fn export_markdown(
     team: Option<String>,
     file: Option<String>,
     limit: Option<usize>,
     all: bool,
 ) -> Result<()> {
-    let client = LinearClient::new()?;
-
     let query = r#"
         query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
             issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
@@ -457,16 +486,20 @@ async fn export_markdown(
         pagination.limit = Some(limit.unwrap_or(250));
     }
 
-    let issues = paginate_nodes(
-        &client,
-        query,
-        vars,
-        &["data", "issues", "nodes"],
-        &["data", "issues", "pageInfo"],
-        &pagination,
-        250,
-    )
-    .await?;
+    let issues = if crate::mirror::is_offline() {
+        offline_issues(team.as_deref(), false, &pagination)?
+    } else {
+        paginate_nodes(
+            &LinearClient::new()?,
+            query,
+            vars,
+            &["data", "issues", "nodes"],
+            &["data", "issues", "pageInfo"],
+            &pagination,
+            250,
+        )
+        .await?
+    };
 
     let mut output = if let Some(ref path) = file {
         ExportDestination::Atomic(AtomicPrivateFile::create(Path::new(path))?)
@@ -537,8 +570,6 @@ async fn export_json(
     all: bool,
     pretty: bool,
 ) -> Result<()> {
-    let client = LinearClient::new()?;
-
     let query = r#"
         query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
             issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
@@ -589,16 +620,20 @@ async fn export_json(
         pagination.limit = Some(limit.unwrap_or(250));
     }
 
-    let issues = paginate_nodes(
-        &client,
-        query,
-        vars,
-        &["data", "issues", "nodes"],
-        &["data", "issues", "pageInfo"],
-        &pagination,
-        250,
-    )
-    .await?;
+    let issues = if crate::mirror::is_offline() {
+        offline_issues(team.as_deref(), include_completed, &pagination)?
+    } else {
+        paginate_nodes(
+            &LinearClient::new()?,
+            query,
+            vars,
+            &["data", "issues", "nodes"],
+            &["data", "issues", "pageInfo"],
+            &pagination,
+            250,
+        )
+        .await?
+    };
 
     // Flatten issue objects for easier re-import
     let flattened: Vec<serde_json::Value> = issues
@@ -649,8 +684,6 @@ async fn export_json(
 }
 
 async fn export_projects_csv(file: Option<String>, include_archived: bool) -> Result<()> {
-    let client = LinearClient::new()?;
-
     let query = r#"
         query($includeArchived: Boolean, $first: Int, $after: String, $last: Int, $before: String) {
             projects(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived) {
@@ -689,16 +722,20 @@ async fn export_projects_csv(file: Option<String>, include_archived: bool) -> Re
         ..Default::default()
     };
 
-    let projects = paginate_nodes(
-        &client,
-        query,
-        vars,
-        &["data", "projects", "nodes"],
-        &["data", "projects", "pageInfo"],
-        &pagination,
-        50,
-    )
-    .await?;
+    let projects = if crate::mirror::is_offline() {
+        crate::mirror::query_projects("", include_archived, &pagination)?
+    } else {
+        paginate_nodes(
+            &LinearClient::new()?,
+            query,
+            vars,
+            &["data", "projects", "nodes"],
+            &["data", "projects", "pageInfo"],
+            &pagination,
+            50,
+        )
+        .await?
+    };
 
     let mut wtr: Writer<ExportDestination> = if let Some(ref path) = file {
         Writer::from_writer(ExportDestination::Atomic(AtomicPrivateFile::create(
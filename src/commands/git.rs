@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Subcommand, ValueEnum};
 use colored::Colorize;
 use serde_json::json;
@@ -7,6 +7,7 @@ use std::process::Command;
 
 use crate::api::LinearClient;
 use crate::display_options;
+use crate::repo_config::{self, render_branch_template};
 use crate::text::truncate;
 use crate::vcs::{generate_branch_name, git_branch_exists, run_git_command, validate_branch_name};
 
@@ -38,7 +39,7 @@ pub enum GitCommands {
     Checkout {
         /// Issue identifier (e.g., "LIN-123") or ID
         issue: String,
-        /// Custom branch name (optional, uses issue's branch name by default)
+        /// Custom branch name (default: branch_template from .linear.toml, else the issue's branch name)
         #[arg(short, long)]
         branch: Option<String>,
         /// Version control system to use (auto-detected by default)
@@ -91,9 +92,9 @@ pub enum GitCommands {
     Pr {
         /// Issue identifier (e.g., "LIN-123") or ID
         issue: String,
-        /// Base branch to merge into (default: main)
-        #[arg(short = 'B', long, default_value = "main")]
-        base: String,
+        /// Base branch to merge into (default: pr_base from .linear.toml, else main)
+        #[arg(short = 'B', long)]
+        base: Option<String>,
         /// Create a draft PR
         #[arg(short, long)]
         draft: bool,
@@ -163,7 +164,12 @@ pub async fn handle(cmd: GitCommands) -> Result<()> {
             base,
             draft,
             web,
-        } => create_pr(&issue, &base, draft, web).await,
+        } => {
+            let base = base
+                .or_else(|| crate::repo_config::current().and_then(|r| r.pr_base.clone()))
+                .unwrap_or_else(|| "main".to_string());
+            create_pr(&issue, &base, draft, web).await
+        }
     }
 }
 
@@ -261,6 +267,30 @@ fn get_current_branch(vcs: Vcs) -> Result<String> {
 }
 
 /// Generate the commit description with Linear issue trailer
+/// Branch for an issue: `-b` wins, then the repository's branch_template,
+/// then Linear's suggested branch name, then one generated from the title
+fn choose_branch_name(
+    custom_branch: Option<String>,
+    identifier: &str,
+    title: &str,
+    linear_branch: String,
+) -> Result<String> {
+    if let Some(custom_branch) = custom_branch {
+        validate_branch_name(&custom_branch)?;
+        return Ok(custom_branch);
+    }
+    if let Some(template) = crate::repo_config::current().and_then(|r| r.branch_template.as_ref()) {
+        let branch = render_branch_template(template, identifier, title)
+            .with_context(|| format!("Invalid branch_template in {}", repo_config::FILE_NAME))?;
+        validate_branch_name(&branch)?;
+        return Ok(branch);
+    }
+    if !linear_branch.is_empty() && validate_branch_name(&linear_branch).is_ok() {
+        return Ok(linear_branch);
+    }
+    Ok(generate_branch_name(identifier, title))
+}
+
 fn generate_jj_description(identifier: &str, title: &str, url: &str) -> String {
     format!(
         "{}: {}\n\nLinear-Issue: {}\nLinear-URL: {}",
@@ -271,14 +301,7 @@ fn generate_jj_description(identifier: &str, title: &str, url: &str) -> String {
 async fn checkout_issue(issue_id: &str, custom_branch: Option<String>, vcs: Vcs) -> Result<()> {
     let (identifier, title, linear_branch, url) = get_issue_info(issue_id).await?;
     let title_width = display_options().max_width(50);
-    let branch_name = if let Some(custom_branch) = custom_branch {
-        validate_branch_name(&custom_branch)?;
-        custom_branch
-    } else if !linear_branch.is_empty() && validate_branch_name(&linear_branch).is_ok() {
-        linear_branch
-    } else {
-        generate_branch_name(&identifier, &title)
-    };
+    let branch_name = choose_branch_name(custom_branch, &identifier, &title, linear_branch)?;
 
     println!(
         "{} {} {}",
@@ -344,8 +367,21 @@ async fn show_branch(issue_id: &str, vcs: Vcs) -> Result<()> {
         println!("Linear branch: {}", linear_branch.green());
     }
 
-    let generated = generate_branch_name(&identifier, &title);
-    println!("Generated:     {}", generated.yellow());
+    let generated = match crate::repo_config::current().and_then(|r| r.branch_template.as_ref()) {
+        Some(template) => {
+            let branch =
+                render_branch_template(template, &identifier, &title).with_context(|| {
+                    format!("Invalid branch_template in {}", repo_config::FILE_NAME)
+                })?;
+            println!("Template:      {}", branch.yellow());
+            branch
+        }
+        None => {
+            let branch = generate_branch_name(&identifier, &title);
+            println!("Generated:     {}", branch.yellow());
+            branch
+        }
+    };
     println!("Issue URL:     {}", url.blue());
 
     match vcs {
@@ -378,14 +414,7 @@ async fn create_branch(issue_id: &str, custom_branch: Option<String>, vcs: Vcs)
     let (identifier, title, linear_branch, url) = get_issue_info(issue_id).await?;
     let title_width = display_options().max_width(50);
 
-    let branch_name = if let Some(custom_branch) = custom_branch {
-        validate_branch_name(&custom_branch)?;
-        custom_branch
-    } else if !linear_branch.is_empty() && validate_branch_name(&linear_branch).is_ok() {
-        linear_branch
-    } else {
-        generate_branch_name(&identifier, &title)
-    };
+    let branch_name = choose_branch_name(custom_branch, &identifier, &title, linear_branch)?;
 
     println!(
         "{} {} {}",
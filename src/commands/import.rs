@@ -5,7 +5,7 @@ use futures::stream::{self, StreamExt};
 use serde_json::{json, Value};
 
 use crate::api::{
-    resolve_label_id, resolve_state_id, resolve_team_id, resolve_user_id, LinearClient,
+    resolve_label_id, resolve_state_id, resolve_team_id, resolve_user_id, Idempotency, LinearClient,
 };
 use crate::output::OutputOptions;
 
@@ -17,7 +17,7 @@ pub enum ImportCommands {
         #[arg(value_hint = ValueHint::FilePath)]
         file: String,
         /// Team key or name (required for new issues)
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Preview without creating (dry run)
         #[arg(long)]
@@ -29,7 +29,7 @@ pub enum ImportCommands {
         #[arg(value_hint = ValueHint::FilePath)]
         file: String,
         /// Team key or name
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Preview without creating (dry run)
         #[arg(long)]
@@ -278,6 +278,12 @@ async fn create_issues(
         }
     "#;
 
+    let recover = r#"
+        query($id: String!) {
+            issue(id: $id) { id identifier title url }
+        }
+    "#;
+
     // Build all inputs first, resolving names to IDs
     let mut inputs: Vec<(usize, Value, String)> = Vec::new();
     for (i, row) in rows.iter().enumerate() {
@@ -376,9 +382,10 @@ async fn create_issues(
     type CreateResult = (usize, String, Result<(String, String), String>);
     let client_ref = &client;
     let results: Vec<CreateResult> = stream::iter(inputs)
-        .map(|(i, input, title)| async move {
+        .map(|(i, mut input, title)| async move {
+            let idempotency = Idempotency::client_id(&mut input, recover);
             let result = client_ref
-                .mutate(mutation, Some(json!({ "input": input })))
+                .mutate_with(mutation, Some(json!({ "input": input })), idempotency)
                 .await;
 
             match result {
@@ -403,7 +410,7 @@ async fn create_issues(
                 Err(e) => (i, title, Err(e.to_string())),
             }
         })
-        .buffer_unordered(5)
+        .buffer_unordered(client.fanout_limit(5))
         .collect()
         .await;
 
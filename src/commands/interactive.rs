@@ -1,13 +1,39 @@
 use anyhow::Result;
-use colored::Colorize;
-use dialoguer::{console::Term, Confirm, Input, Select};
-use serde_json::json;
-use tabled::{Table, Tabled};
-
-use crate::api::LinearClient;
-use crate::display_options;
-use crate::priority::priority_to_string;
-use crate::text::truncate;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Block, Borders, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState, Wrap,
+};
+use ratatui::{DefaultTerminal, Frame};
+use serde_json::{json, Value};
+use std::io::IsTerminal;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use crate::api::{resolve_team_id, LinearClient};
+use crate::cache::CacheOptions;
+
+/// Issue fields shown in the list and detail panes (also returned by mutations)
+const ISSUE_FIELDS: &str = r#"
+    id
+    identifier
+    title
+    description
+    priority
+    estimate
+    dueDate
+    url
+    createdAt
+    updatedAt
+    state { id name type }
+    assignee { id name }
+    labels { nodes { name } }
+    project { name }
+"#;
+
+const ISSUE_LIMIT: usize = 100;
 
 #[derive(Debug, Clone)]
 struct Team {
@@ -16,758 +42,1179 @@ struct Team {
     key: String,
 }
 
-#[derive(Tabled)]
-struct IssueRow {
-    #[tabled(rename = "ID")]
-    identifier: String,
-    #[tabled(rename = "Title")]
-    title: String,
-    #[tabled(rename = "State")]
-    state: String,
-    #[tabled(rename = "Priority")]
-    priority: String,
+/// One option in a popup picker: label shown to the user and the value applied
+#[derive(Debug, Clone, PartialEq)]
+struct PickerOption {
+    label: String,
+    value: Value,
 }
 
-enum MenuAction {
-    CreateIssue,
-    ListIssues,
-    ViewIssue,
-    UpdateIssue,
-    SwitchTeam,
-    Exit,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PickerKind {
+    State,
+    Assignee,
+    Priority,
+    Team,
 }
 
-impl std::fmt::Display for MenuAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl PickerKind {
+    fn title(&self) -> &'static str {
+        match self {
+            PickerKind::State => "Status",
+            PickerKind::Assignee => "Assignee",
+            PickerKind::Priority => "Priority",
+            PickerKind::Team => "Team",
+        }
+    }
+
+    /// `IssueUpdateInput` field set by this picker (None for team switching)
+    fn input_field(&self) -> Option<&'static str> {
         match self {
-            MenuAction::CreateIssue => write!(f, "Create Issue"),
-            MenuAction::ListIssues => write!(f, "List Issues"),
-            MenuAction::ViewIssue => write!(f, "View Issue"),
-            MenuAction::UpdateIssue => write!(f, "Update Issue"),
-            MenuAction::SwitchTeam => write!(f, "Switch Team"),
-            MenuAction::Exit => write!(f, "Exit"),
+            PickerKind::State => Some("stateId"),
+            PickerKind::Assignee => Some("assigneeId"),
+            PickerKind::Priority => Some("priority"),
+            PickerKind::Team => None,
         }
     }
 }
 
-pub async fn run(default_team: Option<String>) -> Result<()> {
-    let term = Term::stdout();
-    let client = LinearClient::new()?;
+#[derive(Debug, Clone, PartialEq)]
+struct Picker {
+    kind: PickerKind,
+    options: Vec<PickerOption>,
+    selected: usize,
+}
 
-    println!("{}", "Linear CLI - Interactive Mode".cyan().bold());
-    println!("{}", "=".repeat(40));
+#[derive(Debug, Clone, PartialEq)]
+enum Mode {
+    Normal,
+    Filter,
+    Create(String),
+    Picker(Picker),
+    Help,
+}
 
-    // Fetch available teams
-    let teams = fetch_teams(&client).await?;
-    if teams.is_empty() {
-        println!("No teams found. Please check your API key.");
-        return Ok(());
-    }
+/// Team states and members used by the pickers
+#[derive(Debug, Clone, Default)]
+struct TeamMeta {
+    team_id: String,
+    states: Vec<Value>,
+    members: Vec<Value>,
+}
 
-    // Select initial team
-    let mut current_team = match default_team {
-        Some(team) => select_team_by_name_or_key(&teams, &team)?,
-        None => select_team(&teams)?,
-    };
-    println!(
-        "\n{} Selected team: {} ({})",
-        "+".green(),
-        current_team.name,
-        current_team.key
-    );
+/// Results of background API calls
+enum Msg {
+    Issues {
+        team_id: String,
+        result: Result<Vec<Value>, String>,
+    },
+    TeamMeta(Result<TeamMeta, String>),
+    Updated(Result<Value, String>),
+    Created(Result<Value, String>),
+}
 
-    loop {
-        println!("\n{}", "-".repeat(40));
-        println!(
-            "Current team: {} {}",
-            current_team.name.cyan(),
-            format!("({})", current_team.key).dimmed()
-        );
+struct App {
+    teams: Vec<Team>,
+    team: usize,
+    issues: Vec<Value>,
+    /// Indexes into `issues` that match the filter
+    visible: Vec<usize>,
+    table: TableState,
+    filter: String,
+    show_completed: bool,
+    mode: Mode,
+    meta: Option<TeamMeta>,
+    status: Option<(String, bool)>,
+    loading: bool,
+    detail_scroll: u16,
+    quit: bool,
+}
 
-        let actions = [
-            MenuAction::CreateIssue,
-            MenuAction::ListIssues,
-            MenuAction::ViewIssue,
-            MenuAction::UpdateIssue,
-            MenuAction::SwitchTeam,
-            MenuAction::Exit,
-        ];
+impl App {
+    fn new(teams: Vec<Team>, team: usize) -> Self {
+        Self {
+            teams,
+            team,
+            issues: Vec::new(),
+            visible: Vec::new(),
+            table: TableState::default(),
+            filter: String::new(),
+            show_completed: false,
+            mode: Mode::Normal,
+            meta: None,
+            status: None,
+            loading: false,
+            detail_scroll: 0,
+            quit: false,
+        }
+    }
 
-        let action_strings: Vec<String> = actions.iter().map(|a| a.to_string()).collect();
+    fn current_team(&self) -> &Team {
+        &self.teams[self.team]
+    }
 
-        let selection = Select::new()
-            .with_prompt("Select action")
-            .items(&action_strings)
-            .default(0)
-            .interact_on(&term)?;
+    fn selected_issue(&self) -> Option<&Value> {
+        let idx = *self.visible.get(self.table.selected()?)?;
+        self.issues.get(idx)
+    }
 
-        let Some(action) = actions.get(selection) else {
-            anyhow::bail!("Invalid menu selection: {}", selection);
-        };
-        match action {
-            MenuAction::CreateIssue => {
-                if let Err(e) = create_issue_interactive(&client, &current_team).await {
-                    println!("{} Error: {}", "!".red(), e);
-                }
-            }
-            MenuAction::ListIssues => {
-                if let Err(e) = list_issues_interactive(&client, &current_team).await {
-                    println!("{} Error: {}", "!".red(), e);
-                }
-            }
-            MenuAction::ViewIssue => {
-                if let Err(e) = view_issue_interactive(&client).await {
-                    println!("{} Error: {}", "!".red(), e);
-                }
-            }
-            MenuAction::UpdateIssue => {
-                if let Err(e) = update_issue_interactive(&client).await {
-                    println!("{} Error: {}", "!".red(), e);
-                }
-            }
-            MenuAction::SwitchTeam => {
-                current_team = select_team(&teams)?;
-                println!(
-                    "{} Switched to team: {} ({})",
-                    "+".green(),
-                    current_team.name,
-                    current_team.key
-                );
-            }
-            MenuAction::Exit => {
-                println!("Goodbye!");
-                break;
+    /// Replace the issue list, keeping the same issue selected when it is still present
+    fn set_issues(&mut self, issues: Vec<Value>) {
+        let previous = self
+            .selected_issue()
+            .and_then(|i| i["id"].as_str())
+            .map(|s| s.to_string());
+        self.issues = issues;
+        self.apply_filter();
+        if let Some(id) = previous {
+            if let Some(pos) = self
+                .visible
+                .iter()
+                .position(|&i| self.issues[i]["id"].as_str() == Some(id.as_str()))
+            {
+                self.table.select(Some(pos));
             }
         }
     }
 
-    Ok(())
-}
+    /// Swap in an updated copy of one issue (after a mutation)
+    fn replace_issue(&mut self, issue: Value) {
+        match self.issues.iter_mut().find(|i| i["id"] == issue["id"]) {
+            Some(existing) => *existing = issue,
+            None => self.issues.insert(0, issue),
+        }
+        let previous = self.table.selected();
+        self.apply_filter();
+        if previous.is_some() {
+            self.table
+                .select(previous.map(|p| p.min(self.visible.len().saturating_sub(1))));
+        }
+    }
 
-async fn fetch_teams(client: &LinearClient) -> Result<Vec<Team>> {
-    let query = r#"
-        query {
-            teams(first: 100) {
-                nodes {
-                    id
-                    name
-                    key
+    fn apply_filter(&mut self) {
+        let needle = self.filter.to_lowercase();
+        self.visible = self
+            .issues
+            .iter()
+            .enumerate()
+            .filter(|(_, issue)| needle.is_empty() || issue_matches(issue, &needle))
+            .map(|(i, _)| i)
+            .collect();
+        let selected = match self.table.selected() {
+            _ if self.visible.is_empty() => None,
+            Some(s) => Some(s.min(self.visible.len() - 1)),
+            None => Some(0),
+        };
+        self.table.select(selected);
+        self.detail_scroll = 0;
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let current = self.table.selected().unwrap_or(0) as isize;
+        let last = self.visible.len() as isize - 1;
+        self.table
+            .select(Some((current + delta).clamp(0, last) as usize));
+        self.detail_scroll = 0;
+    }
+
+    fn set_status(&mut self, message: impl Into<String>, error: bool) {
+        self.status = Some((message.into(), error));
+    }
+
+    fn open_picker(&mut self, kind: PickerKind) {
+        let options = match kind {
+            PickerKind::Priority => priority_options(),
+            PickerKind::Team => self
+                .teams
+                .iter()
+                .map(|t| PickerOption {
+                    label: format!("{} ({})", t.name, t.key),
+                    value: json!(t.id),
+                })
+                .collect(),
+            PickerKind::State | PickerKind::Assignee => {
+                let Some(meta) = self.meta.as_ref() else {
+                    self.set_status("Still loading team states and members...", false);
+                    return;
+                };
+                if kind == PickerKind::State {
+                    state_options(&meta.states)
+                } else {
+                    assignee_options(&meta.members)
                 }
             }
+        };
+        if kind != PickerKind::Team && self.selected_issue().is_none() {
+            self.set_status("No issue selected", true);
+            return;
         }
-    "#;
 
-    let result = client.query(query, None).await?;
-    let empty = vec![];
-    let teams_json = result["data"]["teams"]["nodes"]
-        .as_array()
-        .unwrap_or(&empty);
+        let current = self.selected_issue().map(|issue| match kind {
+            PickerKind::State => issue["state"]["id"].clone(),
+            PickerKind::Assignee => issue["assignee"]["id"].clone(),
+            PickerKind::Priority => issue["priority"].clone(),
+            PickerKind::Team => Value::Null,
+        });
+        let selected = match kind {
+            PickerKind::Team => self.team,
+            _ => current
+                .and_then(|c| options.iter().position(|o| o.value == c))
+                .unwrap_or(0),
+        };
+        self.mode = Mode::Picker(Picker {
+            kind,
+            options,
+            selected,
+        });
+    }
+}
 
-    let teams: Vec<Team> = teams_json
-        .iter()
-        .map(|t| Team {
-            id: t["id"].as_str().unwrap_or("").to_string(),
-            name: t["name"].as_str().unwrap_or("").to_string(),
-            key: t["key"].as_str().unwrap_or("").to_string(),
+/// Case-insensitive match of the filter text against the visible issue fields
+fn issue_matches(issue: &Value, needle: &str) -> bool {
+    let labels = issue["labels"]["nodes"]
+        .as_array()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .filter_map(|l| l["name"].as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
         })
-        .collect();
-
-    Ok(teams)
+        .unwrap_or_default();
+    [
+        issue["identifier"].as_str().unwrap_or(""),
+        issue["title"].as_str().unwrap_or(""),
+        issue["state"]["name"].as_str().unwrap_or(""),
+        issue["assignee"]["name"].as_str().unwrap_or(""),
+        issue["project"]["name"].as_str().unwrap_or(""),
+        &labels,
+    ]
+    .iter()
+    .any(|field| field.to_lowercase().contains(needle))
 }
 
-fn select_team(teams: &[Team]) -> Result<Team> {
-    let team_names: Vec<String> = teams
-        .iter()
-        .map(|t| format!("{} ({})", t.name, t.key))
-        .collect();
+fn priority_label(priority: Option<i64>) -> (&'static str, Color) {
+    match priority {
+        Some(1) => ("Urgent", Color::Red),
+        Some(2) => ("High", Color::Yellow),
+        Some(3) => ("Normal", Color::Reset),
+        Some(4) => ("Low", Color::DarkGray),
+        _ => ("-", Color::DarkGray),
+    }
+}
 
-    let selection = Select::new()
-        .with_prompt("Select a team")
-        .items(&team_names)
-        .default(0)
-        .interact()?;
+fn priority_options() -> Vec<PickerOption> {
+    (0..=4)
+        .map(|p| PickerOption {
+            label: match p {
+                0 => "No priority".to_string(),
+                _ => priority_label(Some(p)).0.to_string(),
+            },
+            value: json!(p),
+        })
+        .collect()
+}
 
-    Ok(teams[selection].clone())
+fn state_options(states: &[Value]) -> Vec<PickerOption> {
+    states
+        .iter()
+        .map(|s| PickerOption {
+            label: s["name"].as_str().unwrap_or("").to_string(),
+            value: s["id"].clone(),
+        })
+        .collect()
 }
 
-async fn create_issue_interactive(client: &LinearClient, team: &Team) -> Result<()> {
-    println!("\n{}", "Create New Issue".cyan().bold());
+fn assignee_options(members: &[Value]) -> Vec<PickerOption> {
+    let mut options = vec![PickerOption {
+        label: "(Unassigned)".to_string(),
+        value: Value::Null,
+    }];
+    options.extend(members.iter().map(|m| PickerOption {
+        label: m["name"].as_str().unwrap_or("").to_string(),
+        value: m["id"].clone(),
+    }));
+    options
+}
 
-    let title: String = Input::new().with_prompt("Title").interact_text()?;
+/// Sort states the way Linear shows them: by workflow type, then position
+fn sort_states(states: &mut [Value]) {
+    let rank = |t: &str| match t {
+        "triage" => 0,
+        "backlog" => 1,
+        "unstarted" => 2,
+        "started" => 3,
+        "completed" => 4,
+        "canceled" => 5,
+        _ => 6,
+    };
+    states.sort_by(|a, b| {
+        rank(a["type"].as_str().unwrap_or(""))
+            .cmp(&rank(b["type"].as_str().unwrap_or("")))
+            .then(
+                a["position"]
+                    .as_f64()
+                    .unwrap_or(0.0)
+                    .total_cmp(&b["position"].as_f64().unwrap_or(0.0)),
+            )
+    });
+}
 
-    if title.trim().is_empty() {
-        println!("Title cannot be empty.");
-        return Ok(());
+pub async fn run(
+    default_team: Option<String>,
+    refresh_secs: u64,
+    cache_opts: &CacheOptions,
+) -> Result<()> {
+    if !std::io::stdout().is_terminal() || !std::io::stdin().is_terminal() {
+        anyhow::bail!(
+            "Interactive mode needs a terminal. Use `linear-cli issues list` in scripts."
+        );
     }
 
-    let description: String = Input::new()
-        .with_prompt("Description (optional)")
-        .allow_empty(true)
-        .interact_text()?;
-
-    let priority_options = vec!["None", "Urgent", "High", "Normal", "Low"];
-    let priority_selection = Select::new()
-        .with_prompt("Priority")
-        .items(&priority_options)
-        .default(3)
-        .interact()?;
+    let client = LinearClient::new()?;
+    let teams = fetch_teams(&client).await?;
+    if teams.is_empty() {
+        anyhow::bail!("No teams found. Please check your API key.");
+    }
 
-    let priority = match priority_selection {
-        0 => 0,
-        1 => 1,
-        2 => 2,
-        3 => 3,
-        4 => 4,
-        _ => 0,
+    let team = match default_team {
+        Some(ref t) => {
+            let team_id = resolve_team_id(&client, t, cache_opts).await?;
+            teams
+                .iter()
+                .position(|team| team.id == team_id)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Team not found: '{}'. Use `linear teams list` to see available teams.",
+                        t
+                    )
+                })?
+        }
+        None => 0,
     };
 
-    // Fetch team states and members for optional fields
-    let meta_query = r#"
-        query($teamId: String!) {
-            team(id: $teamId) {
-                states { nodes { id name type } }
-                members { nodes { id name } }
-                labels { nodes { id name } }
-            }
-        }
-    "#;
-    let meta_result = client
-        .query(meta_query, Some(json!({ "teamId": team.id })))
-        .await?;
-    let team_data = &meta_result["data"]["team"];
-
-    // Assignee selection
-    let mut assignee_id: Option<String> = None;
-    if let Some(members) = team_data["members"]["nodes"].as_array() {
-        if !members.is_empty() {
-            let mut names: Vec<String> = vec!["(Skip)".to_string()];
-            names.extend(
-                members
-                    .iter()
-                    .filter_map(|m| m["name"].as_str().map(|s| s.to_string())),
-            );
-            let sel = Select::new()
-                .with_prompt("Assignee")
-                .items(&names)
-                .default(0)
-                .interact()?;
-            if sel > 0 {
-                assignee_id = members[sel - 1]["id"].as_str().map(|s| s.to_string());
+    let mut terminal = ratatui::try_init()?;
+    let result = event_loop(&mut terminal, client, App::new(teams, team), refresh_secs).await;
+    ratatui::restore();
+    result
+}
+
+/// Read terminal events on a dedicated thread so the async runtime stays free for API calls
+fn spawn_input_thread() -> mpsc::UnboundedReceiver<Event> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    std::thread::spawn(move || loop {
+        match event::poll(Duration::from_millis(100)) {
+            Ok(true) => match event::read() {
+                Ok(ev) => {
+                    if tx.send(ev).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            },
+            Ok(false) => {
+                if tx.is_closed() {
+                    break;
+                }
             }
+            Err(_) => break,
         }
-    }
+    });
+    rx
+}
 
-    // Status selection
-    let mut state_id: Option<String> = None;
-    if let Some(states) = team_data["states"]["nodes"].as_array() {
-        if !states.is_empty() {
-            let mut names: Vec<String> = vec!["(Default)".to_string()];
-            names.extend(
-                states
-                    .iter()
-                    .filter_map(|s| s["name"].as_str().map(|n| n.to_string())),
-            );
-            let sel = Select::new()
-                .with_prompt("Status")
-                .items(&names)
-                .default(0)
-                .interact()?;
-            if sel > 0 {
-                state_id = states[sel - 1]["id"].as_str().map(|s| s.to_string());
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    client: LinearClient,
+    mut app: App,
+    refresh_secs: u64,
+) -> Result<()> {
+    let mut input = spawn_input_thread();
+    let (tx, mut rx) = mpsc::unbounded_channel::<Msg>();
+    let mut refresh = tokio::time::interval(Duration::from_secs(refresh_secs.max(1)));
+    refresh.tick().await;
+
+    load_team(&client, &tx, &mut app);
+
+    while !app.quit {
+        terminal.draw(|frame| draw(frame, &mut app))?;
+
+        tokio::select! {
+            ev = input.recv() => {
+                let Some(ev) = ev else { break };
+                if let Event::Key(key) = ev {
+                    if key.kind == KeyEventKind::Press {
+                        handle_key(&client, &tx, &mut app, key);
+                    }
+                }
+            }
+            Some(msg) = rx.recv() => handle_msg(&mut app, msg),
+            _ = refresh.tick(), if refresh_secs > 0 => {
+                if !app.loading && app.mode == Mode::Normal {
+                    spawn_fetch_issues(&client, &tx, &mut app);
+                }
             }
         }
     }
 
-    // Label selection
-    let mut label_ids: Vec<String> = Vec::new();
-    if let Some(labels) = team_data["labels"]["nodes"].as_array() {
-        if !labels.is_empty() {
-            let mut names: Vec<String> = vec!["(Skip)".to_string()];
-            names.extend(
-                labels
-                    .iter()
-                    .filter_map(|l| l["name"].as_str().map(|n| n.to_string())),
-            );
-            let sel = Select::new()
-                .with_prompt("Label")
-                .items(&names)
-                .default(0)
-                .interact()?;
-            if sel > 0 {
-                if let Some(id) = labels[sel - 1]["id"].as_str() {
-                    label_ids.push(id.to_string());
+    Ok(())
+}
+
+fn handle_msg(app: &mut App, msg: Msg) {
+    match msg {
+        Msg::Issues { team_id, result } => {
+            // Ignore responses for a team we already switched away from
+            if team_id != app.current_team().id {
+                return;
+            }
+            app.loading = false;
+            match result {
+                Ok(issues) => {
+                    let count = issues.len();
+                    app.set_issues(issues);
+                    app.set_status(
+                        format!(
+                            "Loaded {} issues at {}",
+                            count,
+                            chrono::Local::now().format("%H:%M:%S")
+                        ),
+                        false,
+                    );
                 }
+                Err(e) => app.set_status(format!("Failed to load issues: {}", e), true),
             }
         }
+        Msg::TeamMeta(result) => match result {
+            Ok(meta) if meta.team_id == app.current_team().id => app.meta = Some(meta),
+            Ok(_) => {}
+            Err(e) => app.set_status(format!("Failed to load team details: {}", e), true),
+        },
+        Msg::Updated(result) => match result {
+            Ok(issue) => {
+                let label = format!(
+                    "Updated {}",
+                    issue["identifier"].as_str().unwrap_or("issue")
+                );
+                app.replace_issue(issue);
+                app.set_status(label, false);
+            }
+            Err(e) => app.set_status(format!("Update failed: {}", e), true),
+        },
+        Msg::Created(result) => match result {
+            Ok(issue) => {
+                let label = format!(
+                    "Created {}",
+                    issue["identifier"].as_str().unwrap_or("issue")
+                );
+                app.replace_issue(issue);
+                app.table.select(Some(0));
+                app.set_status(label, false);
+            }
+            Err(e) => app.set_status(format!("Create failed: {}", e), true),
+        },
     }
+}
 
-    // Due date
-    let due_date: String = Input::new()
-        .with_prompt("Due date (YYYY-MM-DD, +3d, tomorrow, or empty)")
-        .allow_empty(true)
-        .interact_text()?;
-
-    let confirm = Confirm::new()
-        .with_prompt("Create this issue?")
-        .default(true)
-        .interact()?;
+fn handle_key(
+    client: &LinearClient,
+    tx: &mpsc::UnboundedSender<Msg>,
+    app: &mut App,
+    key: KeyEvent,
+) {
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        app.quit = true;
+        return;
+    }
 
-    if !confirm {
-        println!("Cancelled.");
-        return Ok(());
+    match app.mode.clone() {
+        Mode::Help => app.mode = Mode::Normal,
+        Mode::Filter => match key.code {
+            KeyCode::Esc => {
+                app.filter.clear();
+                app.apply_filter();
+                app.mode = Mode::Normal;
+            }
+            KeyCode::Enter => app.mode = Mode::Normal,
+            KeyCode::Backspace => {
+                app.filter.pop();
+                app.apply_filter();
+            }
+            KeyCode::Char(c) => {
+                app.filter.push(c);
+                app.apply_filter();
+            }
+            _ => {}
+        },
+        Mode::Create(mut title) => match key.code {
+            KeyCode::Esc => app.mode = Mode::Normal,
+            KeyCode::Enter => {
+                app.mode = Mode::Normal;
+                if !title.trim().is_empty() {
+                    spawn_create(client, tx, app, title.trim().to_string());
+                }
+            }
+            KeyCode::Backspace => {
+                title.pop();
+                app.mode = Mode::Create(title);
+            }
+            KeyCode::Char(c) => {
+                title.push(c);
+                app.mode = Mode::Create(title);
+            }
+            _ => {}
+        },
+        Mode::Picker(mut picker) => match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => app.mode = Mode::Normal,
+            KeyCode::Down | KeyCode::Char('j') => {
+                picker.selected = (picker.selected + 1).min(picker.options.len().saturating_sub(1));
+                app.mode = Mode::Picker(picker);
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                picker.selected = picker.selected.saturating_sub(1);
+                app.mode = Mode::Picker(picker);
+            }
+            KeyCode::Enter => {
+                app.mode = Mode::Normal;
+                let Some(option) = picker.options.get(picker.selected).cloned() else {
+                    return;
+                };
+                match picker.kind.input_field() {
+                    Some(field) => {
+                        let Some(issue_id) = app
+                            .selected_issue()
+                            .and_then(|i| i["id"].as_str())
+                            .map(|s| s.to_string())
+                        else {
+                            return;
+                        };
+                        spawn_update(client, tx, app, issue_id, json!({ field: option.value }));
+                    }
+                    None => {
+                        if picker.selected != app.team {
+                            app.team = picker.selected;
+                            load_team(client, tx, app);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        },
+        Mode::Normal => match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => app.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => app.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => app.move_selection(-1),
+            KeyCode::PageDown => app.move_selection(10),
+            KeyCode::PageUp => app.move_selection(-10),
+            KeyCode::Home | KeyCode::Char('g') => app.move_selection(isize::MIN / 2),
+            KeyCode::End | KeyCode::Char('G') => app.move_selection(isize::MAX / 2),
+            KeyCode::Char('J') => app.detail_scroll = app.detail_scroll.saturating_add(3),
+            KeyCode::Char('K') => app.detail_scroll = app.detail_scroll.saturating_sub(3),
+            KeyCode::Char('/') => app.mode = Mode::Filter,
+            KeyCode::Char('s') => app.open_picker(PickerKind::State),
+            KeyCode::Char('a') => app.open_picker(PickerKind::Assignee),
+            KeyCode::Char('p') => app.open_picker(PickerKind::Priority),
+            KeyCode::Char('t') => app.open_picker(PickerKind::Team),
+            KeyCode::Char('c') => app.mode = Mode::Create(String::new()),
+            KeyCode::Char('r') => spawn_fetch_issues(client, tx, app),
+            KeyCode::Char('x') => {
+                app.show_completed = !app.show_completed;
+                spawn_fetch_issues(client, tx, app);
+            }
+            KeyCode::Char('o') => {
+                if let Some(url) = app.selected_issue().and_then(|i| i["url"].as_str()) {
+                    if let Err(e) = open::that(url) {
+                        app.set_status(format!("Failed to open browser: {}", e), true);
+                    }
+                }
+            }
+            KeyCode::Char('?') => app.mode = Mode::Help,
+            _ => {}
+        },
     }
+}
 
-    let mut input = json!({
-        "title": title,
-        "teamId": team.id
+/// Reset per-team state and fetch the team's issues, states and members
+fn load_team(client: &LinearClient, tx: &mpsc::UnboundedSender<Msg>, app: &mut App) {
+    app.issues.clear();
+    app.visible.clear();
+    app.table.select(None);
+    app.meta = None;
+    spawn_fetch_issues(client, tx, app);
+
+    let client = client.clone();
+    let tx = tx.clone();
+    let team_id = app.current_team().id.clone();
+    tokio::spawn(async move {
+        let result = fetch_team_meta(&client, &team_id)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = tx.send(Msg::TeamMeta(result));
     });
+}
 
-    if !description.is_empty() {
-        input["description"] = json!(description);
-    }
-    if priority > 0 {
-        input["priority"] = json!(priority);
-    }
-    if let Some(aid) = assignee_id {
-        input["assigneeId"] = json!(aid);
-    }
-    if let Some(sid) = state_id {
-        input["stateId"] = json!(sid);
-    }
-    if !label_ids.is_empty() {
-        input["labelIds"] = json!(label_ids);
-    }
-    if !due_date.is_empty() {
-        if let Some(parsed) = crate::dates::parse_due_date(&due_date) {
-            input["dueDate"] = json!(parsed);
-        }
-    }
-
-    let mutation = r#"
-        mutation($input: IssueCreateInput!) {
-            issueCreate(input: $input) {
-                success
-                issue {
-                    id
-                    identifier
-                    title
-                    url
-                }
-            }
-        }
-    "#;
+fn spawn_fetch_issues(client: &LinearClient, tx: &mpsc::UnboundedSender<Msg>, app: &mut App) {
+    app.loading = true;
+    app.set_status("Loading issues...", false);
+    let client = client.clone();
+    let tx = tx.clone();
+    let team_id = app.current_team().id.clone();
+    let show_completed = app.show_completed;
+    tokio::spawn(async move {
+        let result = fetch_issues(&client, &team_id, show_completed)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = tx.send(Msg::Issues { team_id, result });
+    });
+}
 
-    let result = client
-        .mutate(mutation, Some(json!({ "input": input })))
-        .await?;
+fn spawn_update(
+    client: &LinearClient,
+    tx: &mpsc::UnboundedSender<Msg>,
+    app: &mut App,
+    issue_id: String,
+    input: Value,
+) {
+    app.set_status("Saving...", false);
+    let client = client.clone();
+    let tx = tx.clone();
+    tokio::spawn(async move {
+        let mutation = format!(
+            r#"mutation($id: String!, $input: IssueUpdateInput!) {{
+                issueUpdate(id: $id, input: $input) {{ success issue {{ {} }} }}
+            }}"#,
+            ISSUE_FIELDS
+        );
+        let result = client
+            .mutate(&mutation, Some(json!({ "id": issue_id, "input": input })))
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|r| mutation_issue(&r["data"]["issueUpdate"]));
+        let _ = tx.send(Msg::Updated(result));
+    });
+}
 
-    if result["data"]["issueCreate"]["success"].as_bool() == Some(true) {
-        let issue = &result["data"]["issueCreate"]["issue"];
-        let identifier = issue["identifier"].as_str().unwrap_or("");
-        let issue_title = issue["title"].as_str().unwrap_or("");
-        println!(
-            "\n{} Created issue: {} {}",
-            "+".green(),
-            identifier.cyan(),
-            issue_title
+fn spawn_create(
+    client: &LinearClient,
+    tx: &mpsc::UnboundedSender<Msg>,
+    app: &mut App,
+    title: String,
+) {
+    app.set_status("Creating issue...", false);
+    let client = client.clone();
+    let tx = tx.clone();
+    let team_id = app.current_team().id.clone();
+    tokio::spawn(async move {
+        let mutation = format!(
+            r#"mutation($input: IssueCreateInput!) {{
+                issueCreate(input: $input) {{ success issue {{ {} }} }}
+            }}"#,
+            ISSUE_FIELDS
         );
-        println!("  URL: {}", issue["url"].as_str().unwrap_or(""));
+        let result = client
+            .mutate(
+                &mutation,
+                Some(json!({ "input": { "title": title, "teamId": team_id } })),
+            )
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|r| mutation_issue(&r["data"]["issueCreate"]));
+        let _ = tx.send(Msg::Created(result));
+    });
+}
+
+fn mutation_issue(payload: &Value) -> Result<Value, String> {
+    if payload["success"].as_bool() == Some(true) && payload["issue"].is_object() {
+        Ok(payload["issue"].clone())
     } else {
-        anyhow::bail!("Failed to create issue");
+        Err("the API did not apply the change".to_string())
     }
-
-    Ok(())
 }
 
-async fn list_issues_interactive(client: &LinearClient, team: &Team) -> Result<()> {
-    println!("\n{}", "Issues".cyan().bold());
-
+async fn fetch_teams(client: &LinearClient) -> Result<Vec<Team>> {
     let query = r#"
-        query($teamId: String!) {
-            team(id: $teamId) {
-                issues(first: 25) {
-                    nodes {
-                        id
-                        identifier
-                        title
-                        priority
-                        state { name }
-                    }
+        query {
+            teams(first: 100) {
+                nodes {
+                    id
+                    name
+                    key
                 }
             }
         }
     "#;
 
-    let result = client
-        .query(query, Some(json!({ "teamId": team.id })))
-        .await?;
-
+    let result = client.query(query, None).await?;
     let empty = vec![];
-    let issues = result["data"]["team"]["issues"]["nodes"]
+    let teams_json = result["data"]["teams"]["nodes"]
         .as_array()
         .unwrap_or(&empty);
 
-    if issues.is_empty() {
-        println!("No issues found for team {}.", team.name);
-        return Ok(());
-    }
-
-    let width = display_options().max_width(50);
-    let rows: Vec<IssueRow> = issues
+    let teams: Vec<Team> = teams_json
         .iter()
-        .map(|issue| IssueRow {
-            identifier: issue["identifier"].as_str().unwrap_or("").to_string(),
-            title: truncate(issue["title"].as_str().unwrap_or(""), width),
-            state: issue["state"]["name"].as_str().unwrap_or("-").to_string(),
-            priority: priority_to_string(issue["priority"].as_i64()),
+        .map(|t| Team {
+            id: t["id"].as_str().unwrap_or("").to_string(),
+            name: t["name"].as_str().unwrap_or("").to_string(),
+            key: t["key"].as_str().unwrap_or("").to_string(),
         })
         .collect();
 
-    let table = Table::new(rows).to_string();
-    println!("{}", table);
-    println!("\n{} issues (showing up to 25)", issues.len());
-
-    Ok(())
+    Ok(teams)
 }
 
-async fn view_issue_interactive(client: &LinearClient) -> Result<()> {
-    println!("\n{}", "View Issue".cyan().bold());
-
-    let issue_id: String = Input::new()
-        .with_prompt("Issue ID (e.g., LIN-123)")
-        .interact_text()?;
-
-    if issue_id.trim().is_empty() {
-        println!("Issue ID cannot be empty.");
-        return Ok(());
+async fn fetch_issues(
+    client: &LinearClient,
+    team_id: &str,
+    show_completed: bool,
+) -> Result<Vec<Value>> {
+    let query = format!(
+        r#"query($filter: IssueFilter, $first: Int) {{
+            issues(first: $first, filter: $filter, orderBy: updatedAt) {{
+                nodes {{ {} }}
+            }}
+        }}"#,
+        ISSUE_FIELDS
+    );
+    let mut filter = json!({ "team": { "id": { "eq": team_id } } });
+    if !show_completed {
+        filter["state"] = json!({ "type": { "nin": ["completed", "canceled"] } });
     }
+    let result = client
+        .query(
+            &query,
+            Some(json!({ "filter": filter, "first": ISSUE_LIMIT })),
+        )
+        .await?;
+    Ok(result["data"]["issues"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default())
+}
 
+async fn fetch_team_meta(client: &LinearClient, team_id: &str) -> Result<TeamMeta> {
     let query = r#"
-        query($id: String!) {
-            issue(id: $id) {
-                id
-                identifier
-                title
-                description
-                priority
-                url
-                state { name }
-                team { name }
-                assignee { name email }
-                labels { nodes { name } }
+        query($teamId: String!) {
+            team(id: $teamId) {
+                states { nodes { id name type position } }
+                members { nodes { id name } }
             }
         }
     "#;
+    let result = client
+        .query(query, Some(json!({ "teamId": team_id })))
+        .await?;
+    let team = &result["data"]["team"];
+    let mut states = team["states"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    sort_states(&mut states);
+    let mut members = team["members"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    members.sort_by_key(|m| m["name"].as_str().unwrap_or("").to_lowercase());
+    Ok(TeamMeta {
+        team_id: team_id.to_string(),
+        states,
+        members,
+    })
+}
 
-    let result = client.query(query, Some(json!({ "id": issue_id }))).await?;
-    let issue = &result["data"]["issue"];
-
-    if issue.is_null() {
-        println!("Issue not found: {}", issue_id);
-        return Ok(());
+fn draw(frame: &mut Frame, app: &mut App) {
+    let [header, body, footer] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+    let [list_area, detail_area] = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .areas(body);
+
+    draw_header(frame, app, header);
+    draw_list(frame, app, list_area);
+    draw_detail(frame, app, detail_area);
+    draw_footer(frame, app, footer);
+
+    match &app.mode {
+        Mode::Picker(picker) => draw_picker(frame, picker),
+        Mode::Help => draw_help(frame),
+        _ => {}
     }
+}
 
-    let identifier = issue["identifier"].as_str().unwrap_or("");
-    let title = issue["title"].as_str().unwrap_or("");
-    println!("\n{} {}", identifier.cyan().bold(), title.bold());
-    println!("{}", "-".repeat(60));
-
-    if let Some(desc) = issue["description"].as_str() {
-        if !desc.is_empty() {
-            let truncated = truncate(desc, display_options().max_width(200));
-            println!("\n{}\n", truncated);
-        }
+fn draw_header(frame: &mut Frame, app: &App, area: Rect) {
+    let team = app.current_team();
+    let mut spans = vec![
+        Span::styled(" Linear ", Style::new().fg(Color::Black).bg(Color::Cyan)),
+        Span::raw(format!(" {} ({}) ", team.name, team.key)),
+        Span::styled(
+            format!("{}/{} issues", app.visible.len(), app.issues.len()),
+            Style::new().fg(Color::DarkGray),
+        ),
+    ];
+    if !app.show_completed {
+        spans.push(Span::styled(
+            "  open only",
+            Style::new().fg(Color::DarkGray),
+        ));
+    }
+    if app.loading {
+        spans.push(Span::styled("  loading...", Style::new().fg(Color::Yellow)));
     }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
 
-    println!(
-        "State:    {}",
-        issue["state"]["name"].as_str().unwrap_or("-")
-    );
-    println!(
-        "Priority: {}",
-        priority_to_string(issue["priority"].as_i64())
-    );
-    println!(
-        "Team:     {}",
-        issue["team"]["name"].as_str().unwrap_or("-")
+fn draw_list(frame: &mut Frame, app: &mut App, area: Rect) {
+    let rows: Vec<Row> = app
+        .visible
+        .iter()
+        .map(|&i| {
+            let issue = &app.issues[i];
+            let (priority, color) = priority_label(issue["priority"].as_i64());
+            Row::new(vec![
+                Span::raw(issue["identifier"].as_str().unwrap_or("").to_string()),
+                Span::styled(priority, Style::new().fg(color)),
+                Span::raw(issue["state"]["name"].as_str().unwrap_or("-").to_string()),
+                Span::raw(issue["title"].as_str().unwrap_or("").to_string()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(7),
+            Constraint::Length(14),
+            Constraint::Min(10),
+        ],
+    )
+    .header(
+        Row::new(vec!["ID", "Pri", "State", "Title"])
+            .style(Style::new().add_modifier(Modifier::BOLD)),
+    )
+    .block(Block::default().borders(Borders::ALL).title(" Issues "))
+    .row_highlight_style(
+        Style::new()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
     );
 
-    if let Some(assignee) = issue["assignee"]["name"].as_str() {
-        println!("Assignee: {}", assignee);
-    } else {
-        println!("Assignee: -");
-    }
+    frame.render_stateful_widget(table, area, &mut app.table);
+}
 
-    let labels = issue["labels"]["nodes"].as_array();
-    if let Some(labels) = labels {
-        if !labels.is_empty() {
-            let label_names: Vec<&str> = labels.iter().filter_map(|l| l["name"].as_str()).collect();
-            println!("Labels:   {}", label_names.join(", "));
+fn draw_detail(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(" Details ");
+    let Some(issue) = app.selected_issue() else {
+        let text = if app.loading {
+            "Loading..."
+        } else {
+            "No issues"
+        };
+        frame.render_widget(Paragraph::new(text).block(block), area);
+        return;
+    };
+
+    let field = |name: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10}", name), Style::new().fg(Color::DarkGray)),
+            Span::raw(value),
+        ])
+    };
+    let labels = issue["labels"]["nodes"]
+        .as_array()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .filter_map(|l| l["name"].as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+    let (priority, _) = priority_label(issue["priority"].as_i64());
+    let text_or_dash = |v: &Value| v.as_str().unwrap_or("-").to_string();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                issue["identifier"].as_str().unwrap_or("").to_string(),
+                Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" "),
+            Span::styled(
+                issue["title"].as_str().unwrap_or("").to_string(),
+                Style::new().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::raw(""),
+        field("Status", text_or_dash(&issue["state"]["name"])),
+        field("Priority", priority.to_string()),
+        field("Assignee", text_or_dash(&issue["assignee"]["name"])),
+        field("Project", text_or_dash(&issue["project"]["name"])),
+        field(
+            "Labels",
+            if labels.is_empty() {
+                "-".to_string()
+            } else {
+                labels
+            },
+        ),
+        field("Due", text_or_dash(&issue["dueDate"])),
+        field(
+            "Estimate",
+            issue["estimate"]
+                .as_f64()
+                .map(|e| e.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        field(
+            "Updated",
+            issue["updatedAt"]
+                .as_str()
+                .map(|s| s.chars().take(16).collect::<String>().replace('T', " "))
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        field("URL", text_or_dash(&issue["url"])),
+        Line::raw(""),
+    ];
+    match issue["description"].as_str() {
+        Some(desc) if !desc.trim().is_empty() => {
+            lines.extend(desc.lines().map(|l| Line::raw(l.to_string())));
         }
+        _ => lines.push(Line::styled(
+            "No description",
+            Style::new().fg(Color::DarkGray),
+        )),
     }
 
-    println!("\nURL: {}", issue["url"].as_str().unwrap_or("-"));
-
-    Ok(())
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .scroll((app.detail_scroll, 0)),
+        area,
+    );
 }
 
-fn select_team_by_name_or_key(teams: &[Team], value: &str) -> Result<Team> {
-    let needle = value.trim().to_lowercase();
-    if needle.is_empty() {
-        return select_team(teams);
-    }
-    if let Some(team) = teams.iter().find(|t| {
-        t.id.eq_ignore_ascii_case(&needle)
-            || t.key.eq_ignore_ascii_case(&needle)
-            || t.name.eq_ignore_ascii_case(&needle)
-    }) {
-        return Ok(team.clone());
-    }
-    anyhow::bail!(
-        "Team not found: '{}'. Use `linear teams list` to see available teams.",
-        value
-    )
+fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let line = match &app.mode {
+        Mode::Filter => Line::from(vec![
+            Span::styled("/", Style::new().fg(Color::Cyan)),
+            Span::raw(app.filter.clone()),
+            Span::styled("█", Style::new().fg(Color::DarkGray)),
+            Span::styled(
+                "  Enter: keep  Esc: clear",
+                Style::new().fg(Color::DarkGray),
+            ),
+        ]),
+        Mode::Create(title) => Line::from(vec![
+            Span::styled("New issue title: ", Style::new().fg(Color::Cyan)),
+            Span::raw(title.clone()),
+            Span::styled("█", Style::new().fg(Color::DarkGray)),
+        ]),
+        _ => match &app.status {
+            Some((message, true)) => Line::styled(message.clone(), Style::new().fg(Color::Red)),
+            Some((message, false)) if !app.filter.is_empty() => Line::from(vec![
+                Span::styled(
+                    format!("filter: {}  ", app.filter),
+                    Style::new().fg(Color::Cyan),
+                ),
+                Span::styled(message.clone(), Style::new().fg(Color::DarkGray)),
+            ]),
+            Some((message, false)) => Line::styled(
+                format!("{}  ·  ? for help", message),
+                Style::new().fg(Color::DarkGray),
+            ),
+            None => Line::styled("? for help", Style::new().fg(Color::DarkGray)),
+        },
+    };
+    frame.render_widget(Paragraph::new(line), area);
 }
 
-async fn update_issue_interactive(client: &LinearClient) -> Result<()> {
-    println!("\n{}", "Update Issue".cyan().bold());
+fn draw_picker(frame: &mut Frame, picker: &Picker) {
+    let height = (picker.options.len() as u16 + 2).min(frame.area().height.saturating_sub(4));
+    let area = centered(frame.area(), 40, height);
+    let items: Vec<ListItem> = picker
+        .options
+        .iter()
+        .map(|o| ListItem::new(o.label.clone()))
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", picker.kind.title())),
+        )
+        .highlight_style(Style::new().bg(Color::Cyan).fg(Color::Black));
+    let mut state = ListState::default().with_selected(Some(picker.selected));
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut state);
+}
 
-    let issue_id: String = Input::new()
-        .with_prompt("Issue ID (e.g., LIN-123)")
-        .interact_text()?;
+fn draw_help(frame: &mut Frame) {
+    let keys = [
+        ("j/k, ↑/↓", "Move selection"),
+        ("g/G, PgUp/PgDn", "Jump"),
+        ("J/K", "Scroll details"),
+        ("/", "Filter the list"),
+        ("s", "Change status"),
+        ("a", "Change assignee"),
+        ("p", "Change priority"),
+        ("c", "Create issue"),
+        ("t", "Switch team"),
+        ("x", "Show/hide completed"),
+        ("r", "Refresh now"),
+        ("o", "Open in browser"),
+        ("q, Esc", "Quit"),
+    ];
+    let lines: Vec<Line> = keys
+        .iter()
+        .map(|(key, action)| {
+            Line::from(vec![
+                Span::styled(format!("{:<16}", key), Style::new().fg(Color::Cyan)),
+                Span::raw(*action),
+            ])
+        })
+        .collect();
+    let area = centered(frame.area(), 44, keys.len() as u16 + 2);
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Keys ")),
+        area,
+    );
+}
 
-    if issue_id.trim().is_empty() {
-        println!("Issue ID cannot be empty.");
-        return Ok(());
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
     }
+}
 
-    // First fetch the issue to show current state and get the UUID
-    let query = r#"
-        query($id: String!) {
-            issue(id: $id) {
-                id
-                identifier
-                title
-                description
-                priority
-                state { id name }
-                assignee { id name }
-                team {
-                    id
-                    states { nodes { id name type } }
-                    members { nodes { id name } }
-                }
-            }
-        }
-    "#;
-
-    let result = client.query(query, Some(json!({ "id": issue_id }))).await?;
-    let issue = &result["data"]["issue"];
-
-    if issue.is_null() {
-        println!("Issue not found: {}", issue_id);
-        return Ok(());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_with_issues() -> App {
+        let mut app = App::new(
+            vec![Team {
+                id: "team-1".to_string(),
+                name: "Engineering".to_string(),
+                key: "ENG".to_string(),
+            }],
+            0,
+        );
+        app.set_issues(vec![
+            json!({ "id": "1", "identifier": "ENG-1", "title": "Fix login", "state": { "id": "s1", "name": "Todo" }, "priority": 2 }),
+            json!({ "id": "2", "identifier": "ENG-2", "title": "Dark mode", "state": { "id": "s2", "name": "Done" }, "labels": { "nodes": [{ "name": "ui" }] } }),
+            json!({ "id": "3", "identifier": "ENG-3", "title": "Login audit", "state": { "id": "s1", "name": "Todo" } }),
+        ]);
+        app
     }
 
-    // Get the UUID for the update mutation
-    let issue_uuid = issue["id"].as_str().unwrap_or(&issue_id);
-    let current_title = issue["title"].as_str().unwrap_or("");
-    let current_description = issue["description"].as_str().unwrap_or("");
-    let identifier = issue["identifier"].as_str().unwrap_or("");
-    let current_state = issue["state"]["name"].as_str().unwrap_or("Unknown");
-    let current_assignee = issue["assignee"]["name"].as_str().unwrap_or("Unassigned");
+    #[test]
+    fn test_filter_matches_fields_and_keeps_selection_in_range() {
+        let mut app = app_with_issues();
+        app.move_selection(2);
+        app.filter = "login".to_string();
+        app.apply_filter();
+        assert_eq!(app.visible, vec![0, 2]);
+        assert_eq!(app.table.selected(), Some(1));
+
+        app.filter = "UI".to_string();
+        app.apply_filter();
+        assert_eq!(app.visible, vec![1]);
+        assert_eq!(app.table.selected(), Some(0));
+
+        app.filter = "nothing".to_string();
+        app.apply_filter();
+        assert!(app.selected_issue().is_none());
+    }
 
-    println!("\nCurrent: {} {}", identifier.cyan(), current_title);
-    println!(
-        "Status: {} | Assignee: {}",
-        current_state.yellow(),
-        current_assignee.dimmed()
-    );
+    #[test]
+    fn test_refresh_keeps_selected_issue() {
+        let mut app = app_with_issues();
+        app.move_selection(1);
+        assert_eq!(app.selected_issue().unwrap()["identifier"], "ENG-2");
+        app.set_issues(vec![
+            json!({ "id": "4", "identifier": "ENG-4", "title": "New" }),
+            json!({ "id": "2", "identifier": "ENG-2", "title": "Dark mode" }),
+        ]);
+        assert_eq!(app.selected_issue().unwrap()["identifier"], "ENG-2");
+    }
 
-    let update_options = vec![
-        "Title",
-        "Priority",
-        "Status",
-        "Assignee",
-        "Description",
-        "Cancel",
-    ];
-    let selection = Select::new()
-        .with_prompt("What to update?")
-        .items(&update_options)
-        .default(0)
-        .interact()?;
-
-    let mut input = json!({});
-
-    match selection {
-        0 => {
-            // Update title
-            let new_title: String = Input::new()
-                .with_prompt("New title")
-                .with_initial_text(current_title)
-                .interact_text()?;
-
-            if !new_title.is_empty() && new_title != current_title {
-                input["title"] = json!(new_title);
-            }
-        }
-        1 => {
-            // Update priority
-            let priority_options = vec!["None", "Urgent", "High", "Normal", "Low"];
-            let current_priority = issue["priority"].as_i64().unwrap_or(0) as usize;
-            let priority_selection = Select::new()
-                .with_prompt("New priority")
-                .items(&priority_options)
-                .default(current_priority)
-                .interact()?;
-
-            input["priority"] = json!(priority_selection);
-        }
-        2 => {
-            // Update status
-            let states = issue["team"]["states"]["nodes"].as_array();
-            if let Some(states) = states {
-                let state_names: Vec<&str> =
-                    states.iter().filter_map(|s| s["name"].as_str()).collect();
-
-                if state_names.is_empty() {
-                    println!("No states available for this team.");
-                    return Ok(());
-                }
+    #[test]
+    fn test_move_selection_clamps() {
+        let mut app = app_with_issues();
+        app.move_selection(-5);
+        assert_eq!(app.table.selected(), Some(0));
+        app.move_selection(isize::MAX / 2);
+        assert_eq!(app.table.selected(), Some(2));
+    }
 
-                // Find current state index
-                let current_state_id = issue["state"]["id"].as_str().unwrap_or("");
-                let current_idx = states
-                    .iter()
-                    .position(|s| s["id"].as_str() == Some(current_state_id))
-                    .unwrap_or(0);
-
-                let state_selection = Select::new()
-                    .with_prompt("New status")
-                    .items(&state_names)
-                    .default(current_idx)
-                    .interact()?;
-
-                if let Some(state_id) = states[state_selection]["id"].as_str() {
-                    input["stateId"] = json!(state_id);
-                }
-            } else {
-                println!("Could not fetch team states.");
-                return Ok(());
-            }
+    #[test]
+    fn test_picker_preselects_current_value() {
+        let mut app = app_with_issues();
+        app.meta = Some(TeamMeta {
+            team_id: "team-1".to_string(),
+            states: vec![
+                json!({ "id": "s2", "name": "Done" }),
+                json!({ "id": "s1", "name": "Todo" }),
+            ],
+            members: vec![json!({ "id": "u1", "name": "Ada" })],
+        });
+        app.open_picker(PickerKind::State);
+        match &app.mode {
+            Mode::Picker(p) => assert_eq!(p.options[p.selected].label, "Todo"),
+            other => panic!("unexpected mode {:?}", other),
         }
-        3 => {
-            // Update assignee
-            let members = issue["team"]["members"]["nodes"].as_array();
-            if let Some(members) = members {
-                let mut assignee_names: Vec<&str> = vec!["(Unassign)"];
-                assignee_names.extend(members.iter().filter_map(|m| m["name"].as_str()));
-
-                // Find current assignee index
-                let current_assignee_id = issue["assignee"]["id"].as_str().unwrap_or("");
-                let current_idx = if current_assignee_id.is_empty() {
-                    0 // Unassigned
-                } else {
-                    members
-                        .iter()
-                        .position(|m| m["id"].as_str() == Some(current_assignee_id))
-                        .map(|i| i + 1) // +1 because of "(Unassign)" at index 0
-                        .unwrap_or(0)
-                };
-
-                let assignee_selection = Select::new()
-                    .with_prompt("Assignee")
-                    .items(&assignee_names)
-                    .default(current_idx)
-                    .interact()?;
 
-                if assignee_selection == 0 {
-                    input["assigneeId"] = json!(null);
-                } else if let Some(member_id) = members[assignee_selection - 1]["id"].as_str() {
-                    input["assigneeId"] = json!(member_id);
-                }
-            } else {
-                println!("Could not fetch team members.");
-                return Ok(());
+        app.mode = Mode::Normal;
+        app.open_picker(PickerKind::Assignee);
+        match &app.mode {
+            Mode::Picker(p) => {
+                assert_eq!(p.options[0].value, Value::Null);
+                assert_eq!(p.selected, 0);
             }
+            other => panic!("unexpected mode {:?}", other),
         }
-        4 => {
-            // Update description
-            println!("Current description:");
-            if current_description.is_empty() {
-                println!("  (empty)");
-            } else {
-                for line in current_description.lines().take(5) {
-                    println!("  {}", line.dimmed());
-                }
-                if current_description.lines().count() > 5 {
-                    println!(
-                        "  {} more lines...",
-                        current_description.lines().count() - 5
-                    );
-                }
-            }
 
-            let new_description: String = Input::new()
-                .with_prompt("New description (markdown, single line)")
-                .with_initial_text(current_description)
-                .allow_empty(true)
-                .interact_text()?;
-
-            if new_description != current_description {
-                input["description"] = json!(new_description);
-            }
-        }
-        _ => {
-            println!("Cancelled.");
-            return Ok(());
+        app.mode = Mode::Normal;
+        app.open_picker(PickerKind::Priority);
+        match &app.mode {
+            Mode::Picker(p) => assert_eq!(p.options[p.selected].label, "High"),
+            other => panic!("unexpected mode {:?}", other),
         }
     }
 
-    if input.as_object().map(|o| o.is_empty()).unwrap_or(true) {
-        println!("No changes made.");
-        return Ok(());
-    }
-
-    let confirm = Confirm::new()
-        .with_prompt("Apply changes?")
-        .default(true)
-        .interact()?;
-
-    if !confirm {
-        println!("Cancelled.");
-        return Ok(());
+    #[test]
+    fn test_replace_issue_updates_in_place() {
+        let mut app = app_with_issues();
+        app.replace_issue(json!({ "id": "3", "identifier": "ENG-3", "title": "Renamed" }));
+        assert_eq!(app.issues.len(), 3);
+        assert_eq!(app.issues[2]["title"], "Renamed");
     }
 
-    let mutation = r#"
-        mutation($id: String!, $input: IssueUpdateInput!) {
-            issueUpdate(id: $id, input: $input) {
-                success
-                issue {
-                    identifier
-                    title
-                }
-            }
-        }
-    "#;
-
-    // Use the UUID for the update mutation
-    let result = client
-        .mutate(mutation, Some(json!({ "id": issue_uuid, "input": input })))
-        .await?;
-
-    if result["data"]["issueUpdate"]["success"].as_bool() == Some(true) {
-        let updated = &result["data"]["issueUpdate"]["issue"];
-        println!(
-            "\n{} Updated issue: {} {}",
-            "+".green(),
-            updated["identifier"].as_str().unwrap_or(""),
-            updated["title"].as_str().unwrap_or("")
-        );
-    } else {
-        anyhow::bail!("Failed to update issue");
+    #[test]
+    fn test_sort_states_by_workflow() {
+        let mut states = vec![
+            json!({ "name": "Done", "type": "completed", "position": 0 }),
+            json!({ "name": "In Progress", "type": "started", "position": 1 }),
+            json!({ "name": "Backlog", "type": "backlog", "position": 0 }),
+        ];
+        sort_states(&mut states);
+        let names: Vec<&str> = states.iter().map(|s| s["name"].as_str().unwrap()).collect();
+        assert_eq!(names, ["Backlog", "In Progress", "Done"]);
     }
-
-    Ok(())
 }
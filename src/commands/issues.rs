@@ -6,12 +6,14 @@ use std::io::{self, BufRead};
 use tabled::{Table, Tabled};
 
 use crate::api::{
-    resolve_label_id, resolve_project_id, resolve_state_id, resolve_team_id, resolve_user_id,
-    LinearClient,
+    resolve_label_id, resolve_state_id, resolve_team_id, resolve_user_id, Idempotency, LinearClient,
 };
 use crate::cache::CacheOptions;
 use crate::display_options;
 use crate::input::read_ids_from_stdin;
+use crate::outbox::{
+    apply_change, create_comment, is_network_error, server_updated_at, IssueChange, OutboxAction,
+};
 use crate::output::{
     ensure_non_empty, filter_values, print_json, print_json_owned, sort_values, OutputOptions,
 };
@@ -21,6 +23,7 @@ use crate::text::{is_uuid, truncate};
 use crate::vcs::{generate_branch_name, git_branch_exists, run_git_command, validate_branch_name};
 use crate::AgentOptions;
 
+use super::outbox;
 use super::templates;
 
 fn safe_terminal_value(value: &str) -> String {
@@ -37,7 +40,19 @@ pub enum IssueCommands {
     linear i list -t ENG -s "In Progress"      # Filter by team and status
     linear i list --assignee me                # Show my assigned issues
     linear i list --project "My Project"       # Filter by project name
-    linear i list --output json                # Output as JSON"#)]
+    linear i list --where 'priority <= high and label in (bug, regression)'
+    linear i list --where 'due < +7d and not assignee' -t ENG
+    linear i list --output json                # Output as JSON
+
+WHERE EXPRESSIONS:
+    Combine comparisons with and, or, not and parentheses. Filtering runs server-side.
+    Fields:    assignee creator state type team project label cycle priority estimate
+               title description due created updated completed parent
+    Operators: = != < <= > >= ~ (contains) !~ in (a, b) not in (a, b)
+    A bare field tests presence (`not assignee` = unassigned); `= none` does the same.
+    Values:    quote with '' or "" when they contain spaces; dates accept today, +7d,
+               -2w, 2024-01-15; priority accepts urgent, high, normal, low, none
+               (< and <= never match "No priority"); assignee accepts "me"."#)]
     List {
         /// Filter by team name or ID
         #[arg(short, long)]
@@ -51,6 +66,9 @@ pub enum IssueCommands {
         /// Show only my assigned issues (shortcut for --assignee me)
         #[arg(long)]
         mine: bool,
+        /// Ignore the profile's default-mine setting for this listing
+        #[arg(long, conflicts_with = "mine")]
+        no_mine: bool,
         /// Filter by project name
         #[arg(long)]
         project: Option<String>,
@@ -63,6 +81,9 @@ pub enum IssueCommands {
         /// Only show issues created after this date (today, -7d, 2024-01-15, etc.)
         #[arg(long, alias = "newer-than")]
         since: Option<String>,
+        /// Filter expression, e.g. 'priority <= high and not assignee' (see below)
+        #[arg(long = "where", value_name = "EXPR")]
+        where_expr: Option<String>,
         /// Include archived issues
         #[arg(long)]
         archived: bool,
@@ -134,6 +155,9 @@ pub enum IssueCommands {
         /// Estimate in points (e.g., 1, 2, 3, 5, 8)
         #[arg(short, long)]
         estimate: Option<f64>,
+        /// Project name or ID
+        #[arg(long)]
+        project: Option<String>,
         /// Template name to use for default values
         #[arg(long)]
         template: Option<String>,
@@ -302,22 +326,29 @@ pub async fn handle(
             state,
             assignee,
             mine,
+            no_mine,
             project,
             label,
             view,
             since,
+            where_expr,
             archived,
             group_by,
             count_only,
         } => {
+            // An explicit --assignee beats the profile's default-mine
+            let mine = mine
+                || (!no_mine
+                    && assignee.is_none()
+                    && crate::config::profile_defaults().mine == Some(true));
             let assignee = if mine {
                 Some("me".to_string())
             } else {
                 assignee
             };
             list_issues(
-                team, state, assignee, project, label, view, since, archived, group_by, count_only,
-                output, agent_opts,
+                team, state, assignee, project, label, view, since, where_expr, archived, group_by,
+                count_only, output, agent_opts,
             )
             .await
         }
@@ -347,6 +378,7 @@ pub async fn handle(
             labels,
             due,
             estimate,
+            project,
             template,
             dry_run,
         } => {
@@ -378,12 +410,20 @@ pub async fn handle(
                     .and_then(|t| t.as_str())
                     .map(|s| s.to_string())
             });
-            let final_team = team
-                .or(tpl.team.clone())
-                .or(data_team)
-                .or(data_team_id)
+            // Profile / .linear.toml defaults come last; their project only
+            // applies when the team came from the same defaults
+            let defaults = crate::config::profile_defaults();
+            let explicit_team = team.or(tpl.team.clone()).or(data_team).or(data_team_id);
+            let final_project = match explicit_team {
+                Some(_) => project,
+                None => project.or(defaults.project),
+            };
+            let final_team = explicit_team
+                .or(defaults.team)
                 .ok_or_else(|| {
-                    anyhow::anyhow!("--team is required (or use a template with a default team)")
+                    anyhow::anyhow!(
+                        "--team is required (or use a template with a default team, or run: linear config set default-team <KEY>)"
+                    )
                 })?;
 
             // Build title with optional prefix from template
@@ -410,9 +450,14 @@ pub async fn handle(
             };
             let final_priority = priority.or(tpl.default_priority);
 
-            // Merge labels: template labels + CLI labels
+            // Merge labels: template labels + .linear.toml labels + CLI labels
             let mut final_labels = tpl.default_labels.clone();
+            if let Some(repo) = crate::repo_config::current() {
+                final_labels.extend(repo.labels.iter().cloned());
+            }
             final_labels.extend(labels);
+            let mut seen = std::collections::HashSet::new();
+            final_labels.retain(|l| seen.insert(l.to_lowercase()));
 
             create_issue(
                 &final_title,
@@ -425,6 +470,7 @@ pub async fn handle(
                 final_labels,
                 due,
                 estimate,
+                final_project,
                 output,
                 agent_opts,
                 dry_run,
@@ -489,7 +535,7 @@ pub async fn handle(
         IssueCommands::Close { id } => close_issue(&id).await,
         IssueCommands::Archive { id } => archive_issue(&id, true).await,
         IssueCommands::Unarchive { id } => archive_issue(&id, false).await,
-        IssueCommands::Comment { id, body } => comment_issue(&id, &body).await,
+        IssueCommands::Comment { id, body } => comment_issue(&id, &body, output).await,
         IssueCommands::Link { id } => link_issue(&id).await,
         IssueCommands::Assign { id, user } => assign_issue(&id, user).await,
         IssueCommands::Move { id, project } => move_issue(&id, &project).await,
@@ -518,21 +564,13 @@ async fn list_issues(
     label: Option<String>,
     view: Option<String>,
     since: Option<String>,
+    where_expr: Option<String>,
     include_archived: bool,
     group_by: Option<String>,
     count_only: bool,
     output: &OutputOptions,
     _agent_opts: AgentOptions,
 ) -> Result<()> {
-    let client = LinearClient::new()?;
-
-    // Resolve team key/name to ID upfront (supports both key like "SCW" and full name)
-    let team_id = if let Some(ref t) = team {
-        Some(resolve_team_id(&client, t, &output.cache).await?)
-    } else {
-        None
-    };
-
     // Parse --since date
     let since_date = if let Some(ref since_str) = since {
         let date = crate::dates::parse_due_date(since_str).ok_or_else(|| {
@@ -545,83 +583,79 @@ async fn list_issues(
     } else {
         None
     };
+    let where_filter = where_expr
+        .as_deref()
+        .map(crate::where_expr::compile)
+        .transpose()?;
 
-    // If --view is specified, fetch the view's filterData and use it
-    let filter_data = if let Some(ref view_name) = view {
-        Some(super::views::fetch_view_filter(&client, view_name, &output.cache).await?)
-    } else {
-        None
-    };
-
-    let query = r#"
-        query($filter: IssueFilter, $includeArchived: Boolean, $first: Int, $after: String, $last: Int, $before: String) {
-            issues(
-                first: $first,
-                after: $after,
-                last: $last,
-                before: $before,
-                includeArchived: $includeArchived,
-                filter: $filter
-            ) {
-                nodes {
-                    id
-                    identifier
-                    title
-                    priority
-                    state { name }
-                    assignee { name }
-                    project { name }
-                }
-                pageInfo {
-                    hasNextPage
-                    endCursor
-                    hasPreviousPage
-                    startCursor
-                }
-            }
+    if crate::mirror::is_offline() {
+        if crate::config::fanout_profiles().is_some() {
+            anyhow::bail!("--offline cannot be combined with multiple profiles");
         }
-    "#;
-
-    let mut variables = Map::new();
-    variables.insert("includeArchived".to_string(), json!(include_archived));
-
-    // Build filter dynamically — start from view filter if present, otherwise empty
-    let mut filter = match filter_data {
-        Some(fd) => fd,
-        None => json!({}),
+        if view.is_some() {
+            anyhow::bail!("--view is not supported with --offline");
+        }
+        if where_filter.is_some() {
+            anyhow::bail!("--where is not supported with --offline");
+        }
+        let query = crate::mirror::IssueQuery {
+            team,
+            state,
+            assignee,
+            project,
+            label,
+            created_since: since_date,
+            include_archived,
+            ..Default::default()
+        };
+        let issues =
+            crate::mirror::query_issues(&query, &output.pagination.with_default_limit(50))?;
+        return print_issue_list(issues, group_by, count_only, output);
+    }
+
+    let filters = IssueListFilters {
+        team,
+        state,
+        assignee,
+        project,
+        label,
+        view,
+        since_date,
+        where_filter,
+        include_archived,
     };
+    let pagination = output.pagination.with_default_limit(50);
 
-    if let Some(ref since_ts) = since_date {
-        filter["createdAt"] = json!({ "gte": since_ts });
-    }
-    if let Some(ref t) = team_id {
-        filter["team"] = json!({ "id": { "eq": t } });
-    }
-    if let Some(s) = state {
-        filter["state"] = json!({ "name": { "eqIgnoreCase": s } });
-    }
-    if let Some(a) = assignee {
-        filter["assignee"] = build_issue_assignee_filter(&a);
-    }
-    if let Some(p) = project {
-        filter["project"] = json!({ "name": { "eqIgnoreCase": p } });
-    }
-    if let Some(ref l) = label {
-        filter["labels"] = json!({ "name": { "eqIgnoreCase": l } });
-    }
-    // Only include filter if non-empty
-    if filter.as_object().map(|o| !o.is_empty()).unwrap_or(false) {
-        variables.insert("filter".to_string(), filter);
+    if let Some(profiles) = crate::config::fanout_profiles() {
+        let filters = &filters;
+        let pagination = &pagination;
+        let issues = crate::fanout::collect(profiles, move || async move {
+            let client = LinearClient::new()?;
+            let variables = issue_list_variables(&client, filters, &output.cache).await?;
+            paginate_nodes(
+                &client,
+                ISSUE_LIST_QUERY,
+                variables,
+                &["data", "issues", "nodes"],
+                &["data", "issues", "pageInfo"],
+                pagination,
+                50,
+            )
+            .await
+        })
+        .await?;
+        return print_issue_list(issues, group_by, count_only, output);
     }
 
-    let pagination = output.pagination.with_default_limit(50);
+    let client = LinearClient::new()?;
+    let variables = issue_list_variables(&client, &filters, &output.cache).await?;
 
     // For NDJSON, use streaming to avoid buffering all results
     if output.is_ndjson() {
         let mut count = 0;
         stream_nodes(
             &client,
-            query,
+            ISSUE_LIST_QUERY,
             variables,
             &["data", "issues", "nodes"],
             &["data", "issues", "pageInfo"],
@@ -645,7 +679,7 @@ async fn list_issues(
     // For other formats, use paginate_nodes (need all results for sorting/filtering/tables)
     let issues = paginate_nodes(
         &client,
-        query,
+        ISSUE_LIST_QUERY,
         variables,
         &["data", "issues", "nodes"],
         &["data", "issues", "pageInfo"],
@@ -654,6 +688,118 @@ async fn list_issues(
     )
     .await?;
 
+    print_issue_list(issues, group_by, count_only, output)
+}
+
+const ISSUE_LIST_QUERY: &str = r#"
+    query($filter: IssueFilter, $includeArchived: Boolean, $first: Int, $after: String, $last: Int, $before: String) {
+        issues(
+            first: $first,
+            after: $after,
+            last: $last,
+            before: $before,
+            includeArchived: $includeArchived,
+            filter: $filter
+        ) {
+            nodes {
+                id
+                identifier
+                title
+                priority
+                state { name }
+                assignee { name }
+                project { name }
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+                hasPreviousPage
+                startCursor
+            }
+        }
+    }
+"#;
+
+/// Filters for `issues list`, resolved against a workspace by `issue_list_variables`
+struct IssueListFilters {
+    team: Option<String>,
+    state: Option<String>,
+    assignee: Option<String>,
+    project: Option<String>,
+    label: Option<String>,
+    view: Option<String>,
+    since_date: Option<String>,
+    where_filter: Option<Value>,
+    include_archived: bool,
+}
+
+/// Build the `issues` query variables, resolving the team and view in the client's workspace
+async fn issue_list_variables(
+    client: &LinearClient,
+    filters: &IssueListFilters,
+    cache: &CacheOptions,
+) -> Result<Map<String, Value>> {
+    // Resolve team key/name to ID upfront (supports both key like "SCW" and full name)
+    let team_id = if let Some(ref t) = filters.team {
+        Some(resolve_team_id(client, t, cache).await?)
+    } else {
+        None
+    };
+
+    // If --view is specified, fetch the view's filterData and use it
+    let filter_data = if let Some(ref view_name) = filters.view {
+        Some(super::views::fetch_view_filter(client, view_name, cache).await?)
+    } else {
+        None
+    };
+
+    let mut variables = Map::new();
+    variables.insert(
+        "includeArchived".to_string(),
+        json!(filters.include_archived),
+    );
+
+    // Build filter dynamically — start from view filter if present, otherwise empty
+    let mut filter = match filter_data {
+        Some(fd) => fd,
+        None => json!({}),
+    };
+
+    if let Some(ref since_ts) = filters.since_date {
+        filter["createdAt"] = json!({ "gte": since_ts });
+    }
+    if let Some(ref t) = team_id {
+        filter["team"] = json!({ "id": { "eq": t } });
+    }
+    if let Some(ref s) = filters.state {
+        filter["state"] = json!({ "name": { "eqIgnoreCase": s } });
+    }
+    if let Some(ref a) = filters.assignee {
+        filter["assignee"] = build_issue_assignee_filter(a);
+    }
+    if let Some(ref p) = filters.project {
+        filter["project"] = json!({ "name": { "eqIgnoreCase": p } });
+    }
+    if let Some(ref l) = filters.label {
+        filter["labels"] = json!({ "name": { "eqIgnoreCase": l } });
+    }
+    if let Some(ref compiled) = filters.where_filter {
+        crate::where_expr::merge_into(&mut filter, compiled.clone());
+    }
+    // Only include filter if non-empty
+    if filter.as_object().map(|o| !o.is_empty()).unwrap_or(false) {
+        variables.insert("filter".to_string(), filter);
+    }
+    Ok(variables)
+}
+
+/// Render an `issues list` result (count, JSON, grouped, or table)
+fn print_issue_list(
+    issues: Vec<serde_json::Value>,
+    group_by: Option<String>,
+    count_only: bool,
+    output: &OutputOptions,
+) -> Result<()> {
     if count_only {
         if output.is_json() || output.has_template() {
             print_json_owned(serde_json::json!({"count": issues.len()}), output)?;
@@ -682,6 +828,7 @@ async fn list_issues(
     }
 
     let width = display_options().max_width(50);
+    let fanout = crate::config::fanout_profiles().is_some();
 
     // Grouped output
     if let Some(ref group_field) = group_by {
@@ -723,7 +870,12 @@ async fn list_issues(
             for issue in group_issues {
                 let id = issue["identifier"].as_str().unwrap_or("");
                 let title = truncate(issue["title"].as_str().unwrap_or(""), width);
-                println!("  {} {}", id.cyan(), title);
+                if fanout {
+                    let workspace = crate::fanout::workspace_of(issue);
+                    println!("  {} {} {}", workspace.dimmed(), id.cyan(), title);
+                } else {
+                    println!("  {} {}", id.cyan(), title);
+                }
             }
         }
         println!("\n{} issues in {} groups", issues.len(), groups.len());
@@ -744,7 +896,13 @@ async fn list_issues(
         })
         .collect();
 
-    let table = Table::new(rows).to_string();
+    let mut table = Table::new(rows);
+    if fanout {
+        table = crate::fanout::with_workspace_column(
+            table,
+            issues.iter().map(crate::fanout::workspace_of),
+        );
+    }
     println!("{}", table);
     println!("\n{} issues", issues.len());
 
@@ -763,46 +921,56 @@ async fn get_issues(
         return get_issue(&ids[0], output, history, comments).await;
     }
 
-    let client = LinearClient::new()?;
+    let results: Vec<(String, Result<serde_json::Value>)> = if crate::mirror::is_offline() {
+        let mirror = crate::mirror::Mirror::open()?;
+        ids.iter()
+            .map(|id| {
+                let issue = mirror.issue_detail(id, false);
+                (id.clone(), issue.map(|i| json!({ "data": { "issue": i } })))
+            })
+            .collect()
+    } else {
+        let client = LinearClient::new()?;
 
-    // Limit concurrent requests to avoid rate limiting and socket exhaustion
-    use futures::stream::{self, StreamExt};
-    const MAX_CONCURRENT: usize = 10;
-
-    let results: Vec<_> = stream::iter(ids.iter().cloned())
-        .map(|id| {
-            let client = client.clone();
-            async move {
-                let query = r#"
-                    query($id: String!) {
-                        issue(id: $id) {
-                            id
-                            identifier
-                            title
-                            description
-                            priority
-                            url
-                            createdAt
-                            updatedAt
-                            state { name }
-                            team { name }
-                            assignee { name email }
-                            labels { nodes { name color } }
-                            project { name }
-                            parent { identifier title }
-                            children { nodes { identifier title state { name } } }
-                            dueDate
-                            estimate
+        // Limit concurrent requests to avoid rate limiting and socket exhaustion
+        use futures::stream::{self, StreamExt};
+        const MAX_CONCURRENT: usize = 10;
+
+        stream::iter(ids.iter().cloned())
+            .map(|id| {
+                let client = client.clone();
+                async move {
+                    let query = r#"
+                        query($id: String!) {
+                            issue(id: $id) {
+                                id
+                                identifier
+                                title
+                                description
+                                priority
+                                url
+                                createdAt
+                                updatedAt
+                                state { name }
+                                team { name }
+                                assignee { name email }
+                                labels { nodes { name color } }
+                                project { name }
+                                parent { identifier title }
+                                children { nodes { identifier title state { name } } }
+                                dueDate
+                                estimate
+                            }
                         }
-                    }
-                "#;
-                let result = client.query(query, Some(json!({ "id": id }))).await;
-                (id, result)
-            }
-        })
-        .buffer_unordered(MAX_CONCURRENT)
-        .collect()
-        .await;
+                    "#;
+                    let result = client.query(query, Some(json!({ "id": id }))).await;
+                    (id, result)
+                }
+            })
+            .buffer_unordered(client.fanout_limit(MAX_CONCURRENT))
+            .collect()
+            .await
+    };
 
     // JSON output: array of issues
     if output.is_json() || output.has_template() {
@@ -973,8 +1141,6 @@ fn format_history_entry(entry: &serde_json::Value) -> String {
 }
 
 async fn get_issue(id: &str, output: &OutputOptions, history: bool, comments: bool) -> Result<()> {
-    let client = LinearClient::new()?;
-
     let history_fragment = if history {
         r#"
                 history(first: 15) {
@@ -1051,7 +1217,16 @@ async fn get_issue(id: &str, output: &OutputOptions, history: bool, comments: bo
         history_fragment, comments_fragment
     );
 
-    let result = client.query(&query, Some(json!({ "id": id }))).await?;
+    let result = if crate::mirror::is_offline() {
+        if history {
+            eprintln!("{} History is not available offline", "!".yellow());
+        }
+        let issue = crate::mirror::Mirror::open()?.issue_detail(id, comments)?;
+        json!({ "data": { "issue": issue } })
+    } else {
+        let client = LinearClient::new()?;
+        client.query(&query, Some(json!({ "id": id }))).await?
+    };
     let issue = &result["data"]["issue"];
 
     if issue.is_null() {
@@ -1226,6 +1401,7 @@ async fn create_issue(
     labels: Vec<String>,
     due: Option<String>,
     estimate: Option<f64>,
+    project: Option<String>,
     output: &OutputOptions,
     agent_opts: AgentOptions,
     dry_run: bool,
@@ -1315,6 +1491,14 @@ async fn create_issue(
     if let Some(e) = estimate {
         input["estimate"] = json!(e);
     }
+    if let Some(ref p) = project {
+        if dry_run {
+            input["projectId"] = json!(p);
+        } else {
+            let project_id = crate::api::resolve_project_id(&client, p, &output.cache).await?;
+            input["projectId"] = json!(project_id);
+        }
+    }
 
     // Dry run: show what would be created without actually creating
     if dry_run {
@@ -1333,6 +1517,7 @@ async fn create_issue(
                         "labels": labels,
                         "dueDate": due,
                         "estimate": estimate,
+                        "project": project,
                     }
                 }),
                 output,
@@ -1368,6 +1553,9 @@ async fn create_issue(
             if let Some(e) = estimate {
                 println!("  Estimate:    {}", e);
             }
+            if let Some(ref p) = project {
+                println!("  Project:     {}", p);
+            }
         }
         return Ok(());
     }
@@ -1385,9 +1573,15 @@ async fn create_issue(
             }
         }
     "#;
+    let recover = r#"
+        query($id: String!) {
+            issue(id: $id) { id identifier title url }
+        }
+    "#;
 
+    let idempotency = Idempotency::client_id(&mut input, recover);
     let result = client
-        .mutate(mutation, Some(json!({ "input": input })))
+        .mutate_with(mutation, Some(json!({ "input": input })), idempotency)
         .await?;
 
     if result["data"]["issueCreate"]["success"].as_bool() == Some(true) {
@@ -1445,95 +1639,52 @@ async fn update_issue(
     output: &OutputOptions,
     agent_opts: AgentOptions,
 ) -> Result<()> {
-    let client = LinearClient::new()?;
-
-    let mut input = match data_json {
-        Some(Value::Object(map)) => Value::Object(map),
-        Some(_) => anyhow::bail!("--data must be a JSON object"),
-        None => json!({}),
+    let mut change = IssueChange {
+        input: match data_json {
+            Some(Value::Object(map)) => map,
+            Some(_) => anyhow::bail!("--data must be a JSON object"),
+            None => Map::new(),
+        },
+        state,
+        assignee,
+        labels,
+        ..Default::default()
     };
 
     if let Some(t) = title {
-        input["title"] = json!(t);
+        change.input.insert("title".to_string(), json!(t));
     }
     if let Some(d) = description {
-        input["description"] = json!(d);
+        change.input.insert("description".to_string(), json!(d));
     }
     if let Some(p) = priority {
-        input["priority"] = json!(p);
-    }
-    if let Some(s) = state {
-        if dry_run {
-            input["stateId"] = json!(s);
-        } else {
-            // Fetch the issue's team ID to resolve state name
-            let team_query = r#"
-                query($id: String!) {
-                    issue(id: $id) {
-                        team { id }
-                    }
-                }
-            "#;
-            let team_result = client.query(team_query, Some(json!({ "id": id }))).await?;
-            let issue_team_id = team_result["data"]["issue"]["team"]["id"]
-                .as_str()
-                .ok_or_else(|| anyhow::anyhow!("Could not determine team for issue {}", id))?;
-            let state_id = resolve_state_id(&client, issue_team_id, &s).await?;
-            input["stateId"] = json!(state_id);
-        }
-    }
-    if let Some(a) = assignee {
-        // Resolve user name/email to UUID (skip during dry-run to avoid API calls)
-        if dry_run {
-            input["assigneeId"] = json!(a);
-        } else {
-            let assignee_id = resolve_user_id(&client, &a, &output.cache).await?;
-            input["assigneeId"] = json!(assignee_id);
-        }
-    }
-    if !labels.is_empty() {
-        // Resolve label names to UUIDs (skip during dry-run to avoid API calls)
-        if dry_run {
-            input["labelIds"] = json!(labels);
-        } else {
-            let mut label_ids = Vec::new();
-            for label in &labels {
-                let label_id = resolve_label_id(&client, label, &output.cache).await?;
-                label_ids.push(label_id);
-            }
-            input["labelIds"] = json!(label_ids);
-        }
+        change.input.insert("priority".to_string(), json!(p));
     }
     if let Some(ref d) = due {
         // Support clearing due date with "none"
-        if d.eq_ignore_ascii_case("none") || d.eq_ignore_ascii_case("clear") {
-            input["dueDate"] = json!(null);
+        let value = if d.eq_ignore_ascii_case("none") || d.eq_ignore_ascii_case("clear") {
+            json!(null)
         } else if let Some(parsed) = crate::dates::parse_due_date(d) {
-            input["dueDate"] = json!(parsed);
+            json!(parsed)
         } else {
-            input["dueDate"] = json!(d);
-        }
+            json!(d)
+        };
+        change.input.insert("dueDate".to_string(), value);
     }
     if let Some(e) = estimate {
         // 0 clears the estimate
-        if e == 0.0 {
-            input["estimate"] = json!(null);
-        } else {
-            input["estimate"] = json!(e);
-        }
+        let value = if e == 0.0 { json!(null) } else { json!(e) };
+        change.input.insert("estimate".to_string(), value);
     }
-    if let Some(ref p) = project {
+    if let Some(p) = project {
         if p.eq_ignore_ascii_case("none") || p.eq_ignore_ascii_case("clear") {
-            input["projectId"] = json!(null);
-        } else if dry_run {
-            input["projectId"] = json!(p);
+            change.input.insert("projectId".to_string(), json!(null));
         } else {
-            let project_id = resolve_project_id(&client, p, &output.cache).await?;
-            input["projectId"] = json!(project_id);
+            change.project = Some(p);
         }
     }
 
-    if input.as_object().map(|o| o.is_empty()).unwrap_or(true) {
+    if change.is_empty() {
         if !agent_opts.quiet {
             println!("No updates specified.");
         }
@@ -1547,7 +1698,7 @@ async fn update_issue(
                     "dry_run": true,
                     "would_update": {
                         "id": id,
-                        "input": input,
+                        "input": change.preview_input(),
                     }
                 }),
                 output,
@@ -1559,21 +1710,23 @@ async fn update_issue(
         return Ok(());
     }
 
-    let mutation = r#"
-        mutation($id: String!, $input: IssueUpdateInput!) {
-            issueUpdate(id: $id, input: $input) {
-                success
-                issue {
-                    identifier
-                    title
-                }
-            }
-        }
-    "#;
+    if crate::mirror::is_offline() {
+        let action = OutboxAction::IssueUpdate { change };
+        return outbox::queue(id, None, action, "offline mode", output);
+    }
 
-    let result = client
-        .mutate(mutation, Some(json!({ "id": id, "input": input })))
-        .await?;
+    let client = LinearClient::new()?;
+    let result = match apply_change(&client, id, &change, &output.cache).await {
+        Ok(result) => result,
+        Err(e) if is_network_error(&e) => {
+            // Best effort: a flapping connection may still answer; otherwise the
+            // mirror's copy (if any) becomes the base
+            let base = server_updated_at(&client, id).await;
+            let action = OutboxAction::IssueUpdate { change };
+            return outbox::queue(id, base, action, "network unavailable", output);
+        }
+        Err(e) => return Err(e),
+    };
 
     if result["data"]["issueUpdate"]["success"].as_bool() == Some(true) {
         let issue = &result["data"]["issueUpdate"]["issue"];
@@ -2063,9 +2216,7 @@ async fn archive_issue(id: &str, archive: bool) -> Result<()> {
     Ok(())
 }
 
-async fn comment_issue(id: &str, body: &str) -> Result<()> {
-    let client = LinearClient::new()?;
-
+async fn comment_issue(id: &str, body: &str, output: &OutputOptions) -> Result<()> {
     let actual_body = if body == "-" {
         use std::io::Read;
         let mut buf = String::new();
@@ -2079,25 +2230,28 @@ async fn comment_issue(id: &str, body: &str) -> Result<()> {
         anyhow::bail!("Comment body cannot be empty");
     }
 
-    let mutation = r#"
-        mutation($input: CommentCreateInput!) {
-            commentCreate(input: $input) {
-                success
-                comment {
-                    id
-                    body
-                    issue { identifier }
-                }
-            }
-        }
-    "#;
+    // Generated once so a queued comment is pushed with the same id
+    let comment_id = crate::text::new_uuid();
+    if crate::mirror::is_offline() {
+        let action = OutboxAction::Comment {
+            body: actual_body,
+            comment_id: Some(comment_id),
+        };
+        return outbox::queue(id, None, action, "offline mode", output);
+    }
 
-    let result = client
-        .mutate(
-            mutation,
-            Some(json!({ "input": { "issueId": id, "body": actual_body } })),
-        )
-        .await?;
+    let client = LinearClient::new()?;
+    let result = match create_comment(&client, id, &actual_body, &comment_id).await {
+        Ok(result) => result,
+        Err(e) if is_network_error(&e) => {
+            let action = OutboxAction::Comment {
+                body: actual_body,
+                comment_id: Some(comment_id),
+            };
+            return outbox::queue(id, None, action, "network unavailable", output);
+        }
+        Err(e) => return Err(e),
+    };
 
     if result["data"]["commentCreate"]["success"].as_bool() == Some(true) {
         let comment = &result["data"]["commentCreate"]["comment"];
@@ -330,9 +330,6 @@ async fn create_label(
             label["name"].as_str().unwrap_or("")
         );
         println!("  ID: {}", label["id"].as_str().unwrap_or(""));
-
-        // Invalidate labels cache after successful create
-        let _ = Cache::new().and_then(|c| c.clear_type(CacheType::Labels));
     } else {
         anyhow::bail!("Failed to create label");
     }
@@ -378,9 +375,6 @@ async fn delete_label(id: &str, label_type: &str, force: bool) -> Result<()> {
 
     if result["data"][key]["success"].as_bool() == Some(true) {
         println!("{} Label deleted", "+".green());
-
-        // Invalidate labels cache after successful delete
-        let _ = Cache::new().and_then(|c| c.clear_type(CacheType::Labels));
     } else {
         anyhow::bail!("Failed to delete label");
     }
@@ -459,8 +453,6 @@ async fn update_label(
             label_type,
             label["name"].as_str().unwrap_or("")
         );
-
-        let _ = Cache::new().and_then(|c| c.clear_type(CacheType::Labels));
     } else {
         anyhow::bail!("Failed to update label");
     }
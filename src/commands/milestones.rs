@@ -17,7 +17,7 @@ pub enum MilestoneCommands {
     #[command(alias = "ls")]
     List {
         /// Project name or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_PROJECT")]
         project: String,
     },
     /// Get milestone details
@@ -30,7 +30,7 @@ pub enum MilestoneCommands {
         /// Milestone name
         name: String,
         /// Project name or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_PROJECT")]
         project: String,
         /// Description
         #[arg(short, long)]
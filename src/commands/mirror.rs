@@ -0,0 +1,297 @@
+use anyhow::Result;
+use clap::Subcommand;
+use colored::Colorize;
+use serde_json::{json, Map, Value};
+use tabled::{Table, Tabled};
+
+use crate::api::LinearClient;
+use crate::mirror::{max_updated_at, Mirror, MirrorCursor, MirrorKind};
+use crate::output::{print_json_owned, OutputOptions};
+use crate::pagination::{paginate_nodes, PaginationOptions};
+
+const ISSUES_QUERY: &str = r#"
+    query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+        issues(first: $first, after: $after, last: $last, before: $before, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+            nodes {
+                id
+                identifier
+                title
+                description
+                priority
+                estimate
+                dueDate
+                url
+                createdAt
+                updatedAt
+                archivedAt
+                state { name type }
+                team { id key name }
+                assignee { id name email }
+                labels { nodes { name color } }
+                project { id name }
+                cycle { number name }
+                parent { identifier title }
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+                hasPreviousPage
+                startCursor
+            }
+        }
+    }
+"#;
+
+const COMMENTS_QUERY: &str = r#"
+    query($filter: CommentFilter, $first: Int, $after: String, $last: Int, $before: String) {
+        comments(first: $first, after: $after, last: $last, before: $before, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+            nodes {
+                id
+                body
+                createdAt
+                updatedAt
+                user { name }
+                issue { id identifier }
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+                hasPreviousPage
+                startCursor
+            }
+        }
+    }
+"#;
+
+const PROJECTS_QUERY: &str = r#"
+    query($filter: ProjectFilter, $first: Int, $after: String, $last: Int, $before: String) {
+        projects(first: $first, after: $after, last: $last, before: $before, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+            nodes {
+                id
+                name
+                description
+                state
+                priority
+                progress
+                startDate
+                targetDate
+                url
+                createdAt
+                updatedAt
+                archivedAt
+                status { name }
+                lead { name email }
+                teams { nodes { key name } }
+                members { nodes { name } }
+                labels { nodes { name } }
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+                hasPreviousPage
+                startCursor
+            }
+        }
+    }
+"#;
+
+#[derive(Subcommand)]
+pub enum MirrorCommands {
+    /// Fetch issues, comments and projects changed since the last pull
+    ///
+    /// Incremental pulls only see records that still exist, so anything deleted
+    /// on the server stays in the mirror until the next `--full` pull.
+    #[command(after_help = r#"EXAMPLES:
+    linear mirror pull                      # Incremental sync
+    linear mirror pull --full               # Rebuild from scratch (drops deleted records)"#)]
+    Pull {
+        /// Ignore saved cursors and re-download everything, dropping deleted records
+        #[arg(long)]
+        full: bool,
+    },
+    /// Show what is stored locally and when it was last synced
+    Status,
+    /// Delete the local mirror for the current profile
+    Clear,
+}
+
+#[derive(Tabled)]
+struct MirrorStatusRow {
+    #[tabled(rename = "Type")]
+    kind: String,
+    #[tabled(rename = "Records")]
+    records: String,
+    #[tabled(rename = "Updated Through")]
+    updated_at: String,
+    #[tabled(rename = "Last Pull")]
+    synced_at: String,
+    #[tabled(rename = "Size")]
+    size: String,
+}
+
+pub async fn handle(cmd: MirrorCommands, output: &OutputOptions) -> Result<()> {
+    match cmd {
+        MirrorCommands::Pull { full } => pull(full, output).await,
+        MirrorCommands::Status => status(output),
+        MirrorCommands::Clear => {
+            Mirror::open()?.clear()?;
+            println!("{} Cleared offline mirror", "+".green());
+            Ok(())
+        }
+    }
+}
+
+fn kind_query(kind: MirrorKind) -> (&'static str, &'static str) {
+    match kind {
+        MirrorKind::Issues => (ISSUES_QUERY, "issues"),
+        MirrorKind::Comments => (COMMENTS_QUERY, "comments"),
+        MirrorKind::Projects => (PROJECTS_QUERY, "projects"),
+    }
+}
+
+async fn pull(full: bool, output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    let mirror = Mirror::open()?;
+    let mut state = mirror.state()?;
+
+    let viewer = client
+        .query("query { viewer { id name email } }", None)
+        .await?;
+    state.viewer = Some(viewer["data"]["viewer"].clone());
+
+    let mut summary = Map::new();
+    for kind in MirrorKind::all() {
+        let since = if full {
+            None
+        } else {
+            state.cursor(*kind).and_then(|c| c.updated_at.clone())
+        };
+
+        if !output.is_json() {
+            eprintln!("Pulling {}...", kind.key());
+        }
+
+        let (query, root) = kind_query(*kind);
+        let mut vars = Map::new();
+        // `gte` so records sharing the cursor's timestamp are not skipped; `merge`
+        // upserts by id, so the ones already stored are simply replaced
+        if let Some(ref ts) = since {
+            vars.insert("filter".to_string(), json!({ "updatedAt": { "gte": ts } }));
+        }
+        let pagination = PaginationOptions {
+            all: true,
+            ..Default::default()
+        };
+        let records = paginate_nodes(
+            &client,
+            query,
+            vars,
+            &["data", root, "nodes"],
+            &["data", root, "pageInfo"],
+            &pagination,
+            100,
+        )
+        .await?;
+
+        let fetched = records.len();
+        let newest = max_updated_at(&records);
+        let total = if full {
+            mirror.save(*kind, &records)?;
+            fetched
+        } else {
+            mirror.merge(*kind, records)?
+        };
+
+        let cursor = MirrorCursor {
+            updated_at: newest.or(since),
+            synced_at: Some(chrono::Utc::now().to_rfc3339()),
+        };
+        summary.insert(
+            kind.key().to_string(),
+            json!({
+                "fetched": fetched,
+                "total": total,
+                "updatedAt": cursor.updated_at,
+            }),
+        );
+        state.cursors.insert(kind.key().to_string(), cursor);
+        // Save after each type so an interrupted pull keeps its progress
+        mirror.save_state(&state)?;
+    }
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(Value::Object(summary), output)?;
+        return Ok(());
+    }
+
+    for kind in MirrorKind::all() {
+        let entry = &summary[kind.key()];
+        println!(
+            "{} {}: {} changed, {} stored",
+            "+".green(),
+            kind.display_name(),
+            entry["fetched"],
+            entry["total"]
+        );
+    }
+    Ok(())
+}
+
+fn status(output: &OutputOptions) -> Result<()> {
+    let mirror = Mirror::open()?;
+    let state = mirror.state()?;
+
+    let mut entries = Vec::new();
+    for kind in MirrorKind::all() {
+        let cursor = state.cursor(*kind).cloned().unwrap_or_default();
+        let records = mirror.load(*kind)?.len();
+        entries.push(json!({
+            "type": kind.key(),
+            "records": records,
+            "updatedAt": cursor.updated_at,
+            "syncedAt": cursor.synced_at,
+            "sizeBytes": mirror.size_bytes(*kind),
+        }));
+    }
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({
+                "path": Mirror::dir_path()?.display().to_string(),
+                "types": entries,
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    let rows: Vec<MirrorStatusRow> = entries
+        .iter()
+        .map(|e| MirrorStatusRow {
+            kind: e["type"].as_str().unwrap_or("").to_string(),
+            records: e["records"].to_string(),
+            updated_at: e["updatedAt"].as_str().unwrap_or("-").to_string(),
+            synced_at: e["syncedAt"]
+                .as_str()
+                .map(|s| s.chars().take(19).collect())
+                .unwrap_or_else(|| "never".to_string()),
+            size: format_size(e["sizeBytes"].as_u64().unwrap_or(0)),
+        })
+        .collect();
+
+    println!("{}", "Offline Mirror".bold());
+    println!("{}", "-".repeat(50));
+    println!("{}", Table::new(rows));
+    println!("\nPath: {}", Mirror::dir_path()?.display());
+    Ok(())
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
@@ -5,6 +5,7 @@ pub mod bulk;
 pub mod cache;
 pub mod comments;
 pub mod cycles;
+pub mod dev;
 pub mod doctor;
 pub mod documents;
 pub mod export;
@@ -18,7 +19,9 @@ pub mod issues;
 pub mod labels;
 pub mod metrics;
 pub mod milestones;
+pub mod mirror;
 pub mod notifications;
+pub mod outbox;
 pub mod project_updates;
 pub mod projects;
 pub mod relations;
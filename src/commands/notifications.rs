@@ -83,44 +83,15 @@ fn format_notification_type(notification_type: &str) -> String {
 }
 
 async fn list_notifications(include_all: bool, output: &OutputOptions) -> Result<()> {
-    let client = LinearClient::new()?;
-
-    let query = r#"
-        query($first: Int, $after: String, $last: Int, $before: String) {
-            notifications(first: $first, after: $after, last: $last, before: $before) {
-                nodes {
-                    id
-                    type
-                    createdAt
-                    readAt
-                    ... on IssueNotification {
-                        issue {
-                            identifier
-                            title
-                        }
-                    }
-                }
-                pageInfo {
-                    hasNextPage
-                    endCursor
-                    hasPreviousPage
-                    startCursor
-                }
-            }
-        }
-    "#;
-
     let pagination = output.pagination.with_default_limit(50);
-    let notifications = paginate_nodes(
-        &client,
-        query,
-        serde_json::Map::new(),
-        &["data", "notifications", "nodes"],
-        &["data", "notifications", "pageInfo"],
-        &pagination,
-        50,
-    )
-    .await?;
+    let notifications = match crate::config::fanout_profiles() {
+        Some(profiles) => {
+            let pagination = &pagination;
+            crate::fanout::collect(profiles, move || fetch_notifications(pagination)).await?
+        }
+        None => fetch_notifications(&pagination).await?,
+    };
+    let fanout = crate::config::fanout_profiles().is_some();
 
     let mut filtered: Vec<_> = if include_all {
         notifications.clone()
@@ -167,9 +138,14 @@ async fn list_notifications(include_all: bool, output: &OutputOptions) -> Result
     println!("{}", "-".repeat(60));
 
     let width = display_options().max_width(40);
+    let mut workspaces = Vec::new();
     let rows: Vec<NotificationRow> = filtered
         .iter()
-        .filter_map(|v| serde_json::from_value::<Notification>(v.clone()).ok())
+        .filter_map(|v| {
+            let n = serde_json::from_value::<Notification>(v.clone()).ok()?;
+            workspaces.push(crate::fanout::workspace_of(v));
+            Some(n)
+        })
         .map(|n| {
             let notification_type = n.notification_type.as_deref().unwrap_or("unknown");
             let issue_identifier = n
@@ -211,13 +187,56 @@ async fn list_notifications(include_all: bool, output: &OutputOptions) -> Result
         })
         .collect();
 
-    let table = Table::new(rows).to_string();
+    let mut table = Table::new(rows);
+    if fanout {
+        table = crate::fanout::with_workspace_column(table, workspaces);
+    }
     println!("{}", table);
     println!("\n{} notifications shown", filtered.len());
 
     Ok(())
 }
 
+async fn fetch_notifications(pagination: &PaginationOptions) -> Result<Vec<serde_json::Value>> {
+    let client = LinearClient::new()?;
+
+    let query = r#"
+        query($first: Int, $after: String, $last: Int, $before: String) {
+            notifications(first: $first, after: $after, last: $last, before: $before) {
+                nodes {
+                    id
+                    type
+                    createdAt
+                    readAt
+                    ... on IssueNotification {
+                        issue {
+                            identifier
+                            title
+                        }
+                    }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                    hasPreviousPage
+                    startCursor
+                }
+            }
+        }
+    "#;
+
+    paginate_nodes(
+        &client,
+        query,
+        serde_json::Map::new(),
+        &["data", "notifications", "nodes"],
+        &["data", "notifications", "pageInfo"],
+        pagination,
+        50,
+    )
+    .await
+}
+
 async fn mark_as_read(id: &str) -> Result<()> {
     let client = LinearClient::new()?;
 
@@ -0,0 +1,354 @@
+use anyhow::Result;
+use clap::Subcommand;
+use colored::Colorize;
+use dialoguer::Confirm;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tabled::{Table, Tabled};
+
+use crate::api::LinearClient;
+use crate::outbox::{
+    apply_change, create_comment, is_conflict, is_network_error, Outbox, OutboxAction, OutboxEntry,
+};
+use crate::output::{print_json_owned, OutputOptions};
+use crate::text::truncate;
+
+#[derive(Subcommand)]
+pub enum OutboxCommands {
+    /// Send queued mutations, skipping issues changed on the server since they were queued
+    #[command(after_help = r#"EXAMPLES:
+    linear outbox push                      # Replay queued changes
+    linear outbox push --force              # Overwrite conflicting server changes"#)]
+    Push {
+        /// Apply changes even if the issue was updated on the server after queueing
+        #[arg(long)]
+        force: bool,
+    },
+    /// List queued mutations
+    #[command(alias = "ls")]
+    List,
+    /// Remove one queued mutation without sending it
+    #[command(alias = "rm")]
+    Drop {
+        /// Outbox entry ID (see `outbox list`)
+        id: u64,
+    },
+    /// Remove all queued mutations without sending them
+    Clear {
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+#[derive(Tabled)]
+struct OutboxRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Issue")]
+    issue: String,
+    #[tabled(rename = "Action")]
+    action: String,
+    #[tabled(rename = "Details")]
+    details: String,
+    #[tabled(rename = "Queued")]
+    created_at: String,
+}
+
+#[derive(Tabled)]
+struct PushRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Issue")]
+    issue: String,
+    #[tabled(rename = "Action")]
+    action: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+pub async fn handle(cmd: OutboxCommands, output: &OutputOptions) -> Result<()> {
+    match cmd {
+        OutboxCommands::Push { force } => push(force, output).await,
+        OutboxCommands::List => list(output),
+        OutboxCommands::Drop { id } => {
+            if !Outbox::open()?.remove(id)? {
+                anyhow::bail!("Outbox entry not found: {}", id);
+            }
+            println!("{} Dropped outbox entry {}", "+".green(), id);
+            Ok(())
+        }
+        OutboxCommands::Clear { force } => {
+            let outbox = Outbox::open()?;
+            let count = outbox.load()?.len();
+            if count > 0 && !force && !crate::is_yes() {
+                let confirmed = Confirm::new()
+                    .with_prompt(format!("Discard {} queued change(s)?", count))
+                    .default(false)
+                    .interact()?;
+                if !confirmed {
+                    return Ok(());
+                }
+            }
+            outbox.save(&[])?;
+            println!("{} Cleared {} outbox entries", "+".green(), count);
+            Ok(())
+        }
+    }
+}
+
+/// Journal a mutation that could not be sent and tell the user how to replay it
+pub fn queue(
+    issue: &str,
+    base_updated_at: Option<String>,
+    action: OutboxAction,
+    reason: &str,
+    output: &OutputOptions,
+) -> Result<()> {
+    let entry = Outbox::open()?.enqueue(issue, base_updated_at, action)?;
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({
+                "queued": true,
+                "reason": reason,
+                "outbox_id": entry.id,
+                "issue": entry.issue,
+                "type": entry.action.label(),
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    println!(
+        "{} Queued {} for {} ({}), outbox entry {}",
+        "~".yellow(),
+        entry.action.label(),
+        entry.issue.cyan(),
+        reason,
+        entry.id
+    );
+    println!("  Run `linear-cli outbox push` once you are back online.");
+    Ok(())
+}
+
+fn list(output: &OutputOptions) -> Result<()> {
+    let entries = Outbox::open()?.load()?;
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(serde_json::to_value(&entries)?, output)?;
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("Outbox is empty.");
+        return Ok(());
+    }
+
+    let width = crate::display_options().max_width(50);
+    let rows: Vec<OutboxRow> = entries
+        .iter()
+        .map(|e| OutboxRow {
+            id: e.id.to_string(),
+            issue: e.issue.clone(),
+            action: e.action.label().to_string(),
+            details: truncate(&e.action.describe(), width),
+            created_at: e.created_at.chars().take(19).collect(),
+        })
+        .collect();
+    println!("{}", Table::new(rows));
+    println!("\n{} queued change(s)", entries.len());
+    Ok(())
+}
+
+/// Outcome of replaying one entry
+enum PushOutcome {
+    Pushed,
+    Conflict { server_updated_at: String },
+    Failed(String),
+    Unreachable,
+}
+
+async fn push(force: bool, output: &OutputOptions) -> Result<()> {
+    let outbox = Outbox::open()?;
+    let entries = outbox.load()?;
+    if entries.is_empty() {
+        if output.is_json() || output.has_template() {
+            print_json_owned(json!({ "results": [], "remaining": 0 }), output)?;
+        } else {
+            println!("Outbox is empty.");
+        }
+        return Ok(());
+    }
+
+    let client = LinearClient::new()?;
+    // Issue UUID -> (updatedAt before our push, updatedAt after it), so later entries
+    // for the same issue are not reported as conflicting with our own earlier changes
+    let mut applied: HashMap<String, (Option<String>, Option<String>)> = HashMap::new();
+    let mut remaining: Vec<OutboxEntry> = Vec::new();
+    let mut results: Vec<(OutboxEntry, PushOutcome)> = Vec::new();
+    let mut offline = false;
+
+    for entry in entries {
+        if offline {
+            results.push((entry, PushOutcome::Unreachable));
+            continue;
+        }
+        let outcome = push_entry(&client, &entry, force, &mut applied, output).await;
+        offline = matches!(outcome, PushOutcome::Unreachable);
+        results.push((entry, outcome));
+    }
+
+    for (entry, outcome) in &results {
+        if !matches!(outcome, PushOutcome::Pushed) {
+            remaining.push(entry.clone());
+        }
+    }
+    outbox.save(&remaining)?;
+
+    if output.is_json() || output.has_template() {
+        let items: Vec<Value> = results
+            .iter()
+            .map(|(entry, outcome)| {
+                let (status, detail) = match outcome {
+                    PushOutcome::Pushed => ("pushed", Value::Null),
+                    PushOutcome::Conflict { server_updated_at } => (
+                        "conflict",
+                        json!({
+                            "baseUpdatedAt": entry.base_updated_at,
+                            "serverUpdatedAt": server_updated_at,
+                        }),
+                    ),
+                    PushOutcome::Failed(e) => ("failed", json!(e)),
+                    PushOutcome::Unreachable => ("unreachable", Value::Null),
+                };
+                json!({
+                    "id": entry.id,
+                    "issue": entry.issue,
+                    "type": entry.action.label(),
+                    "status": status,
+                    "detail": detail,
+                })
+            })
+            .collect();
+        print_json_owned(
+            json!({ "results": items, "remaining": remaining.len() }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    let rows: Vec<PushRow> = results
+        .iter()
+        .map(|(entry, outcome)| PushRow {
+            id: entry.id.to_string(),
+            issue: entry.issue.clone(),
+            action: entry.action.label().to_string(),
+            result: match outcome {
+                PushOutcome::Pushed => "pushed".green().to_string(),
+                PushOutcome::Conflict { server_updated_at } => format!(
+                    "{} (server changed {})",
+                    "conflict".yellow(),
+                    server_updated_at
+                ),
+                PushOutcome::Failed(e) => format!(
+                    "{}: {}",
+                    "failed".red(),
+                    truncate(e, crate::display_options().max_width(60))
+                ),
+                PushOutcome::Unreachable => "network unavailable".dimmed().to_string(),
+            },
+        })
+        .collect();
+    println!("{}", Table::new(rows));
+
+    let pushed = results.len() - remaining.len();
+    println!(
+        "\n{} Pushed {}, {} left in outbox",
+        ">>".cyan(),
+        pushed.to_string().green(),
+        remaining.len()
+    );
+    if results
+        .iter()
+        .any(|(_, o)| matches!(o, PushOutcome::Conflict { .. }))
+    {
+        println!(
+            "   Conflicting issues changed on the server after the change was queued,\n   or were queued without a known version to compare against.\n   Review them, then `linear-cli outbox push --force` or `linear-cli outbox drop <ID>`."
+        );
+    }
+    Ok(())
+}
+
+async fn push_entry(
+    client: &LinearClient,
+    entry: &OutboxEntry,
+    force: bool,
+    applied: &mut HashMap<String, (Option<String>, Option<String>)>,
+    output: &OutputOptions,
+) -> PushOutcome {
+    let query = r#"
+        query($id: String!) {
+            issue(id: $id) {
+                id
+                identifier
+                updatedAt
+            }
+        }
+    "#;
+    let current = match client
+        .query(query, Some(json!({ "id": entry.issue })))
+        .await
+    {
+        Ok(result) => result["data"]["issue"].clone(),
+        Err(e) if is_network_error(&e) => return PushOutcome::Unreachable,
+        Err(e) => return PushOutcome::Failed(e.to_string()),
+    };
+    let Some(uuid) = current["id"].as_str().map(|s| s.to_string()) else {
+        return PushOutcome::Failed(format!("Issue not found: {}", entry.issue));
+    };
+    let server_updated_at = current["updatedAt"].as_str().map(|s| s.to_string());
+
+    let mut base = entry.base_updated_at.clone();
+    if let Some((before, after)) = applied.get(&uuid) {
+        if base == *before {
+            base = after.clone();
+        }
+    }
+
+    let result = match &entry.action {
+        OutboxAction::IssueUpdate { change } => {
+            if !force && is_conflict(base.as_deref(), server_updated_at.as_deref()) {
+                return PushOutcome::Conflict {
+                    server_updated_at: server_updated_at.unwrap_or_default(),
+                };
+            }
+            apply_change(client, &uuid, change, &output.cache)
+                .await
+                .map(|r| r["data"]["issueUpdate"].clone())
+        }
+        // Comments only append, so they cannot overwrite anything on the server
+        OutboxAction::Comment { body, comment_id } => {
+            // Entries queued before ids were stored get one for this push
+            let comment_id = comment_id.clone().unwrap_or_else(crate::text::new_uuid);
+            create_comment(client, &uuid, body, &comment_id)
+                .await
+                .map(|r| r["data"]["commentCreate"].clone())
+        }
+    };
+
+    match result {
+        Ok(payload) if payload["success"].as_bool() == Some(true) => {
+            let updated_at = payload["issue"]["updatedAt"]
+                .as_str()
+                .or(payload["comment"]["issue"]["updatedAt"].as_str())
+                .map(|s| s.to_string());
+            applied.insert(uuid, (server_updated_at, updated_at));
+            PushOutcome::Pushed
+        }
+        Ok(_) => PushOutcome::Failed("Mutation was not applied".to_string()),
+        Err(e) if is_network_error(&e) => PushOutcome::Unreachable,
+        Err(e) => PushOutcome::Failed(e.to_string()),
+    }
+}
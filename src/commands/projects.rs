@@ -4,7 +4,7 @@ use colored::Colorize;
 use serde_json::json;
 use tabled::{Table, Tabled};
 
-use crate::api::{resolve_project_id, resolve_team_id, resolve_user_id, LinearClient};
+use crate::api::{resolve_project_id, resolve_team_id, resolve_user_id, Idempotency, LinearClient};
 use crate::cache::{Cache, CacheType};
 use crate::display_options;
 use crate::input::read_ids_from_stdin;
@@ -59,7 +59,7 @@ pub enum ProjectCommands {
         /// Project name
         name: String,
         /// Team name or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Project description
         #[arg(short, long)]
@@ -776,8 +776,15 @@ async fn create_project(
         }
     "#;
 
+    let recover = r#"
+        query($id: String!) {
+            project(id: $id) { id name url }
+        }
+    "#;
+
+    let idempotency = Idempotency::client_id(&mut input, recover);
     let result = client
-        .mutate(mutation, Some(json!({ "input": input })))
+        .mutate_with(mutation, Some(json!({ "input": input })), idempotency)
         .await?;
 
     if result["data"]["projectCreate"]["success"].as_bool() == Some(true) {
@@ -796,9 +803,6 @@ async fn create_project(
         );
         println!("  ID: {}", project["id"].as_str().unwrap_or(""));
         println!("  URL: {}", project["url"].as_str().unwrap_or(""));
-
-        // Invalidate projects cache after successful create
-        let _ = Cache::new().and_then(|c| c.clear_type(CacheType::Projects));
     } else {
         anyhow::bail!("Failed to create project");
     }
@@ -914,9 +918,6 @@ async fn update_project(
         }
 
         println!("{} Project updated", "+".green());
-
-        // Invalidate projects cache after successful update
-        let _ = Cache::new().and_then(|c| c.clear_type(CacheType::Projects));
     } else {
         anyhow::bail!("Failed to update project");
     }
@@ -945,9 +946,6 @@ async fn delete_project(id: &str, force: bool) -> Result<()> {
 
     if result["data"]["projectDelete"]["success"].as_bool() == Some(true) {
         println!("{} Project deleted", "+".green());
-
-        // Invalidate projects cache after successful delete
-        let _ = Cache::new().and_then(|c| c.clear_type(CacheType::Projects));
     } else {
         anyhow::bail!("Failed to delete project");
     }
@@ -997,9 +995,6 @@ async fn archive_project(id: &str, archive: bool, output: &OutputOptions) -> Res
         } else {
             println!("{} {} project: {}", "+".green(), action, id.cyan());
         }
-
-        // Invalidate projects cache after successful archive/unarchive
-        let _ = Cache::new().and_then(|c| c.clear_type(CacheType::Projects));
     } else {
         let action = if archive { "archive" } else { "unarchive" };
         anyhow::bail!("Failed to {} project: {}", action, id);
@@ -5,6 +5,7 @@ use tabled::{Table, Tabled};
 
 use crate::api::LinearClient;
 use crate::display_options;
+use crate::mirror::IssueQuery;
 use crate::output::{
     ensure_non_empty, filter_values, print_json_owned, sort_values, OutputOptions,
 };
@@ -67,8 +68,6 @@ pub async fn handle(cmd: SearchCommands, output: &OutputOptions) -> Result<()> {
 }
 
 async fn search_issues(query: &str, include_archived: bool, output: &OutputOptions) -> Result<()> {
-    let client = LinearClient::new()?;
-
     let graphql_query = r#"
         query($first: Int, $after: String, $last: Int, $before: String, $includeArchived: Boolean, $filter: IssueFilter) {
             issues(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived, filter: $filter) {
@@ -102,16 +101,44 @@ async fn search_issues(query: &str, include_archived: bool, output: &OutputOptio
     );
 
     let pagination = output.pagination.with_default_limit(50);
-    let mut issues = paginate_nodes(
-        &client,
-        graphql_query,
-        variables,
-        &["data", "issues", "nodes"],
-        &["data", "issues", "pageInfo"],
-        &pagination,
-        50,
-    )
-    .await?;
+    let fanout = crate::config::fanout_profiles();
+    let mut issues = if let Some(profiles) = fanout {
+        if crate::mirror::is_offline() {
+            anyhow::bail!("--offline cannot be combined with multiple profiles");
+        }
+        let (variables, pagination) = (&variables, &pagination);
+        crate::fanout::collect(profiles, move || async move {
+            paginate_nodes(
+                &LinearClient::new()?,
+                graphql_query,
+                variables.clone(),
+                &["data", "issues", "nodes"],
+                &["data", "issues", "pageInfo"],
+                pagination,
+                50,
+            )
+            .await
+        })
+        .await?
+    } else if crate::mirror::is_offline() {
+        let local_query = IssueQuery {
+            text: Some(query.to_string()),
+            include_archived,
+            ..Default::default()
+        };
+        crate::mirror::query_issues(&local_query, &pagination)?
+    } else {
+        paginate_nodes(
+            &LinearClient::new()?,
+            graphql_query,
+            variables,
+            &["data", "issues", "nodes"],
+            &["data", "issues", "pageInfo"],
+            &pagination,
+            50,
+        )
+        .await?
+    };
 
     if output.is_json() || output.has_template() {
         print_json_owned(serde_json::json!(issues), output)?;
@@ -152,7 +179,13 @@ async fn search_issues(query: &str, include_archived: bool, output: &OutputOptio
         })
         .collect();
 
-    let table = Table::new(rows).to_string();
+    let mut table = Table::new(rows);
+    if fanout.is_some() {
+        table = crate::fanout::with_workspace_column(
+            table,
+            issues.iter().map(crate::fanout::workspace_of),
+        );
+    }
     println!("{}", table);
     println!("\n{} issues found", issues.len());
 
@@ -164,8 +197,6 @@ async fn search_projects(
     include_archived: bool,
     output: &OutputOptions,
 ) -> Result<()> {
-    let client = LinearClient::new()?;
-
     let graphql_query = r#"
         query($first: Int, $after: String, $last: Int, $before: String, $includeArchived: Boolean, $filter: ProjectFilter) {
             projects(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived, filter: $filter) {
@@ -195,16 +226,20 @@ async fn search_projects(
     );
 
     let pagination = output.pagination.with_default_limit(50);
-    let mut projects = paginate_nodes(
-        &client,
-        graphql_query,
-        variables,
-        &["data", "projects", "nodes"],
-        &["data", "projects", "pageInfo"],
-        &pagination,
-        50,
-    )
-    .await?;
+    let mut projects = if crate::mirror::is_offline() {
+        crate::mirror::query_projects(query, include_archived, &pagination)?
+    } else {
+        paginate_nodes(
+            &LinearClient::new()?,
+            graphql_query,
+            variables,
+            &["data", "projects", "nodes"],
+            &["data", "projects", "pageInfo"],
+            &pagination,
+            50,
+        )
+        .await?
+    };
 
     if output.is_json() || output.has_template() {
         print_json_owned(serde_json::json!(projects), output)?;
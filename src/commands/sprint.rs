@@ -13,25 +13,25 @@ pub enum SprintCommands {
     /// Show current sprint status and progress
     Status {
         /// Team key, name, or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
     },
     /// Show sprint progress (completion %)
     Progress {
         /// Team key, name, or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
     },
     /// List issues planned for next cycle
     Plan {
         /// Team key, name, or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
     },
     /// Move incomplete issues from current cycle to next
     CarryOver {
         /// Team key, name, or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Skip confirmation
         #[arg(short, long)]
@@ -40,7 +40,7 @@ pub enum SprintCommands {
     /// Show ASCII burndown chart for current sprint
     Burndown {
         /// Team key, name, or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Chart width in characters (default: 60)
         #[arg(long, default_value = "60")]
@@ -52,7 +52,7 @@ pub enum SprintCommands {
     /// Show sprint velocity across recent cycles
     Velocity {
         /// Team key, name, or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Number of past cycles to analyze (default: 6)
         #[arg(short = 'n', long, default_value = "6")]
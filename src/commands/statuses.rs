@@ -4,7 +4,7 @@ use colored::Colorize;
 use serde_json::{json, Value};
 use tabled::{Table, Tabled};
 
-use crate::api::{resolve_state_id, resolve_team_id, LinearClient};
+use crate::api::{cached_statuses, resolve_state_id, resolve_team_id, LinearClient};
 use crate::cache::{Cache, CacheType};
 use crate::display_options;
 use crate::input::read_ids_from_stdin;
@@ -20,7 +20,7 @@ pub enum StatusCommands {
     #[command(alias = "ls")]
     List {
         /// Team name or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
     },
     /// Get details of a specific status
@@ -28,7 +28,7 @@ pub enum StatusCommands {
         /// Status name(s) or ID(s). Use "-" to read from stdin.
         ids: Vec<String>,
         /// Team name or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
     },
     /// Update a workflow state
@@ -36,7 +36,7 @@ pub enum StatusCommands {
         /// Status name or ID
         id: String,
         /// Team name or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// New name
         #[arg(short, long)]
@@ -105,8 +105,7 @@ async fn list_statuses(team: &str, output: &OutputOptions) -> Result<()> {
         && output.pagination.limit.is_none();
 
     let (team_name, states): (String, Vec<Value>) = if can_use_cache {
-        let cache = Cache::with_ttl(output.cache.effective_ttl_seconds())?;
-        if let Some(cached) = cache.get_keyed(CacheType::Statuses, &team_id) {
+        if let Some(cached) = cached_statuses(&client, &team_id, &output.cache) {
             let name = cached["team_name"].as_str().unwrap_or("").to_string();
             let states_data = cached["states"].as_array().cloned().unwrap_or_default();
             (name, states_data)
@@ -395,9 +394,6 @@ async fn update_status(
         }
 
         println!("{} Status updated", "+".green());
-
-        // Invalidate statuses cache after successful update
-        let _ = Cache::new().and_then(|c| c.clear_type(CacheType::Statuses));
     } else {
         anyhow::bail!("Failed to update status");
     }
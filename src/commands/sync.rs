@@ -6,7 +6,7 @@ use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
-use crate::api::{resolve_team_id, LinearClient};
+use crate::api::{resolve_team_id, Idempotency, LinearClient};
 use crate::cache::{Cache, CacheType};
 use crate::display_options;
 use crate::output::{print_json_owned, OutputOptions};
@@ -45,7 +45,7 @@ pub enum SyncCommands {
         #[arg(short, long)]
         directory: Option<String>,
         /// Team name or ID to create projects in
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: String,
         /// Only push specific folders (comma-separated)
         #[arg(short, long)]
@@ -159,6 +159,7 @@ fn scan_local_projects(dir: &str) -> Result<Vec<LocalProject>> {
     }
 
     // Sort alphabetically
+    #[allow(clippy::unnecessary_sort_by)]
     projects.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
 
     Ok(projects)
@@ -655,7 +656,7 @@ async fn create_linear_project(
         "Local project synced from a local workspace".to_string()
     };
 
-    let input = json!({
+    let mut input = json!({
         "name": name,
         "teamIds": [team],
         "description": description
@@ -674,8 +675,15 @@ async fn create_linear_project(
         }
     "#;
 
+    let recover = r#"
+        query($id: String!) {
+            project(id: $id) { id name url }
+        }
+    "#;
+
+    let idempotency = Idempotency::client_id(&mut input, recover);
     let result = client
-        .mutate(mutation, Some(json!({ "input": input })))
+        .mutate_with(mutation, Some(json!({ "input": input })), idempotency)
         .await?;
 
     if result["data"]["projectCreate"]["success"].as_bool() == Some(true) {
@@ -640,6 +640,7 @@ async fn remote_get_template(id: &str, output: &OutputOptions) -> Result<()> {
 }
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod tests {
     use super::*;
 
@@ -4,7 +4,6 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::fs;
-use std::io::Write;
 use std::path::PathBuf;
 use std::process::{Command, ExitStatus, Stdio};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -436,30 +435,7 @@ fn save_update_state(state: &UpdateState) -> Result<()> {
     fs::create_dir_all(dir)?;
 
     let content = serde_json::to_string_pretty(state)?;
-    let temp_path = path.with_extension("tmp");
-
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::OpenOptionsExt;
-        let mut file = fs::OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .mode(0o600)
-            .open(&temp_path)?;
-        file.write_all(content.as_bytes())?;
-        file.sync_all()?;
-    }
-
-    #[cfg(not(unix))]
-    {
-        let mut file = fs::File::create(&temp_path)?;
-        file.write_all(content.as_bytes())?;
-        file.sync_all()?;
-    }
-
-    fs::rename(&temp_path, &path)?;
-    Ok(())
+    crate::cache::write_atomic(&path, &content)
 }
 
 fn update_state_path() -> Result<PathBuf> {
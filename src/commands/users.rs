@@ -42,6 +42,20 @@ struct UserRow {
     id: String,
 }
 
+#[derive(Tabled)]
+struct ViewerRow {
+    #[tabled(rename = "Workspace")]
+    workspace: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Email")]
+    email: String,
+    #[tabled(rename = "Admin")]
+    admin: String,
+    #[tabled(rename = "ID")]
+    id: String,
+}
+
 pub async fn handle(cmd: UserCommands, output: &OutputOptions) -> Result<()> {
     match cmd {
         UserCommands::List { team } => list_users(team, output).await,
@@ -186,31 +200,14 @@ async fn list_users(team: Option<String>, output: &OutputOptions) -> Result<()>
 }
 
 async fn get_me(output: &OutputOptions) -> Result<()> {
-    let client = LinearClient::new()?;
-
-    let query = r#"
-        query {
-            viewer {
-                id
-                name
-                email
-                displayName
-                avatarUrl
-                admin
-                active
-                createdAt
-                url
-            }
-        }
-    "#;
-
-    let result = client.query(query, None).await?;
-    let raw = &result["data"]["viewer"];
-
-    if raw.is_null() {
-        anyhow::bail!("Could not fetch current user");
+    if let Some(profiles) = crate::config::fanout_profiles() {
+        let viewers =
+            crate::fanout::collect(profiles, || async { Ok(vec![fetch_viewer().await?]) }).await?;
+        return print_viewers(viewers, output);
     }
 
+    let raw = &fetch_viewer().await?;
+
     if output.is_json() || output.has_template() {
         print_json(raw, output)?;
         return Ok(());
@@ -253,6 +250,60 @@ async fn get_me(output: &OutputOptions) -> Result<()> {
     Ok(())
 }
 
+/// The authenticated user of the current workspace
+async fn fetch_viewer() -> Result<Value> {
+    let client = LinearClient::new()?;
+
+    let query = r#"
+        query {
+            viewer {
+                id
+                name
+                email
+                displayName
+                avatarUrl
+                admin
+                active
+                createdAt
+                url
+            }
+        }
+    "#;
+
+    let result = client.query(query, None).await?;
+    let raw = &result["data"]["viewer"];
+
+    if raw.is_null() {
+        anyhow::bail!("Could not fetch current user");
+    }
+    Ok(raw.clone())
+}
+
+/// `users me` across several workspaces: one row per workspace
+fn print_viewers(viewers: Vec<Value>, output: &OutputOptions) -> Result<()> {
+    if output.is_json() || output.has_template() {
+        return print_json_owned(json!(viewers), output);
+    }
+
+    let rows: Vec<ViewerRow> = viewers
+        .iter()
+        .map(|v| ViewerRow {
+            workspace: crate::fanout::workspace_of(v),
+            name: v["name"].as_str().unwrap_or("-").to_string(),
+            email: v["email"].as_str().unwrap_or("-").to_string(),
+            admin: match v["admin"].as_bool() {
+                Some(true) => "Yes",
+                Some(false) => "No",
+                None => "-",
+            }
+            .to_string(),
+            id: v["id"].as_str().unwrap_or("").to_string(),
+        })
+        .collect();
+    println!("{}", Table::new(rows));
+    Ok(())
+}
+
 async fn get_user(user: &str, output: &OutputOptions) -> Result<()> {
     let client = LinearClient::new()?;
     let user_id = resolve_user_id(&client, user, &CacheOptions::default()).await?;
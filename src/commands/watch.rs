@@ -1,101 +1,137 @@
 use anyhow::Result;
-use serde_json::json;
+use clap::Args;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 
+use super::webhooks::{self, TempWebhook};
 use crate::api::LinearClient;
 use crate::output::{print_json_owned, OutputOptions};
+use crate::text::{sanitize_terminal_text, truncate};
+use crate::OutputFormat;
 
-/// Watch for changes to an issue and print updates
-pub async fn watch_issue(id: &str, interval_secs: u64, output: &OutputOptions) -> Result<()> {
-    let interval_secs = interval_secs.max(5);
-    let client = LinearClient::new()?;
-
-    let query = r#"
-        query($id: String!) {
-            issue(id: $id) {
-                id
-                identifier
-                title
-                updatedAt
-                state { name }
-                assignee { name }
-                priority
-                labels { nodes { name } }
-            }
-        }
-    "#;
+/// Event source options shared by all watch subcommands
+#[derive(Args, Debug, Clone, Default)]
+pub struct StreamArgs {
+    /// Receive changes instantly through a temporary webhook on this local port
+    #[arg(long, value_name = "PORT", conflicts_with = "relay")]
+    pub listen: Option<u16>,
+    /// Public URL forwarding to the local port (e.g., ngrok tunnel URL)
+    #[arg(long, requires = "listen")]
+    pub url: Option<String>,
+    /// Address to bind the local listener to
+    #[arg(long, default_value = "127.0.0.1")]
+    pub bind: String,
+    /// Webhook signing secret (generated by Linear when omitted)
+    #[arg(long, requires = "listen")]
+    pub secret: Option<String>,
+    /// Read webhook events from a relay stream (NDJSON or server-sent events over HTTP)
+    #[arg(long, value_name = "URL")]
+    pub relay: Option<String>,
+    /// Bearer token for the relay (`webhooks serve --events-token`, or its signing secret)
+    #[arg(
+        long,
+        value_name = "TOKEN",
+        env = "LINEAR_RELAY_TOKEN",
+        hide_env_values = true,
+        requires = "relay"
+    )]
+    pub relay_token: Option<String>,
+}
 
-    let mut last_updated: Option<String> = None;
-    let mut iteration = 0;
+impl StreamArgs {
+    fn is_streaming(&self) -> bool {
+        self.listen.is_some() || self.relay.is_some()
+    }
+}
 
-    eprintln!("Watching {} for changes (Ctrl+C to stop)...\n", id);
+const ISSUE_FIELDS: &str = r#"
+    id
+    identifier
+    title
+    description
+    url
+    priority
+    estimate
+    dueDate
+    createdAt
+    updatedAt
+    state { id name }
+    assignee { id name }
+    project { id name }
+    cycle { id name number }
+    labels { nodes { id name } }
+    team { id key }
+    comments(first: 25, orderBy: createdAt) {
+        nodes { id body createdAt user { name } }
+    }
+"#;
 
-    loop {
-        let result = client.query(query, Some(json!({ "id": id }))).await?;
-        let issue = &result["data"]["issue"];
+/// Watch for changes to an issue and print updates
+pub async fn watch_issue(
+    id: &str,
+    interval_secs: u64,
+    only_fields: &[String],
+    stream: &StreamArgs,
+    hooks: &HookArgs,
+    output: &OutputOptions,
+) -> Result<()> {
+    let interval_secs = interval_secs.max(5);
+    let allow = field_allow_list(WatchKind::Issue, only_fields)?;
+    let hooks = Hooks::compile(WatchKind::Issue, hooks)?;
+    let client = LinearClient::new()?;
 
+    let query = format!(
+        "query($id: String!) {{ issue(id: $id) {{ {} }} }}",
+        ISSUE_FIELDS
+    );
+    let fetch = || async {
+        let result = client.query(&query, Some(json!({ "id": id }))).await?;
+        let issue = result["data"]["issue"].clone();
         if issue.is_null() {
             anyhow::bail!("Issue not found: {}", id);
         }
+        Ok(issue)
+    };
 
-        let current_updated = issue["updatedAt"].as_str().map(|s| s.to_string());
-
-        // Check if updated
-        if last_updated.as_ref() != current_updated.as_ref() {
-            if iteration > 0 {
-                // Not the first iteration, so this is a change
-                if output.is_json() {
-                    print_json_owned(
-                        json!({
-                            "event": "updated",
-                            "issue": issue,
-                            "timestamp": chrono::Utc::now().to_rfc3339(),
-                        }),
-                        output,
-                    )?;
-                } else {
-                    println!(
-                        "[{}] {} updated - Status: {}, Assignee: {}",
-                        chrono::Utc::now().format("%H:%M:%S"),
-                        issue["identifier"].as_str().unwrap_or(id),
-                        issue["state"]["name"].as_str().unwrap_or("-"),
-                        issue["assignee"]["name"].as_str().unwrap_or("Unassigned"),
-                    );
-                }
-            } else if output.is_json() {
-                print_json_owned(
-                    json!({
-                        "event": "initial",
-                        "issue": issue,
-                        "timestamp": chrono::Utc::now().to_rfc3339(),
-                    }),
-                    output,
-                )?;
-            } else {
-                println!(
-                    "Initial state: {} - {}",
-                    issue["identifier"].as_str().unwrap_or(id),
-                    issue["title"].as_str().unwrap_or("")
-                );
-                println!(
-                    "  Status: {}, Assignee: {}",
-                    issue["state"]["name"].as_str().unwrap_or("-"),
-                    issue["assignee"]["name"].as_str().unwrap_or("Unassigned"),
-                );
-            }
+    let issue = fetch().await?;
+    print_initial_issue(&issue, id, output)?;
+    let mut tracker = Tracker::new(WatchKind::Issue, issue["id"].as_str().unwrap_or(id), allow);
+    tracker.seed(Entity::Issue, &issue);
+    let reporter = Reporter::new(output.clone(), hooks);
 
-            last_updated = current_updated;
-        }
+    if stream.is_streaming() {
+        let team_id = issue["team"]["id"].as_str().map(|s| s.to_string());
+        return watch_stream(&client, tracker, team_id, stream, reporter).await;
+    }
 
-        iteration += 1;
+    eprintln!("Watching {} for changes (Ctrl+C to stop)...\n", id);
+
+    loop {
         sleep(Duration::from_secs(interval_secs)).await;
+        let issue = fetch().await?;
+        if let Some(event) = tracker.observe(Entity::Issue, &issue) {
+            reporter.emit(&event, "poll").await?;
+        }
     }
 }
 
 /// Watch for changes to a project and print updates
-pub async fn watch_project(id: &str, interval_secs: u64, output: &OutputOptions) -> Result<()> {
+pub async fn watch_project(
+    id: &str,
+    interval_secs: u64,
+    only_fields: &[String],
+    stream: &StreamArgs,
+    hooks: &HookArgs,
+    output: &OutputOptions,
+) -> Result<()> {
     let interval_secs = interval_secs.max(5);
+    let allow = field_allow_list(WatchKind::Project, only_fields)?;
+    let hooks = Hooks::compile(WatchKind::Project, hooks)?;
     let client = LinearClient::new()?;
 
     let query = r#"
@@ -106,163 +142,1686 @@ pub async fn watch_project(id: &str, interval_secs: u64, output: &OutputOptions)
                 state
                 progress
                 updatedAt
+                targetDate
+                lead { id name }
                 teams { nodes { key } }
             }
         }
     "#;
-
-    let mut last_updated: Option<String> = None;
-    let mut iteration = 0;
-
-    eprintln!("Watching project {} for changes (Ctrl+C to stop)...\n", id);
-
-    loop {
+    let fetch = || async {
         let result = client.query(query, Some(json!({ "id": id }))).await?;
-        let project = &result["data"]["project"];
-
+        let project = result["data"]["project"].clone();
         if project.is_null() {
             anyhow::bail!("Project not found: {}", id);
         }
+        Ok(project)
+    };
 
-        let current_updated = project["updatedAt"].as_str().map(|s| s.to_string());
+    let project = fetch().await?;
+    print_initial_project(&project, id, output)?;
+    let mut tracker = Tracker::new(
+        WatchKind::Project,
+        project["id"].as_str().unwrap_or(id),
+        allow,
+    );
+    tracker.seed(Entity::Project, &project);
+    let reporter = Reporter::new(output.clone(), hooks);
 
-        if last_updated.as_ref() != current_updated.as_ref() {
-            if iteration > 0 {
-                if output.is_json() {
-                    print_json_owned(
-                        json!({
-                            "event": "updated",
-                            "project": project,
-                            "timestamp": chrono::Utc::now().to_rfc3339(),
-                        }),
-                        output,
-                    )?;
-                } else {
-                    println!(
-                        "[{}] {} updated - State: {}, Progress: {:.0}%",
-                        chrono::Utc::now().format("%H:%M:%S"),
-                        project["name"].as_str().unwrap_or(id),
-                        project["state"].as_str().unwrap_or("-"),
-                        project["progress"].as_f64().unwrap_or(0.0) * 100.0,
-                    );
-                }
-            } else if output.is_json() {
-                print_json_owned(
-                    json!({
-                        "event": "initial",
-                        "project": project,
-                        "timestamp": chrono::Utc::now().to_rfc3339(),
-                    }),
-                    output,
-                )?;
-            } else {
-                println!(
-                    "Initial state: {} - {}",
-                    project["name"].as_str().unwrap_or(id),
-                    project["state"].as_str().unwrap_or("")
-                );
-                println!(
-                    "  Progress: {:.0}%",
-                    project["progress"].as_f64().unwrap_or(0.0) * 100.0,
-                );
-            }
+    if stream.is_streaming() {
+        return watch_stream(&client, tracker, None, stream, reporter).await;
+    }
 
-            last_updated = current_updated;
-        }
+    eprintln!("Watching project {} for changes (Ctrl+C to stop)...\n", id);
 
-        iteration += 1;
+    loop {
         sleep(Duration::from_secs(interval_secs)).await;
+        let project = fetch().await?;
+        if let Some(event) = tracker.observe(Entity::Project, &project) {
+            reporter.emit(&event, "poll").await?;
+        }
     }
 }
 
-/// Watch for changes to a team and print updates
-pub async fn watch_team(team: &str, interval_secs: u64, output: &OutputOptions) -> Result<()> {
+/// Watch for changes to a team (its active cycle and its issues) and print updates
+pub async fn watch_team(
+    team: &str,
+    interval_secs: u64,
+    only_fields: &[String],
+    stream: &StreamArgs,
+    hooks: &HookArgs,
+    output: &OutputOptions,
+) -> Result<()> {
     let interval_secs = interval_secs.max(5);
+    let allow = field_allow_list(WatchKind::Team, only_fields)?;
+    let hooks = Hooks::compile(WatchKind::Team, hooks)?;
     let client = LinearClient::new()?;
 
-    let query = r#"
-        query($id: String!) {
-            team(id: $id) {
+    let query = format!(
+        r#"query($id: String!, $filter: IssueFilter) {{
+            team(id: $id) {{
                 id
                 name
                 key
                 updatedAt
-                activeCycle {
+                activeCycle {{
                     id
                     name
+                    number
                     progress
-                }
-                issues(first: 1) {
-                    __typename
-                }
-            }
-        }
-    "#;
+                }}
+            }}
+            issues(first: 50, filter: $filter, orderBy: updatedAt) {{
+                nodes {{ {} }}
+            }}
+        }}"#,
+        ISSUE_FIELDS
+    );
 
     // Resolve team key to UUID
     let team_id = crate::api::resolve_team_id(&client, team, &output.cache).await?;
 
-    let mut last_updated: Option<String> = None;
-    let mut iteration = 0;
+    let fetch = |since: Option<String>| {
+        let mut filter = json!({ "team": { "id": { "eq": team_id } } });
+        if let Some(since) = since {
+            filter["updatedAt"] = json!({ "gt": since });
+        }
+        let query = &query;
+        let client = &client;
+        let team_id = &team_id;
+        async move {
+            let result = client
+                .query(query, Some(json!({ "id": team_id, "filter": filter })))
+                .await?;
+            let team_data = result["data"]["team"].clone();
+            if team_data.is_null() {
+                anyhow::bail!("Team not found: {}", team);
+            }
+            let issues = result["data"]["issues"]["nodes"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+            Ok((team_data, issues))
+        }
+    };
+
+    let started = chrono::Utc::now().to_rfc3339();
+    let (team_data, issues) = fetch(None).await?;
+    print_initial_team(&team_data, team, output)?;
+    let mut tracker = Tracker::new(WatchKind::Team, &team_id, allow);
+    tracker.seed(Entity::Team, &team_data);
+    let mut since = latest_updated_at(&issues).unwrap_or(started.clone());
+    for issue in &issues {
+        tracker.seed(Entity::Issue, issue);
+    }
+    let reporter = Reporter::new(output.clone(), hooks);
+
+    if stream.is_streaming() {
+        return watch_stream(&client, tracker, Some(team_id.clone()), stream, reporter).await;
+    }
 
     eprintln!("Watching team {} for changes (Ctrl+C to stop)...\n", team);
 
     loop {
-        let result = client.query(query, Some(json!({ "id": team_id }))).await?;
-        let team_data = &result["data"]["team"];
-
-        if team_data.is_null() {
-            anyhow::bail!("Team not found: {}", team);
-        }
-
-        let current_updated = team_data["updatedAt"].as_str().map(|s| s.to_string());
-
-        if last_updated.as_ref() != current_updated.as_ref() {
-            if iteration > 0 {
-                if output.is_json() {
-                    print_json_owned(
-                        json!({
-                            "event": "updated",
-                            "team": team_data,
-                            "timestamp": chrono::Utc::now().to_rfc3339(),
-                        }),
-                        output,
-                    )?;
+        sleep(Duration::from_secs(interval_secs)).await;
+        let (team_data, issues) = fetch(Some(since.clone())).await?;
+        if let Some(event) = tracker.observe(Entity::Team, &team_data) {
+            reporter.emit(&event, "poll").await?;
+        }
+        // Oldest first so events read in the order they happened
+        for issue in issues.iter().rev() {
+            let created = issue["createdAt"]
+                .as_str()
+                .is_some_and(|c| c > started.as_str());
+            let event = if tracker.has_snapshot(issue) || !created {
+                tracker.observe(Entity::Issue, issue)
+            } else {
+                tracker.created(Entity::Issue, issue)
+            };
+            if let Some(event) = event {
+                reporter.emit(&event, "poll").await?;
+            }
+        }
+        if let Some(latest) = latest_updated_at(&issues) {
+            since = latest;
+        }
+    }
+}
+
+fn latest_updated_at(items: &[Value]) -> Option<String> {
+    items
+        .iter()
+        .filter_map(|i| i["updatedAt"].as_str())
+        .max()
+        .map(|s| s.to_string())
+}
+
+/// Changed fields to report, from `--only-fields` (None reports every field)
+fn field_allow_list(kind: WatchKind, only_fields: &[String]) -> Result<Option<HashSet<String>>> {
+    if only_fields.is_empty() {
+        return Ok(None);
+    }
+    let known = kind.known_fields();
+    let mut allow = HashSet::new();
+    for field in only_fields {
+        let field = field.trim();
+        if field.is_empty() {
+            continue;
+        }
+        if !known.contains(&field) {
+            anyhow::bail!(
+                "Unknown watch field '{}'. Available: {}",
+                field,
+                known.join(", ")
+            );
+        }
+        allow.insert(field.to_string());
+    }
+    Ok(Some(allow))
+}
+
+fn print_initial_issue(issue: &Value, id: &str, output: &OutputOptions) -> Result<()> {
+    if output.is_json() {
+        return print_json_owned(
+            json!({
+                "event": "initial",
+                "issue": issue,
+                "timestamp": chrono::Utc::now().to_rfc3339(),
+            }),
+            output,
+        );
+    }
+    println!(
+        "Initial state: {} - {}",
+        sanitize_terminal_text(issue["identifier"].as_str().unwrap_or(id)),
+        sanitize_terminal_text(issue["title"].as_str().unwrap_or(""))
+    );
+    println!(
+        "  Status: {}, Assignee: {}",
+        sanitize_terminal_text(issue["state"]["name"].as_str().unwrap_or("-")),
+        sanitize_terminal_text(issue["assignee"]["name"].as_str().unwrap_or("Unassigned")),
+    );
+    Ok(())
+}
+
+fn print_initial_project(project: &Value, id: &str, output: &OutputOptions) -> Result<()> {
+    if output.is_json() {
+        return print_json_owned(
+            json!({
+                "event": "initial",
+                "project": project,
+                "timestamp": chrono::Utc::now().to_rfc3339(),
+            }),
+            output,
+        );
+    }
+    println!(
+        "Initial state: {} - {}",
+        sanitize_terminal_text(project["name"].as_str().unwrap_or(id)),
+        sanitize_terminal_text(project["state"].as_str().unwrap_or(""))
+    );
+    println!(
+        "  Progress: {:.0}%",
+        project["progress"].as_f64().unwrap_or(0.0) * 100.0,
+    );
+    Ok(())
+}
+
+fn print_initial_team(team_data: &Value, team: &str, output: &OutputOptions) -> Result<()> {
+    if output.is_json() {
+        return print_json_owned(
+            json!({
+                "event": "initial",
+                "team": team_data,
+                "timestamp": chrono::Utc::now().to_rfc3339(),
+            }),
+            output,
+        );
+    }
+    let cycle_name =
+        sanitize_terminal_text(team_data["activeCycle"]["name"].as_str().unwrap_or("none"));
+    println!(
+        "Initial state: {} ({})",
+        sanitize_terminal_text(team_data["name"].as_str().unwrap_or(team)),
+        sanitize_terminal_text(team_data["key"].as_str().unwrap_or("")),
+    );
+    println!("  Active cycle: {}", cycle_name);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WatchKind {
+    Issue,
+    Project,
+    Team,
+}
+
+impl WatchKind {
+    /// Webhook resource type carrying changes for this kind of watch
+    fn resource_type(&self) -> &'static str {
+        match self {
+            WatchKind::Issue | WatchKind::Team => "Issue",
+            WatchKind::Project => "Project",
+        }
+    }
+
+    /// Entity the webhook events for this kind of watch describe
+    fn entity(&self) -> Entity {
+        match self {
+            WatchKind::Issue | WatchKind::Team => Entity::Issue,
+            WatchKind::Project => Entity::Project,
+        }
+    }
+
+    /// Field names accepted by `--only-fields` and `--on`
+    fn known_fields(&self) -> Vec<&'static str> {
+        match self {
+            WatchKind::Issue => Entity::Issue.fields().to_vec(),
+            WatchKind::Project => Entity::Project.fields().to_vec(),
+            WatchKind::Team => [Entity::Team.fields(), Entity::Issue.fields()].concat(),
+        }
+    }
+}
+
+/// Kinds of entity a snapshot can describe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entity {
+    Issue,
+    Project,
+    Team,
+}
+
+impl Entity {
+    /// Fields compared between snapshots, in display order
+    fn fields(&self) -> &'static [&'static str] {
+        match self {
+            Entity::Issue => &[
+                "title",
+                "state",
+                "assignee",
+                "priority",
+                "estimate",
+                "dueDate",
+                "project",
+                "cycle",
+                "labels",
+                "description",
+                "comments",
+            ],
+            Entity::Project => &["name", "state", "progress", "lead", "targetDate"],
+            Entity::Team => &["name", "activeCycle", "cycleProgress"],
+        }
+    }
+
+    /// Key the entity is reported under in JSON output
+    fn entity_key(&self) -> &'static str {
+        match self {
+            Entity::Issue => "issue",
+            Entity::Project => "project",
+            Entity::Team => "team",
+        }
+    }
+}
+
+/// One field that changed between two snapshots of an entity
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: Value,
+    pub to: Value,
+    /// Labels or comments that appeared
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<Value>,
+    /// Labels that were removed
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<Value>,
+}
+
+impl FieldChange {
+    fn new(field: &str, from: Value, to: Value) -> Self {
+        Self {
+            field: field.to_string(),
+            from,
+            to,
+            added: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+}
+
+/// A change to a watched entity, ready to print
+#[derive(Debug)]
+struct WatchEvent {
+    event: &'static str,
+    entity_type: Entity,
+    label: String,
+    entity: Value,
+    changes: Vec<FieldChange>,
+}
+
+/// Keeps the last known snapshot of each watched entity so changes can be diffed
+struct Tracker {
+    kind: WatchKind,
+    target_id: String,
+    allow: Option<HashSet<String>>,
+    snapshots: HashMap<String, Map<String, Value>>,
+    /// Identifier or name of each tracked entity, for events that only carry an id
+    labels: HashMap<String, String>,
+    /// Display names for state, user, project, cycle and label ids seen so far
+    names: HashMap<String, String>,
+}
+
+impl Tracker {
+    fn new(kind: WatchKind, target_id: &str, allow: Option<HashSet<String>>) -> Self {
+        Self {
+            kind,
+            target_id: target_id.to_string(),
+            allow,
+            snapshots: HashMap::new(),
+            labels: HashMap::new(),
+            names: HashMap::new(),
+        }
+    }
+
+    fn has_snapshot(&self, entity: &Value) -> bool {
+        entity["id"]
+            .as_str()
+            .is_some_and(|id| self.snapshots.contains_key(id))
+    }
+
+    /// Record the state fetched before watching starts
+    fn seed(&mut self, entity_type: Entity, entity: &Value) {
+        let Some(id) = entity["id"].as_str() else {
+            return;
+        };
+        self.learn_names(entity);
+        self.labels.insert(id.to_string(), entity_label(entity));
+        let snapshot = self.snapshot(entity_type, entity);
+        self.snapshots.insert(id.to_string(), snapshot);
+    }
+
+    /// Diff a freshly fetched entity against its last snapshot
+    fn observe(&mut self, entity_type: Entity, entity: &Value) -> Option<WatchEvent> {
+        let id = entity["id"].as_str()?.to_string();
+        let Some(previous) = self.snapshots.get(&id).cloned() else {
+            self.seed(entity_type, entity);
+            return None;
+        };
+        self.learn_names(entity);
+        let current = self.merge(&previous, self.snapshot(entity_type, entity));
+        let changes = self.diff(entity_type, &previous, &current);
+        self.snapshots.insert(id, current);
+        if changes.is_empty() {
+            return None;
+        }
+        Some(WatchEvent {
+            event: "updated",
+            entity_type,
+            label: entity_label(entity),
+            entity: entity.clone(),
+            changes,
+        })
+    }
+
+    /// Start tracking a new entity and report it as created
+    fn created(&mut self, entity_type: Entity, entity: &Value) -> Option<WatchEvent> {
+        self.seed(entity_type, entity);
+        Some(WatchEvent {
+            event: "created",
+            entity_type,
+            label: entity_label(entity),
+            entity: entity.clone(),
+            changes: Vec::new(),
+        })
+    }
+
+    fn matches(&self, payload: &Value) -> bool {
+        if payload["type"].as_str() != Some(self.kind.resource_type()) {
+            return false;
+        }
+        let data = &payload["data"];
+        match self.kind {
+            WatchKind::Issue | WatchKind::Project => {
+                data["id"].as_str() == Some(self.target_id.as_str())
+            }
+            WatchKind::Team => {
+                data["teamId"].as_str() == Some(self.target_id.as_str())
+                    || data["team"]["id"].as_str() == Some(self.target_id.as_str())
+            }
+        }
+    }
+
+    /// Turn a webhook payload into a watch event, or None when it is not about the watched entity
+    fn handle(&mut self, payload: &Value) -> Option<WatchEvent> {
+        let action = payload["action"].as_str().unwrap_or("update");
+        if payload["type"].as_str() == Some("Comment") {
+            return self.handle_comment(action, &payload["data"]);
+        }
+        if !self.matches(payload) {
+            return None;
+        }
+        let entity_type = self.kind.entity();
+        let data = &payload["data"];
+        let id = data["id"].as_str()?.to_string();
+        match action {
+            "create" => self.created(entity_type, data),
+            "remove" => {
+                self.snapshots.remove(&id);
+                Some(WatchEvent {
+                    event: "removed",
+                    entity_type,
+                    label: self
+                        .labels
+                        .remove(&id)
+                        .unwrap_or_else(|| entity_label(data)),
+                    entity: data.clone(),
+                    changes: Vec::new(),
+                })
+            }
+            _ => {
+                self.learn_names(data);
+                let partial = self.snapshot(entity_type, data);
+                let previous = match self.snapshots.get(&id) {
+                    Some(previous) => previous.clone(),
+                    None => self.previous_from_payload(&partial, &payload["updatedFrom"]),
+                };
+                let current = self.merge(&previous, partial);
+                let changes = self.diff(entity_type, &previous, &current);
+                self.snapshots.insert(id.clone(), current);
+                self.labels.insert(id, entity_label(data));
+                if changes.is_empty() {
+                    return None;
+                }
+                Some(WatchEvent {
+                    event: "updated",
+                    entity_type,
+                    label: entity_label(data),
+                    entity: data.clone(),
+                    changes,
+                })
+            }
+        }
+    }
+
+    /// New comments are reported as a `comments` change on their issue
+    fn handle_comment(&mut self, action: &str, data: &Value) -> Option<WatchEvent> {
+        if action != "create" || self.kind == WatchKind::Project {
+            return None;
+        }
+        let issue_id = data["issueId"]
+            .as_str()
+            .or_else(|| data["issue"]["id"].as_str())?
+            .to_string();
+        let tracked = match self.kind {
+            WatchKind::Issue => issue_id == self.target_id,
+            _ => {
+                self.snapshots.contains_key(&issue_id)
+                    || data["issue"]["teamId"].as_str() == Some(self.target_id.as_str())
+            }
+        };
+        if !tracked {
+            return None;
+        }
+
+        let comment = comment_summary(data);
+        let mut snapshot = self.snapshots.get(&issue_id).cloned().unwrap_or_default();
+        let mut comments = snapshot
+            .get("comments")
+            .and_then(|c| c.as_array())
+            .cloned()
+            .unwrap_or_default();
+        if comments.iter().any(|c| c["id"] == comment["id"]) {
+            return None;
+        }
+        let mut change =
+            FieldChange::new("comments", json!(comments.len()), json!(comments.len() + 1));
+        change.added.push(comment.clone());
+        comments.push(comment);
+        snapshot.insert("comments".to_string(), Value::Array(comments));
+        self.snapshots.insert(issue_id.clone(), snapshot);
+
+        let changes = self.allowed(vec![change]);
+        if changes.is_empty() {
+            return None;
+        }
+        let label = self
+            .labels
+            .get(&issue_id)
+            .cloned()
+            .or_else(|| data["issue"]["identifier"].as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| issue_id.clone());
+        Some(WatchEvent {
+            event: "updated",
+            entity_type: Entity::Issue,
+            label,
+            entity: data["issue"].clone(),
+            changes,
+        })
+    }
+
+    /// Normalize a GraphQL node or webhook `data` object into the tracked fields it carries
+    fn snapshot(&self, entity_type: Entity, entity: &Value) -> Map<String, Value> {
+        let mut snapshot = Map::new();
+        for field in entity_type.fields() {
+            let value = match (entity_type, *field) {
+                (Entity::Project, "state") => {
+                    if entity["state"].is_string() {
+                        entity["state"].clone()
+                    } else {
+                        entity["status"]["name"].clone()
+                    }
+                }
+                (_, "progress") => percent(&entity["progress"]),
+                (Entity::Team, "activeCycle") => cycle_name(&entity["activeCycle"]),
+                (Entity::Team, "cycleProgress") => percent(&entity["activeCycle"]["progress"]),
+                (_, "cycle") if entity["cycle"].is_object() => cycle_name(&entity["cycle"]),
+                (_, "state" | "assignee" | "lead" | "project" | "cycle") => {
+                    self.relation_name(entity, field)
+                }
+                (_, "labels") => {
+                    if entity["labels"].is_null() && entity["labelIds"].is_array() {
+                        self.names_for_ids(&entity["labelIds"])
+                    } else {
+                        json!(label_names(entity))
+                    }
+                }
+                (_, "comments") => entity["comments"]["nodes"]
+                    .as_array()
+                    .map(|nodes| Value::Array(nodes.iter().map(comment_summary).collect()))
+                    .unwrap_or(Value::Null),
+                _ => entity[*field].clone(),
+            };
+            // Webhook payloads omit some fields; only record what the entity actually carries
+            let present = entity.get(*field).is_some()
+                || entity.get(format!("{}Id", field)).is_some()
+                || (*field == "labels" && entity.get("labelIds").is_some())
+                || (entity_type == Entity::Team && entity.get("activeCycle").is_some());
+            if present {
+                snapshot.insert(field.to_string(), value);
+            }
+        }
+        snapshot
+    }
+
+    /// `name` of a related object, falling back to the `<field>Id` webhooks send instead
+    fn relation_name(&self, entity: &Value, field: &str) -> Value {
+        if let Some(name) = entity[field]["name"].as_str() {
+            return json!(name);
+        }
+        match entity[format!("{}Id", field)].as_str() {
+            Some(id) => json!(self
+                .names
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| id.to_string())),
+            None => Value::Null,
+        }
+    }
+
+    fn names_for_ids(&self, ids: &Value) -> Value {
+        let mut names: Vec<String> = ids
+            .as_array()
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| id.as_str())
+                    .map(|id| {
+                        self.names
+                            .get(id)
+                            .cloned()
+                            .unwrap_or_else(|| id.to_string())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        json!(names)
+    }
+
+    fn merge(
+        &self,
+        previous: &Map<String, Value>,
+        partial: Map<String, Value>,
+    ) -> Map<String, Value> {
+        let mut current = previous.clone();
+        current.extend(partial);
+        current
+    }
+
+    /// Rebuild the previous snapshot from Linear's `updatedFrom` when we have not seen the entity yet
+    fn previous_from_payload(
+        &self,
+        current: &Map<String, Value>,
+        updated_from: &Value,
+    ) -> Map<String, Value> {
+        let mut previous = current.clone();
+        let Some(old) = updated_from.as_object() else {
+            return previous;
+        };
+        let name_of = |v: &Value| match v.as_str() {
+            Some(id) => json!(self
+                .names
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| id.to_string())),
+            None => Value::Null,
+        };
+        for (key, value) in old {
+            let field = match key.as_str() {
+                "stateId" => "state",
+                "assigneeId" => "assignee",
+                "leadId" => "lead",
+                "projectId" => "project",
+                "cycleId" => "cycle",
+                "labelIds" => "labels",
+                other => other,
+            };
+            if !previous.contains_key(field) {
+                continue;
+            }
+            let value = match key.as_str() {
+                "stateId" | "assigneeId" | "leadId" | "projectId" | "cycleId" => name_of(value),
+                "labelIds" => self.names_for_ids(value),
+                _ => value.clone(),
+            };
+            previous.insert(field.to_string(), value);
+        }
+        previous
+    }
+
+    fn diff(
+        &self,
+        entity_type: Entity,
+        previous: &Map<String, Value>,
+        current: &Map<String, Value>,
+    ) -> Vec<FieldChange> {
+        self.allowed(diff_snapshots(entity_type.fields(), previous, current))
+    }
+
+    fn allowed(&self, changes: Vec<FieldChange>) -> Vec<FieldChange> {
+        match &self.allow {
+            Some(allow) => changes
+                .into_iter()
+                .filter(|c| allow.contains(&c.field))
+                .collect(),
+            None => changes,
+        }
+    }
+
+    fn learn_names(&mut self, entity: &Value) {
+        for key in ["state", "assignee", "lead", "project", "cycle"] {
+            if let Some(id) = entity[key]["id"].as_str() {
+                let name = if key == "cycle" {
+                    cycle_name(&entity[key])
                 } else {
-                    let cycle_name = team_data["activeCycle"]["name"].as_str().unwrap_or("-");
-                    let cycle_progress =
-                        team_data["activeCycle"]["progress"].as_f64().unwrap_or(0.0);
-                    println!(
-                        "[{}] {} updated - Cycle: {} ({:.0}%)",
-                        chrono::Utc::now().format("%H:%M:%S"),
-                        team_data["name"].as_str().unwrap_or(team),
-                        cycle_name,
-                        cycle_progress * 100.0,
+                    entity[key]["name"].clone()
+                };
+                if let Some(name) = name.as_str() {
+                    self.names.insert(id.to_string(), name.to_string());
+                }
+            }
+        }
+        for label in label_nodes(entity) {
+            if let (Some(id), Some(name)) = (label["id"].as_str(), label["name"].as_str()) {
+                self.names.insert(id.to_string(), name.to_string());
+            }
+        }
+    }
+}
+
+fn diff_snapshots(
+    fields: &[&str],
+    previous: &Map<String, Value>,
+    current: &Map<String, Value>,
+) -> Vec<FieldChange> {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let from = previous.get(*field).cloned().unwrap_or(Value::Null);
+            let to = current.get(*field).cloned().unwrap_or(Value::Null);
+            if from == to {
+                return None;
+            }
+            match *field {
+                "labels" => {
+                    let mut change = FieldChange::new(field, from.clone(), to.clone());
+                    change.added = set_difference(&to, &from, |v| v.clone());
+                    change.removed = set_difference(&from, &to, |v| v.clone());
+                    Some(change)
+                }
+                "comments" => {
+                    let added = set_difference(&to, &from, |c| c["id"].clone());
+                    if added.is_empty() {
+                        return None;
+                    }
+                    let count = |v: &Value| v.as_array().map(|a| a.len()).unwrap_or(0);
+                    let mut change =
+                        FieldChange::new(field, json!(count(&from)), json!(count(&to)));
+                    change.added = added;
+                    Some(change)
+                }
+                _ => Some(FieldChange::new(field, from, to)),
+            }
+        })
+        .collect()
+}
+
+/// Items of `a` (an array) whose key does not appear among the items of `b`
+fn set_difference(a: &Value, b: &Value, key: impl Fn(&Value) -> Value) -> Vec<Value> {
+    let empty = Vec::new();
+    let b_keys: Vec<Value> = b.as_array().unwrap_or(&empty).iter().map(&key).collect();
+    a.as_array()
+        .unwrap_or(&empty)
+        .iter()
+        .filter(|item| !b_keys.contains(&key(item)))
+        .cloned()
+        .collect()
+}
+
+fn percent(progress: &Value) -> Value {
+    progress
+        .as_f64()
+        .map(|p| json!((p * 100.0).round() as i64))
+        .unwrap_or(Value::Null)
+}
+
+fn cycle_name(cycle: &Value) -> Value {
+    if let Some(name) = cycle["name"].as_str() {
+        return json!(name);
+    }
+    cycle["number"]
+        .as_i64()
+        .map(|n| json!(format!("Cycle {}", n)))
+        .unwrap_or(Value::Null)
+}
+
+fn comment_summary(comment: &Value) -> Value {
+    json!({
+        "id": comment["id"],
+        "author": comment["user"]["name"],
+        "body": comment["body"],
+        "createdAt": comment["createdAt"],
+    })
+}
+
+/// Labels come back as `labels.nodes` from GraphQL and as a plain `labels` array in webhooks
+fn label_nodes(entity: &Value) -> Vec<&Value> {
+    match &entity["labels"] {
+        Value::Array(items) => items.iter().collect(),
+        other => other["nodes"]
+            .as_array()
+            .map(|nodes| nodes.iter().collect())
+            .unwrap_or_default(),
+    }
+}
+
+fn label_names(entity: &Value) -> Vec<String> {
+    let mut names: Vec<String> = label_nodes(entity)
+        .iter()
+        .filter_map(|l| l["name"].as_str().map(|s| s.to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+fn entity_label(data: &Value) -> String {
+    if let Some(identifier) = data["identifier"].as_str() {
+        return identifier.to_string();
+    }
+    if let (Some(key), Some(number)) = (data["team"]["key"].as_str(), data["number"].as_i64()) {
+        return format!("{}-{}", key, number);
+    }
+    data["name"]
+        .as_str()
+        .or_else(|| data["title"].as_str())
+        .or_else(|| data["id"].as_str())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => "none".to_string(),
+        Value::String(s) => truncate(&s.replace('\n', " "), Some(60)),
+        Value::Array(items) if items.is_empty() => "none".to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => sanitize_terminal_text(&other.to_string()),
+    }
+}
+
+/// Running event source; `close` stops it and removes any temporary webhook
+enum EventStream {
+    Webhook {
+        webhook: TempWebhook,
+        tasks: Vec<JoinHandle<()>>,
+    },
+    Relay(JoinHandle<()>),
+}
+
+impl EventStream {
+    async fn close(self, client: &LinearClient) {
+        match self {
+            EventStream::Webhook { webhook, tasks } => {
+                tasks.iter().for_each(|t| t.abort());
+                if let Err(e) = webhooks::delete_temp_webhook(client, &webhook.id).await {
+                    eprintln!(
+                        "Warning: Failed to clean up temporary webhook {}: {}",
+                        webhook.id, e
                     );
                 }
-            } else if output.is_json() {
-                print_json_owned(
-                    json!({
-                        "event": "initial",
-                        "team": team_data,
-                        "timestamp": chrono::Utc::now().to_rfc3339(),
+            }
+            EventStream::Relay(task) => task.abort(),
+        }
+    }
+}
+
+async fn start_stream(
+    client: &LinearClient,
+    kind: WatchKind,
+    team_id: Option<String>,
+    args: &StreamArgs,
+) -> Result<(mpsc::UnboundedReceiver<Value>, EventStream)> {
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    if let Some(url) = args.relay.clone() {
+        eprintln!("Reading events from relay {}", url);
+        return Ok((
+            rx,
+            EventStream::Relay(tokio::spawn(relay_events(
+                url,
+                args.relay_token.clone(),
+                tx,
+            ))),
+        ));
+    }
+
+    let port = args.listen.unwrap_or(9000);
+    let webhook = webhooks::create_temp_webhook(
+        client,
+        args.url.as_deref(),
+        port,
+        &resource_types(kind),
+        team_id,
+        args.secret.clone(),
+    )
+    .await?;
+    let listener = webhooks::bind_listener(client, &webhook, &args.bind, port).await?;
+    eprintln!(
+        "Temporary webhook {} registered for {} (listening on {}:{})",
+        webhook.id, webhook.url, args.bind, port
+    );
+
+    let (events_tx, mut events_rx) = mpsc::unbounded_channel();
+    let server = tokio::spawn(webhooks::accept_events(
+        listener,
+        webhook.secret.clone(),
+        events_tx,
+        None,
+    ));
+    let forward = tokio::spawn(async move {
+        while let Some(event) = events_rx.recv().await {
+            if tx.send(event.payload).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok((
+        rx,
+        EventStream::Webhook {
+            webhook,
+            tasks: vec![server, forward],
+        },
+    ))
+}
+
+/// Issue watches also subscribe to comments, which are reported on their issue
+fn resource_types(kind: WatchKind) -> Vec<String> {
+    let mut types = vec![kind.resource_type().to_string()];
+    if kind != WatchKind::Project {
+        types.push("Comment".to_string());
+    }
+    types
+}
+
+/// Follow a relay's event stream, reconnecting with backoff when it drops
+async fn relay_events(url: String, token: Option<String>, tx: mpsc::UnboundedSender<Value>) {
+    let http = match reqwest::Client::builder()
+        .user_agent(format!("linear-cli/{}", env!("CARGO_PKG_VERSION")))
+        .connect_timeout(Duration::from_secs(10))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create relay client: {}", e);
+            return;
+        }
+    };
+    let mut backoff = 1;
+
+    loop {
+        let mut request = http
+            .get(&url)
+            .header("Accept", "application/x-ndjson, text/event-stream");
+        if let Some(token) = token.as_deref() {
+            request = request.bearer_auth(token);
+        }
+        match request.send().await {
+            Ok(mut response) if response.status().is_success() => {
+                backoff = 1;
+                let mut buf: Vec<u8> = Vec::new();
+                while let Ok(Some(chunk)) = response.chunk().await {
+                    buf.extend_from_slice(&chunk);
+                    while let Some(pos) = buf.iter().position(|&b| b == b'\n') {
+                        let line: Vec<u8> = buf.drain(..=pos).collect();
+                        if let Some(event) = parse_relay_line(&String::from_utf8_lossy(&line)) {
+                            if tx.send(event).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+                eprintln!("Relay stream closed, reconnecting...");
+            }
+            Ok(response) => eprintln!("Relay returned HTTP {}", response.status()),
+            Err(e) => eprintln!("Relay connection failed: {}", e),
+        }
+        sleep(Duration::from_secs(backoff)).await;
+        backoff = (backoff * 2).min(30);
+    }
+}
+
+/// Parse one line of a relay stream: a JSON object, optionally in SSE `data:` framing
+fn parse_relay_line(line: &str) -> Option<Value> {
+    let line = line.trim();
+    let line = line.strip_prefix("data:").map(str::trim).unwrap_or(line);
+    if !line.starts_with('{') {
+        return None;
+    }
+    serde_json::from_str::<Value>(line)
+        .ok()
+        .filter(|v| v.is_object())
+}
+
+async fn watch_stream(
+    client: &LinearClient,
+    mut tracker: Tracker,
+    team_id: Option<String>,
+    args: &StreamArgs,
+    mut reporter: Reporter,
+) -> Result<()> {
+    let (mut rx, stream) = start_stream(client, tracker.kind, team_id, args).await?;
+    let source = if args.relay.is_some() {
+        "relay"
+    } else {
+        "webhook"
+    };
+    // Streamed events are emitted one JSON object per line
+    if reporter.output.is_json() {
+        reporter.output.format = OutputFormat::Ndjson;
+    }
+
+    eprintln!("Waiting for changes (Ctrl+C to stop)...\n");
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+
+    let result = loop {
+        tokio::select! {
+            payload = rx.recv() => {
+                let Some(payload) = payload else { break Ok(()) };
+                if let Some(event) = tracker.handle(&payload) {
+                    if let Err(e) = reporter.emit(&event, source).await {
+                        break Err(e);
+                    }
+                }
+            }
+            _ = &mut shutdown => break Ok(()),
+        }
+    };
+
+    stream.close(client).await;
+    result
+}
+
+/// Actions to run when a watched entity changes
+#[derive(Args, Debug, Clone, Default)]
+pub struct HookArgs {
+    /// Run a shell command for each change, with the event JSON on stdin (repeatable)
+    #[arg(long, value_name = "CMD")]
+    pub exec: Vec<String>,
+    /// Only run --exec for changes matching FIELD, FIELD=VALUE or event=KIND (repeatable, all must match)
+    #[arg(long, value_name = "COND", requires = "exec")]
+    pub on: Vec<String>,
+}
+
+/// A parsed `--on` condition
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    /// `event=created|updated|removed`
+    Event(String),
+    /// `FIELD`: the field changed
+    Changed(String),
+    /// `FIELD=VALUE`: the field changed to VALUE (for labels: VALUE was added)
+    ChangedTo(String, String),
+}
+
+impl Condition {
+    fn parse(kind: WatchKind, input: &str) -> Result<Self> {
+        let (field, value) = match input.split_once('=') {
+            Some((field, value)) => (field.trim(), Some(value.trim())),
+            None => (input.trim(), None),
+        };
+        if field == "event" {
+            return match value {
+                Some(v @ ("created" | "updated" | "removed")) => Ok(Condition::Event(v.to_string())),
+                _ => anyhow::bail!(
+                    "Invalid --on condition '{}': use event=created, event=updated or event=removed",
+                    input
+                ),
+            };
+        }
+        let known = kind.known_fields();
+        if !known.contains(&field) {
+            anyhow::bail!(
+                "Unknown field in --on condition '{}'. Available: event, {}",
+                input,
+                known.join(", ")
+            );
+        }
+        Ok(match value {
+            Some(value) => Condition::ChangedTo(field.to_string(), value.to_string()),
+            None => Condition::Changed(field.to_string()),
+        })
+    }
+
+    fn matches(&self, event: &WatchEvent) -> bool {
+        let change = |field: &str| event.changes.iter().find(|c| c.field == field);
+        match self {
+            Condition::Event(kind) => event.event == kind,
+            Condition::Changed(field) => change(field).is_some(),
+            Condition::ChangedTo(field, value) => change(field).is_some_and(|c| {
+                let eq = |v: &Value| display_value(v).eq_ignore_ascii_case(value);
+                match field.as_str() {
+                    "labels" => c.added.iter().any(eq),
+                    "comments" => c.added.iter().any(|comment| {
+                        comment["body"]
+                            .as_str()
+                            .is_some_and(|b| b.to_lowercase().contains(&value.to_lowercase()))
                     }),
-                    output,
-                )?;
-            } else {
-                let cycle_name = team_data["activeCycle"]["name"].as_str().unwrap_or("none");
-                println!(
-                    "Initial state: {} ({})",
-                    team_data["name"].as_str().unwrap_or(team),
-                    team_data["key"].as_str().unwrap_or(""),
-                );
-                println!("  Active cycle: {}", cycle_name);
+                    _ => eq(&c.to),
+                }
+            }),
+        }
+    }
+}
+
+/// `--exec` commands and the `--on` conditions that gate them
+#[derive(Debug, Default)]
+struct Hooks {
+    commands: Vec<String>,
+    conditions: Vec<Condition>,
+}
+
+impl Hooks {
+    fn compile(kind: WatchKind, args: &HookArgs) -> Result<Self> {
+        let conditions = args
+            .on
+            .iter()
+            .map(|c| Condition::parse(kind, c))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            commands: args.exec.clone(),
+            conditions,
+        })
+    }
+
+    fn matches(&self, event: &WatchEvent) -> bool {
+        !self.commands.is_empty() && self.conditions.iter().all(|c| c.matches(event))
+    }
+
+    /// Run every command with the event on stdin; failures are reported but do not stop watching
+    async fn run(&self, event: &WatchEvent, payload: &Value) {
+        let env = hook_env(event, payload);
+        let stdin = match serde_json::to_vec(payload) {
+            Ok(bytes) => bytes,
+            Err(_) => return,
+        };
+        for command in &self.commands {
+            if let Err(e) = run_hook(command, &env, &stdin).await {
+                eprintln!("! --exec '{}' failed: {}", command, e);
             }
+        }
+    }
+}
+
+async fn run_hook(command: &str, env: &[(String, String)], stdin: &[u8]) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
 
-            last_updated = current_updated;
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    // Hook output goes to stderr so it never mixes with JSON written to stdout
+    let mut child = tokio::process::Command::new(shell)
+        .arg(flag)
+        .arg(command)
+        .envs(env.iter().map(|(k, v)| (k, v)))
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::io::stderr())
+        .spawn()?;
+    if let Some(mut pipe) = child.stdin.take() {
+        // A hook that ignores stdin may exit before reading it; that is not an error
+        let _ = pipe.write_all(stdin).await;
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        anyhow::bail!("exited with {}", status);
+    }
+    Ok(())
+}
+
+/// Environment variables describing the event for `--exec` commands
+fn hook_env(event: &WatchEvent, payload: &Value) -> Vec<(String, String)> {
+    let entity = &event.entity;
+    let text = |v: &Value| v.as_str().unwrap_or("").to_string();
+    let mut env = vec![
+        ("LINEAR_EVENT".to_string(), event.event.to_string()),
+        (
+            "LINEAR_ENTITY".to_string(),
+            event.entity_type.entity_key().to_string(),
+        ),
+        ("LINEAR_SOURCE".to_string(), text(&payload["source"])),
+        (
+            "LINEAR_CHANGED_FIELDS".to_string(),
+            event
+                .changes
+                .iter()
+                .map(|c| c.field.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+    ];
+    let prefix = format!("LINEAR_{}", event.entity_type.entity_key().to_uppercase());
+    env.push((format!("{}_ID", prefix), text(&entity["id"])));
+    match event.entity_type {
+        Entity::Issue => {
+            env.push((format!("{}_IDENTIFIER", prefix), event.label.clone()));
+            env.push((format!("{}_TITLE", prefix), text(&entity["title"])));
+            env.push((format!("{}_STATE", prefix), text(&entity["state"]["name"])));
+            env.push((
+                format!("{}_ASSIGNEE", prefix),
+                text(&entity["assignee"]["name"]),
+            ));
+            env.push((format!("{}_URL", prefix), text(&entity["url"])));
         }
+        Entity::Project | Entity::Team => {
+            env.push((format!("{}_NAME", prefix), text(&entity["name"])));
+        }
+    }
+    for change in &event.changes {
+        let key = change.field.to_uppercase();
+        env.push((format!("LINEAR_FROM_{}", key), display_value(&change.from)));
+        env.push((format!("LINEAR_TO_{}", key), display_value(&change.to)));
+    }
+    env
+}
 
-        iteration += 1;
-        sleep(Duration::from_secs(interval_secs)).await;
+/// Prints watch events and runs any matching `--exec` hooks
+struct Reporter {
+    output: OutputOptions,
+    hooks: Hooks,
+}
+
+impl Reporter {
+    fn new(output: OutputOptions, hooks: Hooks) -> Self {
+        Self { output, hooks }
+    }
+
+    async fn emit(&self, event: &WatchEvent, source: &str) -> Result<()> {
+        let payload = event_json(event, source);
+        print_event(event, &payload, &self.output)?;
+        if self.hooks.matches(event) {
+            self.hooks.run(event, &payload).await;
+        }
+        Ok(())
+    }
+}
+
+fn event_json(event: &WatchEvent, source: &str) -> Value {
+    json!({
+        "event": event.event,
+        event.entity_type.entity_key(): event.entity,
+        "changes": event.changes,
+        "source": source,
+        "timestamp": chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+fn print_event(event: &WatchEvent, payload: &Value, output: &OutputOptions) -> Result<()> {
+    if output.is_json() {
+        return print_json_owned(payload.clone(), output);
+    }
+
+    for line in event_lines(event) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Human-readable lines for one event; every value comes from the server or a
+/// webhook sender, so all of it goes through `sanitize_terminal_text`.
+fn event_lines(event: &WatchEvent) -> Vec<String> {
+    let mut lines = vec![format!(
+        "[{}] {} {}",
+        chrono::Utc::now().format("%H:%M:%S"),
+        sanitize_terminal_text(&event.label),
+        event.event,
+    )];
+    for change in &event.changes {
+        match change.field.as_str() {
+            "labels" => {
+                let added = change
+                    .added
+                    .iter()
+                    .map(|l| format!("+{}", display_value(l)));
+                let removed = change
+                    .removed
+                    .iter()
+                    .map(|l| format!("-{}", display_value(l)));
+                lines.push(format!(
+                    "    labels: {}",
+                    added.chain(removed).collect::<Vec<_>>().join(" ")
+                ));
+            }
+            "comments" => {
+                for comment in &change.added {
+                    lines.push(format!(
+                        "    comment by {}: {}",
+                        sanitize_terminal_text(comment["author"].as_str().unwrap_or("someone")),
+                        display_value(&comment["body"])
+                    ));
+                }
+            }
+            field => lines.push(format!(
+                "    {}: {} → {}",
+                sanitize_terminal_text(field),
+                display_value(&change.from),
+                display_value(&change.to)
+            )),
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_payload(action: &str, data: Value, updated_from: Value) -> Value {
+        json!({ "type": "Issue", "action": action, "data": data, "updatedFrom": updated_from })
+    }
+
+    fn seeded_issue_tracker(allow: Option<HashSet<String>>) -> Tracker {
+        let mut tracker = Tracker::new(WatchKind::Issue, "issue-1", allow);
+        tracker.seed(
+            Entity::Issue,
+            &json!({
+                "id": "issue-1",
+                "identifier": "ENG-1",
+                "title": "Fix login",
+                "state": { "id": "s1", "name": "Todo" },
+                "assignee": null,
+                "priority": 3,
+                "labels": { "nodes": [{ "id": "l1", "name": "bug" }] },
+                "comments": { "nodes": [{ "id": "c1", "body": "First", "user": { "name": "Ada" } }] }
+            }),
+        );
+        tracker
+    }
+
+    #[test]
+    fn test_issue_event_diffs_against_seeded_snapshot() {
+        let mut tracker = seeded_issue_tracker(None);
+
+        let event = tracker
+            .handle(&issue_payload(
+                "update",
+                json!({
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Fix login",
+                    "state": { "id": "s2", "name": "Done" },
+                    "assignee": { "id": "u1", "name": "Ada" },
+                    "priority": 3,
+                    "labels": [{ "id": "l2", "name": "ui" }, { "id": "l1", "name": "bug" }]
+                }),
+                json!({ "stateId": "s1" }),
+            ))
+            .unwrap();
+
+        assert_eq!(event.event, "updated");
+        assert_eq!(event.label, "ENG-1");
+        let fields: Vec<&str> = event.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, ["state", "assignee", "labels"]);
+        assert_eq!(event.changes[0].from, json!("Todo"));
+        assert_eq!(event.changes[0].to, json!("Done"));
+        assert_eq!(event.changes[2].to, json!(["bug", "ui"]));
+    }
+
+    #[test]
+    fn test_observe_reports_field_changes_label_sets_and_new_comments() {
+        let mut tracker = seeded_issue_tracker(None);
+        let event = tracker
+            .observe(
+                Entity::Issue,
+                &json!({
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Fix login",
+                    "state": { "id": "s2", "name": "Done" },
+                    "assignee": { "id": "u1", "name": "Ada" },
+                    "priority": 3,
+                    "labels": { "nodes": [{ "id": "l2", "name": "ui" }] },
+                    "comments": { "nodes": [
+                        { "id": "c2", "body": "Shipped", "user": { "name": "Bo" } },
+                        { "id": "c1", "body": "First", "user": { "name": "Ada" } }
+                    ] }
+                }),
+            )
+            .unwrap();
+
+        assert_eq!(event.event, "updated");
+        assert_eq!(event.label, "ENG-1");
+        let fields: Vec<&str> = event.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, ["state", "assignee", "labels", "comments"]);
+        assert_eq!(event.changes[0].from, json!("Todo"));
+        assert_eq!(event.changes[0].to, json!("Done"));
+        assert_eq!(event.changes[2].added, vec![json!("ui")]);
+        assert_eq!(event.changes[2].removed, vec![json!("bug")]);
+        assert_eq!(event.changes[3].from, json!(1));
+        assert_eq!(event.changes[3].added[0]["author"], "Bo");
+    }
+
+    #[test]
+    fn test_observe_is_quiet_without_changes_and_respects_allow_list() {
+        let allow = Some(HashSet::from(["priority".to_string()]));
+        let mut tracker = seeded_issue_tracker(allow);
+        let same_priority = json!({
+            "id": "issue-1",
+            "identifier": "ENG-1",
+            "title": "Renamed",
+            "priority": 3
+        });
+        assert!(tracker.observe(Entity::Issue, &same_priority).is_none());
+
+        let event = tracker
+            .observe(Entity::Issue, &json!({ "id": "issue-1", "priority": 1 }))
+            .unwrap();
+        assert_eq!(
+            event.changes,
+            vec![FieldChange::new("priority", json!(3), json!(1))]
+        );
+    }
+
+    #[test]
+    fn test_webhook_payload_keeps_fields_it_does_not_carry() {
+        let mut tracker = seeded_issue_tracker(None);
+        // Webhook issue payloads have no comments; they must not read as removed
+        let event = tracker
+            .handle(&issue_payload(
+                "update",
+                json!({
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Fix login",
+                    "stateId": "s1",
+                    "state": { "id": "s1", "name": "Todo" },
+                    "priority": 2,
+                    "labels": [{ "id": "l1", "name": "bug" }]
+                }),
+                json!({ "priority": 3 }),
+            ))
+            .unwrap();
+        assert_eq!(
+            event.changes,
+            vec![FieldChange::new("priority", json!(3), json!(2))]
+        );
+    }
+
+    #[test]
+    fn test_team_event_uses_updated_from_for_unseen_issue() {
+        let mut tracker = Tracker::new(WatchKind::Team, "team-1", None);
+        tracker.names.insert("s1".to_string(), "Todo".to_string());
+
+        let event = tracker
+            .handle(&issue_payload(
+                "update",
+                json!({
+                    "id": "issue-9",
+                    "number": 9,
+                    "teamId": "team-1",
+                    "team": { "id": "team-1", "key": "ENG" },
+                    "title": "Audit",
+                    "state": { "id": "s2", "name": "In Progress" },
+                    "assigneeId": null,
+                    "priority": 1,
+                    "labels": []
+                }),
+                json!({ "stateId": "s1", "priority": 3, "assigneeId": "u-unknown" }),
+            ))
+            .unwrap();
+
+        assert_eq!(event.label, "ENG-9");
+        assert_eq!(
+            event.changes,
+            vec![
+                FieldChange::new("state", json!("Todo"), json!("In Progress")),
+                FieldChange::new("assignee", json!("u-unknown"), Value::Null),
+                FieldChange::new("priority", json!(3), json!(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_comment_webhook_is_reported_on_its_issue() {
+        let mut tracker = seeded_issue_tracker(None);
+        let comment = json!({
+            "type": "Comment",
+            "action": "create",
+            "data": { "id": "c9", "body": "Looks good", "issueId": "issue-1", "user": { "name": "Cy" } }
+        });
+        let event = tracker.handle(&comment).unwrap();
+        assert_eq!(event.label, "ENG-1");
+        assert_eq!(event.changes[0].field, "comments");
+        assert_eq!(event.changes[0].added[0]["body"], "Looks good");
+        // Redelivery of the same comment is ignored
+        assert!(tracker.handle(&comment).is_none());
+    }
+
+    #[test]
+    fn test_tracker_ignores_other_entities() {
+        let mut tracker = seeded_issue_tracker(None);
+        let other = issue_payload("update", json!({ "id": "issue-2" }), Value::Null);
+        assert!(tracker.handle(&other).is_none());
+        let comment = json!({ "type": "Comment", "action": "create", "data": { "id": "issue-1" } });
+        assert!(tracker.handle(&comment).is_none());
+        let project = json!({ "type": "Project", "action": "update", "data": { "id": "issue-1" } });
+        assert!(tracker.handle(&project).is_none());
+
+        let removed = tracker
+            .handle(&issue_payload(
+                "remove",
+                json!({ "id": "issue-1" }),
+                Value::Null,
+            ))
+            .unwrap();
+        assert_eq!(removed.event, "removed");
+        assert_eq!(removed.label, "ENG-1");
+    }
+
+    #[test]
+    fn test_team_snapshot_tracks_active_cycle() {
+        let mut tracker = Tracker::new(WatchKind::Team, "team-1", None);
+        tracker.seed(
+            Entity::Team,
+            &json!({ "id": "team-1", "name": "Eng", "activeCycle": { "number": 4, "progress": 0.25 } }),
+        );
+        let event = tracker
+            .observe(
+                Entity::Team,
+                &json!({ "id": "team-1", "name": "Eng", "activeCycle": { "number": 4, "progress": 0.5 } }),
+            )
+            .unwrap();
+        assert_eq!(
+            event.changes,
+            vec![FieldChange::new("cycleProgress", json!(25), json!(50))]
+        );
+    }
+
+    fn state_change_event() -> WatchEvent {
+        let mut labels = FieldChange::new("labels", json!(["bug"]), json!(["bug", "ui"]));
+        labels.added.push(json!("ui"));
+        WatchEvent {
+            event: "updated",
+            entity_type: Entity::Issue,
+            label: "ENG-1".to_string(),
+            entity: json!({ "id": "issue-1", "title": "Fix login", "state": { "name": "Done" } }),
+            changes: vec![
+                FieldChange::new("state", json!("Todo"), json!("Done")),
+                labels,
+            ],
+        }
+    }
+
+    #[test]
+    fn test_on_conditions() {
+        let event = state_change_event();
+        let matches = |cond: &str| {
+            Condition::parse(WatchKind::Issue, cond)
+                .unwrap()
+                .matches(&event)
+        };
+        assert!(matches("state"));
+        assert!(matches("state=done"));
+        assert!(!matches("state=Todo"));
+        assert!(matches("labels=ui"));
+        assert!(!matches("labels=bug"));
+        assert!(!matches("assignee"));
+        assert!(matches("event=updated"));
+        assert!(!matches("event=created"));
+
+        assert!(Condition::parse(WatchKind::Issue, "bogus=1").is_err());
+        assert!(Condition::parse(WatchKind::Issue, "event=moved").is_err());
+        assert!(Condition::parse(WatchKind::Project, "labels").is_err());
+    }
+
+    #[test]
+    fn test_hooks_require_all_conditions() {
+        let args = HookArgs {
+            exec: vec!["true".to_string()],
+            on: vec!["state=Done".to_string(), "assignee".to_string()],
+        };
+        let hooks = Hooks::compile(WatchKind::Issue, &args).unwrap();
+        assert!(!hooks.matches(&state_change_event()));
+
+        let no_exec = Hooks::compile(WatchKind::Issue, &HookArgs::default()).unwrap();
+        assert!(!no_exec.matches(&state_change_event()));
+    }
+
+    #[test]
+    fn test_hook_env_describes_event() {
+        let event = state_change_event();
+        let env: HashMap<String, String> = hook_env(&event, &event_json(&event, "poll"))
+            .into_iter()
+            .collect();
+        assert_eq!(env["LINEAR_EVENT"], "updated");
+        assert_eq!(env["LINEAR_SOURCE"], "poll");
+        assert_eq!(env["LINEAR_ISSUE_IDENTIFIER"], "ENG-1");
+        assert_eq!(env["LINEAR_ISSUE_STATE"], "Done");
+        assert_eq!(env["LINEAR_CHANGED_FIELDS"], "state,labels");
+        assert_eq!(env["LINEAR_FROM_STATE"], "Todo");
+        assert_eq!(env["LINEAR_TO_LABELS"], "bug, ui");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_hook_passes_event_on_stdin_and_env() {
+        let out = std::env::temp_dir().join(format!("linear-cli-hook-{}.txt", std::process::id()));
+        let command = format!(
+            "cat > '{}'; echo \"$LINEAR_EVENT\" >> '{}'",
+            out.display(),
+            out.display()
+        );
+        let env = vec![("LINEAR_EVENT".to_string(), "updated".to_string())];
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime
+            .block_on(run_hook(&command, &env, b"{\"event\":\"updated\"}"))
+            .unwrap();
+        let written = std::fs::read_to_string(&out).unwrap();
+        let _ = std::fs::remove_file(&out);
+        assert_eq!(written, "{\"event\":\"updated\"}updated\n");
+
+        let failed = runtime.block_on(run_hook("exit 3", &env, b""));
+        assert!(failed.unwrap_err().to_string().contains("exited with"));
+    }
+
+    #[test]
+    fn test_parse_relay_line_accepts_ndjson_and_sse() {
+        assert_eq!(
+            parse_relay_line("{\"type\":\"Issue\"}\n"),
+            Some(json!({ "type": "Issue" }))
+        );
+        assert_eq!(
+            parse_relay_line("data: {\"type\":\"Project\"}"),
+            Some(json!({ "type": "Project" }))
+        );
+        assert_eq!(parse_relay_line(": keep-alive"), None);
+        assert_eq!(parse_relay_line("event: message"), None);
+        assert_eq!(parse_relay_line("{not json"), None);
+        assert_eq!(parse_relay_line(""), None);
+    }
+
+    #[test]
+    fn test_display_value() {
+        assert_eq!(display_value(&Value::Null), "none");
+        assert_eq!(display_value(&json!([])), "none");
+        assert_eq!(display_value(&json!(["bug", "ui"])), "bug, ui");
+        assert_eq!(display_value(&json!(2)), "2");
+    }
+
+    #[test]
+    fn test_event_lines_strip_escape_sequences() {
+        let mut comments = FieldChange::new("comments", Value::Null, Value::Null);
+        comments.added.push(json!({
+            "author": "mallory\u{1b}[2J",
+            "body": "hi \u{1b}]8;;https://evil.example\u{7}click\u{1b}]8;;\u{7}",
+        }));
+        let event = WatchEvent {
+            event: "updated",
+            entity_type: Entity::Issue,
+            label: "ENG-1".to_string(),
+            entity: json!({ "id": "issue-1" }),
+            changes: vec![
+                FieldChange::new(
+                    "title",
+                    json!("Fix login"),
+                    json!("\u{1b}[31mpwned\u{1b}[0m\u{7}"),
+                ),
+                comments,
+            ],
+        };
+
+        let lines = event_lines(&event);
+        assert!(lines
+            .iter()
+            .all(|line| !line.contains('\u{1b}') && !line.contains('\u{7}')));
+        assert_eq!(lines[1], "    title: Fix login → pwned");
+        assert_eq!(lines[2], "    comment by mallory: hi click");
     }
 }
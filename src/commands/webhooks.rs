@@ -1,10 +1,12 @@
 use anyhow::{Context, Result};
 use clap::Subcommand;
 use colored::Colorize;
-use serde_json::json;
-use std::sync::Arc;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use tabled::{Table, Tabled};
-use tokio::sync::Semaphore;
+use tokio::sync::{broadcast, mpsc, watch, Semaphore};
 
 use crate::api::{resolve_team_id, LinearClient};
 use crate::display_options;
@@ -13,6 +15,9 @@ use crate::output::{
 };
 use crate::text::truncate;
 use crate::types::Webhook;
+use crate::webhook_delivery::{
+    log_record, read_log, sign, DeliveryArgs, EventLog, ForwardTarget, Forwarder,
+};
 
 #[derive(Subcommand)]
 pub enum WebhookCommands {
@@ -108,6 +113,11 @@ pub enum WebhookCommands {
     linear webhooks listen --port 9000      # Start local listener
     linear wh listen --port 9000 --events Issue,Comment
     linear wh listen --url https://my-tunnel.ngrok.io --events Issue
+    linear wh listen --log ~/linear-events.log --forward http://localhost:3000/hook
+    linear wh listen --forward unix:/run/linear.sock,http://localhost:4000/events
+
+Forwarded requests carry the original body and Linear-Signature header.
+Unix socket targets receive each event body as one NDJSON line.
 
 NOTE: Linear cannot reach localhost directly.
 Use a tunnel service (ngrok, cloudflare tunnel) and pass --url with your public URL."#)]
@@ -133,6 +143,91 @@ Use a tunnel service (ngrok, cloudflare tunnel) and pass --url with your public
         /// Output events as JSON
         #[arg(long)]
         json: bool,
+        #[command(flatten)]
+        delivery: DeliveryArgs,
+    },
+    /// Run a long-lived receiver for an existing webhook (event gateway)
+    #[command(after_help = r#"EXAMPLES:
+    linear webhooks store-secret WEBHOOK_ID --secure   # Save the signing secret once
+    linear webhooks serve WEBHOOK_ID --port 9000
+    linear wh serve WEBHOOK_ID --bind 0.0.0.0 --events-token $TOKEN --log /var/log/linear/events.log
+    linear wh serve WEBHOOK_ID --forward http://localhost:3000/hook --json
+
+The webhook is reused as-is and never deleted. Its signing secret is read from
+LINEAR_WEBHOOK_SECRET, the keyring, or the config file (see store-secret).
+
+ENDPOINTS:
+    POST /webhook   Signed deliveries from Linear
+    GET  /health    JSON status; 503 while the webhook is disabled or unreachable
+    GET  /events    NDJSON stream of verified payloads (for watch --relay);
+                    requires "Authorization: Bearer TOKEN" (--events-token,
+                    defaulting to the webhook signing secret)
+
+Linear disables webhooks after repeated delivery failures; serve re-checks the
+webhook every --check-interval seconds and re-enables it unless --no-reenable.
+SIGTERM and Ctrl+C stop accepting connections and drain in-flight events."#)]
+    Serve {
+        /// ID of an existing webhook pointing at this server
+        id: String,
+        /// Port for the HTTP server
+        #[arg(short, long, default_value = "9000")]
+        port: u16,
+        /// Address to bind the HTTP server to
+        #[arg(long, default_value = "127.0.0.1")]
+        bind: String,
+        /// Seconds between webhook status checks
+        #[arg(long, default_value = "300")]
+        check_interval: u64,
+        /// Do not re-enable the webhook when Linear has disabled it
+        #[arg(long)]
+        no_reenable: bool,
+        /// Bearer token required by GET /events (default: the webhook signing secret)
+        #[arg(long, env = "LINEAR_WEBHOOK_EVENTS_TOKEN", hide_env_values = true)]
+        events_token: Option<String>,
+        /// Output events as JSON
+        #[arg(long)]
+        json: bool,
+        #[command(flatten)]
+        delivery: DeliveryArgs,
+    },
+    /// Store a webhook signing secret for `webhooks serve` (reads from stdin or prompt)
+    #[command(after_help = r#"EXAMPLES:
+    linear webhooks store-secret WEBHOOK_ID            # Prompt for the secret
+    echo $SECRET | linear wh store-secret WEBHOOK_ID --secure"#)]
+    StoreSecret {
+        /// Webhook ID
+        id: String,
+        /// Store in the OS keyring instead of the config file
+        #[arg(long)]
+        secure: bool,
+    },
+    /// Re-deliver events recorded by `listen --log` to a target
+    #[command(after_help = r#"EXAMPLES:
+    linear webhooks replay events.log --to http://localhost:3000/hook
+    linear wh replay events.log --to http://localhost:3000/hook --secret $SECRET
+    linear wh replay events.log --to unix:/run/linear.sock --events Issue --limit 10
+    linear wh replay events.log --to http://localhost:3000/hook --dry-run
+
+Events are sent oldest first. With --secret each body is signed with a fresh
+Linear-Signature header; without it the requests are unsigned."#)]
+    Replay {
+        /// NDJSON event log written by `webhooks listen --log`
+        log: PathBuf,
+        /// Target: http(s) URL or unix:/path/to.sock (repeatable or comma-separated)
+        #[arg(long, required = true, value_delimiter = ',')]
+        to: Vec<String>,
+        /// Sign each delivery with this webhook secret
+        #[arg(long)]
+        secret: Option<String>,
+        /// Only replay these resource types (e.g., Issue,Comment)
+        #[arg(long, value_delimiter = ',')]
+        events: Vec<String>,
+        /// Replay at most this many events
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Milliseconds to wait between deliveries
+        #[arg(long, default_value = "0")]
+        delay: u64,
     },
 }
 
@@ -197,7 +292,48 @@ pub async fn handle(cmd: WebhookCommands, output: &OutputOptions) -> Result<()>
             secret,
             url,
             json,
-        } => listen(port, bind, events, team, secret, url, json, output).await,
+            delivery,
+        } => {
+            listen(
+                port, bind, events, team, secret, url, json, delivery, output,
+            )
+            .await
+        }
+        WebhookCommands::Serve {
+            id,
+            port,
+            bind,
+            check_interval,
+            no_reenable,
+            events_token,
+            json,
+            delivery,
+        } => {
+            let options = ServeOptions {
+                port,
+                bind,
+                check_interval: std::time::Duration::from_secs(check_interval.max(1)),
+                reenable: !no_reenable,
+                events_token,
+                json_output: json,
+            };
+            serve(&id, options, delivery).await
+        }
+        WebhookCommands::StoreSecret { id, secure } => {
+            crate::config::set_webhook_secret_from_stdin_or_prompt(&id, secure)?;
+            if !crate::output::is_quiet() {
+                println!("{} Signing secret stored for webhook {}", "+".green(), id);
+            }
+            Ok(())
+        }
+        WebhookCommands::Replay {
+            log,
+            to,
+            secret,
+            events,
+            limit,
+            delay,
+        } => replay(&log, to, secret, events, limit, delay, output).await,
     }
 }
 
@@ -210,6 +346,9 @@ fn safe_terminal_value(value: &str) -> String {
 }
 
 const MAX_CONCURRENT_WEBHOOK_CONNECTIONS: usize = 32;
+/// `/events` subscribers are long-lived, so they get their own limit instead of
+/// holding delivery permits
+const MAX_RELAY_SUBSCRIBERS: usize = 16;
 
 async fn list_webhooks(output: &OutputOptions) -> Result<()> {
     let client = LinearClient::new()?;
@@ -653,59 +792,60 @@ fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
     mac.verify_slice(&sig_bytes).is_ok()
 }
 
-#[allow(clippy::too_many_arguments)]
-async fn listen(
+/// A verified webhook delivery received by the local listener
+#[derive(Debug, Clone)]
+pub(crate) struct WebhookEvent {
+    pub payload: Value,
+    /// Raw request body, forwarded byte-for-byte so the signature stays valid
+    pub body: String,
+    pub signature: String,
+    pub addr: std::net::SocketAddr,
+}
+
+/// A webhook registered for the lifetime of a local listener
+pub(crate) struct TempWebhook {
+    pub id: String,
+    pub url: String,
+    pub secret: String,
+}
+
+const DELETE_WEBHOOK_MUTATION: &str = r#"
+    mutation($id: String!) {
+        webhookDelete(id: $id) {
+            success
+        }
+    }
+"#;
+
+/// Register a temporary webhook pointing at `url` (or localhost:`port` when no public URL is given)
+pub(crate) async fn create_temp_webhook(
+    client: &LinearClient,
+    url: Option<&str>,
     port: u16,
-    bind: String,
-    events: Vec<String>,
-    team: Option<String>,
+    events: &[String],
+    team_id: Option<String>,
     secret: Option<String>,
-    url: Option<String>,
-    json_output: bool,
-    output: &OutputOptions,
-) -> Result<()> {
-    let client = LinearClient::new()?;
-
-    // Determine the public URL for the webhook
+) -> Result<TempWebhook> {
     let webhook_url = match url {
-        Some(ref u) => {
-            let base = u.trim_end_matches('/');
-            format!("{}/webhook", base)
-        }
-        None => {
-            println!(
-                "{}",
-                "Note: Linear cannot reach localhost directly.".yellow()
-            );
-            println!(
-                "Use a tunnel service (ngrok, cloudflare tunnel) and pass --url with your public URL."
-            );
-            println!("Starting local server on port {} anyway...", port);
-            format!("http://localhost:{}/webhook", port)
-        }
+        Some(u) => format!("{}/webhook", u.trim_end_matches('/')),
+        None => format!("http://localhost:{}/webhook", port),
     };
 
-    // Create a temporary webhook
     let mut webhook_input = json!({
         "url": webhook_url,
         "enabled": true,
+        "label": "linear-cli-listen",
     });
-
     if !events.is_empty() {
         webhook_input["resourceTypes"] = json!(events);
     }
-
-    if let Some(ref t) = team {
-        let team_id = resolve_team_id(&client, t, &output.cache).await?;
+    if let Some(team_id) = team_id {
         webhook_input["teamId"] = json!(team_id);
     }
-
     if let Some(ref s) = secret {
         webhook_input["secret"] = json!(s);
     }
 
-    webhook_input["label"] = json!("linear-cli-listen");
-
     let create_mutation = r#"
         mutation($input: WebhookCreateInput!) {
             webhookCreate(input: $input) {
@@ -726,78 +866,163 @@ async fn listen(
     }
 
     let webhook_data = &result["data"]["webhookCreate"]["webhook"];
-    let webhook_id = webhook_data["id"]
+    let id = webhook_data["id"]
         .as_str()
         .context("Missing webhook ID")?
         .to_string();
-    let webhook_secret = secret.or_else(|| webhook_data["secret"].as_str().map(|s| s.to_string()));
-    if webhook_secret.is_none() {
-        let delete_mutation = r#"
-            mutation($id: String!) {
-                webhookDelete(id: $id) { success }
-            }
-        "#;
-        let _ = client
-            .mutate(delete_mutation, Some(json!({ "id": webhook_id })))
-            .await;
+    let Some(secret) = secret.or_else(|| webhook_data["secret"].as_str().map(|s| s.to_string()))
+    else {
+        let _ = delete_temp_webhook(client, &id).await;
         anyhow::bail!("Webhook secret was not returned; refusing to start unsigned listener");
-    }
+    };
 
-    println!("{} Temporary webhook created: {}", "+".green(), webhook_id);
-    println!("  URL: {}", webhook_url);
-    println!("  Listening on port {}...", port);
-    println!("  Press Ctrl+C to stop and clean up.\n");
+    Ok(TempWebhook {
+        id,
+        url: webhook_url,
+        secret,
+    })
+}
 
-    // Start the HTTP server
+/// Delete a temporary webhook, warning (not failing) when cleanup does not succeed
+pub(crate) async fn delete_temp_webhook(client: &LinearClient, id: &str) -> Result<()> {
+    let result = client
+        .mutate(DELETE_WEBHOOK_MUTATION, Some(json!({ "id": id })))
+        .await?;
+    if result["data"]["webhookDelete"]["success"].as_bool() != Some(true) {
+        anyhow::bail!("Failed to delete temporary webhook {}", id);
+    }
+    Ok(())
+}
+
+/// Bind the local listener, deleting the temporary webhook if the port is unavailable
+pub(crate) async fn bind_listener(
+    client: &LinearClient,
+    webhook: &TempWebhook,
+    bind: &str,
+    port: u16,
+) -> Result<tokio::net::TcpListener> {
     let listener_addr = format!("{}:{}", bind, port);
-    let listener = match tokio::net::TcpListener::bind(&listener_addr).await {
-        Ok(l) => l,
+    match tokio::net::TcpListener::bind(&listener_addr).await {
+        Ok(l) => Ok(l),
         Err(e) => {
-            // Clean up the webhook we just created before returning the error
             eprintln!("Failed to bind to port {}, cleaning up webhook...", port);
-            let delete_mutation = r#"
-                mutation($id: String!) {
-                    webhookDelete(id: $id) { success }
+            let _ = delete_temp_webhook(client, &webhook.id).await;
+            Err(e).context(format!("Failed to bind to {}", listener_addr))
+        }
+    }
+}
+
+/// Accept webhook deliveries forever, sending every verified event to `events`
+///
+/// When `state` is given (`webhooks serve`), GET `/health` and `/events` are served as well.
+pub(crate) async fn accept_events(
+    listener: tokio::net::TcpListener,
+    secret: String,
+    events: mpsc::UnboundedSender<WebhookEvent>,
+    state: Option<Arc<ServeState>>,
+) {
+    let connection_limit = Arc::new(Semaphore::new(MAX_CONCURRENT_WEBHOOK_CONNECTIONS));
+    let secret = Arc::new(secret);
+
+    loop {
+        match listener.accept().await {
+            Ok((mut stream, addr)) => {
+                if let Ok(permit) = connection_limit.clone().try_acquire_owned() {
+                    let secret = secret.clone();
+                    let events = events.clone();
+                    let state = state.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_connection(
+                            stream,
+                            addr,
+                            &secret,
+                            &events,
+                            state.as_deref(),
+                            permit,
+                        )
+                        .await
+                        {
+                            eprintln!("Error handling connection from {}: {}", addr, e);
+                        }
+                    });
+                } else {
+                    let _ = reject_busy_connection(&mut stream).await;
+                    eprintln!(
+                        "Too many concurrent webhook connections; rejecting {}",
+                        addr
+                    );
                 }
-            "#;
-            let _ = client
-                .mutate(delete_mutation, Some(json!({ "id": webhook_id })))
-                .await;
-            return Err(e).context(format!("Failed to bind to {}", listener_addr));
+            }
+            Err(e) => {
+                // Usually fd exhaustion; back off instead of spinning
+                eprintln!("Accept error: {}", e);
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            }
         }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn listen(
+    port: u16,
+    bind: String,
+    events: Vec<String>,
+    team: Option<String>,
+    secret: Option<String>,
+    url: Option<String>,
+    json_output: bool,
+    delivery: DeliveryArgs,
+    output: &OutputOptions,
+) -> Result<()> {
+    let client = LinearClient::new()?;
+    let event_log = delivery.event_log();
+    let forwarder = delivery.forwarder()?.map(Arc::new);
+
+    if url.is_none() {
+        println!(
+            "{}",
+            "Note: Linear cannot reach localhost directly.".yellow()
+        );
+        println!(
+            "Use a tunnel service (ngrok, cloudflare tunnel) and pass --url with your public URL."
+        );
+        println!("Starting local server on port {} anyway...", port);
+    }
+
+    let team_id = match team {
+        Some(ref t) => Some(resolve_team_id(&client, t, &output.cache).await?),
+        None => None,
     };
 
-    // Set up Ctrl+C handler
-    let webhook_id_clone = webhook_id.clone();
-    let cleanup_client = LinearClient::new()?;
+    let webhook =
+        create_temp_webhook(&client, url.as_deref(), port, &events, team_id, secret).await?;
+
+    println!("{} Temporary webhook created: {}", "+".green(), webhook.id);
+    println!("  URL: {}", webhook.url);
+    println!("  Listening on port {}...", port);
+    if let Some(ref log) = event_log {
+        println!("  Logging events to {}", log.path().display());
+    }
+    if let Some(ref forwarder) = forwarder {
+        for target in forwarder.targets() {
+            println!("  Forwarding events to {}", target);
+        }
+    }
+    println!("  Press Ctrl+C to stop and clean up.\n");
+
+    let listener = bind_listener(&client, &webhook, &bind, port).await?;
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let server = tokio::spawn(accept_events(listener, webhook.secret.clone(), tx, None));
+
     let shutdown = tokio::signal::ctrl_c();
     tokio::pin!(shutdown);
 
-    let connection_limit = Arc::new(Semaphore::new(MAX_CONCURRENT_WEBHOOK_CONNECTIONS));
-
     loop {
         tokio::select! {
-            accept = listener.accept() => {
-                match accept {
-                    Ok((mut stream, addr)) => {
-                        let ws = webhook_secret.clone();
-                        let json_out = json_output;
-                        if let Ok(permit) = connection_limit.clone().try_acquire_owned() {
-                            tokio::spawn(async move {
-                                let _permit = permit;
-                                if let Err(e) = handle_connection(stream, addr, ws.as_deref(), json_out).await {
-                                    eprintln!("Error handling connection from {}: {}", addr, e);
-                                }
-                            });
-                        } else {
-                            let _ = reject_busy_connection(&mut stream).await;
-                            eprintln!("Too many concurrent webhook connections; rejecting {}", addr);
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("Accept error: {}", e);
-                    }
-                }
+            Some(event) = rx.recv() => {
+                print_event(&event, json_output)?;
+                deliver_event(&event, event_log.as_ref(), forwarder.as_ref());
             }
             _ = &mut shutdown => {
                 println!("\n{} Shutting down...", "!".yellow());
@@ -805,50 +1030,497 @@ async fn listen(
             }
         }
     }
+    server.abort();
+
+    match delete_temp_webhook(&client, &webhook.id).await {
+        Ok(()) => println!("{} Temporary webhook cleaned up: {}", "-".red(), webhook.id),
+        Err(e) => eprintln!(
+            "Warning: Failed to clean up temporary webhook {}: {}",
+            webhook.id, e
+        ),
+    }
 
-    // Clean up: delete the temporary webhook
-    let delete_mutation = r#"
-        mutation($id: String!) {
-            webhookDelete(id: $id) {
-                success
+    Ok(())
+}
+
+/// Log and forward one verified event; failures are reported without stopping the listener.
+/// Returns the forwarding task, if any, so callers can wait for it on shutdown.
+pub(crate) fn deliver_event(
+    event: &WebhookEvent,
+    event_log: Option<&EventLog>,
+    forwarder: Option<&Arc<Forwarder>>,
+) -> Option<tokio::task::JoinHandle<()>> {
+    if let Some(log) = event_log {
+        if let Err(e) = log.append(&log_record(&event.payload, Some(&event.signature))) {
+            eprintln!("{} Failed to write event log: {}", "!".red(), e);
+        }
+    }
+    let forwarder = forwarder?.clone();
+    let body = event.body.clone();
+    let signature = event.signature.clone();
+    Some(tokio::spawn(async move {
+        for (target, result) in forwarder.deliver(&body, Some(&signature)).await {
+            if let Err(e) = result {
+                eprintln!("{} Failed to forward event to {}: {}", "!".red(), target, e);
+            }
+        }
+    }))
+}
+
+/// How long shutdown waits for in-flight deliveries and forwards
+const SHUTDOWN_GRACE: std::time::Duration = std::time::Duration::from_secs(10);
+/// Keep-alive interval for idle `/events` streams
+const RELAY_KEEPALIVE: std::time::Duration = std::time::Duration::from_secs(15);
+
+struct ServeOptions {
+    port: u16,
+    bind: String,
+    check_interval: std::time::Duration,
+    reenable: bool,
+    events_token: Option<String>,
+    json_output: bool,
+}
+
+/// Shared state of `webhooks serve`, reported by `/health` and fanned out on `/events`
+pub(crate) struct ServeState {
+    webhook_id: String,
+    started: std::time::Instant,
+    enabled: AtomicBool,
+    received: AtomicU64,
+    last_event_at: Mutex<Option<String>>,
+    last_check_at: Mutex<Option<String>>,
+    last_check_error: Mutex<Option<String>>,
+    relay: broadcast::Sender<String>,
+    relay_limit: Arc<Semaphore>,
+    /// Bearer token `/events` clients must present
+    events_token: String,
+    shutdown: watch::Sender<bool>,
+}
+
+impl ServeState {
+    fn new(webhook_id: &str, enabled: bool, events_token: &str) -> Self {
+        let (relay, _) = broadcast::channel(256);
+        let (shutdown, _) = watch::channel(false);
+        Self {
+            webhook_id: webhook_id.to_string(),
+            started: std::time::Instant::now(),
+            enabled: AtomicBool::new(enabled),
+            received: AtomicU64::new(0),
+            last_event_at: Mutex::new(None),
+            last_check_at: Mutex::new(None),
+            last_check_error: Mutex::new(None),
+            relay,
+            relay_limit: Arc::new(Semaphore::new(MAX_RELAY_SUBSCRIBERS)),
+            events_token: events_token.to_string(),
+            shutdown,
+        }
+    }
+
+    /// Whether the request headers carry `Authorization: Bearer <events token>`
+    fn is_authorized(&self, headers: &str) -> bool {
+        headers
+            .lines()
+            .find(|l| l.to_lowercase().starts_with("authorization:"))
+            .and_then(|l| l.split_once(':'))
+            .map(|(_, v)| v.trim())
+            .and_then(|v| {
+                v.strip_prefix("Bearer ")
+                    .or_else(|| v.strip_prefix("bearer "))
+            })
+            .is_some_and(|token| constant_time_eq(token.trim(), &self.events_token))
+    }
+
+    fn record_event(&self, event: &WebhookEvent) {
+        self.received.fetch_add(1, Ordering::Relaxed);
+        *self.last_event_at.lock().unwrap() = Some(chrono::Utc::now().to_rfc3339());
+        if let Ok(line) = serde_json::to_string(&event.payload) {
+            // No subscribers is not an error
+            let _ = self.relay.send(line);
+        }
+    }
+
+    fn record_check(&self, result: &Result<bool>) {
+        *self.last_check_at.lock().unwrap() = Some(chrono::Utc::now().to_rfc3339());
+        match result {
+            Ok(enabled) => {
+                self.enabled.store(*enabled, Ordering::Relaxed);
+                *self.last_check_error.lock().unwrap() = None;
+            }
+            Err(e) => *self.last_check_error.lock().unwrap() = Some(e.to_string()),
+        }
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed) && self.last_check_error.lock().unwrap().is_none()
+    }
+
+    fn health(&self) -> Value {
+        json!({
+            "status": if self.is_healthy() { "ok" } else { "degraded" },
+            "webhookId": self.webhook_id,
+            "enabled": self.enabled.load(Ordering::Relaxed),
+            "uptimeSecs": self.started.elapsed().as_secs(),
+            "eventsReceived": self.received.load(Ordering::Relaxed),
+            "lastEventAt": *self.last_event_at.lock().unwrap(),
+            "lastCheckAt": *self.last_check_at.lock().unwrap(),
+            "lastCheckError": *self.last_check_error.lock().unwrap(),
+            "relayClients": self.relay.receiver_count(),
+        })
+    }
+}
+
+/// Fetch the webhook's enabled flag, re-enabling it when Linear has switched it off
+async fn check_webhook(client: &LinearClient, id: &str, reenable: bool) -> Result<bool> {
+    let query = r#"
+        query($id: String!) {
+            webhook(id: $id) { id enabled }
+        }
+    "#;
+    let result = client.query(query, Some(json!({ "id": id }))).await?;
+    let webhook = &result["data"]["webhook"];
+    if webhook.is_null() {
+        anyhow::bail!("Webhook not found: {}", id);
+    }
+    if webhook["enabled"].as_bool() != Some(false) {
+        return Ok(true);
+    }
+    if !reenable {
+        return Ok(false);
+    }
+
+    let mutation = r#"
+        mutation($id: String!, $input: WebhookUpdateInput!) {
+            webhookUpdate(id: $id, input: $input) { success }
+        }
+    "#;
+    let result = client
+        .mutate(
+            mutation,
+            Some(json!({ "id": id, "input": { "enabled": true } })),
+        )
+        .await?;
+    if result["data"]["webhookUpdate"]["success"].as_bool() != Some(true) {
+        anyhow::bail!("Failed to re-enable webhook {}", id);
+    }
+    eprintln!(
+        "{} Webhook {} was disabled; re-enabled it",
+        "!".yellow(),
+        id
+    );
+    Ok(true)
+}
+
+/// Resolves when the process is asked to stop (Ctrl+C, or SIGTERM on Unix)
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        if let Ok(mut term) = signal(SignalKind::terminate()) {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = term.recv() => {}
             }
+            return;
+        }
+    }
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+async fn serve(id: &str, options: ServeOptions, delivery: DeliveryArgs) -> Result<()> {
+    let client = LinearClient::new()?;
+    let event_log = delivery.event_log();
+    let forwarder = delivery.forwarder()?.map(Arc::new);
+
+    let secret = crate::config::get_webhook_secret(id)?.with_context(|| {
+        format!(
+            "No signing secret stored for webhook {}. Run `linear webhooks store-secret {}` or set LINEAR_WEBHOOK_SECRET",
+            id, id
+        )
+    })?;
+
+    let query = r#"
+        query($id: String!) {
+            webhook(id: $id) { id url label enabled }
         }
     "#;
+    let result = client.query(query, Some(json!({ "id": id }))).await?;
+    let webhook = &result["data"]["webhook"];
+    if webhook.is_null() {
+        anyhow::bail!("Webhook not found: {}", id);
+    }
 
-    match cleanup_client
-        .mutate(delete_mutation, Some(json!({ "id": webhook_id_clone })))
+    let events_token = options
+        .events_token
+        .clone()
+        .unwrap_or_else(|| secret.clone());
+    if events_token.is_empty() {
+        anyhow::bail!("--events-token must not be empty");
+    }
+    let state = Arc::new(ServeState::new(id, true, &events_token));
+    state.record_check(&check_webhook(&client, id, options.reenable).await);
+
+    let listener_addr = format!("{}:{}", options.bind, options.port);
+    let listener = tokio::net::TcpListener::bind(&listener_addr)
         .await
-    {
-        Ok(result) => {
-            if result["data"]["webhookDelete"]["success"].as_bool() == Some(true) {
+        .with_context(|| format!("Failed to bind to {}", listener_addr))?;
+    let listener_addr = listener.local_addr()?;
+
+    println!(
+        "{} Serving webhook {} ({})",
+        "+".green(),
+        id,
+        safe_terminal_value(webhook["label"].as_str().unwrap_or("-"))
+    );
+    println!(
+        "  Registered URL: {}",
+        safe_terminal_value(webhook["url"].as_str().unwrap_or("-"))
+    );
+    println!("  Listening on http://{}", listener_addr);
+    println!("  Health: http://{}/health", listener_addr);
+    println!("  Relay:  http://{}/events", listener_addr);
+    if let Some(ref log) = event_log {
+        println!("  Logging events to {}", log.path().display());
+    }
+    if let Some(ref forwarder) = forwarder {
+        for target in forwarder.targets() {
+            println!("  Forwarding events to {}", target);
+        }
+    }
+    if !state.is_healthy() {
+        eprintln!(
+            "{} Webhook is not active: {}",
+            "!".yellow(),
+            state.health()["lastCheckError"]
+                .as_str()
+                .unwrap_or("disabled in Linear")
+        );
+    }
+    println!();
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let server = tokio::spawn(accept_events(listener, secret, tx, Some(state.clone())));
+
+    let mut forwards = Vec::new();
+    let mut checks = tokio::time::interval(options.check_interval);
+    checks.tick().await;
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            Some(event) = rx.recv() => {
+                state.record_event(&event);
+                print_event(&event, options.json_output)?;
+                forwards.retain(|t: &tokio::task::JoinHandle<()>| !t.is_finished());
+                forwards.extend(deliver_event(&event, event_log.as_ref(), forwarder.as_ref()));
+            }
+            _ = checks.tick() => {
+                let result = check_webhook(&client, id, options.reenable).await;
+                if let Err(ref e) = result {
+                    eprintln!("{} Webhook check failed: {}", "!".yellow(), e);
+                }
+                state.record_check(&result);
+            }
+            _ = &mut shutdown => {
+                eprintln!("\n{} Shutting down...", "!".yellow());
+                break;
+            }
+        }
+    }
+
+    // Stop accepting, close relay streams, then drain what is already in flight
+    server.abort();
+    let _ = state.shutdown.send(true);
+    let drained = tokio::time::timeout(SHUTDOWN_GRACE, async {
+        while let Some(event) = rx.recv().await {
+            state.record_event(&event);
+            print_event(&event, options.json_output)?;
+            forwards.extend(deliver_event(
+                &event,
+                event_log.as_ref(),
+                forwarder.as_ref(),
+            ));
+        }
+        for task in forwards {
+            let _ = task.await;
+        }
+        Ok::<_, anyhow::Error>(())
+    })
+    .await;
+    match drained {
+        Ok(result) => result?,
+        Err(_) => eprintln!(
+            "{} Timed out waiting for in-flight events after {}s",
+            "!".yellow(),
+            SHUTDOWN_GRACE.as_secs()
+        ),
+    }
+
+    Ok(())
+}
+
+async fn replay(
+    log: &Path,
+    to: Vec<String>,
+    secret: Option<String>,
+    events: Vec<String>,
+    limit: Option<usize>,
+    delay: u64,
+    output: &OutputOptions,
+) -> Result<()> {
+    let targets = to
+        .iter()
+        .map(|t| t.parse())
+        .collect::<Result<Vec<ForwardTarget>>>()?;
+    let forwarder = Forwarder::new(targets)?;
+
+    let mut records = read_log(log)?;
+    if !events.is_empty() {
+        records.retain(|r| {
+            let event_type = r["payload"]["type"].as_str().unwrap_or("");
+            events.iter().any(|e| e.eq_ignore_ascii_case(event_type))
+        });
+    }
+    if let Some(limit) = limit {
+        records.truncate(limit);
+    }
+
+    let mut results = Vec::new();
+    let mut failed = 0;
+    for (i, record) in records.iter().enumerate() {
+        if i > 0 && delay > 0 && !output.dry_run {
+            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+        }
+        let payload = &record["payload"];
+        let event_type = payload["type"].as_str().unwrap_or("unknown");
+        let action = payload["action"].as_str().unwrap_or("unknown");
+        let body = serde_json::to_string(payload)?;
+        let signature = secret.as_deref().map(|s| sign(s, body.as_bytes()));
+
+        let outcomes: Vec<(String, Result<()>)> = if output.dry_run {
+            forwarder
+                .targets()
+                .iter()
+                .map(|t| (t.to_string(), Ok(())))
+                .collect()
+        } else {
+            forwarder
+                .deliver(&body, signature.as_deref())
+                .await
+                .into_iter()
+                .map(|(t, r)| (t.to_string(), r))
+                .collect()
+        };
+
+        for (target, outcome) in outcomes {
+            let status = match (&outcome, output.dry_run) {
+                (Ok(()), true) => "would-send",
+                (Ok(()), false) => "sent",
+                (Err(_), _) => "failed",
+            };
+            if outcome.is_err() {
+                failed += 1;
+            }
+            let error = outcome.err().map(|e| e.to_string());
+            if !output.is_json() {
+                let marker = match status {
+                    "failed" => "x".red().to_string(),
+                    "would-send" => "~".yellow().to_string(),
+                    _ => "+".green().to_string(),
+                };
                 println!(
-                    "{} Temporary webhook cleaned up: {}",
-                    "-".red(),
-                    webhook_id_clone
-                );
-            } else {
-                eprintln!(
-                    "Warning: Failed to delete temporary webhook {}",
-                    webhook_id_clone
+                    "{} {} {} -> {}{}",
+                    marker,
+                    safe_terminal_value(event_type).cyan(),
+                    safe_terminal_value(action),
+                    target,
+                    error
+                        .as_deref()
+                        .map(|e| format!(" ({})", e))
+                        .unwrap_or_default()
                 );
             }
-        }
-        Err(e) => {
-            eprintln!(
-                "Warning: Failed to clean up temporary webhook {}: {}",
-                webhook_id_clone, e
-            );
+            results.push(json!({
+                "index": i,
+                "type": event_type,
+                "action": action,
+                "target": target,
+                "status": status,
+                "error": error,
+            }));
         }
     }
 
+    if output.is_json() {
+        print_json_owned(
+            json!({
+                "events": records.len(),
+                "failed": failed,
+                "dry_run": output.dry_run,
+                "results": results,
+            }),
+            output,
+        )?;
+    } else {
+        println!(
+            "\n{} event(s) replayed from {}, {} failed delivery(ies)",
+            records.len(),
+            log.display(),
+            failed
+        );
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} deliveries failed", failed);
+    }
+    Ok(())
+}
+
+fn print_event(event: &WebhookEvent, json_output: bool) -> Result<()> {
+    let body_json = &event.payload;
+    if json_output {
+        println!("{}", serde_json::to_string(body_json)?);
+        return Ok(());
+    }
+
+    let action = safe_terminal_value(body_json["action"].as_str().unwrap_or("unknown"));
+    let event_type = safe_terminal_value(body_json["type"].as_str().unwrap_or("unknown"));
+    let timestamp = chrono::Utc::now().format("%H:%M:%S");
+
+    println!(
+        "[{}] {} {} {}",
+        timestamp.to_string().dimmed(),
+        event_type.cyan(),
+        action.yellow(),
+        format!("from {}", event.addr).dimmed()
+    );
+
+    // Show key data fields
+    if let Some(data) = body_json.get("data") {
+        if let Some(id) = data["id"].as_str() {
+            print!("  ID: {}", safe_terminal_value(id));
+        }
+        if let Some(title) = data["title"].as_str() {
+            print!("  Title: {}", safe_terminal_value(title));
+        }
+        if let Some(identifier) = data["identifier"].as_str() {
+            print!("  Key: {}", safe_terminal_value(identifier));
+        }
+        if let Some(name) = data["name"].as_str() {
+            print!("  Name: {}", safe_terminal_value(name));
+        }
+        println!();
+    }
     Ok(())
 }
 
 async fn handle_connection(
     mut stream: tokio::net::TcpStream,
     addr: std::net::SocketAddr,
-    secret: Option<&str>,
-    json_output: bool,
+    secret: &str,
+    events: &mpsc::UnboundedSender<WebhookEvent>,
+    state: Option<&ServeState>,
+    permit: tokio::sync::OwnedSemaphorePermit,
 ) -> Result<()> {
     use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
@@ -938,6 +1610,10 @@ async fn handle_connection(
     let method = request_parts.next().unwrap_or("");
     let path = request_parts.next().unwrap_or("");
 
+    if let (Some(state), "GET") = (state, method) {
+        return serve_get(stream, path, &headers_str, state, permit).await;
+    }
+
     if method != "POST" {
         let response = "HTTP/1.1 405 Method Not Allowed\r\nContent-Length: 0\r\n\r\n";
         stream.write_all(response.as_bytes()).await?;
@@ -957,21 +1633,19 @@ async fn handle_connection(
         .and_then(|l| l.split_once(':'))
         .map(|(_, v)| v.trim().to_string());
 
-    // Verify signature if secret is provided
-    if let Some(s) = secret {
-        if let Some(ref sig) = signature {
-            if !verify_signature(s, body.as_bytes(), sig) {
-                eprintln!("{} Invalid signature from {}", "!".red(), addr);
-                let response = "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n";
-                stream.write_all(response.as_bytes()).await?;
-                return Ok(());
-            }
+    let verified = signature
+        .as_deref()
+        .is_some_and(|sig| verify_signature(secret, body.as_bytes(), sig));
+    if !verified {
+        let reason = if signature.is_some() {
+            "Invalid"
         } else {
-            eprintln!("{} Missing signature from {}", "!".red(), addr);
-            let response = "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n";
-            stream.write_all(response.as_bytes()).await?;
-            return Ok(());
-        }
+            "Missing"
+        };
+        eprintln!("{} {} signature from {}", "!".red(), reason, addr);
+        let response = "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n";
+        stream.write_all(response.as_bytes()).await?;
+        return Ok(());
     }
 
     // Parse body as JSON
@@ -984,47 +1658,103 @@ async fn handle_connection(
         }
     };
 
-    // Output the event
-    if json_output {
-        println!("{}", serde_json::to_string(&body_json)?);
-    } else {
-        let action = safe_terminal_value(body_json["action"].as_str().unwrap_or("unknown"));
-        let event_type = safe_terminal_value(body_json["type"].as_str().unwrap_or("unknown"));
-        let timestamp = chrono::Utc::now().format("%H:%M:%S");
+    let _ = events.send(WebhookEvent {
+        payload: body_json,
+        body,
+        signature: signature.unwrap_or_default(),
+        addr,
+    });
 
-        println!(
-            "[{}] {} {} {}",
-            timestamp.to_string().dimmed(),
-            event_type.cyan(),
-            action.yellow(),
-            format!("from {}", addr).dimmed()
-        );
+    // Send 200 OK
+    let response = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok";
+    stream.write_all(response.as_bytes()).await?;
 
-        // Show key data fields
-        if let Some(data) = body_json.get("data") {
-            if let Some(id) = data["id"].as_str() {
-                print!("  ID: {}", safe_terminal_value(id));
-            }
-            if let Some(title) = data["title"].as_str() {
-                print!("  Title: {}", safe_terminal_value(title));
-            }
-            if let Some(identifier) = data["identifier"].as_str() {
-                print!("  Key: {}", safe_terminal_value(identifier));
+    Ok(())
+}
+
+/// Serve the read-only endpoints of `webhooks serve`
+async fn serve_get(
+    mut stream: tokio::net::TcpStream,
+    path: &str,
+    headers: &str,
+    state: &ServeState,
+    permit: tokio::sync::OwnedSemaphorePermit,
+) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let path = path.split_once('?').map(|(base, _)| base).unwrap_or(path);
+    match path {
+        "/health" => {
+            let body = serde_json::to_string(&state.health())?;
+            let status = if state.is_healthy() {
+                "200 OK"
+            } else {
+                "503 Service Unavailable"
+            };
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).await?;
+        }
+        "/events" => {
+            if !state.is_authorized(headers) {
+                let response = "HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Bearer\r\nConnection: close\r\nContent-Length: 0\r\n\r\n";
+                stream.write_all(response.as_bytes()).await?;
+                return Ok(());
             }
-            if let Some(name) = data["name"].as_str() {
-                print!("  Name: {}", safe_terminal_value(name));
+            let Ok(_subscriber) = state.relay_limit.clone().try_acquire_owned() else {
+                reject_busy_connection(&mut stream).await?;
+                return Ok(());
+            };
+            // Streams live for hours; free the delivery permit for Linear's POSTs
+            drop(permit);
+
+            // Close-delimited NDJSON stream; blank lines keep idle proxies from timing out
+            let mut relay = state.relay.subscribe();
+            let mut shutdown = state.shutdown.subscribe();
+            let response =
+                "HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n";
+            stream.write_all(response.as_bytes()).await?;
+            let mut keepalive = tokio::time::interval(RELAY_KEEPALIVE);
+            keepalive.tick().await;
+            loop {
+                tokio::select! {
+                    line = relay.recv() => match line {
+                        Ok(line) => {
+                            stream.write_all(line.as_bytes()).await?;
+                            stream.write_all(b"\n").await?;
+                        }
+                        Err(broadcast::error::RecvError::Lagged(n)) => {
+                            eprintln!("{} Relay client fell behind; dropped {} event(s)", "!".yellow(), n);
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = keepalive.tick() => stream.write_all(b"\n").await?,
+                    _ = shutdown.changed() => break,
+                }
             }
-            println!();
+            let _ = stream.shutdown().await;
+        }
+        _ => {
+            let response = "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n";
+            stream.write_all(response.as_bytes()).await?;
         }
     }
-
-    // Send 200 OK
-    let response = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok";
-    stream.write_all(response.as_bytes()).await?;
-
     Ok(())
 }
 
+/// Compare secrets without short-circuiting on the first differing byte
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
 async fn reject_busy_connection(stream: &mut tokio::net::TcpStream) -> Result<()> {
     use tokio::io::AsyncWriteExt;
 
@@ -1080,6 +1810,46 @@ mod tests {
         assert!(!is_expected_webhook_path("/other"));
     }
 
+    #[test]
+    fn test_serve_state_health_reflects_checks_and_events() {
+        let state = ServeState::new("webhook-1", true, "token");
+        assert!(state.is_healthy());
+        assert_eq!(state.health()["status"], "ok");
+
+        let mut relay = state.relay.subscribe();
+        state.record_event(&WebhookEvent {
+            payload: json!({ "type": "Issue", "action": "create" }),
+            body: String::new(),
+            signature: String::new(),
+            addr: "127.0.0.1:1".parse().unwrap(),
+        });
+        assert_eq!(state.health()["eventsReceived"], 1);
+        assert_eq!(
+            relay.try_recv().unwrap(),
+            r#"{"action":"create","type":"Issue"}"#
+        );
+
+        state.record_check(&Err(anyhow::anyhow!("network down")));
+        assert!(!state.is_healthy());
+        assert_eq!(state.health()["lastCheckError"], "network down");
+
+        state.record_check(&Ok(false));
+        let health = state.health();
+        assert_eq!(health["status"], "degraded");
+        assert_eq!(health["enabled"], false);
+        assert!(health["lastCheckError"].is_null());
+    }
+
+    #[test]
+    fn test_serve_state_requires_events_token() {
+        let state = ServeState::new("webhook-1", true, "s3cret");
+        assert!(state.is_authorized("GET /events HTTP/1.1\r\nAuthorization: Bearer s3cret"));
+        assert!(state.is_authorized("GET /events HTTP/1.1\r\nauthorization: bearer s3cret"));
+        assert!(!state.is_authorized("GET /events HTTP/1.1\r\nAuthorization: Bearer wrong"));
+        assert!(!state.is_authorized("GET /events HTTP/1.1\r\nAuthorization: s3cret"));
+        assert!(!state.is_authorized("GET /events HTTP/1.1\r\nHost: localhost"));
+    }
+
     #[test]
     fn test_safe_terminal_value_removes_escape_sequences() {
         assert_eq!(
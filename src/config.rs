@@ -1,11 +1,11 @@
 use anyhow::{Context, Result};
 use dialoguer::Password;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::{IsTerminal, Read};
 use std::path::PathBuf;
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 
 #[cfg(unix)]
 use std::io::Write;
@@ -21,11 +21,292 @@ pub struct OAuthConfig {
     pub scopes: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Workspace {
     pub api_key: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oauth: Option<OAuthConfig>,
+    /// GraphQL endpoint override (e.g. a staging proxy or local mock server)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    /// Shell command that prints the API key (e.g. `pass show linear/api-key`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key_command: Option<String>,
+    /// Webhook signing secrets by webhook ID (used by `webhooks serve`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub webhook_secrets: HashMap<String, String>,
+    /// Defaults applied when the matching flag is not given
+    #[serde(default, skip_serializing_if = "Defaults::is_empty")]
+    pub defaults: Defaults,
+}
+
+/// Per-profile defaults, set with `config set <key> <value>`.
+///
+/// Most become the default values of the flags read from `LINEAR_CLI_*`
+/// variables, so an explicit flag or environment variable always wins.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Defaults {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mine: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_ttl: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stale_while_revalidate: Option<bool>,
+}
+
+/// `config set` keys for profile defaults, with the environment variable each one feeds
+pub const DEFAULT_KEYS: &[(&str, Option<&str>)] = &[
+    ("default-team", Some("LINEAR_CLI_TEAM")),
+    ("default-project", Some("LINEAR_CLI_PROJECT")),
+    ("default-output", Some("LINEAR_CLI_OUTPUT")),
+    ("default-mine", None),
+    ("page-size", Some("LINEAR_CLI_PAGE_SIZE")),
+    ("cache-ttl", Some("LINEAR_CLI_CACHE_TTL")),
+    ("retry", Some("LINEAR_CLI_RETRY")),
+    (
+        "stale-while-revalidate",
+        Some("LINEAR_CLI_STALE_WHILE_REVALIDATE"),
+    ),
+];
+
+impl Defaults {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Current value of a default key, formatted as it would be passed to `config set`
+    pub fn get(&self, key: &str) -> Result<Option<String>> {
+        Ok(match normalize_default_key(key)? {
+            "default-team" => self.team.clone(),
+            "default-project" => self.project.clone(),
+            "default-output" => self.output.clone(),
+            "default-mine" => self.mine.map(|v| v.to_string()),
+            "page-size" => self.page_size.map(|v| v.to_string()),
+            "cache-ttl" => self.cache_ttl.map(|v| v.to_string()),
+            "stale-while-revalidate" => self.stale_while_revalidate.map(|v| v.to_string()),
+            _ => self.retry.map(|v| v.to_string()),
+        })
+    }
+
+    /// Validate and set a default key; an empty value or "default" clears it
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        let key = normalize_default_key(key)?;
+        let value = match value.trim() {
+            "" | "default" => None,
+            v => Some(v),
+        };
+        fn number<T: std::str::FromStr>(key: &str, value: Option<&str>) -> Result<Option<T>> {
+            value
+                .map(|v| {
+                    v.parse()
+                        .map_err(|_| anyhow::anyhow!("Invalid value for {}: '{}'", key, v))
+                })
+                .transpose()
+        }
+        fn boolean(key: &str, value: Option<&str>) -> Result<Option<bool>> {
+            value
+                .map(|v| match v.to_lowercase().as_str() {
+                    "true" | "yes" | "on" | "1" => Ok(true),
+                    "false" | "no" | "off" | "0" => Ok(false),
+                    _ => anyhow::bail!("Invalid value for {}: '{}' (use true or false)", key, v),
+                })
+                .transpose()
+        }
+        match key {
+            "default-team" => self.team = value.map(str::to_string),
+            "default-project" => self.project = value.map(str::to_string),
+            "default-output" => {
+                if let Some(v) = value {
+                    <crate::OutputFormat as clap::ValueEnum>::from_str(v, true).map_err(|_| {
+                        anyhow::anyhow!("Invalid output format '{}'. Use table, json, or ndjson", v)
+                    })?;
+                }
+                self.output = value.map(|v| v.to_lowercase());
+            }
+            "default-mine" => self.mine = boolean(key, value)?,
+            "page-size" => {
+                self.page_size = number(key, value)?;
+                if self.page_size == Some(0) {
+                    anyhow::bail!("page-size must be at least 1");
+                }
+            }
+            "cache-ttl" => self.cache_ttl = number(key, value)?,
+            "stale-while-revalidate" => self.stale_while_revalidate = boolean(key, value)?,
+            _ => self.retry = number(key, value)?,
+        }
+        Ok(())
+    }
+
+    /// The defaults that map onto existing flags, keyed by the flags' `env` variable
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        DEFAULT_KEYS
+            .iter()
+            .filter_map(|(key, env)| {
+                let value = self.get(key).ok().flatten()?;
+                Some(((*env)?, value))
+            })
+            .collect()
+    }
+}
+
+fn normalize_default_key(key: &str) -> Result<&'static str> {
+    let key = key.to_lowercase().replace('_', "-");
+    DEFAULT_KEYS
+        .iter()
+        .map(|(k, _)| *k)
+        .find(|k| *k == key)
+        .with_context(|| format!("Unknown config key: {}", key))
+}
+
+static PROFILE_DEFAULTS: OnceLock<Defaults> = OnceLock::new();
+static REPO_PROFILE: OnceLock<String> = OnceLock::new();
+
+tokio::task_local! {
+    /// Profile a fan-out task runs against, overriding `--profile` and LINEAR_CLI_PROFILE
+    static PROFILE_SCOPE: String;
+}
+
+/// Run `fut` with every config lookup (API key, API URL, OAuth, cache) bound to `profile`
+pub async fn with_profile<F: std::future::Future>(profile: String, fut: F) -> F::Output {
+    PROFILE_SCOPE.scope(profile, fut).await
+}
+
+/// The profile scoped by `with_profile`, if any
+fn scoped_profile() -> Option<String> {
+    PROFILE_SCOPE.try_with(String::clone).ok()
+}
+
+/// The explicitly selected profile: a `with_profile` scope, then LINEAR_CLI_PROFILE,
+/// then the profile pinned by a repository `.linear.toml`
+fn profile_override() -> Option<String> {
+    scoped_profile()
+        .or_else(|| {
+            std::env::var("LINEAR_CLI_PROFILE")
+                .ok()
+                .filter(|p| !p.is_empty())
+        })
+        .or_else(|| REPO_PROFILE.get().cloned())
+}
+
+static FANOUT_PROFILES: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Resolve `--profile a,b,c` or `--all-profiles` into the profiles to fan out over.
+///
+/// Returns `None` for the usual single-profile case.
+pub fn resolve_fanout_profiles(
+    profile: Option<&str>,
+    all_profiles: bool,
+) -> Result<Option<Vec<String>>> {
+    if !all_profiles && !profile.is_some_and(|p| p.contains(',')) {
+        return Ok(None);
+    }
+    let config = load_config()?;
+    let names: Vec<String> = match profile {
+        Some(list) if !all_profiles => {
+            let mut names: Vec<String> = Vec::new();
+            for name in list.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                if !names.iter().any(|n| n == name) {
+                    names.push(name.to_string());
+                }
+            }
+            names
+        }
+        _ => {
+            let mut names: Vec<String> = config.workspaces.keys().cloned().collect();
+            names.sort();
+            names
+        }
+    };
+    if names.is_empty() {
+        anyhow::bail!("No workspaces configured. Run: linear config workspace-add <name>");
+    }
+    for name in &names {
+        if !config.workspaces.contains_key(name) {
+            anyhow::bail!(
+                "Workspace '{}' not found. Use 'config workspace-list' to see available workspaces.",
+                name
+            );
+        }
+    }
+    Ok(Some(names))
+}
+
+/// Remember the fan-out profiles for this invocation
+pub fn set_fanout_profiles(profiles: Vec<String>) {
+    let _ = FANOUT_PROFILES.set(profiles);
+}
+
+/// Profiles a read command should query concurrently, if more than one was requested
+pub fn fanout_profiles() -> Option<&'static [String]> {
+    FANOUT_PROFILES.get().map(Vec::as_slice)
+}
+
+/// Load the defaults of the active profile and return them as flag fallbacks,
+/// keyed by the `env` variable of the flag each one feeds.
+///
+/// Runs before argument parsing, so `profile` is the raw `--profile` value if one was given.
+/// A repository `.linear.toml` can pin the profile and overrides its team and project.
+/// Errors are ignored here; a broken config file is reported by the command that needs it.
+pub fn resolve_profile_defaults(profile: Option<&str>) -> Vec<(&'static str, String)> {
+    let repo = crate::repo_config::current();
+    let env_profile = std::env::var("LINEAR_CLI_PROFILE")
+        .ok()
+        .filter(|p| !p.is_empty());
+    let pinned = repo.and_then(|r| r.profile.clone());
+    if let Some(ref name) = pinned {
+        let _ = REPO_PROFILE.set(name.clone());
+    }
+
+    let config = load_config().ok();
+    let current = config.as_ref().and_then(|c| c.current.clone());
+    if let (Some(repo), Some(name), None, None) = (repo, &pinned, profile, &env_profile) {
+        if current.as_ref() != Some(name) {
+            eprintln!(
+                "Note: Using profile '{}' from {}",
+                name,
+                repo.path.display()
+            );
+        }
+    }
+
+    let mut defaults = config
+        .and_then(|config| {
+            let name = profile
+                .map(str::to_string)
+                .or(env_profile)
+                .or(pinned)
+                .or(current)?;
+            config.workspaces.get(&name).map(|w| w.defaults.clone())
+        })
+        .unwrap_or_default();
+    if let Some(repo) = repo {
+        if repo.team.is_some() {
+            defaults.team = repo.team.clone();
+        }
+        if repo.project.is_some() {
+            defaults.project = repo.project.clone();
+        }
+    }
+
+    let fallbacks = defaults.env_vars();
+    let _ = PROFILE_DEFAULTS.set(defaults);
+    fallbacks
+}
+
+/// Defaults of the active profile (empty until `resolve_profile_defaults` has run,
+/// and when a read command fans out over several profiles)
+pub fn profile_defaults() -> Defaults {
+    PROFILE_DEFAULTS.get().cloned().unwrap_or_default()
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -36,6 +317,12 @@ pub struct Config {
     // Legacy field for backward compatibility
     #[serde(skip_serializing_if = "Option::is_none")]
     pub api_key: Option<String>,
+    /// Command aliases, e.g. `standup = "i list --mine --since 1d"`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+    /// Multi-step command macros, run in order until one fails
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub macros: BTreeMap<String, Vec<String>>,
 }
 
 fn config_path() -> Result<PathBuf> {
@@ -60,7 +347,7 @@ pub fn load_config() -> Result<Config> {
                     "default".to_string(),
                     Workspace {
                         api_key: legacy_key,
-                        oauth: None,
+                        ..Default::default()
                     },
                 );
                 if config.current.is_none() {
@@ -111,23 +398,15 @@ pub fn save_config(config: &Config) -> Result<()> {
 
 pub fn set_api_key(key: &str) -> Result<()> {
     let mut config = load_config()?;
-    let profile = std::env::var("LINEAR_CLI_PROFILE")
-        .ok()
-        .filter(|p| !p.is_empty());
+    let profile = profile_override();
     let workspace_name = profile
         .or_else(|| config.current.clone())
         .unwrap_or_else(|| "default".to_string());
-    let existing_oauth = config
+    config
         .workspaces
-        .get(&workspace_name)
-        .and_then(|w| w.oauth.clone());
-    config.workspaces.insert(
-        workspace_name.clone(),
-        Workspace {
-            api_key: key.to_string(),
-            oauth: existing_oauth,
-        },
-    );
+        .entry(workspace_name.clone())
+        .or_default()
+        .api_key = key.to_string();
     if config.current.is_none() {
         config.current = Some(workspace_name.clone());
     }
@@ -164,20 +443,24 @@ pub fn set_api_key_from_stdin_or_prompt() -> Result<()> {
 }
 
 pub fn get_api_key() -> Result<String> {
-    // Check for LINEAR_API_KEY environment variable first
+    // Check for LINEAR_API_KEY environment variable first, unless fanning out
+    // across profiles where it would point every workspace at the same key
     if let Ok(api_key) = std::env::var("LINEAR_API_KEY") {
-        if !api_key.is_empty() {
+        if !api_key.is_empty() && scoped_profile().is_none() {
             return Ok(api_key);
         }
     }
 
+    // Then a credential helper configured for the profile
+    if let Some(command) = get_api_key_command()? {
+        return run_api_key_command(&command);
+    }
+
     // Try keyring if feature is enabled
     #[cfg(feature = "secure-storage")]
     {
         let config = load_config()?;
-        let profile = std::env::var("LINEAR_CLI_PROFILE")
-            .ok()
-            .filter(|p| !p.is_empty())
+        let profile = profile_override()
             .or(config.current.clone())
             .unwrap_or_else(|| "default".to_string());
 
@@ -188,9 +471,7 @@ pub fn get_api_key() -> Result<String> {
 
     // Fall back to config file
     let config = load_config()?;
-    let profile = std::env::var("LINEAR_CLI_PROFILE")
-        .ok()
-        .filter(|p| !p.is_empty());
+    let profile = profile_override();
     let current = profile.or(config.current.clone()).context(
         "No workspace selected. Run: linear config workspace-add <name> or set LINEAR_CLI_PROFILE",
     )?;
@@ -201,12 +482,176 @@ pub fn get_api_key() -> Result<String> {
     Ok(workspace.api_key.clone())
 }
 
+/// The current workspace's `api_key_command`, if any
+pub fn get_api_key_command() -> Result<Option<String>> {
+    let config = load_config()?;
+    let profile = profile_override().or(config.current.clone());
+    Ok(profile
+        .and_then(|p| config.workspaces.get(&p))
+        .and_then(|w| w.api_key_command.clone()))
+}
+
+static API_KEY_COMMAND_CACHE: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+
+/// Run a credential helper and return the key it prints on stdout.
+///
+/// The result is cached for the rest of the process, so the helper runs at most
+/// once per invocation. Its stderr (and stdin, when interactive) pass through so
+/// helpers like `op` or `pass` can prompt for unlock.
+pub fn run_api_key_command(command: &str) -> Result<String> {
+    let cache = API_KEY_COMMAND_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(key) = cache.lock().unwrap().get(command) {
+        return Ok(key.clone());
+    }
+
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = std::process::Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    };
+    #[cfg(not(windows))]
+    let mut cmd = {
+        let mut cmd = std::process::Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+    let stdin = if std::io::stdin().is_terminal() {
+        std::process::Stdio::inherit()
+    } else {
+        std::process::Stdio::null()
+    };
+    let output = cmd
+        .stdin(stdin)
+        .stderr(std::process::Stdio::inherit())
+        .output()
+        .with_context(|| format!("Failed to run api_key_command `{}`", command))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "api_key_command `{}` failed with {}",
+            command,
+            output.status
+        );
+    }
+
+    let stdout =
+        String::from_utf8(output.stdout).context("api_key_command printed invalid UTF-8")?;
+    // Helpers like `pass` print the secret on the first line, followed by metadata
+    let key = stdout.lines().next().unwrap_or_default().trim().to_string();
+    if key.is_empty() {
+        anyhow::bail!("api_key_command `{}` printed no API key", command);
+    }
+    cache
+        .lock()
+        .unwrap()
+        .insert(command.to_string(), key.clone());
+    Ok(key)
+}
+
+/// Set (or clear with "") the credential helper for the current workspace
+fn set_api_key_command(value: &str) -> Result<()> {
+    let command = Some(value.trim().to_string()).filter(|c| !c.is_empty());
+
+    let mut config = load_config()?;
+    let profile = profile_override()
+        .or(config.current.clone())
+        .unwrap_or_else(|| "default".to_string());
+    config
+        .workspaces
+        .entry(profile.clone())
+        .or_default()
+        .api_key_command = command.clone();
+    if config.current.is_none() {
+        config.current = Some(profile.clone());
+    }
+    save_config(&config)?;
+
+    match command {
+        Some(command) => println!("API key command for '{}' set to: {}", profile, command),
+        None => println!("API key command for '{}' cleared", profile),
+    }
+    Ok(())
+}
+
+/// Get the GraphQL endpoint override (LINEAR_API_URL env first, then the profile's `api_url`)
+pub fn get_api_url() -> Result<Option<String>> {
+    let env_url = std::env::var("LINEAR_API_URL")
+        .ok()
+        .filter(|u| !u.is_empty());
+    // When fanning out, each profile's own endpoint wins over the env override
+    if scoped_profile().is_none() && env_url.is_some() {
+        return Ok(env_url);
+    }
+
+    let config = load_config()?;
+    let profile = profile_override().or(config.current.clone());
+    Ok(profile
+        .and_then(|p| config.workspaces.get(&p))
+        .and_then(|w| w.api_url.clone())
+        .or(env_url))
+}
+
+/// Get a webhook signing secret (LINEAR_WEBHOOK_SECRET env first, then keyring, then config)
+pub fn get_webhook_secret(webhook_id: &str) -> Result<Option<String>> {
+    if let Ok(secret) = std::env::var("LINEAR_WEBHOOK_SECRET") {
+        if !secret.is_empty() {
+            return Ok(Some(secret));
+        }
+    }
+
+    let profile = current_profile().unwrap_or_else(|_| "default".to_string());
+
+    #[cfg(feature = "secure-storage")]
+    {
+        if let Ok(Some(secret)) = crate::keyring::get_webhook_secret(&profile, webhook_id) {
+            return Ok(Some(secret));
+        }
+    }
+
+    let config = load_config()?;
+    Ok(config
+        .workspaces
+        .get(&profile)
+        .and_then(|w| w.webhook_secrets.get(webhook_id))
+        .cloned())
+}
+
+/// Store a webhook signing secret for the current profile
+pub fn set_webhook_secret(webhook_id: &str, secret: &str, secure: bool) -> Result<()> {
+    let profile = current_profile().unwrap_or_else(|_| "default".to_string());
+
+    if secure {
+        #[cfg(feature = "secure-storage")]
+        {
+            return crate::keyring::set_webhook_secret(&profile, webhook_id, secret);
+        }
+        #[cfg(not(feature = "secure-storage"))]
+        anyhow::bail!("Secure storage requires the 'secure-storage' feature. Rebuild with: cargo build --features secure-storage");
+    }
+
+    let mut config = load_config()?;
+    config
+        .workspaces
+        .entry(profile)
+        .or_default()
+        .webhook_secrets
+        .insert(webhook_id.to_string(), secret.to_string());
+    save_config(&config)
+}
+
+pub fn set_webhook_secret_from_stdin_or_prompt(webhook_id: &str, secure: bool) -> Result<()> {
+    let secret = read_secret_from_stdin_or_prompt("Webhook signing secret")?;
+    set_webhook_secret(webhook_id, &secret, secure)
+}
+
 pub fn config_file_path() -> Result<PathBuf> {
     config_path()
 }
 
 /// Returns the current workspace profile name.
 ///
+/// Inside `with_profile` the scoped profile is returned instead.
+///
 /// NOTE: The result is cached via OnceLock for the lifetime of the process.
 /// If the profile is changed in-process (e.g., via `workspace_switch`), the
 /// cached value will be stale. This is acceptable because profile switches
@@ -214,14 +659,15 @@ pub fn config_file_path() -> Result<PathBuf> {
 pub fn current_profile() -> Result<String> {
     static PROFILE: OnceLock<String> = OnceLock::new();
 
+    if let Ok(scoped) = PROFILE_SCOPE.try_with(String::clone) {
+        return Ok(scoped);
+    }
     if let Some(cached) = PROFILE.get() {
         return Ok(cached.clone());
     }
 
     let config = load_config()?;
-    let profile = std::env::var("LINEAR_CLI_PROFILE")
-        .ok()
-        .filter(|p| !p.is_empty());
+    let profile = profile_override();
     let resolved = profile
         .or(config.current)
         .context("No workspace selected")?;
@@ -233,14 +679,11 @@ pub fn current_profile() -> Result<String> {
 
 pub fn set_workspace_key(name: &str, api_key: &str) -> Result<()> {
     let mut config = load_config()?;
-    let existing_oauth = config.workspaces.get(name).and_then(|w| w.oauth.clone());
-    config.workspaces.insert(
-        name.to_string(),
-        Workspace {
-            api_key: api_key.to_string(),
-            oauth: existing_oauth,
-        },
-    );
+    config
+        .workspaces
+        .entry(name.to_string())
+        .or_default()
+        .api_key = api_key.to_string();
     if config.current.is_none() {
         config.current = Some(name.to_string());
     }
@@ -262,7 +705,16 @@ pub fn config_get(key: &str, raw: bool) -> Result<()> {
             let profile = current_profile()?;
             println!("{}", profile);
         }
-        _ => anyhow::bail!("Unknown config key: {}", key),
+        "api-url" | "api_url" => {
+            println!("{}", crate::api::api_url()?);
+        }
+        "api-key-command" | "api_key_command" => {
+            println!("{}", get_api_key_command()?.unwrap_or_default());
+        }
+        _ => {
+            let value = profile_defaults().get(key)?;
+            println!("{}", value.unwrap_or_default());
+        }
     }
     Ok(())
 }
@@ -273,10 +725,77 @@ pub fn config_set(key: &str, value: &str) -> Result<()> {
             "Setting api-key via argv is disabled. Use `linear config set-key` and provide the key via stdin or the hidden prompt."
         ),
         "profile" => workspace_switch(value),
-        _ => anyhow::bail!("Unknown config key: {}", key),
+        "api-url" | "api_url" => set_api_url(value),
+        "api-key-command" | "api_key_command" => set_api_key_command(value),
+        _ => {
+            let (profile, stored) = set_profile_default(key, value)?;
+            let key = normalize_default_key(key)?;
+            match stored {
+                Some(value) => println!("{} for '{}' set to {}", key, profile, value),
+                None => println!("{} for '{}' cleared", key, profile),
+            }
+            Ok(())
+        }
     }
 }
 
+/// Set (or clear with "default"/"") one of the current workspace's defaults.
+/// Returns the workspace name and the value as stored (`None` once cleared).
+pub fn set_profile_default(key: &str, value: &str) -> Result<(String, Option<String>)> {
+    let mut config = load_config()?;
+    let profile = profile_override()
+        .or(config.current.clone())
+        .unwrap_or_else(|| "default".to_string());
+    let defaults = &mut config
+        .workspaces
+        .entry(profile.clone())
+        .or_default()
+        .defaults;
+    defaults.set(key, value)?;
+    let stored = defaults.get(key)?;
+    if config.current.is_none() {
+        config.current = Some(profile.clone());
+    }
+    save_config(&config)?;
+    Ok((profile, stored))
+}
+
+fn print_defaults(defaults: &Defaults) {
+    for (key, _) in DEFAULT_KEYS {
+        if let Ok(Some(value)) = defaults.get(key) {
+            println!("  {}: {}", key, value);
+        }
+    }
+}
+
+/// Set (or clear with "default"/"") the GraphQL endpoint for the current workspace
+fn set_api_url(value: &str) -> Result<()> {
+    let api_url = match value.trim() {
+        "" | "default" => None,
+        raw => Some(crate::api::validate_api_url(raw)?.to_string()),
+    };
+
+    let mut config = load_config()?;
+    let profile = profile_override()
+        .or(config.current.clone())
+        .unwrap_or_else(|| "default".to_string());
+    config
+        .workspaces
+        .entry(profile.clone())
+        .or_default()
+        .api_url = api_url.clone();
+    if config.current.is_none() {
+        config.current = Some(profile.clone());
+    }
+    save_config(&config)?;
+
+    match api_url {
+        Some(url) => println!("API URL for '{}' set to {}", profile, url),
+        None => println!("API URL for '{}' reset to default", profile),
+    }
+    Ok(())
+}
+
 pub fn show_config() -> Result<()> {
     let config = load_config()?;
     let path = config_path()?;
@@ -287,12 +806,103 @@ pub fn show_config() -> Result<()> {
     if let Some(current) = &config.current {
         println!("Current workspace: {}", current);
         if let Some(workspace) = config.workspaces.get(current) {
-            println!("API Key: {}", mask_api_key_for_display(&workspace.api_key));
+            println!("API Key: {}", workspace_key_display(workspace));
+            if let Some(api_url) = &workspace.api_url {
+                println!("API URL: {}", api_url);
+            }
+            if !workspace.defaults.is_empty() {
+                println!("Defaults:");
+                print_defaults(&workspace.defaults);
+            }
         }
     } else {
         println!("No workspace configured. Run: linear workspace add <name>");
     }
 
+    if let Some(repo) = crate::repo_config::current() {
+        println!();
+        println!("Repository config: {}", repo.path.display());
+        let entries = [
+            ("profile", repo.profile.clone()),
+            ("team", repo.team.clone()),
+            ("project", repo.project.clone()),
+            (
+                "labels",
+                (!repo.labels.is_empty()).then(|| repo.labels.join(", ")),
+            ),
+            ("branch_template", repo.branch_template.clone()),
+            ("pr_base", repo.pr_base.clone()),
+        ];
+        for (key, value) in entries {
+            if let Some(value) = value {
+                println!("  {}: {}", key, value);
+            }
+        }
+    }
+
+    if !config.aliases.is_empty() || !config.macros.is_empty() {
+        println!();
+        print_aliases(&config);
+    }
+
+    Ok(())
+}
+
+fn print_aliases(config: &Config) {
+    if !config.aliases.is_empty() {
+        println!("Aliases:");
+        for (name, expansion) in &config.aliases {
+            println!("  {} = {}", name, expansion);
+        }
+    }
+    if !config.macros.is_empty() {
+        println!("Macros:");
+        for (name, steps) in &config.macros {
+            println!("  {}:", name);
+            for step in steps {
+                println!("    {}", step);
+            }
+        }
+    }
+}
+
+/// List configured aliases and macros
+pub fn list_aliases() -> Result<()> {
+    let config = load_config()?;
+    if config.aliases.is_empty() && config.macros.is_empty() {
+        println!("No aliases configured. Add one with: linear config alias <name> \"<command>\"");
+        return Ok(());
+    }
+    print_aliases(&config);
+    Ok(())
+}
+
+/// Define or replace a command alias
+pub fn set_alias(name: &str, expansion: &str) -> Result<()> {
+    if name.is_empty() || name.starts_with('-') || name.contains(char::is_whitespace) {
+        anyhow::bail!("Invalid alias name '{}'", name);
+    }
+    if expansion.trim().is_empty() {
+        anyhow::bail!("Alias expansion cannot be empty");
+    }
+    let mut config = load_config()?;
+    config
+        .aliases
+        .insert(name.to_string(), expansion.to_string());
+    save_config(&config)?;
+    println!("Alias '{}' set to: {}", name, expansion);
+    Ok(())
+}
+
+/// Remove a command alias or macro
+pub fn remove_alias(name: &str) -> Result<()> {
+    let mut config = load_config()?;
+    let removed = config.aliases.remove(name).is_some() | config.macros.remove(name).is_some();
+    if !removed {
+        anyhow::bail!("No alias or macro named '{}'", name);
+    }
+    save_config(&config)?;
+    println!("Removed '{}'", name);
     Ok(())
 }
 
@@ -312,7 +922,7 @@ pub fn workspace_add(name: &str, api_key: &str) -> Result<()> {
         name.to_string(),
         Workspace {
             api_key: api_key.to_string(),
-            oauth: None,
+            ..Default::default()
         },
     );
 
@@ -351,8 +961,7 @@ pub fn workspace_list() -> Result<()> {
     for (name, workspace) in &config.workspaces {
         let is_current = config.current.as_ref() == Some(name);
         let marker = if is_current { "*" } else { " " };
-        let masked = mask_api_key_for_display(&workspace.api_key);
-        println!("{} {} ({})", marker, name, masked);
+        println!("{} {} ({})", marker, name, workspace_key_display(workspace));
     }
 
     println!();
@@ -384,7 +993,10 @@ pub fn workspace_current() -> Result<()> {
     if let Some(current) = &config.current {
         println!("Current workspace: {}", current);
         if let Some(workspace) = config.workspaces.get(current) {
-            println!("API Key: {}", mask_api_key_for_display(&workspace.api_key));
+            println!("API Key: {}", workspace_key_display(workspace));
+            if let Some(api_url) = &workspace.api_url {
+                println!("API URL: {}", api_url);
+            }
         }
     } else {
         println!("No workspace selected. Run: linear workspace add <name>");
@@ -424,7 +1036,7 @@ pub fn save_oauth_config(profile: &str, oauth_config: &OAuthConfig) -> Result<()
         .entry(profile.to_string())
         .or_insert_with(|| Workspace {
             api_key: String::new(),
-            oauth: None,
+            ..Default::default()
         });
     workspace.oauth = Some(oauth_config.clone());
     if config.current.is_none() {
@@ -443,7 +1055,15 @@ fn oauth_config_has_secrets(oauth_config: &OAuthConfig) -> bool {
             .unwrap_or(false)
 }
 
-fn mask_api_key_for_display(api_key: &str) -> String {
+/// How a workspace's key is shown in listings; helpers are named, never run
+fn workspace_key_display(workspace: &Workspace) -> String {
+    match &workspace.api_key_command {
+        Some(command) => format!("from command: {}", command),
+        None => mask_api_key_for_display(&workspace.api_key),
+    }
+}
+
+pub fn mask_api_key_for_display(api_key: &str) -> String {
     if api_key.len() > 12 {
         format!("{}***{}", &api_key[..4], &api_key[api_key.len() - 4..])
     } else if api_key.starts_with("lin_") {
@@ -554,14 +1174,14 @@ mod tests {
             "prod".to_string(),
             Workspace {
                 api_key: "lin_api_prod123".to_string(),
-                oauth: None,
+                ..Default::default()
             },
         );
         config.workspaces.insert(
             "staging".to_string(),
             Workspace {
                 api_key: "lin_api_staging456".to_string(),
-                oauth: None,
+                ..Default::default()
             },
         );
 
@@ -611,12 +1231,97 @@ mod tests {
             current: Some("default".to_string()),
             workspaces: HashMap::new(),
             api_key: None,
+            ..Default::default()
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
         assert!(!toml_str.contains("api_key"));
     }
 
+    #[test]
+    fn test_webhook_secrets_parse_and_skip_when_empty() {
+        let toml_str = r#"
+current = "work"
+
+[workspaces.work]
+api_key = "lin_api_work"
+
+[workspaces.work.webhook_secrets]
+webhook-1 = "s3cret"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let work = &config.workspaces["work"];
+        assert_eq!(work.webhook_secrets["webhook-1"], "s3cret");
+
+        let plain = Workspace::default();
+        assert!(!toml::to_string(&plain).unwrap().contains("webhook_secrets"));
+    }
+
+    #[test]
+    fn test_defaults_set_validates_and_clears() {
+        let mut defaults = Defaults::default();
+        defaults.set("default-team", "ENG").unwrap();
+        defaults.set("default_output", "JSON").unwrap();
+        defaults.set("default-mine", "yes").unwrap();
+        defaults.set("page-size", "100").unwrap();
+        assert_eq!(defaults.team.as_deref(), Some("ENG"));
+        assert_eq!(defaults.output.as_deref(), Some("json"));
+        assert_eq!(defaults.mine, Some(true));
+        assert_eq!(defaults.get("page-size").unwrap().as_deref(), Some("100"));
+
+        assert!(defaults.set("default-output", "yaml").is_err());
+        assert!(defaults.set("page-size", "0").is_err());
+        assert!(defaults.set("retry", "-1").is_err());
+        assert!(defaults.set("default-mine", "maybe").is_err());
+        assert!(defaults.set("default-color", "red").is_err());
+
+        defaults.set("default-team", "").unwrap();
+        defaults.set("page-size", "default").unwrap();
+        assert_eq!(defaults.team, None);
+        assert_eq!(defaults.page_size, None);
+    }
+
+    #[test]
+    fn test_defaults_env_vars_skip_unmapped_keys() {
+        let mut defaults = Defaults::default();
+        defaults.set("default-team", "ENG").unwrap();
+        defaults.set("default-mine", "true").unwrap();
+        defaults.set("retry", "3").unwrap();
+        defaults.set("stale-while-revalidate", "on").unwrap();
+        let mut vars = defaults.env_vars();
+        vars.sort();
+        assert_eq!(
+            vars,
+            vec![
+                ("LINEAR_CLI_RETRY", "3".to_string()),
+                ("LINEAR_CLI_STALE_WHILE_REVALIDATE", "true".to_string()),
+                ("LINEAR_CLI_TEAM", "ENG".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_defaults_toml_roundtrip() {
+        let toml_str = r#"
+current = "work"
+
+[workspaces.work]
+api_key = "lin_api_work"
+
+[workspaces.work.defaults]
+team = "ENG"
+cache_ttl = 600
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let defaults = &config.workspaces["work"].defaults;
+        assert_eq!(defaults.team.as_deref(), Some("ENG"));
+        assert_eq!(defaults.cache_ttl, Some(600));
+
+        let saved = toml::to_string(&config).unwrap();
+        assert!(saved.contains("[workspaces.work.defaults]"));
+        assert!(!saved.contains("page_size"));
+    }
+
     #[test]
     fn test_config_with_oauth_parse() {
         let toml_str = r#"
@@ -716,6 +1421,7 @@ mod tests {
                     token_type: "Bearer".to_string(),
                     scopes: vec!["read".to_string(), "write".to_string()],
                 }),
+                ..Default::default()
             },
         );
 
@@ -742,7 +1448,7 @@ mod tests {
             "default".to_string(),
             Workspace {
                 api_key: "lin_api_key".to_string(),
-                oauth: None,
+                ..Default::default()
             },
         );
 
@@ -837,6 +1543,30 @@ mod tests {
         assert_eq!(mask_api_key_for_display("secret"), "***");
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_run_api_key_command_uses_first_line() {
+        let key = run_api_key_command("printf 'lin_api_helper\\nurl: linear.app\\n'").unwrap();
+        assert_eq!(key, "lin_api_helper");
+
+        let err = run_api_key_command("exit 3").unwrap_err();
+        assert!(err.to_string().contains("failed"), "{}", err);
+        let err = run_api_key_command("true").unwrap_err();
+        assert!(err.to_string().contains("printed no API key"), "{}", err);
+    }
+
+    #[test]
+    fn test_workspace_key_display_names_helper() {
+        let workspace = Workspace {
+            api_key_command: Some("pass show linear".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            workspace_key_display(&workspace),
+            "from command: pass show linear"
+        );
+    }
+
     #[test]
     fn test_config_set_rejects_api_key_on_argv() {
         let err = config_set("api-key", "lin_api_secret").unwrap_err();
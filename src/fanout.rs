@@ -0,0 +1,127 @@
+//! Cross-workspace fan-out for read commands (`--profile a,b,c` / `--all-profiles`).
+//!
+//! Each profile gets its own `LinearClient` (built inside `config::with_profile`),
+//! all profiles are queried concurrently, and every result object is tagged with
+//! a `workspace` field before the command renders the merged list.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::future::Future;
+use tabled::builder::Builder;
+use tabled::Table;
+
+use crate::config;
+
+/// Run `fetch` once per profile concurrently and merge the results in profile order.
+///
+/// `fetch` must do its work inside the returned future (not in the closure itself):
+/// only code running inside the future sees the scoped profile, which selects the
+/// API key, endpoint and cache directory of each workspace.
+pub async fn collect<F, Fut>(profiles: &[String], fetch: F) -> Result<Vec<Value>>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<Vec<Value>>>,
+{
+    let tasks = profiles.iter().map(|profile| {
+        let fut = fetch();
+        async move {
+            let items = config::with_profile(profile.clone(), fut)
+                .await
+                .with_context(|| format!("Workspace '{}'", profile))?;
+            Ok::<_, anyhow::Error>(
+                items
+                    .into_iter()
+                    .map(|item| tag(item, profile))
+                    .collect::<Vec<_>>(),
+            )
+        }
+    });
+
+    let mut merged = Vec::new();
+    for result in futures::future::join_all(tasks).await {
+        merged.extend(result?);
+    }
+    Ok(merged)
+}
+
+/// Add a `workspace` field to a result object
+pub fn tag(mut item: Value, workspace: &str) -> Value {
+    match item.as_object_mut() {
+        Some(obj) => {
+            obj.insert(
+                "workspace".to_string(),
+                Value::String(workspace.to_string()),
+            );
+            item
+        }
+        None => serde_json::json!({ "workspace": workspace, "value": item }),
+    }
+}
+
+/// The `workspace` field of a tagged result
+pub fn workspace_of(item: &Value) -> String {
+    item["workspace"].as_str().unwrap_or("-").to_string()
+}
+
+/// Prepend a "Workspace" column to a rendered table, one name per row
+pub fn with_workspace_column(table: Table, workspaces: impl IntoIterator<Item = String>) -> Table {
+    let mut builder = Builder::from(table);
+    builder.insert_column(
+        0,
+        std::iter::once("Workspace".to_string()).chain(workspaces),
+    );
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_collect_tags_results_in_profile_order() {
+        let profiles = vec!["work".to_string(), "oss".to_string()];
+        let merged = collect(&profiles, || async {
+            let profile = config::current_profile()?;
+            Ok(vec![json!({ "id": format!("{}-1", profile) })])
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            merged,
+            vec![
+                json!({ "id": "work-1", "workspace": "work" }),
+                json!({ "id": "oss-1", "workspace": "oss" }),
+            ]
+        );
+
+        let err = collect(&profiles, || async {
+            let profile = config::current_profile()?;
+            if profile == "oss" {
+                anyhow::bail!("boom");
+            }
+            Ok(vec![])
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(format!("{:#}", err), "Workspace 'oss': boom");
+    }
+
+    #[test]
+    fn test_with_workspace_column() {
+        #[derive(tabled::Tabled)]
+        struct Row {
+            #[tabled(rename = "ID")]
+            id: &'static str,
+        }
+        let items = [
+            json!({ "workspace": "work" }),
+            json!({ "workspace": "oss" }),
+        ];
+        let table = Table::new(vec![Row { id: "A-1" }, Row { id: "B-2" }]);
+        let rendered = with_workspace_column(table, items.iter().map(workspace_of)).to_string();
+        assert!(rendered.contains("Workspace"));
+        let work_line = rendered.lines().find(|l| l.contains("A-1")).unwrap();
+        assert!(work_line.contains("work"));
+    }
+}
@@ -0,0 +1,776 @@
+//! Lightweight helpers for inspecting GraphQL documents, plus a small
+//! executable-document parser used to check raw queries against the cached schema.
+
+/// A lexical GraphQL token
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Name(String),
+    Punct(char),
+    Spread,
+    Str(String),
+    Number(String),
+}
+
+use anyhow::{bail, Result};
+
+/// Split a GraphQL document into tokens, skipping whitespace, commas, and comments.
+pub fn tokenize(source: &str) -> Vec<Token> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() || c == ',' || c == '\u{feff}' {
+            i += 1;
+        } else if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '.' && chars.get(i + 1) == Some(&'.') && chars.get(i + 2) == Some(&'.') {
+            tokens.push(Token::Spread);
+            i += 3;
+        } else if c == '"' {
+            let block = chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"');
+            let mut value = String::new();
+            if block {
+                i += 3;
+                while i < chars.len() {
+                    if chars[i] == '"'
+                        && chars.get(i + 1) == Some(&'"')
+                        && chars.get(i + 2) == Some(&'"')
+                    {
+                        i += 3;
+                        break;
+                    }
+                    value.push(chars[i]);
+                    i += 1;
+                }
+            } else {
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    if chars[i] == '\\' && i + 1 < chars.len() {
+                        value.push(chars[i]);
+                        i += 1;
+                    }
+                    value.push(chars[i]);
+                    i += 1;
+                }
+                i += 1;
+            }
+            tokens.push(Token::Str(value));
+        } else if c == '_' || c.is_ascii_alphabetic() {
+            let start = i;
+            while i < chars.len() && (chars[i] == '_' || chars[i].is_ascii_alphanumeric()) {
+                i += 1;
+            }
+            tokens.push(Token::Name(chars[start..i].iter().collect()));
+        } else if c == '-' || c.is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric() || chars[i] == '.' || chars[i] == '+')
+            {
+                i += 1;
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+
+    tokens
+}
+
+/// Index just past the bracket that closes the one at `start`.
+fn skip_balanced(tokens: &[Token], start: usize) -> usize {
+    let (open, close) = match tokens.get(start) {
+        Some(Token::Punct('(')) => ('(', ')'),
+        Some(Token::Punct('{')) => ('{', '}'),
+        Some(Token::Punct('[')) => ('[', ']'),
+        _ => return start + 1,
+    };
+    let mut depth = 0;
+    let mut i = start;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Punct(c) if *c == open => depth += 1,
+            Token::Punct(c) if *c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    tokens.len()
+}
+
+/// Locate the first operation: its type ("query", "mutation", "subscription"),
+/// optional name, and the index of its selection set's opening brace.
+fn first_operation(tokens: &[Token]) -> Option<(String, Option<String>, usize)> {
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Name(kw) if kw == "fragment" => {
+                while i < tokens.len() && tokens[i] != Token::Punct('{') {
+                    i += 1;
+                }
+                i = skip_balanced(tokens, i);
+            }
+            Token::Punct('{') => return Some(("query".to_string(), None, i)),
+            Token::Name(kw) if matches!(kw.as_str(), "query" | "mutation" | "subscription") => {
+                let kind = kw.clone();
+                let mut name = None;
+                let mut j = i + 1;
+                if let Some(Token::Name(n)) = tokens.get(j) {
+                    name = Some(n.clone());
+                    j += 1;
+                }
+                while j < tokens.len() && tokens[j] != Token::Punct('{') {
+                    if tokens[j] == Token::Punct('(') {
+                        j = skip_balanced(tokens, j);
+                    } else {
+                        j += 1;
+                    }
+                }
+                return (j < tokens.len()).then_some((kind, name, j));
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Operation type of the first operation ("query", "mutation", or "subscription")
+pub fn operation_type(source: &str) -> Option<String> {
+    first_operation(&tokenize(source)).map(|(kind, _, _)| kind)
+}
+
+/// Name of the first operation, if it has one
+pub fn operation_name(source: &str) -> Option<String> {
+    first_operation(&tokenize(source)).and_then(|(_, name, _)| name)
+}
+
+/// Top-level field names selected by the first operation (aliases resolved to fields)
+pub fn root_fields(source: &str) -> Vec<String> {
+    let tokens = tokenize(source);
+    let Some((_, _, start)) = first_operation(&tokens) else {
+        return Vec::new();
+    };
+
+    let mut fields = Vec::new();
+    let mut i = start + 1;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Punct('}') => break,
+            Token::Punct('{') | Token::Punct('(') => i = skip_balanced(&tokens, i),
+            Token::Punct('@') => i += 2,
+            Token::Spread => {
+                i += 1;
+                if tokens.get(i) == Some(&Token::Name("on".to_string())) {
+                    i += 1;
+                }
+                i += 1;
+            }
+            Token::Name(name) => {
+                if tokens.get(i + 1) == Some(&Token::Punct(':')) {
+                    if let Some(Token::Name(field)) = tokens.get(i + 2) {
+                        fields.push(field.clone());
+                    }
+                    i += 3;
+                } else {
+                    fields.push(name.clone());
+                    i += 1;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    fields
+}
+
+/// A parsed executable document: operations and fragment definitions
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Document {
+    pub operations: Vec<Operation>,
+    pub fragments: Vec<Fragment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    /// "query", "mutation", or "subscription"
+    pub kind: String,
+    pub name: Option<String>,
+    pub variables: Vec<VariableDefinition>,
+    pub selections: Vec<Selection>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableDefinition {
+    pub name: String,
+    /// Type as written, e.g. `[String!]!`
+    pub ty: String,
+    pub has_default: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fragment {
+    pub name: String,
+    pub type_condition: String,
+    pub selections: Vec<Selection>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    Field {
+        name: String,
+        arguments: Vec<String>,
+        selections: Vec<Selection>,
+    },
+    FragmentSpread(String),
+    InlineFragment {
+        type_condition: Option<String>,
+        selections: Vec<Selection>,
+    },
+}
+
+impl Document {
+    pub fn fragment(&self, name: &str) -> Option<&Fragment> {
+        self.fragments.iter().find(|f| f.name == name)
+    }
+}
+
+/// Parse an executable GraphQL document (operations and fragments)
+pub fn parse(source: &str) -> Result<Document> {
+    let document = parse_definitions(source)?;
+    if document.operations.is_empty() {
+        bail!("Document contains no operation");
+    }
+    Ok(document)
+}
+
+fn parse_definitions(source: &str) -> Result<Document> {
+    let mut parser = Parser {
+        tokens: tokenize(source),
+        pos: 0,
+    };
+    let mut document = Document::default();
+    while let Some(token) = parser.peek() {
+        match token {
+            Token::Name(kw) if kw == "fragment" => {
+                parser.pos += 1;
+                document.fragments.push(parser.fragment()?);
+            }
+            Token::Name(kw) if matches!(kw.as_str(), "query" | "mutation" | "subscription") => {
+                let kind = kw.clone();
+                parser.pos += 1;
+                document.operations.push(parser.operation(kind)?);
+            }
+            Token::Punct('{') => {
+                let selections = parser.selection_set()?;
+                document.operations.push(Operation {
+                    kind: "query".to_string(),
+                    name: None,
+                    variables: Vec::new(),
+                    selections,
+                });
+            }
+            other => bail!("Unexpected {} at top level", describe(other)),
+        }
+    }
+    Ok(document)
+}
+
+/// Split a document into the source text of each top-level definition
+pub fn definitions(source: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+    let mut defs = Vec::new();
+    let mut start: Option<usize> = None;
+    let (mut braces, mut parens) = (0i32, 0i32);
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        if c == '#' {
+            while i < chars.len() && chars[i].1 != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if c.is_whitespace() || c == ',' || c == '\u{feff}' {
+            i += 1;
+            continue;
+        }
+        start.get_or_insert(pos);
+        if c == '"' {
+            let block = source[pos..].starts_with("\"\"\"");
+            i += if block { 3 } else { 1 };
+            while i < chars.len() {
+                let (p, ch) = chars[i];
+                if block && source[p..].starts_with("\"\"\"") {
+                    i += 3;
+                    break;
+                }
+                if !block && ch == '\\' {
+                    i += 2;
+                    continue;
+                }
+                i += 1;
+                if !block && ch == '"' {
+                    break;
+                }
+            }
+            continue;
+        }
+        match c {
+            '{' => braces += 1,
+            '(' => parens += 1,
+            ')' => parens -= 1,
+            '}' => {
+                braces -= 1;
+                if braces == 0 && parens == 0 {
+                    if let Some(s) = start.take() {
+                        defs.push(&source[s..pos + 1]);
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    if let Some(s) = start {
+        defs.push(source[s..].trim_end());
+    }
+    defs
+}
+
+fn collect_spreads(selections: &[Selection], out: &mut Vec<String>) {
+    for selection in selections {
+        match selection {
+            Selection::FragmentSpread(name) => out.push(name.clone()),
+            Selection::Field { selections, .. } | Selection::InlineFragment { selections, .. } => {
+                collect_spreads(selections, out)
+            }
+        }
+    }
+}
+
+/// Pick one operation out of a multi-operation document, keeping only the
+/// fragments it uses. Without a name, the document must define one operation.
+pub fn extract_operation(source: &str, name: Option<&str>) -> Result<String> {
+    let mut operations = Vec::new();
+    let mut fragments = Vec::new();
+    for text in definitions(source) {
+        let mut parsed = parse_definitions(text)?;
+        if let Some(op) = parsed.operations.pop() {
+            operations.push((text, op));
+        } else if let Some(fragment) = parsed.fragments.pop() {
+            fragments.push((text, fragment));
+        }
+    }
+    let names: Vec<&str> = operations
+        .iter()
+        .map(|(_, op)| op.name.as_deref().unwrap_or("(anonymous)"))
+        .collect();
+
+    let (text, operation) = match name {
+        None => match operations.len() {
+            0 => bail!("Document contains no operation"),
+            1 => return Ok(source.to_string()),
+            n => bail!(
+                "Document defines {} operations ({}); choose one with --operation",
+                n,
+                names.join(", ")
+            ),
+        },
+        Some(name) => operations
+            .iter()
+            .find(|(_, op)| op.name.as_deref() == Some(name))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Operation '{}' not found (available: {})",
+                    name,
+                    names.join(", ")
+                )
+            })?,
+    };
+
+    let mut pending = Vec::new();
+    collect_spreads(&operation.selections, &mut pending);
+    let mut used = Vec::new();
+    while let Some(spread) = pending.pop() {
+        if used.contains(&spread) {
+            continue;
+        }
+        if let Some((_, fragment)) = fragments.iter().find(|(_, f)| f.name == spread) {
+            collect_spreads(&fragment.selections, &mut pending);
+        }
+        used.push(spread);
+    }
+
+    let mut document = text.to_string();
+    for (fragment_text, fragment) in &fragments {
+        if used.contains(&fragment.name) {
+            document.push_str("\n\n");
+            document.push_str(fragment_text);
+        }
+    }
+    Ok(document)
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Name(n) => format!("'{}'", n),
+        Token::Punct(c) => format!("'{}'", c),
+        Token::Spread => "'...'".to_string(),
+        Token::Str(_) => "string".to_string(),
+        Token::Number(n) => format!("number {}", n),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn is_punct(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Punct(c))
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<()> {
+        match self.peek() {
+            Some(Token::Punct(p)) if *p == c => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(other) => bail!("Expected '{}', found {}", c, describe(other)),
+            None => bail!("Expected '{}', found end of document", c),
+        }
+    }
+
+    fn expect_name(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(Token::Name(n)) => {
+                let name = n.clone();
+                self.pos += 1;
+                Ok(name)
+            }
+            Some(other) => bail!("Expected a name, found {}", describe(other)),
+            None => bail!("Expected a name, found end of document"),
+        }
+    }
+
+    fn operation(&mut self, kind: String) -> Result<Operation> {
+        let name = match self.peek() {
+            Some(Token::Name(_)) => Some(self.expect_name()?),
+            _ => None,
+        };
+        let mut variables = Vec::new();
+        if self.is_punct('(') {
+            self.pos += 1;
+            while !self.is_punct(')') {
+                self.expect_punct('$')?;
+                let name = self.expect_name()?;
+                self.expect_punct(':')?;
+                let ty = self.type_ref()?;
+                let has_default = self.is_punct('=');
+                if has_default {
+                    self.pos += 1;
+                    self.skip_value()?;
+                }
+                self.skip_directives()?;
+                variables.push(VariableDefinition {
+                    name,
+                    ty,
+                    has_default,
+                });
+            }
+            self.pos += 1;
+        }
+        self.skip_directives()?;
+        let selections = self.selection_set()?;
+        Ok(Operation {
+            kind,
+            name,
+            variables,
+            selections,
+        })
+    }
+
+    fn fragment(&mut self) -> Result<Fragment> {
+        let name = self.expect_name()?;
+        if self.expect_name()? != "on" {
+            bail!("Expected 'on' after fragment name '{}'", name);
+        }
+        let type_condition = self.expect_name()?;
+        self.skip_directives()?;
+        let selections = self.selection_set()?;
+        Ok(Fragment {
+            name,
+            type_condition,
+            selections,
+        })
+    }
+
+    fn type_ref(&mut self) -> Result<String> {
+        let mut ty = if self.is_punct('[') {
+            self.pos += 1;
+            let inner = self.type_ref()?;
+            self.expect_punct(']')?;
+            format!("[{}]", inner)
+        } else {
+            self.expect_name()?
+        };
+        if self.is_punct('!') {
+            self.pos += 1;
+            ty.push('!');
+        }
+        Ok(ty)
+    }
+
+    fn skip_value(&mut self) -> Result<()> {
+        match self.peek() {
+            Some(Token::Punct('{')) | Some(Token::Punct('[')) => {
+                self.pos = skip_balanced(&self.tokens, self.pos);
+            }
+            Some(Token::Punct('$')) => self.pos += 2,
+            Some(_) => self.pos += 1,
+            None => bail!("Expected a value, found end of document"),
+        }
+        Ok(())
+    }
+
+    fn skip_directives(&mut self) -> Result<()> {
+        while self.is_punct('@') {
+            self.pos += 1;
+            self.expect_name()?;
+            if self.is_punct('(') {
+                self.arguments()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn arguments(&mut self) -> Result<Vec<String>> {
+        self.expect_punct('(')?;
+        let mut names = Vec::new();
+        while !self.is_punct(')') {
+            names.push(self.expect_name()?);
+            self.expect_punct(':')?;
+            self.skip_value()?;
+        }
+        self.pos += 1;
+        Ok(names)
+    }
+
+    fn selection_set(&mut self) -> Result<Vec<Selection>> {
+        self.expect_punct('{')?;
+        let mut selections = Vec::new();
+        loop {
+            match self.peek() {
+                Some(Token::Punct('}')) => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Token::Spread) => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(Token::Name(n)) if n != "on" => {
+                            let name = self.expect_name()?;
+                            self.skip_directives()?;
+                            selections.push(Selection::FragmentSpread(name));
+                        }
+                        _ => {
+                            let type_condition = if self.peek() == Some(&Token::Name("on".into())) {
+                                self.pos += 1;
+                                Some(self.expect_name()?)
+                            } else {
+                                None
+                            };
+                            self.skip_directives()?;
+                            let inner = self.selection_set()?;
+                            selections.push(Selection::InlineFragment {
+                                type_condition,
+                                selections: inner,
+                            });
+                        }
+                    }
+                }
+                Some(_) => {
+                    let mut name = self.expect_name()?;
+                    if self.is_punct(':') {
+                        self.pos += 1;
+                        name = self.expect_name()?;
+                    }
+                    let arguments = if self.is_punct('(') {
+                        self.arguments()?
+                    } else {
+                        Vec::new()
+                    };
+                    self.skip_directives()?;
+                    let inner = if self.is_punct('{') {
+                        self.selection_set()?
+                    } else {
+                        Vec::new()
+                    };
+                    selections.push(Selection::Field {
+                        name,
+                        arguments,
+                        selections: inner,
+                    });
+                }
+                None => bail!("Unterminated selection set (missing '}}')"),
+            }
+        }
+        Ok(selections)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_fields_named_query() {
+        let query = r#"
+            query GetIssue($id: String!) {
+                issue(id: $id) { id title labels { nodes { name } } }
+            }
+        "#;
+        assert_eq!(root_fields(query), vec!["issue"]);
+        assert_eq!(operation_name(query).as_deref(), Some("GetIssue"));
+        assert_eq!(operation_type(query).as_deref(), Some("query"));
+    }
+
+    #[test]
+    fn test_root_fields_anonymous_and_aliases() {
+        let query = "{ me: viewer { id } teams(first: 5) { nodes { id } } }";
+        assert_eq!(root_fields(query), vec!["viewer", "teams"]);
+        assert_eq!(operation_name(query), None);
+    }
+
+    #[test]
+    fn test_root_fields_skips_fragments_and_comments() {
+        let query = r#"
+            # leading comment
+            fragment IssueBits on Issue { id title }
+            mutation($input: IssueCreateInput!) {
+                issueCreate(input: $input) { issue { ...IssueBits } }
+            }
+        "#;
+        assert_eq!(root_fields(query), vec!["issueCreate"]);
+        assert_eq!(operation_type(query).as_deref(), Some("mutation"));
+    }
+
+    #[test]
+    fn test_tokenize_strings_and_spread() {
+        let tokens = tokenize(r#"{ a(b: "x, y") ...F }"#);
+        assert!(tokens.contains(&Token::Str("x, y".to_string())));
+        assert!(tokens.contains(&Token::Spread));
+    }
+
+    #[test]
+    fn test_parse_document() {
+        let doc = parse(
+            r#"
+            query Issues($first: Int = 10, $filter: IssueFilter, $ids: [ID!]!) @cached {
+                issues(first: $first, filter: $filter) {
+                    nodes { id ...Bits ... on Issue { title } }
+                }
+                me: viewer { id }
+            }
+            fragment Bits on Issue { identifier }
+        "#,
+        )
+        .unwrap();
+        let op = &doc.operations[0];
+        assert_eq!(op.name.as_deref(), Some("Issues"));
+        assert_eq!(
+            op.variables
+                .iter()
+                .map(|v| (v.name.as_str(), v.ty.as_str(), v.has_default))
+                .collect::<Vec<_>>(),
+            vec![
+                ("first", "Int", true),
+                ("filter", "IssueFilter", false),
+                ("ids", "[ID!]!", false)
+            ]
+        );
+        let Selection::Field {
+            name,
+            arguments,
+            selections,
+        } = &op.selections[0]
+        else {
+            panic!("expected field");
+        };
+        assert_eq!(name, "issues");
+        assert_eq!(arguments, &vec!["first".to_string(), "filter".to_string()]);
+        let Selection::Field { selections, .. } = &selections[0] else {
+            panic!("expected nodes");
+        };
+        assert_eq!(selections[1], Selection::FragmentSpread("Bits".to_string()));
+        assert!(matches!(
+            &selections[2],
+            Selection::InlineFragment { type_condition: Some(t), .. } if t == "Issue"
+        ));
+        assert!(matches!(&op.selections[1], Selection::Field { name, .. } if name == "viewer"));
+        assert_eq!(doc.fragment("Bits").unwrap().type_condition, "Issue");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("{ viewer { id }").is_err());
+        assert!(parse("query ($id String) { a }").is_err());
+        assert!(parse("fragment F on Issue { id }").is_err());
+    }
+
+    #[test]
+    fn test_extract_operation() {
+        let source = r#"
+            # Saved operations
+            query MyIssues($first: Int = 10) { issues(first: $first) { nodes { ...Bits } } }
+            fragment Bits on Issue { id ...More }
+            fragment More on Issue { title(format: "a } b") }
+            fragment Unused on Issue { url }
+            mutation Rename($id: String!, $input: IssueUpdateInput = { title: "x" }) {
+                issueUpdate(id: $id, input: $input) { success }
+            }
+        "#;
+        assert_eq!(definitions(source).len(), 5);
+
+        let extracted = extract_operation(source, Some("MyIssues")).unwrap();
+        assert!(extracted.starts_with("query MyIssues"));
+        assert!(extracted.contains("fragment Bits on Issue"));
+        assert!(extracted.contains("fragment More on Issue"));
+        assert!(!extracted.contains("Unused"));
+        assert!(!extracted.contains("mutation"));
+
+        let rename = extract_operation(source, Some("Rename")).unwrap();
+        assert!(rename.starts_with("mutation Rename"));
+        assert!(rename.ends_with("}"));
+        assert!(!rename.contains("fragment"));
+
+        let err = extract_operation(source, None).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Document defines 2 operations (MyIssues, Rename); choose one with --operation"
+        );
+        let err = extract_operation(source, Some("Nope"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("available: MyIssues, Rename"));
+
+        let single = "{ viewer { id } }";
+        assert_eq!(extract_operation(single, None).unwrap(), single);
+    }
+}
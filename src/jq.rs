@@ -0,0 +1,1620 @@
+//! `--jq` expressions: a jq subset applied to JSON output before printing.
+//!
+//! ```text
+//! .[] | select(.priority <= 2) | {id: .identifier, state: .state.name}
+//! group_by(.state.name) | map({state: .[0].state.name, count: length})
+//! map(.estimate // 0) | add
+//! ```
+//!
+//! Supported: paths (`.a.b`, `.[0]`, `.[]`, `.["key"]`, `.[1:3]`, `..`, `?`),
+//! pipes and commas, literals, array/object construction, string interpolation,
+//! arithmetic, comparisons, `and`/`or`, `//`, `if ... then ... else ... end` and
+//! the builtins in [`BUILTINS`]. Variables, `reduce` and `def` are not.
+
+use anyhow::Result;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+use std::cmp::Ordering;
+
+/// Builtin functions as `name/arity` (shown in error messages)
+pub const BUILTINS: &[&str] = &[
+    "add/0",
+    "all/0",
+    "all/1",
+    "any/0",
+    "any/1",
+    "ascii_downcase/0",
+    "ascii_upcase/0",
+    "ceil/0",
+    "contains/1",
+    "empty/0",
+    "endswith/1",
+    "first/0",
+    "flatten/0",
+    "floor/0",
+    "from_entries/0",
+    "group_by/1",
+    "has/1",
+    "join/1",
+    "keys/0",
+    "last/0",
+    "length/0",
+    "map/1",
+    "map_values/1",
+    "max/0",
+    "max_by/1",
+    "min/0",
+    "min_by/1",
+    "not/0",
+    "reverse/0",
+    "round/0",
+    "select/1",
+    "sort/0",
+    "sort_by/1",
+    "split/1",
+    "startswith/1",
+    "test/1",
+    "to_entries/0",
+    "tojson/0",
+    "tonumber/0",
+    "tostring/0",
+    "type/0",
+    "unique/0",
+    "unique_by/1",
+    "with_entries/1",
+];
+
+/// A parsed `--jq` expression
+#[derive(Debug, Clone)]
+pub struct Filter {
+    expr: Expr,
+}
+
+impl Filter {
+    pub fn parse(input: &str) -> Result<Filter> {
+        let expr = parse(input).map_err(|e| anyhow::anyhow!("Invalid --jq expression: {}", e))?;
+        Ok(Filter { expr })
+    }
+
+    /// Run the expression, returning every value it produces
+    pub fn run(&self, input: &Value) -> Result<Vec<Value>> {
+        eval(&self.expr, input).map_err(|e| anyhow::anyhow!("--jq: {}", e))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum StrPart {
+    Lit(String),
+    /// Source of a `\(...)` interpolation
+    Interp(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Dot,
+    DotDot,
+    Field(String),
+    Ident(String),
+    Num(f64),
+    Str(Vec<StrPart>),
+    Op(&'static str),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    Colon,
+    Semi,
+    Comma,
+    Pipe,
+    Question,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Identity,
+    Recurse,
+    Literal(Value),
+    /// `target[key]`, with `key` evaluated against the original input
+    Index(Box<Expr>, Box<Expr>),
+    Slice(Box<Expr>, Option<Box<Expr>>, Option<Box<Expr>>),
+    Iterate(Box<Expr>),
+    Try(Box<Expr>),
+    Pipe(Box<Expr>, Box<Expr>),
+    Comma(Box<Expr>, Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Alternative(Box<Expr>, Box<Expr>),
+    Neg(Box<Expr>),
+    Array(Option<Box<Expr>>),
+    Object(Vec<(Expr, Expr)>),
+    Format(Vec<FormatPart>),
+    If(Vec<(Expr, Expr)>, Option<Box<Expr>>),
+    Call(String, Vec<Expr>),
+}
+
+#[derive(Debug, Clone)]
+enum FormatPart {
+    Lit(String),
+    Expr(Expr),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '.' => {
+                if chars.get(i + 1) == Some(&'.') {
+                    tokens.push(Token::DotDot);
+                    i += 2;
+                } else if chars.get(i + 1).is_some_and(|ch| is_ident_start(*ch)) {
+                    let start = i + 1;
+                    i += 1;
+                    while i < chars.len() && is_ident_char(chars[i]) {
+                        i += 1;
+                    }
+                    tokens.push(Token::Field(chars[start..i].iter().collect()));
+                } else {
+                    tokens.push(Token::Dot);
+                    i += 1;
+                }
+            }
+            '"' => {
+                let (parts, next) = read_string(&chars, i)?;
+                tokens.push(Token::Str(parts));
+                i = next;
+            }
+            '0'..='9' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                if i < chars.len() && matches!(chars[i], 'e' | 'E') {
+                    i += 1;
+                    if i < chars.len() && matches!(chars[i], '+' | '-') {
+                        i += 1;
+                    }
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+                let text: String = chars[start..i].iter().collect();
+                let num = text
+                    .parse::<f64>()
+                    .map_err(|_| anyhow::anyhow!("Invalid number '{}'", text))?;
+                tokens.push(Token::Num(num));
+            }
+            _ if is_ident_start(c) => {
+                let start = i;
+                while i < chars.len() && is_ident_char(chars[i]) {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+            '$' => anyhow::bail!("Variables are not supported (column {})", i + 1),
+            '(' | ')' | '[' | ']' | '{' | '}' | ':' | ';' | ',' | '|' | '?' => {
+                tokens.push(match c {
+                    '(' => Token::LParen,
+                    ')' => Token::RParen,
+                    '[' => Token::LBracket,
+                    ']' => Token::RBracket,
+                    '{' => Token::LBrace,
+                    '}' => Token::RBrace,
+                    ':' => Token::Colon,
+                    ';' => Token::Semi,
+                    ',' => Token::Comma,
+                    '|' => Token::Pipe,
+                    _ => Token::Question,
+                });
+                i += 1;
+            }
+            _ => {
+                let next = chars.get(i + 1).copied();
+                let (op, len) = match (c, next) {
+                    ('=', Some('=')) => ("==", 2),
+                    ('!', Some('=')) => ("!=", 2),
+                    ('<', Some('=')) => ("<=", 2),
+                    ('>', Some('=')) => (">=", 2),
+                    ('/', Some('/')) => ("//", 2),
+                    ('<', _) => ("<", 1),
+                    ('>', _) => (">", 1),
+                    ('+', _) => ("+", 1),
+                    ('-', _) => ("-", 1),
+                    ('*', _) => ("*", 1),
+                    ('/', _) => ("/", 1),
+                    ('%', _) => ("%", 1),
+                    ('=', _) => anyhow::bail!(
+                        "Assignment is not supported; use == to compare (column {})",
+                        i + 1
+                    ),
+                    _ => anyhow::bail!("Unexpected '{}' at column {}", c, i + 1),
+                };
+                tokens.push(Token::Op(op));
+                i += len;
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Read a string literal starting at the opening quote; returns its parts and
+/// the index just past the closing quote
+fn read_string(chars: &[char], start: usize) -> Result<(Vec<StrPart>, usize)> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut i = start + 1;
+    loop {
+        match chars.get(i) {
+            None => anyhow::bail!("Unterminated string starting at column {}", start + 1),
+            Some('"') => break,
+            Some('\\') => {
+                let escaped = chars.get(i + 1).ok_or_else(|| {
+                    anyhow::anyhow!("Unterminated string starting at column {}", start + 1)
+                })?;
+                i += 2;
+                match escaped {
+                    'n' => current.push('\n'),
+                    't' => current.push('\t'),
+                    'r' => current.push('\r'),
+                    'u' => {
+                        let hex: String = chars.iter().skip(i).take(4).collect();
+                        let code = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| anyhow::anyhow!("Invalid escape \\u{}", hex))?;
+                        current.push(code);
+                        i += 4;
+                    }
+                    '(' => {
+                        let begin = i;
+                        let mut depth = 1;
+                        let mut in_string = false;
+                        while depth > 0 {
+                            match chars.get(i) {
+                                None => anyhow::bail!(
+                                    "Unterminated interpolation starting at column {}",
+                                    begin - 1
+                                ),
+                                Some('\\') if in_string => i += 1,
+                                Some('"') => in_string = !in_string,
+                                Some('(') if !in_string => depth += 1,
+                                Some(')') if !in_string => depth -= 1,
+                                _ => {}
+                            }
+                            i += 1;
+                        }
+                        if !current.is_empty() {
+                            parts.push(StrPart::Lit(std::mem::take(&mut current)));
+                        }
+                        parts.push(StrPart::Interp(chars[begin..i - 1].iter().collect()));
+                    }
+                    other => current.push(*other),
+                }
+            }
+            Some(&ch) => {
+                current.push(ch);
+                i += 1;
+            }
+        }
+    }
+    if !current.is_empty() || parts.is_empty() {
+        parts.push(StrPart::Lit(current));
+    }
+    Ok((parts, i + 1))
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(w)) if w == keyword)
+    }
+
+    fn peek_op(&self, op: &str) -> bool {
+        matches!(self.peek(), Some(Token::Op(o)) if *o == op)
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<()> {
+        match self.next() {
+            Some(ref t) if *t == expected => Ok(()),
+            Some(t) => anyhow::bail!("Expected {} but found {}", what, describe(&t)),
+            None => anyhow::bail!("Expected {} but the expression ended", what),
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        self.expect(Token::Ident(keyword.to_string()), &format!("'{}'", keyword))
+    }
+
+    /// `a | b`, right-associative; `allow_comma` is off inside object values
+    fn parse_pipe(&mut self, allow_comma: bool) -> Result<Expr> {
+        let lhs = if allow_comma {
+            self.parse_comma()?
+        } else {
+            self.parse_alternative()?
+        };
+        if self.eat(&Token::Pipe) {
+            let rhs = self.parse_pipe(allow_comma)?;
+            return Ok(Expr::Pipe(Box::new(lhs), Box::new(rhs)));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_comma(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_alternative()?;
+        while self.eat(&Token::Comma) {
+            let rhs = self.parse_alternative()?;
+            expr = Expr::Comma(Box::new(expr), Box::new(rhs));
+        }
+        Ok(expr)
+    }
+
+    fn parse_alternative(&mut self) -> Result<Expr> {
+        let lhs = self.parse_or()?;
+        if self.peek_op("//") {
+            self.pos += 1;
+            let rhs = self.parse_alternative()?;
+            return Ok(Expr::Alternative(Box::new(lhs), Box::new(rhs)));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_or(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_and()?;
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            expr = Expr::Or(Box::new(expr), Box::new(rhs));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_comparison()?;
+        while self.peek_keyword("and") {
+            self.pos += 1;
+            let rhs = self.parse_comparison()?;
+            expr = Expr::And(Box::new(expr), Box::new(rhs));
+        }
+        Ok(expr)
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr> {
+        let lhs = self.parse_additive()?;
+        let op = match self.peek() {
+            Some(Token::Op("==")) => BinOp::Eq,
+            Some(Token::Op("!=")) => BinOp::Ne,
+            Some(Token::Op("<")) => BinOp::Lt,
+            Some(Token::Op("<=")) => BinOp::Le,
+            Some(Token::Op(">")) => BinOp::Gt,
+            Some(Token::Op(">=")) => BinOp::Ge,
+            _ => return Ok(lhs),
+        };
+        self.pos += 1;
+        let rhs = self.parse_additive()?;
+        Ok(Expr::Binary(op, Box::new(lhs), Box::new(rhs)))
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op("+")) => BinOp::Add,
+                Some(Token::Op("-")) => BinOp::Sub,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let rhs = self.parse_multiplicative()?;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(rhs));
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op("*")) => BinOp::Mul,
+                Some(Token::Op("/")) => BinOp::Div,
+                Some(Token::Op("%")) => BinOp::Mod,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let rhs = self.parse_unary()?;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(rhs));
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr> {
+        if self.peek_op("-") {
+            self.pos += 1;
+            return Ok(Expr::Neg(Box::new(self.parse_postfix()?)));
+        }
+        self.parse_postfix()
+    }
+
+    fn parse_postfix(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_primary()?;
+        loop {
+            match self.peek() {
+                Some(Token::Field(name)) => {
+                    let key = Expr::Literal(json!(name));
+                    self.pos += 1;
+                    expr = Expr::Index(Box::new(expr), Box::new(key));
+                }
+                Some(Token::Dot)
+                    if matches!(
+                        self.tokens.get(self.pos + 1),
+                        Some(Token::Str(_)) | Some(Token::LBracket)
+                    ) =>
+                {
+                    self.pos += 1;
+                    if let Some(Token::Str(parts)) = self.peek().cloned() {
+                        self.pos += 1;
+                        let key = format_expr(parts)?;
+                        expr = Expr::Index(Box::new(expr), Box::new(key));
+                    }
+                }
+                Some(Token::LBracket) => {
+                    self.pos += 1;
+                    expr = self.parse_bracket(expr)?;
+                }
+                Some(Token::Question) => {
+                    self.pos += 1;
+                    expr = Expr::Try(Box::new(expr));
+                }
+                _ => return Ok(expr),
+            }
+        }
+    }
+
+    /// After `[`: iteration, index or slice
+    fn parse_bracket(&mut self, target: Expr) -> Result<Expr> {
+        if self.eat(&Token::RBracket) {
+            return Ok(Expr::Iterate(Box::new(target)));
+        }
+        if self.eat(&Token::Colon) {
+            let to = self.parse_pipe(true)?;
+            self.expect(Token::RBracket, "']'")?;
+            return Ok(Expr::Slice(Box::new(target), None, Some(Box::new(to))));
+        }
+        let index = self.parse_pipe(true)?;
+        if self.eat(&Token::Colon) {
+            let to = if self.peek() == Some(&Token::RBracket) {
+                None
+            } else {
+                Some(Box::new(self.parse_pipe(true)?))
+            };
+            self.expect(Token::RBracket, "']'")?;
+            return Ok(Expr::Slice(Box::new(target), Some(Box::new(index)), to));
+        }
+        self.expect(Token::RBracket, "']'")?;
+        Ok(Expr::Index(Box::new(target), Box::new(index)))
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr> {
+        match self.next() {
+            Some(Token::Dot) => {
+                // `."key"` on the identity
+                if let Some(Token::Str(parts)) = self.peek().cloned() {
+                    self.pos += 1;
+                    let key = format_expr(parts)?;
+                    return Ok(Expr::Index(Box::new(Expr::Identity), Box::new(key)));
+                }
+                Ok(Expr::Identity)
+            }
+            Some(Token::DotDot) => Ok(Expr::Recurse),
+            Some(Token::Field(name)) => Ok(Expr::Index(
+                Box::new(Expr::Identity),
+                Box::new(Expr::Literal(json!(name))),
+            )),
+            Some(Token::Num(n)) => Ok(Expr::Literal(number(n))),
+            Some(Token::Str(parts)) => format_expr(parts),
+            Some(Token::LParen) => {
+                let expr = self.parse_pipe(true)?;
+                self.expect(Token::RParen, "')'")?;
+                Ok(expr)
+            }
+            Some(Token::LBracket) => {
+                if self.eat(&Token::RBracket) {
+                    return Ok(Expr::Array(None));
+                }
+                let inner = self.parse_pipe(true)?;
+                self.expect(Token::RBracket, "']'")?;
+                Ok(Expr::Array(Some(Box::new(inner))))
+            }
+            Some(Token::LBrace) => self.parse_object(),
+            Some(Token::Ident(word)) => match word.as_str() {
+                "true" => Ok(Expr::Literal(Value::Bool(true))),
+                "false" => Ok(Expr::Literal(Value::Bool(false))),
+                "null" => Ok(Expr::Literal(Value::Null)),
+                "if" => self.parse_if(),
+                "reduce" | "foreach" | "def" | "as" | "label" | "try" => {
+                    anyhow::bail!("'{}' is not supported", word)
+                }
+                _ => self.parse_call(word),
+            },
+            Some(t) => anyhow::bail!("Unexpected {}", describe(&t)),
+            None => anyhow::bail!("Unexpected end of expression"),
+        }
+    }
+
+    fn parse_call(&mut self, name: String) -> Result<Expr> {
+        let mut args = Vec::new();
+        if self.eat(&Token::LParen) {
+            args.push(self.parse_pipe(true)?);
+            while self.eat(&Token::Semi) {
+                args.push(self.parse_pipe(true)?);
+            }
+            self.expect(Token::RParen, "')'")?;
+        }
+        let signature = format!("{}/{}", name, args.len());
+        if !BUILTINS.contains(&signature.as_str()) {
+            anyhow::bail!(
+                "Unknown function '{}'. Available: {}",
+                signature,
+                BUILTINS.join(", ")
+            );
+        }
+        Ok(Expr::Call(name, args))
+    }
+
+    fn parse_if(&mut self) -> Result<Expr> {
+        let mut branches = Vec::new();
+        let cond = self.parse_pipe(true)?;
+        self.expect_keyword("then")?;
+        branches.push((cond, self.parse_pipe(true)?));
+        loop {
+            if self.peek_keyword("elif") {
+                self.pos += 1;
+                let cond = self.parse_pipe(true)?;
+                self.expect_keyword("then")?;
+                branches.push((cond, self.parse_pipe(true)?));
+            } else if self.peek_keyword("else") {
+                self.pos += 1;
+                let otherwise = self.parse_pipe(true)?;
+                self.expect_keyword("end")?;
+                return Ok(Expr::If(branches, Some(Box::new(otherwise))));
+            } else {
+                self.expect_keyword("end")?;
+                return Ok(Expr::If(branches, None));
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Expr> {
+        let mut entries = Vec::new();
+        if self.eat(&Token::RBrace) {
+            return Ok(Expr::Object(entries));
+        }
+        loop {
+            let (key, shorthand) = match self.next() {
+                Some(Token::Ident(name)) => (Expr::Literal(json!(name)), Some(name)),
+                Some(Token::Str(parts)) => {
+                    let shorthand = match parts.as_slice() {
+                        [StrPart::Lit(s)] => Some(s.clone()),
+                        _ => None,
+                    };
+                    (format_expr(parts)?, shorthand)
+                }
+                Some(Token::LParen) => {
+                    let key = self.parse_pipe(true)?;
+                    self.expect(Token::RParen, "')'")?;
+                    (key, None)
+                }
+                Some(t) => anyhow::bail!("Expected an object key but found {}", describe(&t)),
+                None => anyhow::bail!("Expected an object key but the expression ended"),
+            };
+            let value = if self.eat(&Token::Colon) {
+                self.parse_pipe(false)?
+            } else if let Some(name) = shorthand {
+                Expr::Index(
+                    Box::new(Expr::Identity),
+                    Box::new(Expr::Literal(json!(name))),
+                )
+            } else {
+                anyhow::bail!("Expected ':' after computed object key");
+            };
+            entries.push((key, value));
+            match self.next() {
+                Some(Token::Comma) => continue,
+                Some(Token::RBrace) => return Ok(Expr::Object(entries)),
+                Some(t) => anyhow::bail!("Expected ',' or '}}' but found {}", describe(&t)),
+                None => anyhow::bail!("Expected '}}' but the expression ended"),
+            }
+        }
+    }
+}
+
+fn format_expr(parts: Vec<StrPart>) -> Result<Expr> {
+    if let [StrPart::Lit(s)] = parts.as_slice() {
+        return Ok(Expr::Literal(json!(s)));
+    }
+    let parts = parts
+        .into_iter()
+        .map(|part| match part {
+            StrPart::Lit(s) => Ok(FormatPart::Lit(s)),
+            StrPart::Interp(source) => parse(&source).map(FormatPart::Expr),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Expr::Format(parts))
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Dot => "'.'".to_string(),
+        Token::DotDot => "'..'".to_string(),
+        Token::Field(name) => format!("'.{}'", name),
+        Token::Ident(word) => format!("'{}'", word),
+        Token::Num(n) => format!("'{}'", n),
+        Token::Str(_) => "a string".to_string(),
+        Token::Op(op) => format!("'{}'", op),
+        Token::LParen => "'('".to_string(),
+        Token::RParen => "')'".to_string(),
+        Token::LBracket => "'['".to_string(),
+        Token::RBracket => "']'".to_string(),
+        Token::LBrace => "'{'".to_string(),
+        Token::RBrace => "'}'".to_string(),
+        Token::Colon => "':'".to_string(),
+        Token::Semi => "';'".to_string(),
+        Token::Comma => "','".to_string(),
+        Token::Pipe => "'|'".to_string(),
+        Token::Question => "'?'".to_string(),
+    }
+}
+
+fn parse(input: &str) -> Result<Expr> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        anyhow::bail!("Empty expression");
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_pipe(true)?;
+    if let Some(t) = parser.peek() {
+        anyhow::bail!("Unexpected {}", describe(t));
+    }
+    Ok(expr)
+}
+
+fn eval(expr: &Expr, input: &Value) -> Result<Vec<Value>> {
+    match expr {
+        Expr::Identity => Ok(vec![input.clone()]),
+        Expr::Recurse => {
+            let mut out = Vec::new();
+            recurse(input, &mut out);
+            Ok(out)
+        }
+        Expr::Literal(value) => Ok(vec![value.clone()]),
+        Expr::Index(target, key) => {
+            let mut out = Vec::new();
+            for t in eval(target, input)? {
+                for k in eval(key, input)? {
+                    out.push(index(&t, &k)?);
+                }
+            }
+            Ok(out)
+        }
+        Expr::Slice(target, from, to) => {
+            let bound = |e: &Option<Box<Expr>>| -> Result<Option<Value>> {
+                match e {
+                    Some(e) => Ok(eval(e, input)?.into_iter().next()),
+                    None => Ok(None),
+                }
+            };
+            let (from, to) = (bound(from)?, bound(to)?);
+            eval(target, input)?
+                .iter()
+                .map(|t| slice(t, from.as_ref(), to.as_ref()))
+                .collect()
+        }
+        Expr::Iterate(target) => {
+            let mut out = Vec::new();
+            for t in eval(target, input)? {
+                out.extend(iterate(&t)?);
+            }
+            Ok(out)
+        }
+        Expr::Try(inner) => Ok(eval(inner, input).unwrap_or_default()),
+        Expr::Pipe(lhs, rhs) => {
+            let mut out = Vec::new();
+            for v in eval(lhs, input)? {
+                out.extend(eval(rhs, &v)?);
+            }
+            Ok(out)
+        }
+        Expr::Comma(lhs, rhs) => {
+            let mut out = eval(lhs, input)?;
+            out.extend(eval(rhs, input)?);
+            Ok(out)
+        }
+        Expr::Binary(op, lhs, rhs) => {
+            let rhs = eval(rhs, input)?;
+            let mut out = Vec::new();
+            for l in eval(lhs, input)? {
+                for r in &rhs {
+                    out.push(binary(*op, &l, r)?);
+                }
+            }
+            Ok(out)
+        }
+        Expr::And(lhs, rhs) | Expr::Or(lhs, rhs) => {
+            let is_and = matches!(expr, Expr::And(..));
+            let mut out = Vec::new();
+            for l in eval(lhs, input)? {
+                if truthy(&l) != is_and {
+                    out.push(Value::Bool(!is_and));
+                    continue;
+                }
+                for r in eval(rhs, input)? {
+                    out.push(Value::Bool(truthy(&r)));
+                }
+            }
+            Ok(out)
+        }
+        Expr::Alternative(lhs, rhs) => {
+            let found: Vec<Value> = eval(lhs, input)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(truthy)
+                .collect();
+            if found.is_empty() {
+                eval(rhs, input)
+            } else {
+                Ok(found)
+            }
+        }
+        Expr::Neg(inner) => eval(inner, input)?
+            .iter()
+            .map(|v| match v.as_f64() {
+                Some(n) => Ok(number(-n)),
+                None => anyhow::bail!("{} cannot be negated", type_name(v)),
+            })
+            .collect(),
+        Expr::Array(inner) => Ok(vec![Value::Array(match inner {
+            Some(inner) => eval(inner, input)?,
+            None => Vec::new(),
+        })]),
+        Expr::Object(entries) => {
+            let mut objects = vec![Map::new()];
+            for (key_expr, value_expr) in entries {
+                let keys = eval(key_expr, input)?;
+                let values = eval(value_expr, input)?;
+                let mut next = Vec::new();
+                for object in &objects {
+                    for key in &keys {
+                        let Value::String(key) = key else {
+                            anyhow::bail!("Object keys must be strings, not {}", type_name(key));
+                        };
+                        for value in &values {
+                            let mut object = object.clone();
+                            object.insert(key.clone(), value.clone());
+                            next.push(object);
+                        }
+                    }
+                }
+                objects = next;
+            }
+            Ok(objects.into_iter().map(Value::Object).collect())
+        }
+        Expr::Format(parts) => {
+            let mut strings = vec![String::new()];
+            for part in parts {
+                match part {
+                    FormatPart::Lit(s) => strings.iter_mut().for_each(|out| out.push_str(s)),
+                    FormatPart::Expr(e) => {
+                        let values = eval(e, input)?;
+                        strings = strings
+                            .iter()
+                            .flat_map(|prefix| {
+                                values
+                                    .iter()
+                                    .map(move |v| format!("{}{}", prefix, to_text(v)))
+                            })
+                            .collect();
+                    }
+                }
+            }
+            Ok(strings.into_iter().map(Value::String).collect())
+        }
+        Expr::If(branches, otherwise) => eval_if(branches, otherwise.as_deref(), input),
+        Expr::Call(name, args) => call(name, args, input),
+    }
+}
+
+fn eval_if(
+    branches: &[(Expr, Expr)],
+    otherwise: Option<&Expr>,
+    input: &Value,
+) -> Result<Vec<Value>> {
+    let Some(((cond, then), rest)) = branches.split_first() else {
+        return match otherwise {
+            Some(e) => eval(e, input),
+            None => Ok(vec![input.clone()]),
+        };
+    };
+    let mut out = Vec::new();
+    for c in eval(cond, input)? {
+        if truthy(&c) {
+            out.extend(eval(then, input)?);
+        } else {
+            out.extend(eval_if(rest, otherwise, input)?);
+        }
+    }
+    Ok(out)
+}
+
+fn call(name: &str, args: &[Expr], input: &Value) -> Result<Vec<Value>> {
+    let one = |v: Value| Ok(vec![v]);
+    match (name, args) {
+        ("empty", []) => Ok(Vec::new()),
+        ("not", []) => one(Value::Bool(!truthy(input))),
+        ("length", []) => one(match input {
+            Value::Null => json!(0),
+            Value::Bool(_) => anyhow::bail!("boolean has no length"),
+            Value::Number(n) => number(n.as_f64().unwrap_or(0.0).abs()),
+            Value::String(s) => json!(s.chars().count()),
+            Value::Array(items) => json!(items.len()),
+            Value::Object(map) => json!(map.len()),
+        }),
+        ("keys", []) => one(match input {
+            Value::Object(map) => {
+                let mut keys: Vec<&String> = map.keys().collect();
+                keys.sort();
+                json!(keys)
+            }
+            Value::Array(items) => json!((0..items.len()).collect::<Vec<_>>()),
+            _ => anyhow::bail!("{} has no keys", type_name(input)),
+        }),
+        ("add", []) => {
+            let mut total = Value::Null;
+            for item in iterate(input)? {
+                total = binary(BinOp::Add, &total, &item)?;
+            }
+            one(total)
+        }
+        ("any", []) => one(Value::Bool(iterate(input)?.iter().any(truthy))),
+        ("all", []) => one(Value::Bool(iterate(input)?.iter().all(truthy))),
+        ("any", [f]) | ("all", [f]) => {
+            let mut results = Vec::new();
+            for item in iterate(input)? {
+                results.extend(eval(f, &item)?);
+            }
+            let result = if name == "any" {
+                results.iter().any(truthy)
+            } else {
+                results.iter().all(truthy)
+            };
+            one(Value::Bool(result))
+        }
+        ("first", []) => one(index(input, &json!(0))?),
+        ("last", []) => one(index(input, &json!(-1))?),
+        ("type", []) => one(json!(type_name(input))),
+        ("tostring", []) => one(json!(to_text(input))),
+        ("tojson", []) => one(json!(serde_json::to_string(input)?)),
+        ("tonumber", []) => one(match input {
+            Value::Number(_) => input.clone(),
+            Value::String(s) => number(
+                s.trim()
+                    .parse::<f64>()
+                    .map_err(|_| anyhow::anyhow!("Cannot parse '{}' as a number", s))?,
+            ),
+            _ => anyhow::bail!("{} cannot be parsed as a number", type_name(input)),
+        }),
+        ("floor", []) | ("ceil", []) | ("round", []) => {
+            let n = input
+                .as_f64()
+                .ok_or_else(|| anyhow::anyhow!("{} is not a number", type_name(input)))?;
+            one(number(match name {
+                "floor" => n.floor(),
+                "ceil" => n.ceil(),
+                _ => n.round(),
+            }))
+        }
+        ("ascii_downcase", []) | ("ascii_upcase", []) => {
+            let s = expect_str(input, name)?;
+            one(json!(if name == "ascii_downcase" {
+                s.to_ascii_lowercase()
+            } else {
+                s.to_ascii_uppercase()
+            }))
+        }
+        ("reverse", []) => one(match input {
+            Value::Null => json!([]),
+            Value::String(s) => json!(s.chars().rev().collect::<String>()),
+            _ => {
+                let mut items = expect_array(input, name)?.clone();
+                items.reverse();
+                Value::Array(items)
+            }
+        }),
+        ("sort", []) => {
+            let mut items = expect_array(input, name)?.clone();
+            items.sort_by(compare);
+            one(Value::Array(items))
+        }
+        ("unique", []) => {
+            let mut items = expect_array(input, name)?.clone();
+            items.sort_by(compare);
+            items.dedup_by(|a, b| compare(a, b) == Ordering::Equal);
+            one(Value::Array(items))
+        }
+        ("min", []) | ("max", []) => {
+            let items = expect_array(input, name)?;
+            let found = if name == "min" {
+                items.iter().min_by(|a, b| compare(a, b))
+            } else {
+                items.iter().max_by(|a, b| compare(a, b))
+            };
+            one(found.cloned().unwrap_or(Value::Null))
+        }
+        ("flatten", []) => {
+            let mut out = Vec::new();
+            flatten(expect_array(input, name)?, &mut out);
+            one(Value::Array(out))
+        }
+        ("to_entries", []) => one(Value::Array(to_entries(input)?)),
+        ("from_entries", []) => one(from_entries(input)?),
+        ("with_entries", [f]) => {
+            let mut mapped = Vec::new();
+            for entry in to_entries(input)? {
+                mapped.extend(eval(f, &entry)?);
+            }
+            one(from_entries(&Value::Array(mapped))?)
+        }
+        ("map", [f]) => {
+            let mut out = Vec::new();
+            for item in iterate(input)? {
+                out.extend(eval(f, &item)?);
+            }
+            one(Value::Array(out))
+        }
+        ("map_values", [f]) => one(match input {
+            Value::Object(map) => {
+                let mut out = Map::new();
+                for (key, value) in map {
+                    if let Some(v) = eval(f, value)?.into_iter().next() {
+                        out.insert(key.clone(), v);
+                    }
+                }
+                Value::Object(out)
+            }
+            _ => {
+                let mut out = Vec::new();
+                for item in expect_array(input, name)? {
+                    if let Some(v) = eval(f, item)?.into_iter().next() {
+                        out.push(v);
+                    }
+                }
+                Value::Array(out)
+            }
+        }),
+        ("select", [f]) => Ok(eval(f, input)?
+            .iter()
+            .filter(|v| truthy(v))
+            .map(|_| input.clone())
+            .collect()),
+        ("sort_by", [f]) | ("group_by", [f]) | ("unique_by", [f]) => {
+            let mut keyed = keyed_items(input, f, name)?;
+            keyed.sort_by(|a, b| compare(&a.0, &b.0));
+            if name == "sort_by" {
+                return one(Value::Array(keyed.into_iter().map(|(_, v)| v).collect()));
+            }
+            let mut groups: Vec<(Value, Vec<Value>)> = Vec::new();
+            for (key, item) in keyed {
+                match groups.last_mut() {
+                    Some((last, group)) if compare(last, &key) == Ordering::Equal => {
+                        group.push(item)
+                    }
+                    _ => groups.push((key, vec![item])),
+                }
+            }
+            one(Value::Array(
+                groups
+                    .into_iter()
+                    .map(|(_, group)| {
+                        if name == "group_by" {
+                            Value::Array(group)
+                        } else {
+                            group.into_iter().next().unwrap_or(Value::Null)
+                        }
+                    })
+                    .collect(),
+            ))
+        }
+        ("min_by", [f]) | ("max_by", [f]) => {
+            let keyed = keyed_items(input, f, name)?;
+            let found = if name == "min_by" {
+                keyed.into_iter().min_by(|a, b| compare(&a.0, &b.0))
+            } else {
+                keyed.into_iter().max_by(|a, b| compare(&a.0, &b.0))
+            };
+            one(found.map(|(_, v)| v).unwrap_or(Value::Null))
+        }
+        (_, [arg]) => {
+            let mut out = Vec::new();
+            for a in eval(arg, input)? {
+                out.push(call_with_value(name, input, &a)?);
+            }
+            Ok(out)
+        }
+        _ => anyhow::bail!("Unknown function '{}/{}'", name, args.len()),
+    }
+}
+
+/// Builtins whose single argument is a plain value rather than a filter
+fn call_with_value(name: &str, input: &Value, arg: &Value) -> Result<Value> {
+    Ok(match name {
+        "has" => match (input, arg) {
+            (Value::Object(map), Value::String(key)) => json!(map.contains_key(key)),
+            (Value::Array(items), Value::Number(n)) => {
+                json!(n
+                    .as_f64()
+                    .is_some_and(|i| i >= 0.0 && (i as usize) < items.len()))
+            }
+            _ => anyhow::bail!(
+                "Cannot check whether {} has a {} key",
+                type_name(input),
+                type_name(arg)
+            ),
+        },
+        "contains" => json!(contains(input, arg)?),
+        "startswith" => json!(expect_str(input, name)?.starts_with(expect_str(arg, name)?)),
+        "endswith" => json!(expect_str(input, name)?.ends_with(expect_str(arg, name)?)),
+        "test" => {
+            let pattern = expect_str(arg, name)?;
+            let regex = Regex::new(pattern)
+                .map_err(|e| anyhow::anyhow!("Invalid regex '{}': {}", pattern, e))?;
+            json!(regex.is_match(expect_str(input, name)?))
+        }
+        "split" => json!(expect_str(input, name)?
+            .split(expect_str(arg, name)?)
+            .collect::<Vec<_>>()),
+        "join" => {
+            let sep = expect_str(arg, name)?;
+            let parts = expect_array(input, name)?
+                .iter()
+                .map(|v| match v {
+                    Value::Null => Ok(String::new()),
+                    Value::Array(_) | Value::Object(_) => {
+                        anyhow::bail!("Cannot join {}", type_name(v))
+                    }
+                    _ => Ok(to_text(v)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            json!(parts.join(sep))
+        }
+        _ => anyhow::bail!("Unknown function '{}/1'", name),
+    })
+}
+
+/// Pair each array element with the first output of `f` (as jq's `[f]` key)
+fn keyed_items(input: &Value, f: &Expr, name: &str) -> Result<Vec<(Value, Value)>> {
+    expect_array(input, name)?
+        .iter()
+        .map(|item| Ok((Value::Array(eval(f, item)?), item.clone())))
+        .collect()
+}
+
+fn index(target: &Value, key: &Value) -> Result<Value> {
+    match (target, key) {
+        (Value::Null, Value::String(_) | Value::Number(_)) => Ok(Value::Null),
+        (Value::Object(map), Value::String(k)) => Ok(map.get(k).cloned().unwrap_or(Value::Null)),
+        (Value::Array(items), Value::Number(n)) => {
+            let i = n.as_f64().unwrap_or(0.0).floor() as i64;
+            let i = if i < 0 { items.len() as i64 + i } else { i };
+            Ok(usize::try_from(i)
+                .ok()
+                .and_then(|i| items.get(i))
+                .cloned()
+                .unwrap_or(Value::Null))
+        }
+        _ => anyhow::bail!(
+            "Cannot index {} with {}",
+            type_name(target),
+            match key {
+                Value::String(s) => format!("\"{}\"", s),
+                other => type_name(other).to_string(),
+            }
+        ),
+    }
+}
+
+fn slice(target: &Value, from: Option<&Value>, to: Option<&Value>) -> Result<Value> {
+    let bounds = |len: usize| -> Result<(usize, usize)> {
+        let resolve = |v: Option<&Value>, default: usize| -> Result<usize> {
+            match v {
+                None | Some(Value::Null) => Ok(default),
+                Some(Value::Number(n)) => {
+                    let i = n.as_f64().unwrap_or(0.0).floor() as i64;
+                    let i = if i < 0 { len as i64 + i } else { i };
+                    Ok(i.clamp(0, len as i64) as usize)
+                }
+                Some(other) => {
+                    anyhow::bail!("Slice bounds must be numbers, not {}", type_name(other))
+                }
+            }
+        };
+        let start = resolve(from, 0)?;
+        let end = resolve(to, len)?;
+        Ok((start, end.max(start)))
+    };
+    match target {
+        Value::Null => Ok(Value::Null),
+        Value::Array(items) => {
+            let (start, end) = bounds(items.len())?;
+            Ok(Value::Array(items[start..end].to_vec()))
+        }
+        Value::String(s) => {
+            let chars: Vec<char> = s.chars().collect();
+            let (start, end) = bounds(chars.len())?;
+            Ok(json!(chars[start..end].iter().collect::<String>()))
+        }
+        _ => anyhow::bail!("Cannot slice {}", type_name(target)),
+    }
+}
+
+fn iterate(value: &Value) -> Result<Vec<Value>> {
+    match value {
+        Value::Array(items) => Ok(items.clone()),
+        Value::Object(map) => Ok(map.values().cloned().collect()),
+        _ => anyhow::bail!("Cannot iterate over {}", type_name(value)),
+    }
+}
+
+fn recurse(value: &Value, out: &mut Vec<Value>) {
+    out.push(value.clone());
+    match value {
+        Value::Array(items) => items.iter().for_each(|v| recurse(v, out)),
+        Value::Object(map) => map.values().for_each(|v| recurse(v, out)),
+        _ => {}
+    }
+}
+
+fn flatten(items: &[Value], out: &mut Vec<Value>) {
+    for item in items {
+        match item {
+            Value::Array(inner) => flatten(inner, out),
+            other => out.push(other.clone()),
+        }
+    }
+}
+
+fn to_entries(value: &Value) -> Result<Vec<Value>> {
+    match value {
+        Value::Object(map) => Ok(map
+            .iter()
+            .map(|(k, v)| json!({ "key": k, "value": v }))
+            .collect()),
+        _ => anyhow::bail!("{} has no entries", type_name(value)),
+    }
+}
+
+fn from_entries(value: &Value) -> Result<Value> {
+    let mut out = Map::new();
+    for entry in expect_array(value, "from_entries")? {
+        let key = ["key", "k", "name", "Name", "Key", "K"]
+            .iter()
+            .find_map(|k| entry.get(*k).filter(|v| !v.is_null()))
+            .ok_or_else(|| anyhow::anyhow!("from_entries: entry has no key"))?;
+        let value = ["value", "v", "Value", "V"]
+            .iter()
+            .find_map(|k| entry.get(*k))
+            .cloned()
+            .unwrap_or(Value::Null);
+        out.insert(to_text(key), value);
+    }
+    Ok(Value::Object(out))
+}
+
+fn contains(haystack: &Value, needle: &Value) -> Result<bool> {
+    Ok(match (haystack, needle) {
+        (Value::String(a), Value::String(b)) => a.contains(b.as_str()),
+        (Value::Array(a), Value::Array(b)) => b
+            .iter()
+            .all(|n| a.iter().any(|h| contains(h, n).unwrap_or(false))),
+        (Value::Object(a), Value::Object(b)) => b
+            .iter()
+            .all(|(k, n)| a.get(k).is_some_and(|h| contains(h, n).unwrap_or(false))),
+        (a, b) if std::mem::discriminant(a) == std::mem::discriminant(b) => {
+            compare(a, b) == Ordering::Equal
+        }
+        _ => anyhow::bail!(
+            "{} cannot contain {}",
+            type_name(haystack),
+            type_name(needle)
+        ),
+    })
+}
+
+fn binary(op: BinOp, lhs: &Value, rhs: &Value) -> Result<Value> {
+    let ord = || compare(lhs, rhs);
+    Ok(match op {
+        BinOp::Eq => Value::Bool(ord() == Ordering::Equal),
+        BinOp::Ne => Value::Bool(ord() != Ordering::Equal),
+        BinOp::Lt => Value::Bool(ord() == Ordering::Less),
+        BinOp::Le => Value::Bool(ord() != Ordering::Greater),
+        BinOp::Gt => Value::Bool(ord() == Ordering::Greater),
+        BinOp::Ge => Value::Bool(ord() != Ordering::Less),
+        BinOp::Add => match (lhs, rhs) {
+            (Value::Null, other) | (other, Value::Null) => other.clone(),
+            (Value::Number(a), Value::Number(b)) => {
+                number(a.as_f64().unwrap_or(0.0) + b.as_f64().unwrap_or(0.0))
+            }
+            (Value::String(a), Value::String(b)) => json!(format!("{}{}", a, b)),
+            (Value::Array(a), Value::Array(b)) => {
+                Value::Array(a.iter().chain(b.iter()).cloned().collect())
+            }
+            (Value::Object(a), Value::Object(b)) => {
+                let mut merged = a.clone();
+                merged.extend(b.iter().map(|(k, v)| (k.clone(), v.clone())));
+                Value::Object(merged)
+            }
+            _ => return arith_error("added", lhs, rhs),
+        },
+        BinOp::Sub => match (lhs, rhs) {
+            (Value::Number(a), Value::Number(b)) => {
+                number(a.as_f64().unwrap_or(0.0) - b.as_f64().unwrap_or(0.0))
+            }
+            (Value::Array(a), Value::Array(b)) => Value::Array(
+                a.iter()
+                    .filter(|v| !b.iter().any(|r| compare(v, r) == Ordering::Equal))
+                    .cloned()
+                    .collect(),
+            ),
+            _ => return arith_error("subtracted", lhs, rhs),
+        },
+        BinOp::Mul => match (lhs, rhs) {
+            (Value::Number(a), Value::Number(b)) => {
+                number(a.as_f64().unwrap_or(0.0) * b.as_f64().unwrap_or(0.0))
+            }
+            _ => return arith_error("multiplied", lhs, rhs),
+        },
+        BinOp::Div => match (lhs, rhs) {
+            (Value::Number(a), Value::Number(b)) => {
+                let divisor = b.as_f64().unwrap_or(0.0);
+                if divisor == 0.0 {
+                    anyhow::bail!(
+                        "{} and {} cannot be divided because the divisor is zero",
+                        a,
+                        b
+                    );
+                }
+                number(a.as_f64().unwrap_or(0.0) / divisor)
+            }
+            (Value::String(a), Value::String(b)) => json!(a.split(b.as_str()).collect::<Vec<_>>()),
+            _ => return arith_error("divided", lhs, rhs),
+        },
+        BinOp::Mod => match (lhs, rhs) {
+            (Value::Number(a), Value::Number(b)) => {
+                let divisor = b.as_f64().unwrap_or(0.0) as i64;
+                if divisor == 0 {
+                    anyhow::bail!(
+                        "{} and {} cannot be divided because the divisor is zero",
+                        a,
+                        b
+                    );
+                }
+                json!((a.as_f64().unwrap_or(0.0) as i64) % divisor)
+            }
+            _ => return arith_error("divided", lhs, rhs),
+        },
+    })
+}
+
+fn arith_error(verb: &str, lhs: &Value, rhs: &Value) -> Result<Value> {
+    anyhow::bail!(
+        "{} and {} cannot be {}",
+        type_name(lhs),
+        type_name(rhs),
+        verb
+    )
+}
+
+/// jq's total order: null < false < true < numbers < strings < arrays < objects
+fn compare(a: &Value, b: &Value) -> Ordering {
+    fn rank(v: &Value) -> u8 {
+        match v {
+            Value::Null => 0,
+            Value::Bool(false) => 1,
+            Value::Bool(true) => 2,
+            Value::Number(_) => 3,
+            Value::String(_) => 4,
+            Value::Array(_) => 5,
+            Value::Object(_) => 6,
+        }
+    }
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x
+            .as_f64()
+            .partial_cmp(&y.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        (Value::Array(x), Value::Array(y)) => x
+            .iter()
+            .zip(y.iter())
+            .map(|(l, r)| compare(l, r))
+            .find(|o| *o != Ordering::Equal)
+            .unwrap_or_else(|| x.len().cmp(&y.len())),
+        (Value::Object(x), Value::Object(y)) => {
+            let keys_x: Vec<&String> = x.keys().collect();
+            let keys_y: Vec<&String> = y.keys().collect();
+            keys_x.cmp(&keys_y).then_with(|| {
+                x.values()
+                    .zip(y.values())
+                    .map(|(l, r)| compare(l, r))
+                    .find(|o| *o != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            })
+        }
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+fn truthy(value: &Value) -> bool {
+    !matches!(value, Value::Null | Value::Bool(false))
+}
+
+/// Whole numbers stay integers so `1 + 1` prints `2`, not `2.0`
+fn number(n: f64) -> Value {
+    if n.fract() == 0.0 && n.abs() < 9.007_199_254_740_992e15 {
+        json!(n as i64)
+    } else {
+        json!(n)
+    }
+}
+
+/// Strings as-is, everything else as compact JSON
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn expect_str<'a>(value: &'a Value, name: &str) -> Result<&'a str> {
+    value
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("{} expects a string, got {}", name, type_name(value)))
+}
+
+fn expect_array<'a>(value: &'a Value, name: &str) -> Result<&'a Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("{} expects an array, got {}", name, type_name(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(expr: &str, input: Value) -> Vec<Value> {
+        Filter::parse(expr).unwrap().run(&input).unwrap()
+    }
+
+    fn issues() -> Value {
+        json!([
+            {"identifier": "ENG-1", "priority": 2, "estimate": 3, "state": {"name": "Todo"},
+             "labels": {"nodes": [{"name": "bug"}]}},
+            {"identifier": "ENG-2", "priority": 1, "estimate": null, "state": {"name": "Done"},
+             "labels": {"nodes": []}},
+            {"identifier": "ENG-3", "priority": 3, "estimate": 5, "state": {"name": "Todo"},
+             "labels": {"nodes": [{"name": "ui"}, {"name": "bug"}]}}
+        ])
+    }
+
+    #[test]
+    fn test_paths() {
+        let input = json!({"a": {"b": [10, 20, 30]}, "key with space": 1});
+        assert_eq!(run(".a.b[1]", input.clone()), vec![json!(20)]);
+        assert_eq!(run(".a.b[-1]", input.clone()), vec![json!(30)]);
+        assert_eq!(
+            run(".a.b[]", input.clone()),
+            vec![json!(10), json!(20), json!(30)]
+        );
+        assert_eq!(run(".a.b[1:]", input.clone()), vec![json!([20, 30])]);
+        assert_eq!(run(".[\"key with space\"]", input.clone()), vec![json!(1)]);
+        assert_eq!(run(".\"key with space\"", input.clone()), vec![json!(1)]);
+        assert_eq!(run(".missing.deeper", input.clone()), vec![Value::Null]);
+        assert_eq!(run(".", json!(5)), vec![json!(5)]);
+    }
+
+    #[test]
+    fn test_pipe_map_select() {
+        assert_eq!(
+            run("map(select(.priority <= 2)) | map(.identifier)", issues()),
+            vec![json!(["ENG-1", "ENG-2"])]
+        );
+        assert_eq!(
+            run(
+                ".[] | select(.labels.nodes[].name == \"bug\") | .identifier",
+                issues()
+            ),
+            vec![json!("ENG-1"), json!("ENG-3")]
+        );
+    }
+
+    #[test]
+    fn test_object_construction() {
+        assert_eq!(
+            run(
+                ".[0] | {id: .identifier, state: .state.name, priority}",
+                issues()
+            ),
+            vec![json!({"id": "ENG-1", "state": "Todo", "priority": 2})]
+        );
+        assert_eq!(
+            run("{(.[0].identifier): .[0].priority}", issues()),
+            vec![json!({"ENG-1": 2})]
+        );
+    }
+
+    #[test]
+    fn test_group_by_and_length() {
+        assert_eq!(
+            run(
+                "group_by(.state.name) | map({state: .[0].state.name, count: length})",
+                issues()
+            ),
+            vec![json!([{"state": "Done", "count": 1}, {"state": "Todo", "count": 2}])]
+        );
+        assert_eq!(run("length", issues()), vec![json!(3)]);
+        assert_eq!(run(".[0].identifier | length", issues()), vec![json!(5)]);
+    }
+
+    #[test]
+    fn test_aggregation_and_alternative() {
+        assert_eq!(run("map(.estimate // 0) | add", issues()), vec![json!(8)]);
+        assert_eq!(
+            run("map(.priority) | add / length", issues()),
+            vec![json!(2)]
+        );
+        assert_eq!(
+            run("sort_by(.priority) | map(.identifier) | first", issues()),
+            vec![json!("ENG-2")]
+        );
+        assert_eq!(
+            run("max_by(.priority).identifier", issues()),
+            vec![json!("ENG-3")]
+        );
+        assert_eq!(
+            run("[.[].state.name] | unique", issues()),
+            vec![json!(["Done", "Todo"])]
+        );
+    }
+
+    #[test]
+    fn test_strings_and_conditionals() {
+        assert_eq!(
+            run(".[0] | \"\\(.identifier): \\(.priority)\"", issues()),
+            vec![json!("ENG-1: 2")]
+        );
+        assert_eq!(
+            run(
+                ".[] | if .priority == 1 then \"urgent\" elif .priority == 2 then \"high\" else \"other\" end",
+                issues()
+            ),
+            vec![json!("high"), json!("urgent"), json!("other")]
+        );
+        assert_eq!(
+            run("map(.identifier) | join(\",\")", issues()),
+            vec![json!("ENG-1,ENG-2,ENG-3")]
+        );
+        assert_eq!(
+            run(
+                ".[] | select(.identifier | test(\"-[13]$\")) | .identifier",
+                issues()
+            ),
+            vec![json!("ENG-1"), json!("ENG-3")]
+        );
+    }
+
+    #[test]
+    fn test_comma_and_entries() {
+        assert_eq!(
+            run(".a, .b", json!({"a": 1, "b": 2})),
+            vec![json!(1), json!(2)]
+        );
+        assert_eq!(
+            run(
+                "with_entries(select(.value > 1)) | keys",
+                json!({"a": 1, "b": 2, "c": 3})
+            ),
+            vec![json!(["b", "c"])]
+        );
+        assert_eq!(
+            run("[.[] | has(\"estimate\")] | all", issues()),
+            vec![json!(true)]
+        );
+    }
+
+    #[test]
+    fn test_optional_and_errors() {
+        assert_eq!(run(".[]?", json!(1)), Vec::<Value>::new());
+        assert_eq!(run(".a?", json!([1])), Vec::<Value>::new());
+        let err = Filter::parse(".a")
+            .unwrap()
+            .run(&json!([1]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Cannot index array with \"a\""), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Filter::parse("").is_err());
+        assert!(Filter::parse(".a |").is_err());
+        assert!(Filter::parse("map(.a").is_err());
+        assert!(Filter::parse(".a = 1").is_err());
+        assert!(Filter::parse(". as $x | $x").is_err());
+        let err = Filter::parse("nope(.a)").unwrap_err().to_string();
+        assert!(err.contains("Unknown function 'nope/1'"), "{}", err);
+    }
+}
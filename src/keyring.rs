@@ -120,6 +120,38 @@ pub fn delete_oauth_tokens(profile: &str) -> Result<()> {
     }
 }
 
+const WEBHOOK_SERVICE_NAME: &str = "linear-cli-webhook";
+
+fn webhook_entry(profile: &str, webhook_id: &str) -> Result<keyring::Entry> {
+    keyring::Entry::new(WEBHOOK_SERVICE_NAME, &format!("{}:{}", profile, webhook_id))
+        .context("Failed to create keyring entry")
+}
+
+/// Get a webhook signing secret from keyring for a profile
+pub fn get_webhook_secret(profile: &str, webhook_id: &str) -> Result<Option<String>> {
+    match webhook_entry(profile, webhook_id)?.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) | Err(keyring::Error::NoStorageAccess(_)) => Ok(None),
+        Err(e) => {
+            if !crate::output::is_quiet() {
+                eprintln!(
+                    "Warning: Keyring webhook secret error ({}), falling back to config",
+                    e
+                );
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Store a webhook signing secret in keyring for a profile
+pub fn set_webhook_secret(profile: &str, webhook_id: &str, secret: &str) -> Result<()> {
+    webhook_entry(profile, webhook_id)?
+        .set_password(secret)
+        .context("Failed to store webhook secret in keyring")?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,37 +1,52 @@
+mod aliases;
 mod api;
 mod cache;
 mod commands;
 mod config;
 mod dates;
 mod error;
+mod fanout;
+mod graphql;
 mod input;
+mod jq;
 mod json_path;
 #[cfg(feature = "secure-storage")]
 mod keyring;
+mod mirror;
 mod oauth;
+mod outbox;
 mod output;
 mod pagination;
 mod priority;
+mod rate_limit;
+mod repo_config;
 mod retry;
+mod schema;
+mod template;
 mod text;
+mod trace;
 #[allow(dead_code)]
 mod types;
 mod vcs;
+mod webhook_delivery;
+mod where_expr;
 
-use anyhow::Result;
-use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use anyhow::{Context, Result};
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 use clap_complete::{generate, Shell};
 use commands::{
-    attachments, auth, bulk, comments, cycles, doctor, documents, export, favorites, git, history,
-    import, initiatives, interactive, issues, labels, metrics, notifications, project_updates,
-    projects, relations, roadmaps, search, sprint, statuses, sync, teams, templates, time, triage,
-    update, uploads, users, views, watch, webhooks,
+    attachments, auth, bulk, comments, cycles, dev, doctor, documents, export, favorites, git,
+    history, import, initiatives, interactive, issues, labels, metrics, notifications,
+    project_updates, projects, relations, roadmaps, search, sprint, statuses, sync, teams,
+    templates, time, triage, update, uploads, users, views, watch, webhooks,
 };
 use error::CliError;
 use output::print_json_owned;
-use output::{parse_filters, JsonOutputOptions, OutputOptions, SortOrder};
+use output::{parse_any_filters, parse_filters, JsonOutputOptions, OutputOptions, SortOrder};
 use pagination::PaginationOptions;
 use std::io::IsTerminal;
+use std::path::PathBuf;
 use std::sync::OnceLock;
 
 /// Output format for command results
@@ -99,8 +114,11 @@ COMMON FLAGS:
     --width N                     Max table column width
     --no-truncate                 Disable table truncation
     --quiet                       Reduce decorative output
-    --format TEMPLATE             Template output (e.g. '{{identifier}} {{title}}')
-    --filter field=value          Filter results (=, !=, ~= operators; dot paths; case-insensitive)
+    --format TEMPLATE             Template output (e.g. '{{identifier}} {{title | truncate 40}}')
+    --format-file PATH            Render the whole result with a template file
+    --filter field=value          Filter results (=, !=, ~=, =~, <, <=, >, >=, in, exists; dot paths)
+    --filter-any field=value      Keep results matching any of these filters (OR)
+    --jq EXPR                     Transform JSON output with a jq expression (implies --output json)
     --limit N                     Limit list/search results
     --page-size N                 Page size for list/search
     --after CURSOR                Pagination cursor (after)
@@ -170,21 +188,51 @@ struct Cli {
     #[arg(long, global = true, value_enum, default_value = "asc")]
     order: SortOrder,
 
-    /// Override workspace profile for this invocation
+    /// Override workspace profile for this invocation.
+    /// A comma-separated list (a,b,c) queries several workspaces at once
+    /// (issues list, notifications list, search issues, users me).
     #[arg(long, global = true, env = "LINEAR_CLI_PROFILE")]
     profile: Option<String>,
 
-    /// Output using a template (e.g. '{{identifier}} {{title}}')
+    /// Query every configured workspace profile at once (read commands only)
+    #[arg(long, global = true, conflicts_with = "profile")]
+    all_profiles: bool,
+
+    /// Output using a template, rendered once per list item (e.g. '{{identifier}} {{title}}').
+    /// Supports {{#if}}, {{#unless}}, {{#each}}, {{#with}}, {{else}} and helpers such as
+    /// {{title | truncate 40}}, {{dueDate | date "%b %d"}}, {{priority | priority_name}},
+    /// {{assignee.name | default "-"}} and {{labels.nodes.name | join ", "}}.
     #[arg(long, global = true)]
     format: Option<String>,
 
-    /// Filter results (field=value, field!=value, field~=value).
-    /// Supports dot-notation for nested fields (e.g. state.name=Done).
+    /// Output using a template file, rendered once against the whole result
+    /// (loop over list items with {{#each .}}); same syntax as --format
+    #[arg(long, global = true, value_name = "PATH", conflicts_with = "format")]
+    format_file: Option<PathBuf>,
+
+    /// Filter results (field=value, field!=value, field~=value, field=~regex,
+    /// field<value, field<=value, field>value, field>=value, 'field in a,b',
+    /// 'field not in a,b', 'field exists', 'field not exists').
+    /// Supports dot-notation for nested fields (e.g. state.name=Done); paths step
+    /// into arrays, so labels.nodes.name=bug matches any label.
     /// ~= is a case-insensitive "contains" match. All comparisons are case-insensitive.
+    /// <, <=, >, >= compare numbers numerically and dates chronologically
+    /// (ISO dates or shorthands like today and -7d).
     /// Multiple --filter flags are combined with AND logic.
     #[arg(long, global = true)]
     filter: Vec<String>,
 
+    /// Keep results matching at least one of these filters (OR); same syntax as --filter
+    #[arg(long, global = true)]
+    filter_any: Vec<String>,
+
+    /// Transform JSON output with a jq expression, applied after --filter/--sort/--fields.
+    /// Supports paths, |, map, select, group_by, sort_by, length, add, object and array
+    /// construction and string interpolation. String results print without quotes.
+    /// Implies --output json when no output format is given.
+    #[arg(long, global = true, value_name = "EXPR")]
+    jq: Option<String>,
+
     /// Exit with non-zero status when a list is empty
     #[arg(long, global = true)]
     fail_on_empty: bool,
@@ -202,7 +250,7 @@ struct Cli {
     before: Option<String>,
 
     /// Page size per request for list/search commands
-    #[arg(long, global = true)]
+    #[arg(long, global = true, env = "LINEAR_CLI_PAGE_SIZE")]
     page_size: Option<usize>,
 
     /// Fetch all pages for list/search commands
@@ -217,6 +265,14 @@ struct Cli {
     #[arg(long, global = true, env = "LINEAR_CLI_NO_CACHE")]
     no_cache: bool,
 
+    /// Serve expired lookup caches immediately and refresh them in the background
+    #[arg(long, global = true, env = "LINEAR_CLI_STALE_WHILE_REVALIDATE")]
+    stale_while_revalidate: bool,
+
+    /// Read from the local mirror instead of the API (see `mirror pull`)
+    #[arg(long, global = true, env = "LINEAR_CLI_OFFLINE")]
+    offline: bool,
+
     /// Preview without making changes where supported
     #[arg(long, global = true)]
     dry_run: bool,
@@ -226,9 +282,17 @@ struct Cli {
     yes: bool,
 
     /// Number of retries for failed API requests (with exponential backoff)
-    #[arg(long, global = true, default_value = "0")]
+    #[arg(long, global = true, env = "LINEAR_CLI_RETRY", default_value = "0")]
     retry: u32,
 
+    /// Log every API request (operation, variables, timing, status) to stderr
+    #[arg(long, global = true)]
+    debug: bool,
+
+    /// Append a redacted JSON trace of every API request to FILE ("stderr" or "1" for stderr)
+    #[arg(long, global = true, env = "LINEAR_CLI_TRACE", value_name = "FILE")]
+    trace: Option<String>,
+
     /// Print JSON schema version info and exit
     #[arg(long, global = true)]
     schema: bool,
@@ -325,7 +389,8 @@ enum Commands {
     #[command(after_help = r#"EXAMPLES:
     linear api query '{ viewer { id name } }'
     linear api query -v teamId=abc '...'     # With variables
-    linear api mutate -v title=Bug '...'     # Run mutations"#)]
+    linear api mutate -v title=Bug '...'     # Run mutations
+    linear api rate-limit                    # Remaining request/complexity budget"#)]
     Api {
         #[command(subcommand)]
         action: commands::api::ApiCommands,
@@ -474,16 +539,46 @@ enum Commands {
         #[command(subcommand)]
         action: bulk::BulkCommands,
     },
-    /// Manage cache - clear cached data or view status
+    /// Manage cache - clear, warm or view status of cached data
     #[command(alias = "ca")]
     #[command(after_help = r#"EXAMPLES:
     linear cache status                     # Show cache status
     linear ca clear                         # Clear all cache
-    linear ca clear --type teams            # Clear only teams cache"#)]
+    linear ca clear --type teams            # Clear only teams cache
+    linear cache warm --team ENG            # Pre-populate lookup caches"#)]
     Cache {
         #[command(subcommand)]
         action: commands::cache::CacheCommands,
     },
+    /// Developer tools - local mock GraphQL server for offline testing
+    #[command(after_help = r#"EXAMPLES:
+    linear dev mock-server                           # Serve tests/fixtures on :4000
+    linear dev mock-server --fixtures ./fx --port 0  # Pick a free port
+    LINEAR_API_URL=http://127.0.0.1:4000/graphql linear issues list"#)]
+    Dev {
+        #[command(subcommand)]
+        action: dev::DevCommands,
+    },
+    /// Offline mirror - sync issues, comments and projects for use with --offline
+    #[command(after_help = r#"EXAMPLES:
+    linear mirror pull                      # Sync changes since the last pull
+    linear mirror status                    # Show local record counts
+    linear --offline issues list -t ENG     # Query the mirror without network
+    linear mirror clear                     # Delete local data"#)]
+    Mirror {
+        #[command(subcommand)]
+        action: commands::mirror::MirrorCommands,
+    },
+    /// Outbox - changes queued while offline, replayed with conflict detection
+    #[command(after_help = r#"EXAMPLES:
+    linear --offline issues update ENG-1 -s Done   # Queue instead of sending
+    linear outbox list                      # Show queued changes
+    linear outbox push                      # Send them, reporting conflicts
+    linear outbox drop 3                    # Discard one queued change"#)]
+    Outbox {
+        #[command(subcommand)]
+        action: commands::outbox::OutboxCommands,
+    },
     /// Manage notifications - view and mark as read
     #[command(alias = "n")]
     #[command(after_help = r#"EXAMPLES:
@@ -530,12 +625,22 @@ enum Commands {
     #[command(after_help = r#"EXAMPLES:
     linear interactive                      # Launch interactive mode
     linear interactive --team ENG           # Preselect team
-
-Use arrow keys to navigate, Enter to select, q to quit."#)]
+    linear interactive --refresh 0          # Disable auto-refresh
+
+KEYS:
+    j/k, arrows     Move selection          /   Filter the list
+    s / a / p       Change status, assignee, priority
+    c               Create issue            t   Switch team
+    x               Show/hide completed     r   Refresh now
+    o               Open in browser         ?   Help
+    q, Esc          Quit"#)]
     Interactive {
         /// Preselect team by key, name, or ID
-        #[arg(short, long)]
+        #[arg(short, long, env = "LINEAR_CLI_TEAM")]
         team: Option<String>,
+        /// Auto-refresh interval in seconds (0 disables)
+        #[arg(long, default_value = "60", value_name = "SECS")]
+        refresh: u64,
     },
     /// Detect current Linear issue from git branch - for AI agents
     #[command(alias = "ctx")]
@@ -672,7 +777,35 @@ Detects issue ID from branch names like:
     linear watch issue LIN-123             # Watch single issue
     linear watch issue LIN-123 --interval 30  # Poll every 30 seconds
     linear watch project PROJECT_ID        # Watch a project
-    linear watch team ENG                  # Watch a team"#)]
+    linear watch team ENG                  # Watch a team
+    linear watch issue LIN-123 --listen 9000 --url https://my-tunnel.ngrok.io
+    linear watch team ENG --relay http://relay.internal:8080/events -o ndjson
+
+    linear watch issue LIN-123 --only-fields state,assignee,comments
+
+By default watch polls. With --listen it registers a temporary webhook and
+prints each change as it arrives; --relay reads webhook events from an HTTP
+stream (NDJSON or server-sent events).
+
+Every event lists the fields that changed (old → new), label additions and
+removals, and new comments. --only-fields limits which changed fields are
+reported:
+  issue:   title state assignee priority estimate dueDate project cycle
+           labels description comments
+  project: name state progress lead targetDate
+  team:    name activeCycle cycleProgress, plus the issue fields
+
+--exec runs a shell command for each change with the event JSON on stdin and
+LINEAR_EVENT, LINEAR_CHANGED_FIELDS, LINEAR_ISSUE_IDENTIFIER, LINEAR_ISSUE_TITLE,
+LINEAR_ISSUE_STATE, LINEAR_ISSUE_URL, LINEAR_FROM_<FIELD>/LINEAR_TO_<FIELD> set.
+--on limits it to matching changes (all conditions must hold):
+    --on state              state changed
+    --on state=Done         state changed to Done
+    --on labels=bug         label "bug" added
+    --on event=created      issue created (team watch)
+
+    linear watch team ENG --on state=Done --exec 'notify-send "$LINEAR_ISSUE_IDENTIFIER done"'
+    linear watch issue LIN-123 --on comments --exec ./post-to-chat.sh"#)]
     Watch {
         #[command(subcommand)]
         action: WatchCommands,
@@ -739,7 +872,7 @@ Walks you through:
     /// Internal: provide dynamic completion values (hidden from help)
     #[command(name = "_complete", hide = true)]
     Complete {
-        /// What to complete: teams, projects, issues, statuses, users, labels
+        /// What to complete: teams, projects, issues, statuses, users, labels, schema
         #[arg(long = "type")]
         type_: String,
         /// Partial input to filter
@@ -771,13 +904,31 @@ enum ConfigCommands {
     SetKey,
     /// Get a configuration value
     Get {
-        /// Config key to retrieve (api-key, profile)
+        /// Config key to retrieve (api-key, profile, api-url, api-key-command, or a profile default)
         key: ConfigGetKey,
         /// Output raw value without masking
         #[arg(long)]
         raw: bool,
     },
-    /// Set a configuration value
+    /// Set a configuration value or a default for the current profile
+    #[command(after_help = r#"EXAMPLES:
+    linear config set default-team ENG        # Used wherever -t/--team is required
+    linear config set default-project "Q3 Launch"
+    linear config set default-output json
+    linear config set default-mine true       # issues list shows only your issues
+    linear config set page-size 100
+    linear config set cache-ttl 600
+    linear config set retry 3
+    linear config set stale-while-revalidate true
+    linear config set default-team ""         # Clear a default
+    linear config set api-key-command "pass show linear/api-key"
+    linear config set api-key-command "op read op://Private/Linear/credential"
+
+Defaults belong to the current profile (or --profile). Explicit flags and
+LINEAR_CLI_* environment variables always take precedence.
+
+api-key-command runs once per invocation and its first line of output is used
+as the API key. LINEAR_API_KEY still overrides it."#)]
     Set {
         /// Config key to set
         key: ConfigSetKey,
@@ -825,6 +976,31 @@ enum ConfigCommands {
         /// Workspace name to remove
         name: String,
     },
+    /// Define, show, or remove a command alias
+    #[command(after_help = r#"EXAMPLES:
+    linear config alias standup "i list --mine --since 1d"
+    linear config alias st "i list --state '{1}'"   # linear st Todo
+    linear config alias standup                     # Show an alias
+    linear config alias standup --remove
+
+Macros run several commands in order and are defined in the config file:
+
+    [macros]
+    ship = ["i update {1} -s Done", "g pr {1}"]
+
+{1}, {2}, ... are replaced by arguments after the alias name and {@} by the
+rest. Built-in commands always take precedence over aliases."#)]
+    Alias {
+        /// Alias name
+        name: String,
+        /// Command the alias expands to
+        expansion: Option<String>,
+        /// Remove the alias or macro
+        #[arg(long, conflicts_with = "expansion")]
+        remove: bool,
+    },
+    /// List command aliases and macros
+    Aliases,
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -832,6 +1008,25 @@ enum ConfigGetKey {
     #[value(alias = "api_key")]
     ApiKey,
     Profile,
+    #[value(alias = "api_url")]
+    ApiUrl,
+    #[value(alias = "api_key_command")]
+    ApiKeyCommand,
+    #[value(alias = "default_team")]
+    DefaultTeam,
+    #[value(alias = "default_project")]
+    DefaultProject,
+    #[value(alias = "default_output")]
+    DefaultOutput,
+    #[value(alias = "default_mine")]
+    DefaultMine,
+    #[value(alias = "page_size")]
+    PageSize,
+    #[value(alias = "cache_ttl")]
+    CacheTtl,
+    Retry,
+    #[value(alias = "stale_while_revalidate")]
+    StaleWhileRevalidate,
 }
 
 impl std::fmt::Display for ConfigGetKey {
@@ -839,6 +1034,16 @@ impl std::fmt::Display for ConfigGetKey {
         match self {
             Self::ApiKey => write!(f, "api-key"),
             Self::Profile => write!(f, "profile"),
+            Self::ApiUrl => write!(f, "api-url"),
+            Self::ApiKeyCommand => write!(f, "api-key-command"),
+            Self::DefaultTeam => write!(f, "default-team"),
+            Self::DefaultProject => write!(f, "default-project"),
+            Self::DefaultOutput => write!(f, "default-output"),
+            Self::DefaultMine => write!(f, "default-mine"),
+            Self::PageSize => write!(f, "page-size"),
+            Self::CacheTtl => write!(f, "cache-ttl"),
+            Self::Retry => write!(f, "retry"),
+            Self::StaleWhileRevalidate => write!(f, "stale-while-revalidate"),
         }
     }
 }
@@ -846,12 +1051,41 @@ impl std::fmt::Display for ConfigGetKey {
 #[derive(clap::ValueEnum, Clone, Debug)]
 enum ConfigSetKey {
     Profile,
+    #[value(alias = "api_url")]
+    ApiUrl,
+    #[value(alias = "api_key_command")]
+    ApiKeyCommand,
+    #[value(alias = "default_team")]
+    DefaultTeam,
+    #[value(alias = "default_project")]
+    DefaultProject,
+    #[value(alias = "default_output")]
+    DefaultOutput,
+    #[value(alias = "default_mine")]
+    DefaultMine,
+    #[value(alias = "page_size")]
+    PageSize,
+    #[value(alias = "cache_ttl")]
+    CacheTtl,
+    Retry,
+    #[value(alias = "stale_while_revalidate")]
+    StaleWhileRevalidate,
 }
 
 impl std::fmt::Display for ConfigSetKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Profile => write!(f, "profile"),
+            Self::ApiUrl => write!(f, "api-url"),
+            Self::ApiKeyCommand => write!(f, "api-key-command"),
+            Self::DefaultTeam => write!(f, "default-team"),
+            Self::DefaultProject => write!(f, "default-project"),
+            Self::DefaultOutput => write!(f, "default-output"),
+            Self::DefaultMine => write!(f, "default-mine"),
+            Self::PageSize => write!(f, "page-size"),
+            Self::CacheTtl => write!(f, "cache-ttl"),
+            Self::Retry => write!(f, "retry"),
+            Self::StaleWhileRevalidate => write!(f, "stale-while-revalidate"),
         }
     }
 }
@@ -865,6 +1099,13 @@ enum WatchCommands {
         /// Polling interval in seconds
         #[arg(short, long, default_value = "10")]
         interval: u64,
+        /// Only report changes to these fields (comma-separated)
+        #[arg(long, value_name = "FIELDS", value_delimiter = ',')]
+        only_fields: Vec<String>,
+        #[command(flatten)]
+        stream: watch::StreamArgs,
+        #[command(flatten)]
+        hooks: watch::HookArgs,
     },
     /// Watch a project for updates
     Project {
@@ -873,6 +1114,13 @@ enum WatchCommands {
         /// Polling interval in seconds
         #[arg(short, long, default_value = "10")]
         interval: u64,
+        /// Only report changes to these fields (comma-separated)
+        #[arg(long, value_name = "FIELDS", value_delimiter = ',')]
+        only_fields: Vec<String>,
+        #[command(flatten)]
+        stream: watch::StreamArgs,
+        #[command(flatten)]
+        hooks: watch::HookArgs,
     },
     /// Watch a team for updates
     Team {
@@ -881,6 +1129,13 @@ enum WatchCommands {
         /// Polling interval in seconds
         #[arg(short, long, default_value = "10")]
         interval: u64,
+        /// Only report changes to these fields (comma-separated)
+        #[arg(long, value_name = "FIELDS", value_delimiter = ',')]
+        only_fields: Vec<String>,
+        #[command(flatten)]
+        stream: watch::StreamArgs,
+        #[command(flatten)]
+        hooks: watch::HookArgs,
     },
 }
 
@@ -915,8 +1170,40 @@ fn main() -> Result<()> {
     std::process::exit(exit_code);
 }
 
+/// Profile defaults injected into clap, kept for the whole process so the
+/// parser can borrow them as `'static`
+static FLAG_DEFAULTS: OnceLock<Vec<(&'static str, String)>> = OnceLock::new();
+
 async fn async_main() -> Result<i32> {
-    let cli = Cli::parse();
+    let args = match expand_aliases()? {
+        aliases::Expansion::None => None,
+        aliases::Expansion::Args(args) => Some(args),
+        aliases::Expansion::Macro {
+            name,
+            globals,
+            steps,
+        } => return aliases::run_macro(&name, &globals, &steps),
+    };
+    repo_config::load();
+    let profile = profile_arg(args.as_deref());
+    // One profile's defaults must not leak into the other workspaces of a fan-out
+    let fans_out = profile.as_deref().is_some_and(|p| p.contains(','))
+        || raw_args(args.as_deref())
+            .iter()
+            .any(|a| a == "--all-profiles");
+    let flag_defaults = FLAG_DEFAULTS.get_or_init(|| {
+        if fans_out {
+            Vec::new()
+        } else {
+            config::resolve_profile_defaults(profile.as_deref())
+        }
+    });
+    let command = with_flag_defaults(Cli::command(), flag_defaults);
+    let matches = match args {
+        Some(args) => command.get_matches_from(args),
+        None => command.get_matches(),
+    };
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
     if cli.no_color || cli.color_mode == ColorChoice::Never {
         colored::control::set_override(false);
     } else if cli.color_mode == ColorChoice::Always {
@@ -926,11 +1213,43 @@ async fn async_main() -> Result<i32> {
         width: cli.width,
         no_truncate: cli.no_truncate,
     });
-    if let Some(profile) = cli.profile.as_deref() {
+    if let Some(profiles) =
+        config::resolve_fanout_profiles(cli.profile.as_deref(), cli.all_profiles)?
+    {
+        if !supports_fanout(&cli.command) {
+            anyhow::bail!(
+                "Multiple profiles are only supported by: issues list, notifications list, search issues, users me"
+            );
+        }
+        config::set_fanout_profiles(profiles);
+    } else if let Some(profile) = cli.profile.as_deref() {
         std::env::set_var("LINEAR_CLI_PROFILE", profile);
     }
     api::set_default_retry(cli.retry);
-    let filters = parse_filters(&cli.filter)?;
+    trace::configure(cli.debug, cli.trace.as_deref());
+    let mut filters = parse_filters(&cli.filter)?;
+    filters.extend(parse_any_filters(&cli.filter_any)?);
+    let explicit_output = matches.value_source("output") == Some(ValueSource::CommandLine);
+    if cli.jq.is_some() && explicit_output && cli.output == OutputFormat::Table {
+        anyhow::bail!("--jq produces JSON and cannot be combined with --output table");
+    }
+    // --jq output is JSON, so a bad expression is reported like any other JSON-mode error
+    let jq_filter = match cli.jq.as_deref().map(jq::Filter::parse).transpose() {
+        Ok(filter) => filter,
+        Err(e) => return Ok(report_error(&e, true)),
+    };
+    let format_template = match (cli.format.as_deref(), cli.format_file.as_ref()) {
+        (Some(source), _) if !source.trim().is_empty() => Some(template::Template::parse(source)?),
+        (_, Some(path)) => {
+            let source = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read template file {}", path.display()))?;
+            Some(template::Template::parse(&source)?.into_document())
+        }
+        _ => None,
+    };
+    if jq_filter.is_some() && cli.output == OutputFormat::Table {
+        cli.output = OutputFormat::Json;
+    }
     let pagination = PaginationOptions {
         limit: cli.limit,
         after: cli.after.clone(),
@@ -948,17 +1267,19 @@ async fn async_main() -> Result<i32> {
         cli.sort.clone(),
         cli.order,
         true,
+        jq_filter,
     );
     let output = OutputOptions {
         format: cli.output,
         json: json_opts,
-        format_template: cli.format.clone(),
+        format_template,
         filters,
         fail_on_empty: cli.fail_on_empty,
         pagination,
         cache: cache::CacheOptions {
             ttl_seconds: cli.cache_ttl,
             no_cache: cli.no_cache,
+            stale_while_revalidate: cli.stale_while_revalidate,
         },
         dry_run: cli.dry_run,
     };
@@ -973,6 +1294,7 @@ async fn async_main() -> Result<i32> {
         cli.quiet || matches!(cli.output, OutputFormat::Json | OutputFormat::Ndjson),
     );
     set_yes_mode(cli.yes);
+    mirror::set_offline_mode(cli.offline);
 
     if cli.schema {
         let schema = serde_json::json!({
@@ -1005,45 +1327,51 @@ async fn async_main() -> Result<i32> {
 
         match result {
             Ok(()) => 0,
-            Err(e) => {
-                // Check if JSON output requested for structured errors
-                if output.is_json() {
-                    if let Some(cli_error) = e.downcast_ref::<CliError>() {
-                        let error_json = serde_json::json!({
-                            "error": true,
-                            "message": cli_error.message,
-                            "code": cli_error.code(),
-                            "details": cli_error.details,
-                            "retry_after": cli_error.retry_after,
-                        });
-                        eprintln!(
-                            "{}",
-                            serde_json::to_string(&error_json).unwrap_or_else(|_| e.to_string())
-                        );
-                    } else {
-                        let error_json = serde_json::json!({
-                            "error": true,
-                            "message": e.to_string(),
-                            "code": categorize_error(&e),
-                            "details": null,
-                            "retry_after": null,
-                        });
-                        eprintln!(
-                            "{}",
-                            serde_json::to_string(&error_json).unwrap_or_else(|_| e.to_string())
-                        );
-                    }
-                } else {
-                    eprintln!("Error: {}", e);
-                }
-                categorize_error(&e) as i32
-            }
+            Err(e) => report_error(&e, output.is_json()),
         }
     };
+    // Output is complete; let stale-while-revalidate refreshes land in the cache
+    api::finish_revalidation().await;
 
     Ok(exit_code)
 }
 
+/// Print a command error (structured when JSON output was requested) and return its exit code
+fn report_error(e: &anyhow::Error, json: bool) -> i32 {
+    if json {
+        if let Some(cli_error) = e.downcast_ref::<CliError>() {
+            let error_json = serde_json::json!({
+                "error": true,
+                "message": cli_error.message,
+                "code": cli_error.code(),
+                "details": cli_error.details,
+                "retry_after": cli_error.retry_after,
+                "rate_limit": rate_limit::last().map(|s| s.to_json()),
+            });
+            eprintln!(
+                "{}",
+                serde_json::to_string(&error_json).unwrap_or_else(|_| e.to_string())
+            );
+        } else {
+            let error_json = serde_json::json!({
+                "error": true,
+                "message": e.to_string(),
+                "code": categorize_error(e),
+                "details": null,
+                "retry_after": null,
+                "rate_limit": rate_limit::last().map(|s| s.to_json()),
+            });
+            eprintln!(
+                "{}",
+                serde_json::to_string(&error_json).unwrap_or_else(|_| e.to_string())
+            );
+        }
+    } else {
+        eprintln!("Error: {}", e);
+    }
+    categorize_error(e) as i32
+}
+
 fn should_check_for_updates(cli: &Cli) -> bool {
     let _ = cli;
     false
@@ -1132,12 +1460,17 @@ async fn run_command(
         Commands::Statuses { action } => statuses::handle(action, output).await?,
         Commands::Git { action } => git::handle(action).await?,
         Commands::Bulk { action } => bulk::handle(action, output).await?,
-        Commands::Cache { action } => commands::cache::handle(action).await?,
+        Commands::Cache { action } => commands::cache::handle(action, output).await?,
+        Commands::Dev { action } => dev::handle(action).await?,
+        Commands::Mirror { action } => commands::mirror::handle(action, output).await?,
+        Commands::Outbox { action } => commands::outbox::handle(action, output).await?,
         Commands::Notifications { action } => notifications::handle(action, output).await?,
         Commands::Templates { action } => templates::handle(action, output).await?,
         Commands::Time { action } => time::handle(action, output).await?,
         Commands::Uploads { action } => uploads::handle(action).await?,
-        Commands::Interactive { team } => interactive::run(team).await?,
+        Commands::Interactive { team, refresh } => {
+            interactive::run(team, refresh, &output.cache).await?
+        }
         Commands::Context => handle_context(output, agent_opts, retry).await?,
         Commands::Favorites { action } => favorites::handle(action, output).await?,
         Commands::Roadmaps { action } => {
@@ -1155,15 +1488,27 @@ async fn run_command(
         Commands::Views { action } => views::handle(action, output).await?,
         Commands::Webhooks { action } => webhooks::handle(action, output).await?,
         Commands::Watch { action } => match action {
-            WatchCommands::Issue { id, interval } => {
-                watch::watch_issue(&id, interval, output).await?
-            }
-            WatchCommands::Project { id, interval } => {
-                watch::watch_project(&id, interval, output).await?
-            }
-            WatchCommands::Team { team, interval } => {
-                watch::watch_team(&team, interval, output).await?
-            }
+            WatchCommands::Issue {
+                id,
+                interval,
+                only_fields,
+                stream,
+                hooks,
+            } => watch::watch_issue(&id, interval, &only_fields, &stream, &hooks, output).await?,
+            WatchCommands::Project {
+                id,
+                interval,
+                only_fields,
+                stream,
+                hooks,
+            } => watch::watch_project(&id, interval, &only_fields, &stream, &hooks, output).await?,
+            WatchCommands::Team {
+                team,
+                interval,
+                only_fields,
+                stream,
+                hooks,
+            } => watch::watch_team(&team, interval, &only_fields, &stream, &hooks, output).await?,
         },
         Commands::Relations { action } => relations::handle(action, output).await?,
         Commands::Whoami => users::handle(users::UserCommands::Me, output).await?,
@@ -1183,7 +1528,7 @@ async fn run_command(
             type_,
             prefix,
             team,
-        } => handle_complete(&type_, &prefix, team.as_deref()).await?,
+        } => handle_complete(&type_, &prefix, team.as_deref(), &output.cache).await?,
         Commands::Auth { action } => auth::handle(action, output).await?,
         Commands::Api { action } => commands::api::handle(action, output).await?,
         Commands::Doctor { check_api, fix } => doctor::run(output, check_api, fix).await?,
@@ -1222,12 +1567,115 @@ async fn run_command(
             ConfigCommands::WorkspaceRemove { name } => {
                 config::workspace_remove(&name)?;
             }
+            ConfigCommands::Alias {
+                name,
+                expansion,
+                remove,
+            } => match expansion {
+                _ if remove => config::remove_alias(&name)?,
+                Some(expansion) => config::set_alias(&name, &expansion)?,
+                None => match config::load_config()?.aliases.get(&name) {
+                    Some(expansion) => println!("{}", expansion),
+                    None => anyhow::bail!("No alias named '{}'", name),
+                },
+            },
+            ConfigCommands::Aliases => {
+                config::list_aliases()?;
+            }
         },
     }
 
     Ok(())
 }
 
+/// Read commands that can merge results from several workspaces
+fn supports_fanout(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Issues {
+            action: issues::IssueCommands::List { .. }
+        } | Commands::Notifications {
+            action: notifications::NotificationCommands::List { .. }
+        } | Commands::Search {
+            action: search::SearchCommands::Issues { .. }
+        } | Commands::Users {
+            action: users::UserCommands::Me
+        }
+    )
+}
+
+/// Expand a user-defined alias or macro in argv before clap sees it
+fn expand_aliases() -> Result<aliases::Expansion> {
+    let args: Vec<String> = std::env::args_os()
+        .map(|a| a.to_string_lossy().into_owned())
+        .collect();
+    // A broken config file is reported by the command that needs it
+    let Ok(config) = config::load_config() else {
+        return Ok(aliases::Expansion::None);
+    };
+    if config.aliases.is_empty() && config.macros.is_empty() {
+        return Ok(aliases::Expansion::None);
+    }
+    aliases::expand(&args, &config.aliases, &config.macros, &Cli::command())
+}
+
+/// Use profile defaults as the default values of the flags whose `env` variable
+/// they feed, so an explicit flag or environment variable still wins
+fn with_flag_defaults(
+    command: clap::Command,
+    defaults: &'static [(&'static str, String)],
+) -> clap::Command {
+    if defaults.is_empty() {
+        return command;
+    }
+    let command = command.mut_args(|arg| {
+        let value = arg
+            .get_env()
+            .and_then(|env| defaults.iter().find(|(var, _)| env == *var))
+            .map(|(_, value)| value.as_str());
+        match value {
+            // A default satisfies a required flag
+            Some(value) => arg.required(false).default_value(value),
+            None => arg,
+        }
+    });
+    let names: Vec<String> = command
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    names.iter().fold(command, |command, name| {
+        command.mut_subcommand(name, |sub| with_flag_defaults(sub, defaults))
+    })
+}
+
+/// The `--profile` value from argv, needed before parsing so profile defaults
+/// can act as fallbacks for flags with an `env` attribute
+fn profile_arg(expanded: Option<&[String]>) -> Option<String> {
+    let mut args = raw_args(expanded).into_iter().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == "--profile" {
+            return args.next();
+        }
+        if let Some(value) = arg.strip_prefix("--profile=") {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+/// The alias-expanded arguments, or argv as given
+fn raw_args(expanded: Option<&[String]>) -> Vec<String> {
+    match expanded {
+        Some(args) => args.to_vec(),
+        None => std::env::args_os()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect(),
+    }
+}
+
 /// Determine if pager should be used
 fn should_use_pager(no_pager: bool, format: &OutputFormat, quiet: bool) -> bool {
     if no_pager || quiet {
@@ -1913,8 +2361,16 @@ async fn handle_setup(output: &OutputOptions) -> Result<()> {
                             if num >= 1 && num <= teams_arr.len() {
                                 let team = &teams_arr[num - 1];
                                 let key = team["key"].as_str().unwrap_or("?");
-                                println!("  Default team: {}", key);
-                                println!("  Tip: Use -t {} or set LINEAR_CLI_TEAM={}", key, key);
+                                let (profile, stored) =
+                                    config::set_profile_default("default-team", key)?;
+                                println!(
+                                    "  Default team for '{}' set to {}",
+                                    profile,
+                                    stored.as_deref().unwrap_or(key)
+                                );
+                                println!(
+                                    "  Change it later with: linear config set default-team <KEY>"
+                                );
                             } else {
                                 println!("  Invalid selection, skipping.");
                             }
@@ -1972,23 +2428,89 @@ async fn handle_setup(output: &OutputOptions) -> Result<()> {
 }
 
 /// Handle the hidden `_complete` command -- output dynamic completion values
-async fn handle_complete(type_: &str, prefix: &str, team: Option<&str>) -> Result<()> {
+async fn handle_complete(
+    type_: &str,
+    prefix: &str,
+    team: Option<&str>,
+    cache_opts: &cache::CacheOptions,
+) -> Result<()> {
     // Try to use cache first for fast completions; fall back to API
-    let cache = cache::Cache::new().ok();
+    let cache = cache::Cache::with_ttl(cache_opts.effective_ttl_seconds())
+        .ok()
+        .map(|cache| CompletionCache {
+            cache,
+            cache_opts: *cache_opts,
+        });
 
     match type_ {
         "teams" => complete_teams(cache.as_ref(), prefix).await,
         "projects" => complete_projects(cache.as_ref(), prefix).await,
         "issues" => complete_issues(prefix).await,
-        "statuses" => complete_statuses(cache.as_ref(), prefix, team).await,
+        "statuses" => complete_statuses(cache.as_ref(), prefix, team, cache_opts).await,
         "users" => complete_users(cache.as_ref(), prefix).await,
         "labels" => complete_labels(cache.as_ref(), prefix).await,
+        "schema" => complete_schema(prefix),
         _ => Ok(()), // Unknown type, return empty
     }
 }
 
+/// Lookup caches as read by completions
+struct CompletionCache {
+    cache: cache::Cache,
+    cache_opts: cache::CacheOptions,
+}
+
+impl CompletionCache {
+    /// Cached list for a lookup type; with stale-while-revalidate an expired
+    /// list is still offered and refreshed in the background
+    fn get(&self, cache_type: cache::CacheType) -> Option<serde_json::Value> {
+        if !self.cache_opts.stale_while_revalidate {
+            return self.cache.get(cache_type);
+        }
+        let (data, stale) = self.cache.get_stale(cache_type)?;
+        if stale {
+            if let Ok(client) = api::LinearClient::new() {
+                api::revalidate_in_background(&client, cache_type, &self.cache_opts);
+            }
+        }
+        Some(data)
+    }
+
+    /// A team's cached statuses, with the same stale-while-revalidate handling
+    fn statuses(&self, team_id: &str) -> Option<serde_json::Value> {
+        if !self.cache_opts.stale_while_revalidate {
+            return self.cache.get_keyed(cache::CacheType::Statuses, team_id);
+        }
+        let (data, stale) = self
+            .cache
+            .get_keyed_stale(cache::CacheType::Statuses, team_id)?;
+        if stale {
+            if let Ok(client) = api::LinearClient::new() {
+                api::revalidate_statuses_in_background(&client, team_id, &self.cache_opts);
+            }
+        }
+        Some(data)
+    }
+}
+
+/// Complete GraphQL type names, or `Type.field` once the prefix has a dot
+/// (from the schema cached by `api schema pull`; never hits the API)
+fn complete_schema(prefix: &str) -> Result<()> {
+    let Ok(Some(schema)) = schema::load() else {
+        return Ok(());
+    };
+    for (value, description) in schema::completions(&schema, prefix) {
+        println!(
+            "{}\t{}",
+            sanitize_completion_value(&value),
+            sanitize_completion_field(&description)
+        );
+    }
+    Ok(())
+}
+
 /// Complete team keys with descriptions
-async fn complete_teams(cache: Option<&cache::Cache>, prefix: &str) -> Result<()> {
+async fn complete_teams(cache: Option<&CompletionCache>, prefix: &str) -> Result<()> {
     let teams = if let Some(data) = cache.and_then(|c| c.get(cache::CacheType::Teams)) {
         data.as_array().cloned().unwrap_or_default()
     } else {
@@ -2017,7 +2539,7 @@ async fn complete_teams(cache: Option<&cache::Cache>, prefix: &str) -> Result<()
 }
 
 /// Complete project names with descriptions
-async fn complete_projects(cache: Option<&cache::Cache>, prefix: &str) -> Result<()> {
+async fn complete_projects(cache: Option<&CompletionCache>, prefix: &str) -> Result<()> {
     let projects = if let Some(data) = cache.and_then(|c| c.get(cache::CacheType::Projects)) {
         data.as_array().cloned().unwrap_or_default()
     } else {
@@ -2090,9 +2612,10 @@ async fn complete_issues(prefix: &str) -> Result<()> {
 
 /// Complete workflow state names with category
 async fn complete_statuses(
-    cache: Option<&cache::Cache>,
+    cache: Option<&CompletionCache>,
     prefix: &str,
     team: Option<&str>,
+    cache_opts: &cache::CacheOptions,
 ) -> Result<()> {
     let client = match api::LinearClient::new() {
         Ok(c) => c,
@@ -2101,16 +2624,7 @@ async fn complete_statuses(
 
     // Resolve team to get statuses -- if no team given, try to get first team as default
     let team_id = if let Some(t) = team {
-        match api::resolve_team_id(
-            &client,
-            t,
-            &cache::CacheOptions {
-                ttl_seconds: None,
-                no_cache: false,
-            },
-        )
-        .await
-        {
+        match api::resolve_team_id(&client, t, cache_opts).await {
             Ok(id) => id,
             Err(_) => return Ok(()),
         }
@@ -2131,9 +2645,7 @@ async fn complete_statuses(
     };
 
     // Check cache for statuses
-    let states = if let Some(cached) =
-        cache.and_then(|c| c.get_keyed(cache::CacheType::Statuses, &team_id))
-    {
+    let states = if let Some(cached) = cache.and_then(|c| c.statuses(&team_id)) {
         cached["states"].as_array().cloned().unwrap_or_default()
     } else {
         // Fetch from API
@@ -2179,7 +2691,7 @@ async fn complete_statuses(
 }
 
 /// Complete user display names / emails
-async fn complete_users(cache: Option<&cache::Cache>, prefix: &str) -> Result<()> {
+async fn complete_users(cache: Option<&CompletionCache>, prefix: &str) -> Result<()> {
     let users = if let Some(data) = cache.and_then(|c| c.get(cache::CacheType::Users)) {
         data.as_array().cloned().unwrap_or_default()
     } else {
@@ -2209,7 +2721,7 @@ async fn complete_users(cache: Option<&cache::Cache>, prefix: &str) -> Result<()
 }
 
 /// Complete label names
-async fn complete_labels(cache: Option<&cache::Cache>, prefix: &str) -> Result<()> {
+async fn complete_labels(cache: Option<&CompletionCache>, prefix: &str) -> Result<()> {
     let labels = if let Some(data) = cache.and_then(|c| c.get(cache::CacheType::Labels)) {
         data.as_array().cloned().unwrap_or_default()
     } else {
@@ -2345,6 +2857,12 @@ _linear_cli_dynamic() {
         fi
     done
 
+    # Complete schema type and field names after `api schema show`
+    if [[ "$prev" == "show" && "${COMP_WORDS[COMP_CWORD-2]}" == "schema" ]]; then
+        _linear_cli_collect schema
+        return 0
+    fi
+
     case "$prev" in
         -t|--team)
             _linear_cli_collect teams
@@ -2403,6 +2921,12 @@ _linear_cli_dynamic() {
         fi
     done
 
+    if [[ "$words[$((CURRENT-1))]" == "show" && "$words[$((CURRENT-2))]" == "schema" ]]; then
+        completions=(${(f)"$(linear-cli _complete --type schema --prefix "$words[$CURRENT]" 2>/dev/null)"})
+        _describe 'schema' completions
+        return
+    fi
+
     case "$words[$((CURRENT-1))]" in
         -t|--team)
             completions=(${(f)"$(linear-cli _complete --type teams --prefix "$words[$CURRENT]" 2>/dev/null)"})
@@ -2464,6 +2988,9 @@ complete -c linear-cli -l label -s l -x -a '(linear-cli _complete --type labels
 complete -c linear-cli -l assignee -x -a '(linear-cli _complete --type users 2>/dev/null | string replace \t "\t")'
 complete -c linear-cli -l user -x -a '(linear-cli _complete --type users 2>/dev/null | string replace \t "\t")'
 
+# Schema type and field names for `api schema show`
+complete -c linear-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -x -a '(linear-cli _complete --type schema --prefix (commandline -ct) 2>/dev/null | string replace \t "\t")'
+
 # Issue ID completions for subcommands that take an issue
 for subcmd in get update start close done archive unarchive comment link assign move transfer open
     complete -c linear-cli -n "__fish_seen_subcommand_from $subcmd" -x -a '(linear-cli _complete --type issues 2>/dev/null | string replace \t "\t")'
@@ -2496,6 +3023,7 @@ Register-ArgumentCompleter -CommandName linear-cli -ScriptBlock {
         '--project' { 'projects' }
         { $_ -in '-l', '--label' } { 'labels' }
         { $_ -in '--assignee', '--user' } { 'users' }
+        { $_ -eq 'show' -and $tokens.Length -gt 2 -and $tokens[-3] -eq 'schema' } { 'schema' }
         { $_ -in 'get', 'update', 'start', 'close', 'done', 'archive', 'unarchive', 'comment', 'link', 'assign', 'move', 'transfer', 'open' } { 'issues' }
         default { $null }
     }
@@ -0,0 +1,571 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::cache::write_atomic;
+use crate::config;
+use crate::pagination::PaginationOptions;
+use crate::text::is_uuid;
+
+static OFFLINE_MODE: OnceLock<bool> = OnceLock::new();
+
+/// Enable offline mode (reads come from the local mirror instead of the API)
+pub fn set_offline_mode(offline: bool) {
+    let _ = OFFLINE_MODE.set(offline);
+}
+
+pub fn is_offline() -> bool {
+    OFFLINE_MODE.get().copied().unwrap_or(false)
+}
+
+/// Record types kept in the local mirror
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MirrorKind {
+    Issues,
+    Comments,
+    Projects,
+}
+
+impl MirrorKind {
+    /// Get the JSON-lines filename for this record type
+    pub fn filename(&self) -> &'static str {
+        match self {
+            MirrorKind::Issues => "issues.jsonl",
+            MirrorKind::Comments => "comments.jsonl",
+            MirrorKind::Projects => "projects.jsonl",
+        }
+    }
+
+    /// Key used for this record type in the sync state file
+    pub fn key(&self) -> &'static str {
+        match self {
+            MirrorKind::Issues => "issues",
+            MirrorKind::Comments => "comments",
+            MirrorKind::Projects => "projects",
+        }
+    }
+
+    /// Get display name for this record type
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MirrorKind::Issues => "Issues",
+            MirrorKind::Comments => "Comments",
+            MirrorKind::Projects => "Projects",
+        }
+    }
+
+    /// Get all mirrored record types
+    pub fn all() -> &'static [MirrorKind] {
+        &[
+            MirrorKind::Issues,
+            MirrorKind::Comments,
+            MirrorKind::Projects,
+        ]
+    }
+}
+
+/// Incremental sync position for one record type
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MirrorCursor {
+    /// Highest `updatedAt` seen; the next pull only fetches records changed after it
+    pub updated_at: Option<String>,
+    /// When this record type was last pulled (RFC 3339)
+    pub synced_at: Option<String>,
+}
+
+/// Sync state persisted next to the mirrored records
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct MirrorState {
+    #[serde(default)]
+    pub cursors: HashMap<String, MirrorCursor>,
+    /// The authenticated user at pull time, used to resolve "me" offline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<Value>,
+}
+
+impl MirrorState {
+    pub fn cursor(&self, kind: MirrorKind) -> Option<&MirrorCursor> {
+        self.cursors.get(kind.key())
+    }
+}
+
+/// Local JSON-lines mirror of issues, comments and projects, scoped by profile
+pub struct Mirror {
+    dir: PathBuf,
+}
+
+impl Mirror {
+    /// Open the mirror for the current profile, creating its directory if needed
+    pub fn open() -> Result<Self> {
+        let dir = Self::dir_path()?;
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Get the mirror directory path, scoped by workspace/profile
+    pub fn dir_path() -> Result<PathBuf> {
+        let profile = config::current_profile().unwrap_or_else(|_| "default".to_string());
+        Ok(dirs::config_dir()
+            .context("Could not find config directory")?
+            .join("linear-cli")
+            .join("mirror")
+            .join(profile))
+    }
+
+    fn path(&self, kind: MirrorKind) -> PathBuf {
+        self.dir.join(kind.filename())
+    }
+
+    fn state_path(&self) -> PathBuf {
+        self.dir.join("state.json")
+    }
+
+    /// Size in bytes of the records file for a type (0 if missing)
+    pub fn size_bytes(&self, kind: MirrorKind) -> u64 {
+        fs::metadata(self.path(kind)).map(|m| m.len()).unwrap_or(0)
+    }
+
+    /// Load all records of a type (empty if never pulled)
+    pub fn load(&self, kind: MirrorKind) -> Result<Vec<Value>> {
+        let path = self.path(kind);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("Corrupt record in {}", path.display()))
+            })
+            .collect()
+    }
+
+    /// Replace all records of a type using an atomic write
+    pub fn save(&self, kind: MirrorKind, records: &[Value]) -> Result<()> {
+        let mut content = String::new();
+        for record in records {
+            content.push_str(&serde_json::to_string(record)?);
+            content.push('\n');
+        }
+        write_atomic(&self.path(kind), &content)
+    }
+
+    /// Upsert records by `id`, keeping the store ordered by `updatedAt` (newest first).
+    /// Returns the merged record count.
+    pub fn merge(&self, kind: MirrorKind, updates: Vec<Value>) -> Result<usize> {
+        let mut records = self.load(kind)?;
+        let mut index: HashMap<String, usize> = records
+            .iter()
+            .enumerate()
+            .filter_map(|(i, r)| r["id"].as_str().map(|id| (id.to_string(), i)))
+            .collect();
+
+        for update in updates {
+            let Some(id) = update["id"].as_str().map(|s| s.to_string()) else {
+                continue;
+            };
+            match index.get(&id) {
+                Some(&i) => records[i] = update,
+                None => {
+                    index.insert(id, records.len());
+                    records.push(update);
+                }
+            }
+        }
+
+        sort_by_updated_desc(&mut records);
+        self.save(kind, &records)?;
+        Ok(records.len())
+    }
+
+    pub fn state(&self) -> Result<MirrorState> {
+        let path = self.state_path();
+        if !path.exists() {
+            return Ok(MirrorState::default());
+        }
+        let content = fs::read_to_string(&path)?;
+        serde_json::from_str(&content).context("Corrupt mirror state file")
+    }
+
+    pub fn save_state(&self, state: &MirrorState) -> Result<()> {
+        write_atomic(&self.state_path(), &serde_json::to_string_pretty(state)?)
+    }
+
+    /// Load records of a type, failing with a hint if it has never been pulled
+    pub fn load_synced(&self, kind: MirrorKind) -> Result<Vec<Value>> {
+        if self.state()?.cursor(kind).is_none() {
+            anyhow::bail!(
+                "No offline data for {}. Run: linear-cli mirror pull",
+                kind.key()
+            );
+        }
+        self.load(kind)
+    }
+
+    /// Find an issue by identifier (ENG-123) or UUID
+    pub fn find_issue(&self, id: &str) -> Result<Option<Value>> {
+        let issues = self.load_synced(MirrorKind::Issues)?;
+        let uuid = is_uuid(id);
+        Ok(issues.into_iter().find(|issue| {
+            if uuid {
+                issue["id"].as_str() == Some(id)
+            } else {
+                issue["identifier"]
+                    .as_str()
+                    .is_some_and(|i| i.eq_ignore_ascii_case(id))
+            }
+        }))
+    }
+
+    /// Find an issue and attach its children (and optionally comments) from the mirror,
+    /// in the same shape `issues get` receives from the API. Null if not mirrored.
+    pub fn issue_detail(&self, id: &str, with_comments: bool) -> Result<Value> {
+        let Some(mut issue) = self.find_issue(id)? else {
+            return Ok(Value::Null);
+        };
+
+        let children: Vec<Value> = self
+            .load(MirrorKind::Issues)?
+            .into_iter()
+            .filter(|child| child["parent"]["identifier"] == issue["identifier"])
+            .map(|child| {
+                json!({
+                    "identifier": child["identifier"],
+                    "title": child["title"],
+                    "state": { "name": child["state"]["name"] },
+                })
+            })
+            .collect();
+        issue["children"] = json!({ "nodes": children });
+
+        if with_comments {
+            let mut comments: Vec<Value> = self
+                .load(MirrorKind::Comments)?
+                .into_iter()
+                .filter(|comment| comment["issue"]["id"] == issue["id"])
+                .collect();
+            comments.sort_by(|a, b| {
+                a["createdAt"]
+                    .as_str()
+                    .unwrap_or("")
+                    .cmp(b["createdAt"].as_str().unwrap_or(""))
+            });
+            issue["comments"] = json!({ "nodes": comments });
+        }
+
+        Ok(issue)
+    }
+
+    /// Remove all mirrored data for this profile
+    pub fn clear(&self) -> Result<()> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)?;
+        }
+        Ok(())
+    }
+}
+
+/// Highest `updatedAt` among records (RFC 3339 strings sort chronologically)
+pub fn max_updated_at(records: &[Value]) -> Option<String> {
+    records
+        .iter()
+        .filter_map(|r| r["updatedAt"].as_str())
+        .max()
+        .map(|s| s.to_string())
+}
+
+fn sort_by_updated_desc(records: &mut [Value]) {
+    records.sort_by(|a, b| {
+        b["updatedAt"]
+            .as_str()
+            .unwrap_or("")
+            .cmp(a["updatedAt"].as_str().unwrap_or(""))
+    });
+}
+
+/// Local equivalent of the issue filters supported by `issues list`, `search` and `export`
+#[derive(Debug, Default, Clone)]
+pub struct IssueQuery {
+    pub team: Option<String>,
+    pub state: Option<String>,
+    pub assignee: Option<String>,
+    pub project: Option<String>,
+    pub label: Option<String>,
+    /// Only issues created at or after this timestamp
+    pub created_since: Option<String>,
+    /// Case-insensitive match against title or description
+    pub text: Option<String>,
+    pub include_archived: bool,
+    pub exclude_completed: bool,
+}
+
+impl IssueQuery {
+    pub fn matches(&self, issue: &Value, viewer: Option<&Value>) -> bool {
+        if !self.include_archived && !issue["archivedAt"].is_null() {
+            return false;
+        }
+        if self.exclude_completed && issue["state"]["type"].as_str() == Some("completed") {
+            return false;
+        }
+        if let Some(team) = &self.team {
+            let team_matches = ["id", "key", "name"].iter().any(|field| {
+                issue["team"][field]
+                    .as_str()
+                    .is_some_and(|v| v.eq_ignore_ascii_case(team))
+            });
+            if !team_matches {
+                return false;
+            }
+        }
+        if let Some(state) = &self.state {
+            if !eq_ignore_case(&issue["state"]["name"], state) {
+                return false;
+            }
+        }
+        if let Some(assignee) = &self.assignee {
+            let assigned = &issue["assignee"];
+            let matches = if assignee.eq_ignore_ascii_case("me") {
+                viewer
+                    .and_then(|v| v["id"].as_str())
+                    .is_some_and(|id| assigned["id"].as_str() == Some(id))
+            } else if is_uuid(assignee) {
+                assigned["id"].as_str() == Some(assignee.as_str())
+            } else if assignee.contains('@') {
+                eq_ignore_case(&assigned["email"], assignee)
+            } else {
+                eq_ignore_case(&assigned["name"], assignee)
+            };
+            if !matches {
+                return false;
+            }
+        }
+        if let Some(project) = &self.project {
+            if !eq_ignore_case(&issue["project"]["name"], project) {
+                return false;
+            }
+        }
+        if let Some(label) = &self.label {
+            let has_label = issue["labels"]["nodes"]
+                .as_array()
+                .is_some_and(|labels| labels.iter().any(|l| eq_ignore_case(&l["name"], label)));
+            if !has_label {
+                return false;
+            }
+        }
+        if let Some(since) = &self.created_since {
+            if issue["createdAt"].as_str().unwrap_or("") < since.as_str() {
+                return false;
+            }
+        }
+        if let Some(text) = &self.text {
+            let needle = text.to_lowercase();
+            let found = ["title", "description"].iter().any(|field| {
+                issue[field]
+                    .as_str()
+                    .is_some_and(|v| v.to_lowercase().contains(&needle))
+            });
+            if !found {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn eq_ignore_case(value: &Value, expected: &str) -> bool {
+    value
+        .as_str()
+        .is_some_and(|v| v.eq_ignore_ascii_case(expected))
+}
+
+/// Query the mirrored issues, honoring the pagination limit
+pub fn query_issues(query: &IssueQuery, pagination: &PaginationOptions) -> Result<Vec<Value>> {
+    let mirror = Mirror::open()?;
+    let state = mirror.state()?;
+    let issues = mirror.load_synced(MirrorKind::Issues)?;
+    Ok(issues
+        .into_iter()
+        .filter(|issue| query.matches(issue, state.viewer.as_ref()))
+        .take(result_limit(pagination))
+        .collect())
+}
+
+/// Query mirrored projects by case-insensitive name match
+pub fn query_projects(
+    text: &str,
+    include_archived: bool,
+    pagination: &PaginationOptions,
+) -> Result<Vec<Value>> {
+    let needle = text.to_lowercase();
+    let projects = Mirror::open()?.load_synced(MirrorKind::Projects)?;
+    Ok(projects
+        .into_iter()
+        .filter(|p| include_archived || p["archivedAt"].is_null())
+        .filter(|p| {
+            p["name"]
+                .as_str()
+                .is_some_and(|n| n.to_lowercase().contains(&needle))
+        })
+        .take(result_limit(pagination))
+        .collect())
+}
+
+fn result_limit(pagination: &PaginationOptions) -> usize {
+    if pagination.all {
+        usize::MAX
+    } else {
+        pagination.limit.unwrap_or(usize::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_issue() -> Value {
+        json!({
+            "id": "issue-1",
+            "identifier": "ENG-1",
+            "title": "Fix login redirect",
+            "description": "Blank page after SSO",
+            "createdAt": "2024-01-02T00:00:00.000Z",
+            "updatedAt": "2024-01-03T00:00:00.000Z",
+            "archivedAt": null,
+            "state": { "name": "In Progress", "type": "started" },
+            "team": { "id": "team-1", "key": "ENG", "name": "Engineering" },
+            "assignee": { "id": "user-1", "name": "Alice", "email": "alice@example.com" },
+            "project": { "name": "Auth" },
+            "labels": { "nodes": [{ "name": "Bug" }] }
+        })
+    }
+
+    #[test]
+    fn test_issue_query_default_matches() {
+        assert!(IssueQuery::default().matches(&sample_issue(), None));
+    }
+
+    #[test]
+    fn test_issue_query_field_filters() {
+        let issue = sample_issue();
+        let query = IssueQuery {
+            team: Some("eng".to_string()),
+            state: Some("in progress".to_string()),
+            assignee: Some("alice@example.com".to_string()),
+            project: Some("auth".to_string()),
+            label: Some("bug".to_string()),
+            ..Default::default()
+        };
+        assert!(query.matches(&issue, None));
+
+        let query = IssueQuery {
+            label: Some("feature".to_string()),
+            ..Default::default()
+        };
+        assert!(!query.matches(&issue, None));
+    }
+
+    #[test]
+    fn test_issue_query_assignee_me_uses_viewer() {
+        let issue = sample_issue();
+        let query = IssueQuery {
+            assignee: Some("me".to_string()),
+            ..Default::default()
+        };
+        assert!(query.matches(&issue, Some(&json!({ "id": "user-1" }))));
+        assert!(!query.matches(&issue, Some(&json!({ "id": "user-2" }))));
+        assert!(!query.matches(&issue, None));
+    }
+
+    #[test]
+    fn test_issue_query_archived_completed_and_text() {
+        let mut issue = sample_issue();
+        assert!(IssueQuery {
+            text: Some("sso".to_string()),
+            ..Default::default()
+        }
+        .matches(&issue, None));
+
+        issue["archivedAt"] = json!("2024-02-01T00:00:00.000Z");
+        assert!(!IssueQuery::default().matches(&issue, None));
+        assert!(IssueQuery {
+            include_archived: true,
+            ..Default::default()
+        }
+        .matches(&issue, None));
+
+        issue["state"]["type"] = json!("completed");
+        assert!(!IssueQuery {
+            include_archived: true,
+            exclude_completed: true,
+            ..Default::default()
+        }
+        .matches(&issue, None));
+    }
+
+    #[test]
+    fn test_issue_query_created_since() {
+        let query = IssueQuery {
+            created_since: Some("2024-01-05T00:00:00.000Z".to_string()),
+            ..Default::default()
+        };
+        assert!(!query.matches(&sample_issue(), None));
+    }
+
+    #[test]
+    fn test_max_updated_at() {
+        let records = vec![
+            json!({ "updatedAt": "2024-01-01T00:00:00.000Z" }),
+            json!({ "updatedAt": "2024-03-01T00:00:00.000Z" }),
+            json!({ "id": "no-timestamp" }),
+        ];
+        assert_eq!(
+            max_updated_at(&records).as_deref(),
+            Some("2024-03-01T00:00:00.000Z")
+        );
+        assert_eq!(max_updated_at(&[]), None);
+    }
+
+    #[test]
+    fn test_merge_upserts_by_id() {
+        let dir = std::env::temp_dir().join(format!(
+            "linear-cli-mirror-test-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let mirror = Mirror { dir: dir.clone() };
+
+        mirror
+            .merge(
+                MirrorKind::Issues,
+                vec![
+                    json!({ "id": "a", "title": "old", "updatedAt": "2024-01-01" }),
+                    json!({ "id": "b", "title": "b", "updatedAt": "2024-01-02" }),
+                ],
+            )
+            .unwrap();
+        let count = mirror
+            .merge(
+                MirrorKind::Issues,
+                vec![json!({ "id": "a", "title": "new", "updatedAt": "2024-01-03" })],
+            )
+            .unwrap();
+
+        let records = mirror.load(MirrorKind::Issues).unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(records[0]["title"], "new");
+        assert_eq!(records[1]["id"], "b");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,478 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::api::{
+    resolve_label_id, resolve_project_id, resolve_state_id, resolve_user_id, Idempotency,
+    LinearClient,
+};
+use crate::cache::{write_atomic, CacheOptions};
+use crate::config;
+use crate::mirror::Mirror;
+
+const ISSUE_UPDATE_MUTATION: &str = r#"
+    mutation($id: String!, $input: IssueUpdateInput!) {
+        issueUpdate(id: $id, input: $input) {
+            success
+            issue {
+                id
+                identifier
+                title
+                updatedAt
+            }
+        }
+    }
+"#;
+
+const COMMENT_CREATE_MUTATION: &str = r#"
+    mutation($input: CommentCreateInput!) {
+        commentCreate(input: $input) {
+            success
+            comment {
+                id
+                body
+                issue { id identifier updatedAt }
+            }
+        }
+    }
+"#;
+
+const COMMENT_RECOVER_QUERY: &str = r#"
+    query($id: String!) {
+        comment(id: $id) {
+            id
+            body
+            issue { id identifier updatedAt }
+        }
+    }
+"#;
+
+/// Field changes for an issue. Names (state, assignee, labels, project) are kept
+/// unresolved so a queued change can be resolved against the server when pushed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueChange {
+    /// Fields sent as-is in `IssueUpdateInput`
+    #[serde(default)]
+    pub input: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// Replace the issue's labels
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Add labels while keeping the existing ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add_labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+impl IssueChange {
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+            && self.state.is_none()
+            && self.assignee.is_none()
+            && self.labels.is_empty()
+            && self.add_labels.is_empty()
+            && self.project.is_none()
+    }
+
+    /// The update input with names left unresolved (used for dry runs)
+    pub fn preview_input(&self) -> Value {
+        let mut input = self.input.clone();
+        if let Some(ref s) = self.state {
+            input.insert("stateId".to_string(), json!(s));
+        }
+        if let Some(ref a) = self.assignee {
+            input.insert("assigneeId".to_string(), json!(a));
+        }
+        if !self.labels.is_empty() {
+            input.insert("labelIds".to_string(), json!(self.labels));
+        }
+        if !self.add_labels.is_empty() {
+            input.insert("addedLabelIds".to_string(), json!(self.add_labels));
+        }
+        if let Some(ref p) = self.project {
+            input.insert("projectId".to_string(), json!(p));
+        }
+        Value::Object(input)
+    }
+
+    /// Short human-readable summary, e.g. "state=Done, assignee=me"
+    pub fn describe(&self) -> String {
+        let mut parts: Vec<String> = self.input.keys().cloned().collect();
+        if let Some(ref s) = self.state {
+            parts.push(format!("state={}", s));
+        }
+        if let Some(ref a) = self.assignee {
+            parts.push(format!("assignee={}", a));
+        }
+        if !self.labels.is_empty() {
+            parts.push(format!("labels={}", self.labels.join(",")));
+        }
+        if !self.add_labels.is_empty() {
+            parts.push(format!("+labels={}", self.add_labels.join(",")));
+        }
+        if let Some(ref p) = self.project {
+            parts.push(format!("project={}", p));
+        }
+        parts.join(", ")
+    }
+}
+
+/// A queued mutation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutboxAction {
+    IssueUpdate {
+        change: IssueChange,
+    },
+    Comment {
+        body: String,
+        /// Client-generated comment id, reused by every push so a retry after a
+        /// lost response cannot post the comment twice
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        comment_id: Option<String>,
+    },
+}
+
+impl OutboxAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            OutboxAction::IssueUpdate { .. } => "update",
+            OutboxAction::Comment { .. } => "comment",
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            OutboxAction::IssueUpdate { change } => change.describe(),
+            OutboxAction::Comment { body, .. } => body.lines().next().unwrap_or("").to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    pub id: u64,
+    /// When the mutation was queued (RFC 3339)
+    pub created_at: String,
+    /// Issue identifier or UUID as given on the command line
+    pub issue: String,
+    /// The issue's `updatedAt` when the change was queued. Without it a pushed
+    /// update is treated as conflicting unless forced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_updated_at: Option<String>,
+    #[serde(flatten)]
+    pub action: OutboxAction,
+}
+
+/// Journal of mutations waiting to be sent, scoped by profile
+pub struct Outbox {
+    path: PathBuf,
+}
+
+impl Outbox {
+    pub fn open() -> Result<Self> {
+        let path = Self::file_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(Self { path })
+    }
+
+    /// Get the outbox file path, scoped by workspace/profile
+    pub fn file_path() -> Result<PathBuf> {
+        let profile = config::current_profile().unwrap_or_else(|_| "default".to_string());
+        Ok(dirs::config_dir()
+            .context("Could not find config directory")?
+            .join("linear-cli")
+            .join("outbox")
+            .join(format!("{}.jsonl", profile)))
+    }
+
+    pub fn load(&self) -> Result<Vec<OutboxEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("Corrupt entry in {}", self.path.display()))
+            })
+            .collect()
+    }
+
+    pub fn save(&self, entries: &[OutboxEntry]) -> Result<()> {
+        if entries.is_empty() {
+            if self.path.exists() {
+                fs::remove_file(&self.path)?;
+            }
+            return Ok(());
+        }
+        let mut content = String::new();
+        for entry in entries {
+            content.push_str(&serde_json::to_string(entry)?);
+            content.push('\n');
+        }
+        write_atomic(&self.path, &content)
+    }
+
+    /// Append a mutation to the journal and return the stored entry.
+    ///
+    /// `base_updated_at` is the server's `updatedAt` if it could be fetched;
+    /// otherwise the offline mirror's copy is used.
+    pub fn enqueue(
+        &self,
+        issue: &str,
+        base_updated_at: Option<String>,
+        action: OutboxAction,
+    ) -> Result<OutboxEntry> {
+        let mut entries = self.load()?;
+        let entry = OutboxEntry {
+            id: entries.iter().map(|e| e.id).max().unwrap_or(0) + 1,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            issue: issue.to_string(),
+            base_updated_at: base_updated_at.or_else(|| mirrored_updated_at(issue)),
+            action,
+        };
+        entries.push(entry.clone());
+        self.save(&entries)?;
+        Ok(entry)
+    }
+
+    /// Remove one entry by id. Returns false if it did not exist.
+    pub fn remove(&self, id: u64) -> Result<bool> {
+        let mut entries = self.load()?;
+        let before = entries.len();
+        entries.retain(|e| e.id != id);
+        if entries.len() == before {
+            return Ok(false);
+        }
+        self.save(&entries)?;
+        Ok(true)
+    }
+}
+
+/// The issue's `updatedAt` from the offline mirror, if it has been pulled
+fn mirrored_updated_at(issue: &str) -> Option<String> {
+    let mirror = Mirror::open().ok()?;
+    let record = mirror.find_issue(issue).ok()??;
+    record["updatedAt"].as_str().map(|s| s.to_string())
+}
+
+/// The issue's current `updatedAt` on the server, if it can be reached
+pub async fn server_updated_at(client: &LinearClient, issue: &str) -> Option<String> {
+    let query = r#"
+        query($id: String!) {
+            issue(id: $id) {
+                updatedAt
+            }
+        }
+    "#;
+    let result = client
+        .query(query, Some(json!({ "id": issue })))
+        .await
+        .ok()?;
+    result["data"]["issue"]["updatedAt"]
+        .as_str()
+        .map(|s| s.to_string())
+}
+
+/// True when the request never reached the server (DNS failure, refused connection,
+/// no route), so the mutation is safe to queue and replay later. Timeouts are not
+/// included because the server may already have applied the change.
+pub fn is_network_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        cause
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_connect())
+    })
+}
+
+/// True when the server copy changed after the change was queued, or when the
+/// version the change was based on is unknown
+pub fn is_conflict(base_updated_at: Option<&str>, server_updated_at: Option<&str>) -> bool {
+    match (base_updated_at, server_updated_at) {
+        (Some(base), Some(server)) => match (
+            chrono::DateTime::parse_from_rfc3339(base),
+            chrono::DateTime::parse_from_rfc3339(server),
+        ) {
+            (Ok(base), Ok(server)) => server > base,
+            _ => server > base,
+        },
+        (None, _) => true,
+        (Some(_), None) => false,
+    }
+}
+
+/// Resolve the names in a change and build the `IssueUpdateInput` for an issue
+pub async fn resolve_change(
+    client: &LinearClient,
+    issue_id: &str,
+    change: &IssueChange,
+    cache: &CacheOptions,
+) -> Result<Value> {
+    let mut input = Value::Object(change.input.clone());
+
+    if change.state.is_some() || !change.add_labels.is_empty() {
+        let query = r#"
+            query($id: String!) {
+                issue(id: $id) {
+                    team { id }
+                    labels { nodes { id } }
+                }
+            }
+        "#;
+        let result = client.query(query, Some(json!({ "id": issue_id }))).await?;
+        let issue = &result["data"]["issue"];
+        if issue.is_null() {
+            anyhow::bail!("Issue not found: {}", issue_id);
+        }
+
+        if let Some(ref s) = change.state {
+            let team_id = issue["team"]["id"].as_str().ok_or_else(|| {
+                anyhow::anyhow!("Could not determine team for issue {}", issue_id)
+            })?;
+            input["stateId"] = json!(resolve_state_id(client, team_id, s).await?);
+        }
+
+        if !change.add_labels.is_empty() {
+            let mut label_ids: Vec<String> = issue["labels"]["nodes"]
+                .as_array()
+                .map(|nodes| {
+                    nodes
+                        .iter()
+                        .filter_map(|l| l["id"].as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            for label in &change.add_labels {
+                let label_id = resolve_label_id(client, label, cache).await?;
+                if !label_ids.contains(&label_id) {
+                    label_ids.push(label_id);
+                }
+            }
+            input["labelIds"] = json!(label_ids);
+        }
+    }
+
+    if let Some(ref a) = change.assignee {
+        input["assigneeId"] = json!(resolve_user_id(client, a, cache).await?);
+    }
+    if !change.labels.is_empty() {
+        let mut label_ids = Vec::new();
+        for label in &change.labels {
+            label_ids.push(resolve_label_id(client, label, cache).await?);
+        }
+        input["labelIds"] = json!(label_ids);
+    }
+    if let Some(ref p) = change.project {
+        input["projectId"] = json!(resolve_project_id(client, p, cache).await?);
+    }
+
+    Ok(input)
+}
+
+/// Resolve and apply a change to an issue. Returns the raw mutation response.
+pub async fn apply_change(
+    client: &LinearClient,
+    issue_id: &str,
+    change: &IssueChange,
+    cache: &CacheOptions,
+) -> Result<Value> {
+    let input = resolve_change(client, issue_id, change, cache).await?;
+    client
+        .mutate_with(
+            ISSUE_UPDATE_MUTATION,
+            Some(json!({ "id": issue_id, "input": input })),
+            Idempotency::Repeatable,
+        )
+        .await
+}
+
+/// Add a comment to an issue with a client-generated id. Returns the raw
+/// mutation response.
+pub async fn create_comment(
+    client: &LinearClient,
+    issue_id: &str,
+    body: &str,
+    comment_id: &str,
+) -> Result<Value> {
+    let mut input = json!({ "id": comment_id, "issueId": issue_id, "body": body });
+    let idempotency = Idempotency::client_id(&mut input, COMMENT_RECOVER_QUERY);
+    client
+        .mutate_with(
+            COMMENT_CREATE_MUTATION,
+            Some(json!({ "input": input })),
+            idempotency,
+        )
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_conflict() {
+        assert!(is_conflict(
+            Some("2024-01-01T00:00:00.000Z"),
+            Some("2024-01-02T00:00:00.000Z")
+        ));
+        assert!(!is_conflict(
+            Some("2024-01-02T00:00:00.000Z"),
+            Some("2024-01-02T00:00:00.000Z")
+        ));
+        // Offsets are compared as instants, not strings
+        assert!(!is_conflict(
+            Some("2024-01-02T01:00:00+01:00"),
+            Some("2024-01-02T00:00:00Z")
+        ));
+        // Without a known base the server copy may have changed since
+        assert!(is_conflict(None, Some("2024-01-02T00:00:00.000Z")));
+        assert!(is_conflict(None, None));
+    }
+
+    #[test]
+    fn test_issue_change_preview_and_describe() {
+        let mut change = IssueChange {
+            state: Some("Done".to_string()),
+            add_labels: vec!["Bug".to_string()],
+            ..Default::default()
+        };
+        change.input.insert("priority".to_string(), json!(1));
+        assert!(!change.is_empty());
+        assert_eq!(
+            change.preview_input(),
+            json!({ "priority": 1, "stateId": "Done", "addedLabelIds": ["Bug"] })
+        );
+        assert_eq!(change.describe(), "priority, state=Done, +labels=Bug");
+        assert!(IssueChange::default().is_empty());
+    }
+
+    #[test]
+    fn test_entry_round_trips_as_json_line() {
+        let entry = OutboxEntry {
+            id: 3,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            issue: "ENG-1".to_string(),
+            base_updated_at: Some("2024-01-01T00:00:00.000Z".to_string()),
+            action: OutboxAction::Comment {
+                body: "hello".to_string(),
+                comment_id: Some("0b5f7c52-2f7e-4b0e-9a37-3d0f1a6f5e21".to_string()),
+            },
+        };
+        let line = serde_json::to_string(&entry).unwrap();
+        assert!(line.contains(r#""type":"comment""#));
+        let parsed: OutboxEntry = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed, entry);
+    }
+}
@@ -8,8 +8,10 @@ use regex::Regex;
 
 use crate::cache::CacheOptions;
 use crate::error::CliError;
+use crate::jq;
 use crate::json_path::get_path;
 use crate::pagination::PaginationOptions;
+use crate::template::Template;
 use crate::OutputFormat;
 
 static QUIET_MODE: OnceLock<bool> = OnceLock::new();
@@ -36,6 +38,7 @@ pub struct JsonOutputOptions {
     pub sort: Option<String>,
     pub order: SortOrder,
     pub default_sort: bool,
+    pub jq: Option<jq::Filter>,
 }
 
 impl JsonOutputOptions {
@@ -45,6 +48,7 @@ impl JsonOutputOptions {
         sort: Option<String>,
         order: SortOrder,
         default_sort: bool,
+        jq: Option<jq::Filter>,
     ) -> Self {
         Self {
             compact,
@@ -52,6 +56,7 @@ impl JsonOutputOptions {
             sort,
             order,
             default_sort,
+            jq,
         }
     }
 }
@@ -60,7 +65,7 @@ impl JsonOutputOptions {
 pub struct OutputOptions {
     pub format: OutputFormat,
     pub json: JsonOutputOptions,
-    pub format_template: Option<String>,
+    pub format_template: Option<Template>,
     pub filters: Vec<FilterExpr>,
     pub fail_on_empty: bool,
     pub pagination: PaginationOptions,
@@ -78,10 +83,7 @@ impl OutputOptions {
     }
 
     pub fn has_template(&self) -> bool {
-        self.format_template
-            .as_deref()
-            .map(|t| !t.trim().is_empty())
-            .unwrap_or(false)
+        self.format_template.is_some()
     }
 }
 
@@ -90,6 +92,26 @@ pub enum FilterOp {
     Eq,
     NotEq,
     Contains,
+    /// `=~`: case-insensitive regular expression
+    Matches(Regex),
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    In(Vec<String>),
+    NotIn(Vec<String>),
+    Exists,
+    Missing,
+}
+
+impl FilterOp {
+    /// Negated operators hold when no value along the path matches the positive form
+    fn is_negated(&self) -> bool {
+        matches!(
+            self,
+            FilterOp::NotEq | FilterOp::NotIn(_) | FilterOp::Missing
+        )
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -97,8 +119,13 @@ pub struct FilterExpr {
     pub path: Vec<String>,
     pub op: FilterOp,
     pub value: String,
+    /// Part of the `--filter-any` group (at least one must match) rather than the AND list
+    pub any: bool,
 }
 
+/// Symbol operators, two-character ones first so `<=` wins over `<` at the same position
+const FILTER_OPERATORS: &[&str] = &["!=", "~=", "=~", "<=", ">=", "=", "<", ">"];
+
 pub fn parse_filters(filters: &[String]) -> Result<Vec<FilterExpr>> {
     filters
         .iter()
@@ -107,19 +134,83 @@ pub fn parse_filters(filters: &[String]) -> Result<Vec<FilterExpr>> {
         .collect()
 }
 
+/// Parse `--filter-any` conditions: an item passes the group when any one matches
+pub fn parse_any_filters(filters: &[String]) -> Result<Vec<FilterExpr>> {
+    let mut parsed = parse_filters(filters)?;
+    for filter in &mut parsed {
+        filter.any = true;
+    }
+    Ok(parsed)
+}
+
+fn keyword_filter_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"(?i)^([^\s=!<>~]+)\s+(not\s+in|in|not\s+exists|exists)(?:\s+(.*))?$").unwrap()
+    })
+}
+
 fn parse_filter(input: &str) -> Result<FilterExpr> {
     let trimmed = input.trim();
-    let (path, op, value) = if let Some((left, right)) = trimmed.split_once("!=") {
-        (left, FilterOp::NotEq, right)
-    } else if let Some((left, right)) = trimmed.split_once("~=") {
-        (left, FilterOp::Contains, right)
-    } else if let Some((left, right)) = trimmed.split_once('=') {
-        (left, FilterOp::Eq, right)
+    let (path, op, value) = if let Some(caps) = keyword_filter_regex().captures(trimmed) {
+        let path = caps.get(1).map_or("", |m| m.as_str());
+        let keyword = caps[2].split_whitespace().collect::<Vec<_>>().join(" ");
+        let value = caps.get(3).map_or("", |m| m.as_str()).trim();
+        let op = match keyword.to_lowercase().as_str() {
+            "exists" | "not exists" if !value.is_empty() => {
+                anyhow::bail!("Invalid filter '{}': {} takes no value", input, keyword)
+            }
+            "exists" => FilterOp::Exists,
+            "not exists" => FilterOp::Missing,
+            negated => {
+                let list = parse_filter_list(value);
+                if list.is_empty() {
+                    anyhow::bail!(
+                        "Invalid filter '{}': {} needs a list like a,b",
+                        input,
+                        keyword
+                    );
+                }
+                if negated == "in" {
+                    FilterOp::In(list)
+                } else {
+                    FilterOp::NotIn(list)
+                }
+            }
+        };
+        (path, op, value)
     } else {
-        anyhow::bail!(
-            "Invalid filter '{}'. Use field=value, field!=value, or field~=value",
-            input
-        );
+        let found = trimmed.char_indices().find_map(|(idx, _)| {
+            FILTER_OPERATORS
+                .iter()
+                .find(|sym| trimmed[idx..].starts_with(**sym))
+                .map(|sym| (idx, *sym))
+        });
+        let Some((idx, symbol)) = found else {
+            anyhow::bail!(
+                "Invalid filter '{}'. Use field=value, field!=value, field~=value, field=~regex, \
+                 field<value (also <=, >, >=), 'field in a,b', 'field not in a,b', \
+                 'field exists' or 'field not exists'",
+                input
+            );
+        };
+        let value = &trimmed[idx + symbol.len()..];
+        let op = match symbol {
+            "!=" => FilterOp::NotEq,
+            "~=" => FilterOp::Contains,
+            "=~" => FilterOp::Matches(
+                regex::RegexBuilder::new(value.trim())
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|e| anyhow::anyhow!("Invalid regex in filter '{}': {}", input, e))?,
+            ),
+            "<=" => FilterOp::Le,
+            ">=" => FilterOp::Ge,
+            "<" => FilterOp::Lt,
+            ">" => FilterOp::Gt,
+            _ => FilterOp::Eq,
+        };
+        (&trimmed[..idx], op, value)
     };
 
     let path_parts: Vec<String> = path
@@ -137,9 +228,24 @@ fn parse_filter(input: &str) -> Result<FilterExpr> {
         path: path_parts,
         op,
         value: value.trim().to_string(),
+        any: false,
     })
 }
 
+/// Split an `in` list: `a,b,c` or `(a, b, c)`
+fn parse_filter_list(value: &str) -> Vec<String> {
+    let value = value.trim();
+    let value = value
+        .strip_prefix('(')
+        .and_then(|v| v.strip_suffix(')'))
+        .unwrap_or(value);
+    value
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
 pub fn print_json(value: &Value, output: &OutputOptions) -> Result<()> {
     print_json_owned(value.clone(), output)
 }
@@ -150,6 +256,9 @@ pub fn print_json_owned(mut out: Value, output: &OutputOptions) -> Result<()> {
     if let Some(fields) = output.json.fields.as_ref() {
         out = select_fields(&out, fields);
     }
+    if let Some(filter) = output.json.jq.as_ref() {
+        return print_jq(&out, filter, output);
+    }
 
     if output.fail_on_empty {
         if let Value::Array(items) = &out {
@@ -159,7 +268,7 @@ pub fn print_json_owned(mut out: Value, output: &OutputOptions) -> Result<()> {
         }
     }
 
-    if let Some(template) = output.format_template.as_deref() {
+    if let Some(template) = output.format_template.as_ref() {
         return print_template(&out, template);
     }
 
@@ -176,6 +285,35 @@ pub fn print_json_owned(mut out: Value, output: &OutputOptions) -> Result<()> {
     Ok(())
 }
 
+/// Print every value a `--jq` expression produces; strings print raw so
+/// results can feed shell pipelines directly
+fn print_jq(value: &Value, filter: &jq::Filter, output: &OutputOptions) -> Result<()> {
+    let results = filter.run(value)?;
+    let empty = match results.as_slice() {
+        [] => true,
+        [Value::Array(items)] => items.is_empty(),
+        _ => false,
+    };
+    if output.fail_on_empty && empty {
+        return Err(CliError::not_found("No results found").into());
+    }
+
+    for result in &results {
+        if let Some(template) = output.format_template.as_ref() {
+            print_template(result, template)?;
+        } else if output.is_ndjson() {
+            print_ndjson(result)?;
+        } else if let Value::String(s) = result {
+            println!("{}", s);
+        } else if output.json.compact {
+            println!("{}", serde_json::to_string(result)?);
+        } else {
+            println!("{}", serde_json::to_string_pretty(result)?);
+        }
+    }
+    Ok(())
+}
+
 fn apply_sort(value: &mut Value, opts: &JsonOutputOptions) {
     let Value::Array(items) = value else { return };
 
@@ -232,22 +370,111 @@ pub fn filter_values(values: &mut Vec<Value>, filters: &[FilterExpr]) {
 }
 
 fn matches_filters(value: &Value, filters: &[FilterExpr]) -> bool {
-    filters.iter().all(|filter| {
-        let mut current = value;
-        for part in &filter.path {
-            match current.get(part.as_str()) {
-                Some(next) => current = next,
-                None => return matches!(filter.op, FilterOp::NotEq),
+    let mut has_any = false;
+    let mut any_matched = false;
+    for filter in filters {
+        if filter.any {
+            has_any = true;
+            any_matched = any_matched || matches_filter(value, filter);
+        } else if !matches_filter(value, filter) {
+            return false;
+        }
+    }
+    !has_any || any_matched
+}
+
+fn matches_filter(value: &Value, filter: &FilterExpr) -> bool {
+    let mut leaves = Vec::new();
+    collect_leaves(value, &filter.path, &mut leaves);
+    let hit = leaves
+        .iter()
+        .any(|leaf| leaf_matches(leaf, &filter.op, &filter.value));
+    hit != filter.op.is_negated()
+}
+
+/// Values at `path`, stepping into every element of arrays along the way
+/// (so `labels.nodes.name` yields each label name); numeric parts index arrays.
+fn collect_leaves<'a>(value: &'a Value, path: &[String], out: &mut Vec<&'a Value>) {
+    match value {
+        Value::Array(items) => match path.first().and_then(|p| p.parse::<usize>().ok()) {
+            Some(index) => {
+                if let Some(item) = items.get(index) {
+                    collect_leaves(item, &path[1..], out);
+                }
+            }
+            None => {
+                for item in items {
+                    collect_leaves(item, path, out);
+                }
+            }
+        },
+        _ => match path.split_first() {
+            None => out.push(value),
+            Some((head, rest)) => {
+                if let Some(next) = value.get(head.as_str()) {
+                    collect_leaves(next, rest, out);
+                }
+            }
+        },
+    }
+}
+
+/// Whether one value satisfies the positive form of `op`
+fn leaf_matches(leaf: &Value, op: &FilterOp, expected: &str) -> bool {
+    let actual = value_to_string(leaf).to_lowercase();
+    let equals = |candidate: &str| actual == candidate.to_lowercase();
+    match op {
+        FilterOp::Eq | FilterOp::NotEq => equals(expected),
+        FilterOp::Contains => actual.contains(&expected.to_lowercase()),
+        FilterOp::Matches(regex) => !leaf.is_null() && regex.is_match(&value_to_string(leaf)),
+        FilterOp::In(list) | FilterOp::NotIn(list) => list.iter().any(|v| equals(v)),
+        FilterOp::Exists | FilterOp::Missing => !leaf.is_null(),
+        FilterOp::Lt | FilterOp::Le | FilterOp::Gt | FilterOp::Ge => {
+            let Some(ord) = compare_filter_value(leaf, expected) else {
+                return false;
+            };
+            match op {
+                FilterOp::Lt => ord == Ordering::Less,
+                FilterOp::Le => ord != Ordering::Greater,
+                FilterOp::Gt => ord == Ordering::Greater,
+                _ => ord != Ordering::Less,
             }
         }
-        let actual = value_to_string(current).to_lowercase();
-        let expected = filter.value.to_lowercase();
-        match filter.op {
-            FilterOp::Eq => actual == expected,
-            FilterOp::NotEq => actual != expected,
-            FilterOp::Contains => actual.contains(&expected),
+    }
+}
+
+/// Order a value against a filter operand: numerically when both are numbers,
+/// by time when both are dates (ISO timestamps, YYYY-MM-DD, or shorthands like
+/// today and -7d), otherwise as case-insensitive text. Null never compares.
+fn compare_filter_value(leaf: &Value, expected: &str) -> Option<Ordering> {
+    if leaf.is_null() {
+        return None;
+    }
+    let actual = value_to_string(leaf);
+    let expected = expected.trim();
+    if let (Ok(a), Ok(b)) = (actual.trim().parse::<f64>(), expected.parse::<f64>()) {
+        return a.partial_cmp(&b);
+    }
+    if let Some(a) = parse_filter_time(&actual) {
+        let b = parse_filter_time(expected)
+            .or_else(|| crate::dates::parse_due_date(expected).and_then(|d| parse_filter_time(&d)));
+        if let Some(b) = b {
+            return Some(a.cmp(&b));
         }
-    })
+    }
+    Some(actual.to_lowercase().cmp(&expected.to_lowercase()))
+}
+
+/// RFC 3339 timestamp, or a YYYY-MM-DD date taken as UTC midnight
+fn parse_filter_time(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    let value = value.trim();
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
 }
 
 fn value_to_string(value: &Value) -> String {
@@ -260,20 +487,22 @@ fn value_to_string(value: &Value) -> String {
     }
 }
 
-fn template_regex() -> &'static Regex {
-    static REGEX: OnceLock<Regex> = OnceLock::new();
-    REGEX.get_or_init(|| Regex::new(r"\{\{\s*\.?([a-zA-Z0-9_.-]*)\s*\}\}").unwrap())
-}
-
-pub fn print_template(value: &Value, template: &str) -> Result<()> {
+pub fn print_template(value: &Value, template: &Template) -> Result<()> {
     match value {
-        Value::Array(items) => {
+        Value::Array(items) if !template.is_document() => {
             for item in items {
-                println!("{}", render_template(template, item));
+                println!("{}", template.render(item)?);
+            }
+        }
+        _ if template.is_document() => {
+            let text = template.render(value)?;
+            print!("{}", text);
+            if !text.is_empty() && !text.ends_with('\n') {
+                println!();
             }
         }
         _ => {
-            println!("{}", render_template(template, value));
+            println!("{}", template.render(value)?);
         }
     }
     Ok(())
@@ -286,22 +515,6 @@ pub fn ensure_non_empty(values: &[Value], output: &OutputOptions) -> Result<()>
     Ok(())
 }
 
-fn render_template(template: &str, value: &Value) -> String {
-    template_regex()
-        .replace_all(template, |caps: &regex::Captures| {
-            let path = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-            if path.is_empty() || path == "." {
-                return value_to_string(value);
-            }
-            let parts: Vec<&str> = path.split('.').filter(|p| !p.is_empty()).collect();
-            match get_path(value, &parts) {
-                Some(found) => value_to_string(found),
-                None => String::new(),
-            }
-        })
-        .to_string()
-}
-
 fn print_ndjson(value: &Value) -> Result<()> {
     match value {
         Value::Array(items) => {
@@ -722,6 +935,140 @@ mod tests {
         assert_eq!(values.len(), 2);
     }
 
+    fn filtered(values: Vec<Value>, filters: &[&str]) -> Vec<Value> {
+        let mut values = values;
+        let filters: Vec<String> = filters.iter().map(|f| f.to_string()).collect();
+        filter_values(&mut values, &parse_filters(&filters).unwrap());
+        values
+    }
+
+    #[test]
+    fn test_parse_filter_operators() {
+        let filters = parse_filters(&[
+            "priority<=2".to_string(),
+            "priority>1".to_string(),
+            "title=~^fix".to_string(),
+            "state.name in (Todo, Done)".to_string(),
+            "state.name NOT IN Backlog".to_string(),
+            "dueDate exists".to_string(),
+            "dueDate not exists".to_string(),
+        ])
+        .unwrap();
+        assert!(matches!(filters[0].op, FilterOp::Le));
+        assert_eq!(filters[0].value, "2");
+        assert!(matches!(filters[1].op, FilterOp::Gt));
+        assert!(matches!(filters[2].op, FilterOp::Matches(_)));
+        assert!(
+            matches!(&filters[3].op, FilterOp::In(list) if list == &["Todo".to_string(), "Done".to_string()])
+        );
+        assert_eq!(filters[3].path, vec!["state", "name"]);
+        assert!(matches!(&filters[4].op, FilterOp::NotIn(list) if list.len() == 1));
+        assert!(matches!(filters[5].op, FilterOp::Exists));
+        assert!(matches!(filters[6].op, FilterOp::Missing));
+    }
+
+    #[test]
+    fn test_parse_filter_invalid_operands() {
+        assert!(parse_filters(&["title=~(".to_string()]).is_err());
+        assert!(parse_filters(&["state.name in".to_string()]).is_err());
+        assert!(parse_filters(&["dueDate exists yes".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_filter_numeric_comparison() {
+        let values = vec![
+            json!({"priority": 1}),
+            json!({"priority": 2}),
+            json!({"priority": 10}),
+            json!({"priority": null}),
+        ];
+        assert_eq!(filtered(values.clone(), &["priority<=2"]).len(), 2);
+        // Numeric, not lexicographic: 10 > 2
+        let high = filtered(values.clone(), &["priority>2"]);
+        assert_eq!(high.len(), 1);
+        assert_eq!(high[0]["priority"], 10);
+        assert_eq!(filtered(values, &["priority>=1", "priority<10"]).len(), 2);
+    }
+
+    #[test]
+    fn test_filter_date_comparison() {
+        let values = vec![
+            json!({"id": 1, "updatedAt": "2024-01-05T10:00:00.000Z"}),
+            json!({"id": 2, "updatedAt": "2024-02-01T00:00:00.000Z"}),
+            json!({"id": 3, "dueDate": "2024-01-31"}),
+        ];
+        let after = filtered(values.clone(), &["updatedAt>2024-01-31"]);
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0]["id"], 2);
+        assert_eq!(filtered(values.clone(), &["dueDate<=2024-01-31"]).len(), 1);
+        // Relative shorthands resolve against today
+        assert_eq!(filtered(values, &["updatedAt<today"]).len(), 2);
+    }
+
+    #[test]
+    fn test_filter_regex_and_lists() {
+        let values = vec![
+            json!({"title": "Fix login", "state": {"name": "Todo"}}),
+            json!({"title": "Add feature", "state": {"name": "Done"}}),
+            json!({"title": "fix signup", "state": {"name": "Backlog"}}),
+        ];
+        assert_eq!(filtered(values.clone(), &["title=~^fix"]).len(), 2);
+        assert_eq!(
+            filtered(values.clone(), &["state.name in todo,done"]).len(),
+            2
+        );
+        let rest = filtered(values, &["state.name not in (Todo, Done)"]);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0]["state"]["name"], "Backlog");
+    }
+
+    #[test]
+    fn test_filter_array_elements() {
+        let values = vec![
+            json!({"id": 1, "labels": {"nodes": [{"name": "bug"}, {"name": "ui"}]}}),
+            json!({"id": 2, "labels": {"nodes": [{"name": "feature"}]}}),
+            json!({"id": 3, "labels": {"nodes": []}}),
+        ];
+        let bugs = filtered(values.clone(), &["labels.nodes.name=bug"]);
+        assert_eq!(bugs.len(), 1);
+        assert_eq!(bugs[0]["id"], 1);
+        // Negation holds only when no element matches
+        assert_eq!(
+            filtered(values.clone(), &["labels.nodes.name!=bug"]).len(),
+            2
+        );
+        assert_eq!(
+            filtered(values.clone(), &["labels.nodes.0.name=feature"]).len(),
+            1
+        );
+        assert_eq!(filtered(values, &["labels.nodes.name not exists"]).len(), 1);
+    }
+
+    #[test]
+    fn test_filter_exists() {
+        let values = vec![
+            json!({"id": 1, "dueDate": "2024-01-01"}),
+            json!({"id": 2, "dueDate": null}),
+            json!({"id": 3}),
+        ];
+        assert_eq!(filtered(values.clone(), &["dueDate exists"]).len(), 1);
+        assert_eq!(filtered(values, &["dueDate not exists"]).len(), 2);
+    }
+
+    #[test]
+    fn test_filter_any_group() {
+        let mut values = vec![
+            json!({"key": "ENG", "private": false}),
+            json!({"key": "DES", "private": true}),
+            json!({"key": "OPS", "private": false}),
+        ];
+        let mut filters = parse_filters(&["private=false".to_string()]).unwrap();
+        filters.extend(parse_any_filters(&["key=ENG".to_string(), "key=DES".to_string()]).unwrap());
+        filter_values(&mut values, &filters);
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0]["key"], "ENG");
+    }
+
     #[test]
     fn test_select_fields_single() {
         let value = json!({"id": "abc", "name": "Test", "description": "Long desc"});
@@ -763,6 +1110,10 @@ mod tests {
         assert_eq!(result, json!({"id": "abc"}));
     }
 
+    fn render_template(template: &str, value: &Value) -> String {
+        Template::parse(template).unwrap().render(value).unwrap()
+    }
+
     #[test]
     fn test_render_template_simple() {
         let value = json!({"name": "Test", "id": "abc"});
@@ -11,6 +11,30 @@ pub fn priority_to_string(priority: Option<i64>) -> String {
     }
 }
 
+/// Plain priority label, e.g. for templates
+pub fn priority_name(priority: i64) -> &'static str {
+    match priority {
+        0 => "No priority",
+        1 => "Urgent",
+        2 => "High",
+        3 => "Normal",
+        4 => "Low",
+        _ => "-",
+    }
+}
+
+/// Parse a priority number (0-4) or name into Linear's numeric priority
+pub fn parse_priority(input: &str) -> Option<i64> {
+    match input.trim().to_lowercase().as_str() {
+        "none" | "no" => Some(0),
+        "urgent" => Some(1),
+        "high" => Some(2),
+        "normal" | "medium" => Some(3),
+        "low" => Some(4),
+        other => other.parse().ok().filter(|n| (0..=4).contains(n)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,4 +74,22 @@ mod tests {
         assert_eq!(priority_to_string(Some(5)), "-");
         assert_eq!(priority_to_string(Some(-1)), "-");
     }
+
+    #[test]
+    fn test_priority_name() {
+        assert_eq!(priority_name(0), "No priority");
+        assert_eq!(priority_name(1), "Urgent");
+        assert_eq!(priority_name(4), "Low");
+        assert_eq!(priority_name(7), "-");
+    }
+
+    #[test]
+    fn test_parse_priority() {
+        assert_eq!(parse_priority("Urgent"), Some(1));
+        assert_eq!(parse_priority("medium"), Some(3));
+        assert_eq!(parse_priority("4"), Some(4));
+        assert_eq!(parse_priority("none"), Some(0));
+        assert_eq!(parse_priority("5"), None);
+        assert_eq!(parse_priority("soon"), None);
+    }
 }
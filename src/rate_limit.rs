@@ -0,0 +1,235 @@
+//! Rate-limit and query-complexity budget reported by Linear on every response.
+//!
+//! Linear sends `X-RateLimit-Requests-*` and `X-RateLimit-Complexity-*` headers
+//! (limit, remaining, reset as epoch milliseconds) plus `X-Complexity` for the
+//! query just run. `LinearClient` keeps the latest values and uses them to pace
+//! requests before the budget runs out instead of waiting for a 429.
+
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Below this fraction of the limit, requests are spread over the reset window
+const LOW_BUDGET_RATIO: f64 = 0.1;
+/// Never sleep longer than this before a single request
+const MAX_THROTTLE: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RateLimitStatus {
+    pub requests_limit: Option<u64>,
+    pub requests_remaining: Option<u64>,
+    /// When the request budget resets (epoch milliseconds)
+    pub requests_reset: Option<i64>,
+    pub complexity_limit: Option<u64>,
+    pub complexity_remaining: Option<u64>,
+    /// When the complexity budget resets (epoch milliseconds)
+    pub complexity_reset: Option<i64>,
+    /// Complexity of the most recent query
+    pub last_complexity: Option<u64>,
+}
+
+/// One budget (requests or complexity) and the cost of the next call against it
+struct Budget {
+    limit: u64,
+    remaining: u64,
+    reset: i64,
+    cost: u64,
+}
+
+impl Budget {
+    fn is_low(&self) -> bool {
+        (self.remaining as f64) < self.limit as f64 * LOW_BUDGET_RATIO
+    }
+
+    /// Spread the calls still allowed evenly over the time left until reset
+    fn delay(&self, now_ms: i64) -> Option<Duration> {
+        if !self.is_low() {
+            return None;
+        }
+        let window_ms = (self.reset - now_ms).max(0) as u64;
+        let calls_left = self.remaining / self.cost.max(1);
+        let delay = Duration::from_millis(window_ms / calls_left.max(1));
+        Some(delay.min(MAX_THROTTLE)).filter(|d| !d.is_zero())
+    }
+}
+
+fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+impl RateLimitStatus {
+    /// Read the budget from response headers; `None` if the server sent none
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let status = Self {
+            requests_limit: header(headers, "x-ratelimit-requests-limit"),
+            requests_remaining: header(headers, "x-ratelimit-requests-remaining"),
+            requests_reset: header(headers, "x-ratelimit-requests-reset"),
+            complexity_limit: header(headers, "x-ratelimit-complexity-limit"),
+            complexity_remaining: header(headers, "x-ratelimit-complexity-remaining"),
+            complexity_reset: header(headers, "x-ratelimit-complexity-reset"),
+            last_complexity: header(headers, "x-complexity"),
+        };
+        (status != Self::default()).then_some(status)
+    }
+
+    fn budgets(&self) -> impl Iterator<Item = Budget> {
+        let requests = match (
+            self.requests_limit,
+            self.requests_remaining,
+            self.requests_reset,
+        ) {
+            (Some(limit), Some(remaining), Some(reset)) => Some(Budget {
+                limit,
+                remaining,
+                reset,
+                cost: 1,
+            }),
+            _ => None,
+        };
+        let complexity = match (
+            self.complexity_limit,
+            self.complexity_remaining,
+            self.complexity_reset,
+        ) {
+            (Some(limit), Some(remaining), Some(reset)) => Some(Budget {
+                limit,
+                remaining,
+                reset,
+                cost: self.last_complexity.unwrap_or(1),
+            }),
+            _ => None,
+        };
+        requests.into_iter().chain(complexity)
+    }
+
+    /// How long to wait before the next request so the budget lasts until it resets
+    pub fn throttle_delay(&self, now_ms: i64) -> Option<Duration> {
+        self.budgets().filter_map(|b| b.delay(now_ms)).max()
+    }
+
+    /// Concurrency for fan-out work: serial once either budget runs low
+    pub fn concurrency(&self, desired: usize) -> usize {
+        if self.budgets().any(|b| b.is_low()) {
+            1
+        } else {
+            desired
+        }
+    }
+
+    /// Seconds until the exhausted budget resets, for `Retry-After`-style hints
+    pub fn retry_after_secs(&self, now_ms: i64) -> Option<u64> {
+        self.budgets()
+            .filter(|b| b.remaining < b.cost)
+            .map(|b| ((b.reset - now_ms).max(0) as u64).div_ceil(1000))
+            .max()
+    }
+
+    pub fn to_json(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+}
+
+/// True when a GraphQL error list contains Linear's `RATELIMITED` error
+pub fn is_rate_limited(errors: &Value) -> bool {
+    errors.as_array().is_some_and(|errors| {
+        errors
+            .iter()
+            .any(|e| e["extensions"]["code"].as_str() == Some("RATELIMITED"))
+    })
+}
+
+static LAST_STATUS: Mutex<Option<RateLimitStatus>> = Mutex::new(None);
+
+/// Remember the latest budget seen by any client, for structured error output
+pub fn record(status: &RateLimitStatus) {
+    *LAST_STATUS.lock().unwrap() = Some(status.clone());
+}
+
+/// The latest budget seen by any client in this process
+pub fn last() -> Option<RateLimitStatus> {
+    LAST_STATUS.lock().unwrap().clone()
+}
+
+pub fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn test_from_headers() {
+        assert_eq!(RateLimitStatus::from_headers(&HeaderMap::new()), None);
+        let status = RateLimitStatus::from_headers(&headers(&[
+            ("x-ratelimit-requests-limit", "1500"),
+            ("x-ratelimit-requests-remaining", "1499"),
+            ("x-ratelimit-requests-reset", "1700000000000"),
+            ("x-complexity", "12"),
+        ]))
+        .unwrap();
+        assert_eq!(status.requests_limit, Some(1500));
+        assert_eq!(status.requests_remaining, Some(1499));
+        assert_eq!(status.requests_reset, Some(1_700_000_000_000));
+        assert_eq!(status.last_complexity, Some(12));
+        assert_eq!(status.complexity_limit, None);
+    }
+
+    #[test]
+    fn test_throttle_only_when_budget_is_low() {
+        let now = 1_000_000;
+        let mut status = RateLimitStatus {
+            requests_limit: Some(1000),
+            requests_remaining: Some(900),
+            requests_reset: Some(now + 60_000),
+            ..Default::default()
+        };
+        assert_eq!(status.throttle_delay(now), None);
+        assert_eq!(status.concurrency(10), 10);
+
+        // 10 requests left for 60s: one every 6s
+        status.requests_remaining = Some(10);
+        assert_eq!(status.throttle_delay(now), Some(Duration::from_secs(6)));
+        assert_eq!(status.concurrency(10), 1);
+
+        // Exhausted: wait for the reset, capped
+        status.requests_remaining = Some(0);
+        status.requests_reset = Some(now + 3_600_000);
+        assert_eq!(status.throttle_delay(now), Some(MAX_THROTTLE));
+        assert_eq!(status.retry_after_secs(now), Some(3600));
+    }
+
+    #[test]
+    fn test_complexity_budget_uses_last_query_cost() {
+        let now = 0;
+        let status = RateLimitStatus {
+            complexity_limit: Some(250_000),
+            complexity_remaining: Some(2_000),
+            complexity_reset: Some(10_000),
+            last_complexity: Some(1_000),
+            ..Default::default()
+        };
+        // Two more queries of this cost fit in 10s
+        assert_eq!(status.throttle_delay(now), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_is_rate_limited() {
+        let errors = serde_json::json!([{ "message": "Rate limit exceeded", "extensions": { "code": "RATELIMITED" } }]);
+        assert!(is_rate_limited(&errors));
+        assert!(!is_rate_limited(
+            &serde_json::json!([{ "message": "nope" }])
+        ));
+    }
+}
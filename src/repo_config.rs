@@ -0,0 +1,231 @@
+//! Repository-local configuration from a `.linear.toml`, discovered upward from
+//! the working directory the same way git finds `.git`.
+//!
+//! ```toml
+//! profile = "work"
+//! team = "MOB"
+//! project = "Mobile App"
+//! labels = ["mobile"]
+//! branch_template = "{team}/{id}-{slug}"
+//! pr_base = "develop"
+//! ```
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub const FILE_NAME: &str = ".linear.toml";
+
+/// Keys understood in a `.linear.toml`; anything else is ignored with a warning
+const KEYS: &[&str] = &[
+    "profile",
+    "team",
+    "project",
+    "labels",
+    "branch_template",
+    "pr_base",
+];
+
+#[derive(Debug, Default, Clone, Deserialize, PartialEq)]
+pub struct RepoConfig {
+    /// Workspace profile to use inside this tree
+    pub profile: Option<String>,
+    /// Team key, name, or ID used where a team is required
+    pub team: Option<String>,
+    /// Project for new issues created without an explicit team
+    pub project: Option<String>,
+    /// Labels added to every issue created inside this tree
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Branch name template for `git checkout` / `git create`
+    pub branch_template: Option<String>,
+    /// Base branch for `git pr`
+    pub pr_base: Option<String>,
+    /// Where this config was found
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+impl RepoConfig {
+    /// Parse a config, returning the unknown keys that were skipped alongside it.
+    ///
+    /// A bad `branch_template` is kept as-is; the git commands that use it report it.
+    fn parse(content: &str, path: &Path) -> Result<(Self, Vec<String>)> {
+        let mut table: toml::Table =
+            toml::from_str(content).with_context(|| format!("Invalid {}", path.display()))?;
+        let unknown: Vec<String> = table
+            .keys()
+            .filter(|key| !KEYS.contains(&key.as_str()))
+            .cloned()
+            .collect();
+        for key in &unknown {
+            table.remove(key);
+        }
+        let mut config: RepoConfig = toml::Value::Table(table)
+            .try_into()
+            .with_context(|| format!("Invalid {}", path.display()))?;
+        config.path = path.to_path_buf();
+        Ok((config, unknown))
+    }
+}
+
+/// Find and parse the nearest `.linear.toml` at or above `start`
+pub fn discover(start: &Path) -> Result<Option<RepoConfig>> {
+    for dir in start.ancestors() {
+        let path = dir.join(FILE_NAME);
+        if path.is_file() {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let (config, unknown) = RepoConfig::parse(&content, &path)?;
+            for key in unknown {
+                eprintln!(
+                    "Warning: Ignoring unknown key '{}' in {}",
+                    key,
+                    path.display()
+                );
+            }
+            return Ok(Some(config));
+        }
+    }
+    Ok(None)
+}
+
+static REPO_CONFIG: OnceLock<Option<RepoConfig>> = OnceLock::new();
+
+/// Discover the repository config for the current directory (once per process).
+///
+/// Runs before argument parsing, so an unreadable or invalid file is reported as
+/// a warning and ignored rather than breaking every command (including `--help`).
+pub fn load() -> Option<&'static RepoConfig> {
+    if let Some(config) = REPO_CONFIG.get() {
+        return config.as_ref();
+    }
+    let found = match std::env::current_dir().map(|dir| discover(&dir)) {
+        Ok(Ok(found)) => found,
+        Ok(Err(e)) => {
+            eprintln!("Warning: {:#}; ignoring it", e);
+            None
+        }
+        Err(_) => None,
+    };
+    REPO_CONFIG.get_or_init(|| found).as_ref()
+}
+
+/// The repository config, if one was found by `load`
+pub fn current() -> Option<&'static RepoConfig> {
+    REPO_CONFIG.get().and_then(|c| c.as_ref())
+}
+
+const BRANCH_PLACEHOLDERS: &[&str] = &["id", "identifier", "team", "number", "slug"];
+
+fn check_branch_template(template: &str) -> Result<()> {
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .context("Unclosed '{' in branch template")?;
+        let name = &rest[start + 1..start + end];
+        if !BRANCH_PLACEHOLDERS.contains(&name) {
+            anyhow::bail!(
+                "Unknown placeholder '{{{}}}'. Available: {}",
+                name,
+                BRANCH_PLACEHOLDERS
+                    .iter()
+                    .map(|p| format!("{{{}}}", p))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        rest = &rest[start + end + 1..];
+    }
+    if !template.contains("{id}") && !template.contains("{identifier}") {
+        anyhow::bail!("Branch template must include {{id}} or {{identifier}} so `linear done` can find the issue");
+    }
+    Ok(())
+}
+
+/// Render a branch template for an issue.
+///
+/// `{identifier}` is the issue key as-is (ENG-123), `{id}` its lowercase form,
+/// `{team}` and `{number}` its two halves, and `{slug}` the kebab-cased title.
+pub fn render_branch_template(template: &str, identifier: &str, title: &str) -> Result<String> {
+    check_branch_template(template)?;
+    let (team, number) = identifier.split_once('-').unwrap_or((identifier, ""));
+    Ok(template
+        .replace("{identifier}", identifier)
+        .replace("{id}", &identifier.to_lowercase())
+        .replace("{team}", team)
+        .replace("{number}", number)
+        .replace("{slug}", &crate::vcs::slugify(title)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover_walks_up_to_nearest_file() {
+        let root = std::env::temp_dir().join(format!("linear-cli-repo-{}", std::process::id()));
+        let nested = root.join("apps/mobile/src");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join(FILE_NAME), "team = \"ENG\"\n").unwrap();
+        fs::write(
+            root.join("apps/mobile").join(FILE_NAME),
+            "team = \"MOB\"\nlabels = [\"mobile\"]\n",
+        )
+        .unwrap();
+
+        let found = discover(&nested).unwrap().unwrap();
+        assert_eq!(found.team.as_deref(), Some("MOB"));
+        assert_eq!(found.labels, vec!["mobile".to_string()]);
+        assert_eq!(found.path, root.join("apps/mobile").join(FILE_NAME));
+
+        let found = discover(&root.join("apps")).unwrap().unwrap();
+        assert_eq!(found.team.as_deref(), Some("ENG"));
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_parse_skips_unknown_keys_and_rejects_bad_types() {
+        let path = Path::new(".linear.toml");
+        let (config, unknown) =
+            RepoConfig::parse("teams = \"ENG\"\nproject = \"Web\"\n", path).unwrap();
+        assert_eq!(unknown, vec!["teams".to_string()]);
+        assert_eq!(config.project.as_deref(), Some("Web"));
+        assert_eq!(config.team, None);
+
+        let err = RepoConfig::parse("labels = \"mobile\"\n", path).unwrap_err();
+        assert!(
+            format!("{:#}", err).contains("Invalid .linear.toml"),
+            "{:#}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_branch_template_errors() {
+        let err = check_branch_template("{slug}").unwrap_err();
+        assert!(format!("{:#}", err).contains("{id} or {identifier}"));
+
+        let err = check_branch_template("{id}-{title}").unwrap_err();
+        assert!(format!("{:#}", err).contains("Unknown placeholder '{title}'"));
+    }
+
+    #[test]
+    fn test_render_branch_template() {
+        assert_eq!(
+            render_branch_template("{team}/{id}-{slug}", "MOB-42", "Fix login: redirect!").unwrap(),
+            "MOB/mob-42-fix-login-redirect"
+        );
+        assert_eq!(
+            render_branch_template("feature/{identifier}", "ENG-7", "x").unwrap(),
+            "feature/ENG-7"
+        );
+        assert_eq!(
+            render_branch_template("{number}-{id}", "ENG-7", "x").unwrap(),
+            "7-eng-7"
+        );
+    }
+}
@@ -75,7 +75,11 @@ where
         match f().await {
             Ok(result) => return Ok(result),
             Err(e) => {
-                if attempt < config.max_retries && e.is_retryable() {
+                // A reset further out than the longest backoff is reported, not waited out
+                let waits_too_long = e
+                    .retry_after()
+                    .is_some_and(|secs| secs.saturating_mul(1000) > config.max_delay_ms);
+                if attempt < config.max_retries && e.is_retryable() && !waits_too_long {
                     let retry_after = e.retry_after();
                     let delay = config.delay_for_attempt(attempt, retry_after);
                     if !crate::output::is_quiet() {
@@ -126,6 +130,15 @@ impl IsRetryable for anyhow::Error {
         if let Some(cli) = self.downcast_ref::<CliError>() {
             return cli.is_retryable();
         }
+        // Refused connections, timeouts and dropped responses surface as
+        // reqwest errors, whose messages don't name the cause
+        if self
+            .chain()
+            .filter_map(|cause| cause.downcast_ref::<reqwest::Error>())
+            .any(|e| e.is_timeout() || e.is_connect() || e.is_request())
+        {
+            return true;
+        }
         let msg = self.to_string().to_lowercase();
         // Retry on rate limits, timeouts, and transient network errors
         msg.contains("rate limit")
@@ -237,4 +250,28 @@ mod tests {
         let err_no_retry = CliError::general("Error");
         assert_eq!(err_no_retry.retry_after(), None);
     }
+
+    #[tokio::test]
+    async fn test_refused_connection_is_retryable() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let err = reqwest::Client::new().get(&url).send().await.unwrap_err();
+        let err = anyhow::Error::from(err).context("Failed to reach Linear");
+        assert!(err.is_retryable());
+        assert!(!anyhow::anyhow!("Invalid input").is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_gives_up_when_reset_is_far_away() {
+        let mut attempts = 0;
+        let result: Result<(), CliError> = with_retry(&RetryConfig::new(3), || {
+            attempts += 1;
+            async { Err(CliError::rate_limited("Rate limited").with_retry_after(Some(3600))) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
 }
@@ -0,0 +1,632 @@
+//! Cached GraphQL schema for raw `api` queries (`api schema pull`).
+//!
+//! The introspection result is reduced to type names, fields, arguments, input
+//! fields and enum values, and stored per profile next to the other caches. It is
+//! used to validate queries before they are sent, to coerce `-v key=value`
+//! variables to their declared types, and to complete type and field names.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+use crate::cache;
+use crate::graphql::{Document, Operation, Selection};
+
+pub const INTROSPECTION_QUERY: &str = r#"
+    query IntrospectionQuery {
+        __schema {
+            queryType { name }
+            mutationType { name }
+            types {
+                kind
+                name
+                fields(includeDeprecated: true) {
+                    name
+                    args { name defaultValue type { ...TypeRef } }
+                    type { ...TypeRef }
+                }
+                inputFields { name defaultValue type { ...TypeRef } }
+                enumValues(includeDeprecated: true) { name }
+                possibleTypes { name }
+            }
+        }
+    }
+    fragment TypeRef on __Type {
+        kind name
+        ofType { kind name ofType { kind name ofType { kind name ofType { kind name } } } }
+    }
+"#;
+
+const BUILTIN_SCALARS: &[&str] = &["String", "ID", "Int", "Float", "Boolean"];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Schema {
+    pub pulled_at: String,
+    pub query_type: String,
+    #[serde(default)]
+    pub mutation_type: Option<String>,
+    pub types: BTreeMap<String, TypeDef>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TypeDef {
+    /// OBJECT, INTERFACE, UNION, INPUT_OBJECT, ENUM or SCALAR
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, FieldDef>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub input_fields: BTreeMap<String, InputValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enum_values: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub possible_types: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldDef {
+    /// Type as written in SDL, e.g. `[Issue!]!`
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub args: BTreeMap<String, InputValue>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputValue {
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub has_default: bool,
+}
+
+impl InputValue {
+    pub fn is_required(&self) -> bool {
+        self.ty.ends_with('!') && !self.has_default
+    }
+}
+
+/// Render an introspection `__Type` reference as SDL (`[String!]!`)
+fn type_ref(value: &Value) -> String {
+    match value["kind"].as_str() {
+        Some("NON_NULL") => format!("{}!", type_ref(&value["ofType"])),
+        Some("LIST") => format!("[{}]", type_ref(&value["ofType"])),
+        _ => value["name"].as_str().unwrap_or("?").to_string(),
+    }
+}
+
+/// The named type inside list and non-null wrappers
+pub fn named_type(ty: &str) -> &str {
+    ty.trim_matches(|c| c == '[' || c == ']' || c == '!')
+}
+
+fn input_values(values: &Value) -> BTreeMap<String, InputValue> {
+    values
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| {
+            Some((
+                v["name"].as_str()?.to_string(),
+                InputValue {
+                    ty: type_ref(&v["type"]),
+                    has_default: !v["defaultValue"].is_null(),
+                },
+            ))
+        })
+        .collect()
+}
+
+fn names(values: &Value) -> Vec<String> {
+    values
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v["name"].as_str().map(str::to_string))
+        .collect()
+}
+
+impl Schema {
+    /// Reduce an introspection response (`{ data: { __schema } }` or `{ __schema }`)
+    pub fn from_introspection(result: &Value) -> Result<Self> {
+        let schema = result
+            .pointer("/data/__schema")
+            .or_else(|| result.get("__schema"))
+            .context("Introspection result has no __schema")?;
+        let query_type = schema["queryType"]["name"]
+            .as_str()
+            .context("Introspection result has no query type")?
+            .to_string();
+
+        let mut types = BTreeMap::new();
+        for ty in schema["types"].as_array().into_iter().flatten() {
+            let Some(name) = ty["name"].as_str() else {
+                continue;
+            };
+            if name.starts_with("__") {
+                continue;
+            }
+            let fields = ty["fields"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|f| {
+                    Some((
+                        f["name"].as_str()?.to_string(),
+                        FieldDef {
+                            ty: type_ref(&f["type"]),
+                            args: input_values(&f["args"]),
+                        },
+                    ))
+                })
+                .collect();
+            types.insert(
+                name.to_string(),
+                TypeDef {
+                    kind: ty["kind"].as_str().unwrap_or("").to_string(),
+                    fields,
+                    input_fields: input_values(&ty["inputFields"]),
+                    enum_values: names(&ty["enumValues"]),
+                    possible_types: names(&ty["possibleTypes"]),
+                },
+            );
+        }
+
+        Ok(Self {
+            pulled_at: chrono::Utc::now().to_rfc3339(),
+            query_type,
+            mutation_type: schema["mutationType"]["name"].as_str().map(str::to_string),
+            types,
+        })
+    }
+
+    fn root_type(&self, kind: &str) -> Option<&str> {
+        match kind {
+            "query" => Some(&self.query_type),
+            "mutation" => self.mutation_type.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Check every operation in a document against the schema.
+    /// Returns one message per problem found.
+    pub fn validate(&self, document: &Document) -> Vec<String> {
+        let mut errors = Vec::new();
+        for op in &document.operations {
+            self.validate_operation(document, op, &mut errors);
+        }
+        errors
+    }
+
+    fn validate_operation(&self, document: &Document, op: &Operation, errors: &mut Vec<String>) {
+        for var in &op.variables {
+            let named = named_type(&var.ty);
+            if !BUILTIN_SCALARS.contains(&named) && !self.types.contains_key(named) {
+                errors.push(format!(
+                    "Unknown type '{}' for variable '${}'{}",
+                    named,
+                    var.name,
+                    suggestion(named, self.types.keys())
+                ));
+            }
+        }
+        let Some(root) = self.root_type(&op.kind) else {
+            if op.kind == "mutation" {
+                errors.push("Schema has no mutation type".to_string());
+            }
+            return;
+        };
+        let mut visited = HashSet::new();
+        self.validate_selections(document, root, &op.selections, &mut visited, errors);
+    }
+
+    fn validate_selections(
+        &self,
+        document: &Document,
+        type_name: &str,
+        selections: &[Selection],
+        visited: &mut HashSet<String>,
+        errors: &mut Vec<String>,
+    ) {
+        let Some(ty) = self.types.get(type_name) else {
+            return;
+        };
+        for selection in selections {
+            match selection {
+                Selection::Field {
+                    name,
+                    arguments,
+                    selections,
+                } => {
+                    if name.starts_with("__") {
+                        continue;
+                    }
+                    let Some(field) = ty.fields.get(name) else {
+                        errors.push(format!(
+                            "Unknown field '{}' on type '{}'{}",
+                            name,
+                            type_name,
+                            suggestion(name, ty.fields.keys())
+                        ));
+                        continue;
+                    };
+                    let path = format!("{}.{}", type_name, name);
+                    for arg in arguments {
+                        if !field.args.contains_key(arg) {
+                            errors.push(format!(
+                                "Unknown argument '{}' on field '{}'{}",
+                                arg,
+                                path,
+                                suggestion(arg, field.args.keys())
+                            ));
+                        }
+                    }
+                    for (arg, def) in &field.args {
+                        if def.is_required() && !arguments.contains(arg) {
+                            errors.push(format!(
+                                "Field '{}' requires argument '{}: {}'",
+                                path, arg, def.ty
+                            ));
+                        }
+                    }
+
+                    let target = named_type(&field.ty);
+                    let composite = self.types.get(target).is_some_and(|t| {
+                        matches!(t.kind.as_str(), "OBJECT" | "INTERFACE" | "UNION")
+                    });
+                    if composite && selections.is_empty() {
+                        errors.push(format!(
+                            "Field '{}' of type '{}' needs a selection of subfields",
+                            path, field.ty
+                        ));
+                    } else if !composite && !selections.is_empty() {
+                        errors.push(format!(
+                            "Field '{}' of type '{}' cannot have subfields",
+                            path, field.ty
+                        ));
+                    } else {
+                        self.validate_selections(document, target, selections, visited, errors);
+                    }
+                }
+                Selection::InlineFragment {
+                    type_condition,
+                    selections,
+                } => {
+                    let target = type_condition.as_deref().unwrap_or(type_name);
+                    if !self.types.contains_key(target) {
+                        errors.push(format!(
+                            "Unknown type '{}' in inline fragment{}",
+                            target,
+                            suggestion(target, self.types.keys())
+                        ));
+                        continue;
+                    }
+                    self.validate_selections(document, target, selections, visited, errors);
+                }
+                Selection::FragmentSpread(name) => {
+                    let Some(fragment) = document.fragment(name) else {
+                        errors.push(format!("Unknown fragment '{}'", name));
+                        continue;
+                    };
+                    if !visited.insert(name.clone()) {
+                        continue;
+                    }
+                    if !self.types.contains_key(&fragment.type_condition) {
+                        errors.push(format!(
+                            "Unknown type '{}' in fragment '{}'{}",
+                            fragment.type_condition,
+                            name,
+                            suggestion(&fragment.type_condition, self.types.keys())
+                        ));
+                        continue;
+                    }
+                    self.validate_selections(
+                        document,
+                        &fragment.type_condition,
+                        &fragment.selections,
+                        visited,
+                        errors,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Edit distance used for "did you mean" hints
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            row.push((prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+fn suggestion<'a>(name: &str, candidates: impl Iterator<Item = &'a String>) -> String {
+    let lower = name.to_lowercase();
+    candidates
+        .map(|c| (distance(&lower, &c.to_lowercase()), c))
+        .filter(|(d, _)| *d <= 2.max(name.len() / 4))
+        .min()
+        .map(|(_, c)| format!(" (did you mean '{}'?)", c))
+        .unwrap_or_default()
+}
+
+/// Coerce a `-v` string to the JSON value for a declared GraphQL type.
+/// Without a schema, built-in scalars are still coerced and other types fall
+/// back to JSON when the value parses as an object or array. `null` becomes
+/// JSON null only for nullable types; a non-null type gets the literal text.
+pub fn coerce(raw: &str, ty: &str, schema: Option<&Schema>) -> Result<Value> {
+    if let Some(inner) = ty.strip_suffix('!') {
+        return coerce_value(raw, inner, schema);
+    }
+    if raw == "null" {
+        return Ok(Value::Null);
+    }
+    coerce_value(raw, ty, schema)
+}
+
+fn coerce_value(raw: &str, ty: &str, schema: Option<&Schema>) -> Result<Value> {
+    if let Some(item_ty) = ty.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        let items: Vec<String> = if raw.trim_start().starts_with('[') {
+            let parsed: Vec<Value> = serde_json::from_str(raw)
+                .with_context(|| format!("expects {}, got invalid JSON list", ty))?;
+            parsed
+                .into_iter()
+                .map(|v| match v {
+                    Value::String(s) => s,
+                    other => other.to_string(),
+                })
+                .collect()
+        } else if raw.is_empty() {
+            Vec::new()
+        } else {
+            raw.split(',').map(|s| s.trim().to_string()).collect()
+        };
+        return items
+            .iter()
+            .map(|item| coerce(item, item_ty, schema))
+            .collect::<Result<Vec<_>>>()
+            .map(Value::Array);
+    }
+
+    match ty {
+        "String" | "ID" => Ok(json!(raw)),
+        "Int" => raw
+            .trim()
+            .parse::<i64>()
+            .map(|n| json!(n))
+            .map_err(|_| anyhow::anyhow!("expects Int, got '{}'", raw)),
+        "Float" => raw
+            .trim()
+            .parse::<f64>()
+            .map(|n| json!(n))
+            .map_err(|_| anyhow::anyhow!("expects Float, got '{}'", raw)),
+        "Boolean" => match raw.trim().to_ascii_lowercase().as_str() {
+            "true" => Ok(json!(true)),
+            "false" => Ok(json!(false)),
+            _ => bail!("expects Boolean (true/false), got '{}'", raw),
+        },
+        named => {
+            let def = schema.and_then(|s| s.types.get(named));
+            match def.map(|d| d.kind.as_str()) {
+                Some("ENUM") => {
+                    let values = &def.unwrap().enum_values;
+                    if values.iter().any(|v| v == raw) {
+                        Ok(json!(raw))
+                    } else {
+                        bail!(
+                            "expects {} (one of: {}), got '{}'",
+                            named,
+                            values.join(", "),
+                            raw
+                        )
+                    }
+                }
+                Some("INPUT_OBJECT") => match serde_json::from_str::<Value>(raw) {
+                    Ok(value @ Value::Object(_)) => Ok(value),
+                    _ => bail!("expects {} as a JSON object, got '{}'", named, raw),
+                },
+                _ if named.contains("JSON") => serde_json::from_str(raw)
+                    .with_context(|| format!("expects {} as JSON, got '{}'", named, raw)),
+                Some(_) => Ok(json!(raw)),
+                None => match serde_json::from_str::<Value>(raw) {
+                    Ok(value @ (Value::Object(_) | Value::Array(_))) => Ok(value),
+                    _ => Ok(json!(raw)),
+                },
+            }
+        }
+    }
+}
+
+/// Where the schema for the current profile is cached
+pub fn path() -> Result<PathBuf> {
+    Ok(cache::cache_dir_path()?.join("schema.json"))
+}
+
+/// Load the cached schema, if `api schema pull` has been run
+pub fn load() -> Result<Option<Schema>> {
+    let path = path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let schema = serde_json::from_str(&content).with_context(|| {
+        format!(
+            "Invalid schema cache {} (run `linear-cli api schema pull`)",
+            path.display()
+        )
+    })?;
+    Ok(Some(schema))
+}
+
+pub fn save(schema: &Schema) -> Result<PathBuf> {
+    let path = path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    crate::cache::write_atomic(&path, &serde_json::to_string(schema)?)?;
+    Ok(path)
+}
+
+/// Completion candidates: type names, or `Type.field` once the prefix has a dot
+pub fn completions(schema: &Schema, prefix: &str) -> Vec<(String, String)> {
+    match prefix.split_once('.') {
+        Some((type_name, field_prefix)) => {
+            let Some(ty) = schema.types.get(type_name) else {
+                return Vec::new();
+            };
+            let fields = ty
+                .fields
+                .iter()
+                .map(|(name, f)| (name, f.ty.as_str()))
+                .chain(
+                    ty.input_fields
+                        .iter()
+                        .map(|(name, f)| (name, f.ty.as_str())),
+                );
+            fields
+                .filter(|(name, _)| name.starts_with(field_prefix))
+                .map(|(name, ty)| (format!("{}.{}", type_name, name), ty.to_string()))
+                .collect()
+        }
+        None => schema
+            .types
+            .iter()
+            .filter(|(name, _)| name.starts_with(prefix))
+            .map(|(name, ty)| (name.clone(), ty.kind.to_lowercase()))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphql;
+
+    fn schema() -> Schema {
+        let introspection = json!({ "data": { "__schema": {
+            "queryType": { "name": "Query" },
+            "mutationType": null,
+            "types": [
+                { "kind": "OBJECT", "name": "Query", "fields": [
+                    { "name": "issue", "args": [
+                        { "name": "id", "defaultValue": null, "type": { "kind": "NON_NULL", "ofType": { "kind": "SCALAR", "name": "String" } } }
+                    ], "type": { "kind": "OBJECT", "name": "Issue" } },
+                    { "name": "viewer", "args": [], "type": { "kind": "NON_NULL", "ofType": { "kind": "OBJECT", "name": "User" } } }
+                ] },
+                { "kind": "OBJECT", "name": "Issue", "fields": [
+                    { "name": "id", "args": [], "type": { "kind": "SCALAR", "name": "ID" } },
+                    { "name": "title", "args": [], "type": { "kind": "SCALAR", "name": "String" } },
+                    { "name": "assignee", "args": [], "type": { "kind": "OBJECT", "name": "User" } }
+                ] },
+                { "kind": "OBJECT", "name": "User", "fields": [
+                    { "name": "name", "args": [], "type": { "kind": "SCALAR", "name": "String" } }
+                ] },
+                { "kind": "ENUM", "name": "Order", "enumValues": [{ "name": "createdAt" }, { "name": "updatedAt" }] },
+                { "kind": "INPUT_OBJECT", "name": "IssueFilter", "inputFields": [] },
+                { "kind": "SCALAR", "name": "JSONObject" },
+                { "kind": "OBJECT", "name": "__Type", "fields": [] }
+            ]
+        } } });
+        Schema::from_introspection(&introspection).unwrap()
+    }
+
+    #[test]
+    fn test_from_introspection() {
+        let schema = schema();
+        assert_eq!(schema.query_type, "Query");
+        assert!(!schema.types.contains_key("__Type"));
+        let issue = &schema.types["Query"].fields["issue"];
+        assert_eq!(issue.ty, "Issue");
+        assert_eq!(issue.args["id"].ty, "String!");
+        assert!(issue.args["id"].is_required());
+        assert_eq!(
+            schema.types["Order"].enum_values,
+            vec!["createdAt", "updatedAt"]
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let schema = schema();
+        let ok = graphql::parse(
+            "query($id: String!) { issue(id: $id) { id ...F assignee { name } __typename } }
+             fragment F on Issue { title }",
+        )
+        .unwrap();
+        assert!(schema.validate(&ok).is_empty());
+
+        let bad =
+            graphql::parse("query($f: IssueFiltr) { issue(ids: 1) { titel assignee } viewer }")
+                .unwrap();
+        assert_eq!(
+            schema.validate(&bad),
+            vec![
+                "Unknown type 'IssueFiltr' for variable '$f' (did you mean 'IssueFilter'?)",
+                "Unknown argument 'ids' on field 'Query.issue' (did you mean 'id'?)",
+                "Field 'Query.issue' requires argument 'id: String!'",
+                "Unknown field 'titel' on type 'Issue' (did you mean 'title'?)",
+                "Field 'Issue.assignee' of type 'User' needs a selection of subfields",
+                "Field 'Query.viewer' of type 'User!' needs a selection of subfields",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coerce() {
+        let schema = schema();
+        let s = Some(&schema);
+        assert_eq!(coerce("123", "String!", s).unwrap(), json!("123"));
+        assert_eq!(coerce("5", "Int", s).unwrap(), json!(5));
+        assert_eq!(coerce("0.5", "Float", None).unwrap(), json!(0.5));
+        assert_eq!(coerce("TRUE", "Boolean", None).unwrap(), json!(true));
+        assert_eq!(coerce("null", "Int", None).unwrap(), Value::Null);
+        assert_eq!(coerce("a, b", "[ID!]", None).unwrap(), json!(["a", "b"]));
+        assert_eq!(coerce("[1, 2]", "[Int!]!", None).unwrap(), json!([1, 2]));
+        assert_eq!(coerce("createdAt", "Order", s).unwrap(), json!("createdAt"));
+        assert_eq!(
+            coerce(r#"{"a":1}"#, "JSONObject", s).unwrap(),
+            json!({ "a": 1 })
+        );
+        assert_eq!(
+            coerce(r#"{"title":{"eq":"x"}}"#, "IssueFilter", None).unwrap(),
+            json!({ "title": { "eq": "x" } })
+        );
+
+        assert!(coerce("five", "Int", None).is_err());
+        assert_eq!(coerce("null", "String!", None).unwrap(), json!("null"));
+        assert!(coerce("null", "Int!", None).is_err());
+        assert!(coerce("yes", "Boolean", None).is_err());
+        let err = coerce("name", "Order", s).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "expects Order (one of: createdAt, updatedAt), got 'name'"
+        );
+        assert!(coerce("title=x", "IssueFilter", s).is_err());
+    }
+
+    #[test]
+    fn test_completions() {
+        let schema = schema();
+        assert_eq!(
+            completions(&schema, "Iss"),
+            vec![
+                ("Issue".to_string(), "object".to_string()),
+                ("IssueFilter".to_string(), "input_object".to_string())
+            ]
+        );
+        assert_eq!(
+            completions(&schema, "Issue.t"),
+            vec![("Issue.title".to_string(), "String".to_string())]
+        );
+    }
+}
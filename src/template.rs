@@ -0,0 +1,951 @@
+//! `--format` / `--format-file` templates.
+//!
+//! ```text
+//! {{identifier}} {{title | truncate 40}} [{{assignee.name | default "-"}}]
+//! {{#if dueDate}}due {{dueDate | date "%b %d"}}{{else}}no due date{{/if}}
+//! {{#each labels.nodes}}{{name}}{{#unless @last}}, {{/unless}}{{/each}}
+//! ```
+//!
+//! Tags hold a path (`a.b`, `.`, `this`, `../x`, `@index`, `@key`, `@first`,
+//! `@last`) or a literal, piped through helpers (`value | helper arg`); the
+//! prefix form `helper value arg` works too. Paths step into arrays, so
+//! `labels.nodes.name` yields every label name. Blocks are `#if`, `#unless`,
+//! `#each` and `#with`, each with an optional `{{else}}`; `#if` also takes
+//! `{{else if ...}}`. `{{! comment }}` is dropped, `{{~`/`~}}` trim adjacent
+//! whitespace, and block tags alone on a line don't leave blank lines behind.
+
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+
+use crate::text::truncate;
+
+/// Helpers as `name args` (shown in error messages)
+pub const HELPERS: &[&str] = &[
+    "and VALUE",
+    "contains VALUE",
+    "date [FORMAT]",
+    "default VALUE",
+    "eq VALUE",
+    "first",
+    "gt VALUE",
+    "gte VALUE",
+    "join [SEPARATOR]",
+    "json",
+    "last",
+    "length",
+    "lower",
+    "lt VALUE",
+    "lte VALUE",
+    "ne VALUE",
+    "not",
+    "or VALUE",
+    "pad WIDTH",
+    "priority_name",
+    "replace FROM TO",
+    "trim",
+    "truncate WIDTH",
+    "upper",
+];
+
+/// A parsed template
+#[derive(Debug, Clone)]
+pub struct Template {
+    nodes: Vec<Node>,
+    document: bool,
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Result<Template> {
+        let nodes = parse(source).map_err(|e| anyhow::anyhow!("Invalid template: {}", e))?;
+        Ok(Template {
+            nodes,
+            document: false,
+        })
+    }
+
+    /// Render once against the whole result instead of once per list item
+    pub fn into_document(self) -> Template {
+        Template {
+            document: true,
+            ..self
+        }
+    }
+
+    pub fn is_document(&self) -> bool {
+        self.document
+    }
+
+    pub fn render(&self, value: &Value) -> Result<String> {
+        let mut out = String::new();
+        let scope = vec![Frame::new(value.clone())];
+        render_nodes(&self.nodes, &scope, &mut out)?;
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    /// `../` count and path parts; empty parts is the current value
+    Path(usize, Vec<String>),
+    Literal(Value),
+    Special(String),
+}
+
+/// A helper applied to the piped value plus its own arguments
+#[derive(Debug, Clone)]
+struct Call {
+    helper: String,
+    args: Vec<Operand>,
+}
+
+#[derive(Debug, Clone)]
+struct Pipeline {
+    head: Operand,
+    calls: Vec<Call>,
+}
+
+#[derive(Debug, Clone)]
+enum Block {
+    If,
+    Unless,
+    Each,
+    With,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    Expr(Pipeline),
+    Block {
+        kind: Block,
+        target: Pipeline,
+        body: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    Tag(String),
+}
+
+/// Split the source into alternating text and tag segments (always starting
+/// and ending with text), applying `~` trimming and dropping comments
+fn lex(source: &str) -> Result<Vec<Segment>> {
+    let mut segments = vec![Segment::Text(String::new())];
+    let mut rest = source;
+    let mut trim_next = false;
+    while let Some(start) = rest.find("{{") {
+        let mut text = rest[..start].to_string();
+        if trim_next {
+            text = text.trim_start().to_string();
+        }
+        push_text(&mut segments, &text);
+        let mut after = &rest[start + 2..];
+        // `{{{x}}}` keeps its outer braces as text (`{value}`), as the old renderer did
+        if after.starts_with('{') && !after.starts_with("{{") {
+            push_text(&mut segments, "{");
+            after = &after[1..];
+        }
+        let (body, consumed) = if after.starts_with("!--") {
+            let end = after
+                .find("--}}")
+                .ok_or_else(|| anyhow::anyhow!("Unclosed comment"))?;
+            (&after[..end + 2], end + 4)
+        } else {
+            let end = after
+                .find("}}")
+                .ok_or_else(|| anyhow::anyhow!("Unclosed '{{{{' tag"))?;
+            (&after[..end], end + 2)
+        };
+        rest = &after[consumed..];
+
+        let trim_before = body.starts_with('~');
+        trim_next = body.ends_with('~') && body.len() > 1;
+        let body = body.strip_prefix('~').unwrap_or(body);
+        let body = if trim_next {
+            body.strip_suffix('~').unwrap_or(body)
+        } else {
+            body
+        };
+        if trim_before {
+            if let Some(Segment::Text(prev)) = segments.last_mut() {
+                *prev = prev.trim_end().to_string();
+            }
+        }
+        let body = body.trim();
+        if body.starts_with('!') {
+            segments.push(Segment::Tag("!".to_string()));
+        } else {
+            segments.push(Segment::Tag(body.to_string()));
+        }
+        segments.push(Segment::Text(String::new()));
+    }
+    let mut text = rest.to_string();
+    if trim_next {
+        text = text.trim_start().to_string();
+    }
+    push_text(&mut segments, &text);
+    strip_standalone(&mut segments);
+    Ok(segments)
+}
+
+fn push_text(segments: &mut [Segment], text: &str) {
+    if let Some(Segment::Text(last)) = segments.last_mut() {
+        last.push_str(text);
+    }
+}
+
+/// Block tags (and comments) alone on a line take the whole line with them
+fn strip_standalone(segments: &mut [Segment]) {
+    // Whether the text before the current tag begins at the start of a line
+    let mut line_start = true;
+    for i in (1..segments.len()).step_by(2) {
+        let Segment::Tag(tag) = &segments[i] else {
+            continue;
+        };
+        let is_block = tag == "!"
+            || tag.starts_with('#')
+            || tag.starts_with('/')
+            || tag == "else"
+            || tag.starts_with("else ");
+        let (Segment::Text(prev), Segment::Text(next)) = (&segments[i - 1], &segments[i + 1])
+        else {
+            continue;
+        };
+        let prev_tail = prev.rfind('\n').map_or(prev.as_str(), |p| &prev[p + 1..]);
+        let prev_ok = prev_tail.trim().is_empty() && (prev.contains('\n') || line_start);
+        let next_line = next.find('\n');
+        let next_head = next_line.map_or(next.as_str(), |p| &next[..p]);
+        let next_ok =
+            next_head.trim().is_empty() && (next_line.is_some() || i + 2 == segments.len());
+
+        if is_block && prev_ok && next_ok {
+            let keep = prev.len() - prev_tail.len();
+            let skip = next_line.map_or(next.len(), |p| p + 1);
+            if let Segment::Text(prev) = &mut segments[i - 1] {
+                prev.truncate(keep);
+            }
+            if let Segment::Text(next) = &mut segments[i + 1] {
+                next.drain(..skip);
+            }
+            line_start = true;
+        } else {
+            line_start = false;
+        }
+    }
+}
+
+fn parse(source: &str) -> Result<Vec<Node>> {
+    let segments = lex(source)?;
+    let mut iter = segments.into_iter();
+    let (nodes, end) = parse_nodes(&mut iter, None)?;
+    if let Some(end) = end {
+        anyhow::bail!("Unexpected '{{{{{}}}}}'", end);
+    }
+    Ok(nodes)
+}
+
+/// Parse until a closing or `else` tag; returns the nodes and the tag that stopped them
+fn parse_nodes(
+    iter: &mut std::vec::IntoIter<Segment>,
+    open: Option<&str>,
+) -> Result<(Vec<Node>, Option<String>)> {
+    let mut nodes = Vec::new();
+    while let Some(segment) = iter.next() {
+        let tag = match segment {
+            Segment::Text(text) => {
+                if !text.is_empty() {
+                    nodes.push(Node::Text(text));
+                }
+                continue;
+            }
+            Segment::Tag(tag) => tag,
+        };
+        if tag == "!" {
+            continue;
+        }
+        if tag.starts_with('/') || tag == "else" || tag.starts_with("else ") {
+            if open.is_none() {
+                anyhow::bail!("Unexpected '{{{{{}}}}}'", tag);
+            }
+            return Ok((nodes, Some(tag)));
+        }
+        if let Some(block) = tag.strip_prefix('#') {
+            nodes.push(parse_block(iter, block)?);
+            continue;
+        }
+        nodes.push(Node::Expr(parse_pipeline(&tag)?));
+    }
+    if let Some(open) = open {
+        anyhow::bail!("Unclosed '{{{{#{}}}}}'", open);
+    }
+    Ok((nodes, None))
+}
+
+fn parse_block(iter: &mut std::vec::IntoIter<Segment>, block: &str) -> Result<Node> {
+    let (name, target) = block
+        .split_once(char::is_whitespace)
+        .map(|(n, t)| (n, t.trim()))
+        .unwrap_or((block, ""));
+    let kind = match name {
+        "if" => Block::If,
+        "unless" => Block::Unless,
+        "each" => Block::Each,
+        "with" => Block::With,
+        _ => anyhow::bail!(
+            "Unknown block '#{}'. Use #if, #unless, #each or #with",
+            name
+        ),
+    };
+    if target.is_empty() {
+        anyhow::bail!("'#{}' needs a value", name);
+    }
+    let target = parse_pipeline(target)?;
+    let (body, end) = parse_nodes(iter, Some(name))?;
+    let close = format!("/{}", name);
+    let otherwise = match end.as_deref() {
+        Some(tag) if tag == close => Vec::new(),
+        Some("else") => {
+            let (otherwise, end) = parse_nodes(iter, Some(name))?;
+            if end.as_deref() != Some(close.as_str()) {
+                anyhow::bail!("Expected '{{{{{}}}}}' after '{{{{else}}}}'", close);
+            }
+            otherwise
+        }
+        // `{{else if x}}` chains a nested #if that shares this block's close tag
+        Some(tag) if tag.starts_with("else if ") && matches!(kind, Block::If) => {
+            let mut chained = vec![Segment::Tag(format!("#{}", &tag[5..]))];
+            let mut depth = 0;
+            let mut closed = false;
+            for segment in iter.by_ref() {
+                if let Segment::Tag(t) = &segment {
+                    if t.starts_with("#if ") {
+                        depth += 1;
+                    } else if t == "/if" {
+                        if depth == 0 {
+                            closed = true;
+                            break;
+                        }
+                        depth -= 1;
+                    }
+                }
+                chained.push(segment);
+            }
+            if !closed {
+                anyhow::bail!("Unclosed '{{{{#if}}}}'");
+            }
+            chained.push(Segment::Tag("/if".to_string()));
+            let (otherwise, _) = parse_nodes(&mut chained.into_iter(), None)?;
+            otherwise
+        }
+        Some(tag) => anyhow::bail!("Expected '{{{{{}}}}}' but found '{{{{{}}}}}'", close, tag),
+        None => anyhow::bail!("Unclosed '{{{{#{}}}}}'", name),
+    };
+    Ok(Node::Block {
+        kind,
+        target,
+        body,
+        otherwise,
+    })
+}
+
+/// Split tag contents into words and `|`, keeping quoted strings together
+fn split_words(input: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '|' {
+            chars.next();
+            words.push("|".to_string());
+        } else if c == '"' || c == '\'' {
+            chars.next();
+            let mut word = String::from('"');
+            loop {
+                match chars.next() {
+                    None => anyhow::bail!("Unterminated string in '{}'", input),
+                    Some('\\') => {
+                        if let Some(escaped) = chars.next() {
+                            word.push(match escaped {
+                                'n' => '\n',
+                                't' => '\t',
+                                other => other,
+                            });
+                        }
+                    }
+                    Some(ch) if ch == c => break,
+                    Some(ch) => word.push(ch),
+                }
+            }
+            words.push(word);
+        } else {
+            let mut word = String::new();
+            while let Some(&ch) = chars.peek() {
+                if ch.is_whitespace() || ch == '|' {
+                    break;
+                }
+                word.push(ch);
+                chars.next();
+            }
+            words.push(word);
+        }
+    }
+    Ok(words)
+}
+
+fn parse_pipeline(input: &str) -> Result<Pipeline> {
+    let words = split_words(input)?;
+    let mut stages = words.split(|w| w == "|");
+    let first = stages.next().unwrap_or_default();
+    let (head, mut calls) = match first {
+        [] => anyhow::bail!("Empty tag '{{{{{}}}}}'", input),
+        [single] => (parse_operand(single), Vec::new()),
+        [helper, value, args @ ..] => {
+            check_helper(helper)?;
+            let call = Call {
+                helper: helper.clone(),
+                args: args.iter().map(|a| parse_operand(a)).collect(),
+            };
+            (parse_operand(value), vec![call])
+        }
+    };
+    for stage in stages {
+        let Some((helper, args)) = stage.split_first() else {
+            anyhow::bail!("Missing helper after '|' in '{}'", input);
+        };
+        check_helper(helper)?;
+        calls.push(Call {
+            helper: helper.clone(),
+            args: args.iter().map(|a| parse_operand(a)).collect(),
+        });
+    }
+    Ok(Pipeline { head, calls })
+}
+
+fn check_helper(name: &str) -> Result<()> {
+    if HELPERS
+        .iter()
+        .any(|h| h.split_whitespace().next() == Some(name))
+    {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "Unknown helper '{}'. Available: {}",
+        name,
+        HELPERS
+            .iter()
+            .filter_map(|h| h.split_whitespace().next())
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn parse_operand(word: &str) -> Operand {
+    if let Some(s) = word.strip_prefix('"') {
+        return Operand::Literal(json!(s));
+    }
+    match word {
+        "true" => return Operand::Literal(json!(true)),
+        "false" => return Operand::Literal(json!(false)),
+        "null" => return Operand::Literal(Value::Null),
+        _ => {}
+    }
+    if let Ok(n) = word.parse::<i64>() {
+        return Operand::Literal(json!(n));
+    }
+    if let Ok(n) = word.parse::<f64>() {
+        return Operand::Literal(json!(n));
+    }
+    if let Some(name) = word.strip_prefix('@') {
+        return Operand::Special(name.to_string());
+    }
+    let mut rest = word;
+    let mut up = 0;
+    while let Some(stripped) = rest.strip_prefix("../") {
+        up += 1;
+        rest = stripped;
+    }
+    let rest = rest
+        .strip_prefix("this")
+        .filter(|r| r.is_empty() || r.starts_with('.'))
+        .unwrap_or(rest);
+    let parts = rest
+        .split('.')
+        .filter(|p| !p.is_empty())
+        .map(|p| p.to_string())
+        .collect();
+    Operand::Path(up, parts)
+}
+
+/// One level of scope: the current value plus loop metadata
+struct Frame {
+    value: Value,
+    index: Option<usize>,
+    key: Option<String>,
+    len: usize,
+}
+
+impl Frame {
+    fn new(value: Value) -> Frame {
+        Frame {
+            value,
+            index: None,
+            key: None,
+            len: 0,
+        }
+    }
+}
+
+fn render_nodes(nodes: &[Node], scope: &[Frame], out: &mut String) -> Result<()> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Expr(pipeline) => out.push_str(&to_text(&eval_pipeline(pipeline, scope)?)),
+            Node::Block {
+                kind,
+                target,
+                body,
+                otherwise,
+            } => {
+                let value = eval_pipeline(target, scope)?;
+                match kind {
+                    Block::If | Block::Unless => {
+                        let take = truthy(&value) == matches!(kind, Block::If);
+                        render_nodes(if take { body } else { otherwise }, scope, out)?;
+                    }
+                    Block::With => {
+                        if truthy(&value) {
+                            let mut inner = Vec::with_capacity(scope.len() + 1);
+                            inner.extend(scope.iter().map(copy_frame));
+                            inner.push(Frame::new(value));
+                            render_nodes(body, &inner, out)?;
+                        } else {
+                            render_nodes(otherwise, scope, out)?;
+                        }
+                    }
+                    Block::Each => {
+                        let items: Vec<(Option<String>, Value)> = match value {
+                            Value::Array(items) => items.into_iter().map(|v| (None, v)).collect(),
+                            Value::Object(map) => {
+                                map.into_iter().map(|(k, v)| (Some(k), v)).collect()
+                            }
+                            _ => Vec::new(),
+                        };
+                        if items.is_empty() {
+                            render_nodes(otherwise, scope, out)?;
+                            continue;
+                        }
+                        let len = items.len();
+                        let mut inner: Vec<Frame> = scope.iter().map(copy_frame).collect();
+                        for (index, (key, item)) in items.into_iter().enumerate() {
+                            inner.push(Frame {
+                                value: item,
+                                index: Some(index),
+                                key,
+                                len,
+                            });
+                            render_nodes(body, &inner, out)?;
+                            inner.pop();
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn copy_frame(frame: &Frame) -> Frame {
+    Frame {
+        value: frame.value.clone(),
+        index: frame.index,
+        key: frame.key.clone(),
+        len: frame.len,
+    }
+}
+
+fn eval_pipeline(pipeline: &Pipeline, scope: &[Frame]) -> Result<Value> {
+    let mut value = resolve(&pipeline.head, scope);
+    for call in &pipeline.calls {
+        let args: Vec<Value> = call.args.iter().map(|a| resolve(a, scope)).collect();
+        value = apply_helper(&call.helper, &value, &args)?;
+    }
+    Ok(value)
+}
+
+fn resolve(operand: &Operand, scope: &[Frame]) -> Value {
+    match operand {
+        Operand::Literal(value) => value.clone(),
+        Operand::Special(name) => {
+            // Loop metadata comes from the innermost #each
+            let Some(frame) = scope.iter().rev().find(|f| f.index.is_some()) else {
+                return Value::Null;
+            };
+            let index = frame.index.unwrap_or(0);
+            match name.as_str() {
+                "index" => json!(index),
+                "key" => frame.key.clone().map_or(json!(index), Value::String),
+                "first" => json!(index == 0),
+                "last" => json!(index + 1 == frame.len),
+                _ => Value::Null,
+            }
+        }
+        Operand::Path(up, parts) => {
+            let Some(frame) = scope.len().checked_sub(up + 1).and_then(|i| scope.get(i)) else {
+                return Value::Null;
+            };
+            lookup(&frame.value, parts)
+        }
+    }
+}
+
+/// Follow a dotted path, mapping over arrays for non-numeric parts
+fn lookup(value: &Value, parts: &[String]) -> Value {
+    let Some((head, rest)) = parts.split_first() else {
+        return value.clone();
+    };
+    match value {
+        Value::Object(map) => map.get(head).map_or(Value::Null, |v| lookup(v, rest)),
+        Value::Array(items) => match head.parse::<usize>() {
+            Ok(index) => items.get(index).map_or(Value::Null, |v| lookup(v, rest)),
+            Err(_) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| lookup(item, parts))
+                    .flat_map(|v| match v {
+                        Value::Array(inner) => inner,
+                        Value::Null => Vec::new(),
+                        other => vec![other],
+                    })
+                    .collect(),
+            ),
+        },
+        _ => Value::Null,
+    }
+}
+
+fn apply_helper(name: &str, value: &Value, args: &[Value]) -> Result<Value> {
+    let arg = |i: usize| args.get(i).cloned().unwrap_or(Value::Null);
+    let need = |i: usize, what: &str| -> Result<Value> {
+        args.get(i)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("'{}' needs {}", name, what))
+    };
+    Ok(match name {
+        "upper" => json!(to_text(value).to_uppercase()),
+        "lower" => json!(to_text(value).to_lowercase()),
+        "trim" => json!(to_text(value).trim()),
+        "truncate" => {
+            let width = need(0, "a width")?
+                .as_u64()
+                .ok_or_else(|| anyhow::anyhow!("'truncate' width must be a number"))?;
+            json!(truncate(&to_text(value), Some(width as usize)))
+        }
+        "pad" => {
+            let width = need(0, "a width")?
+                .as_u64()
+                .ok_or_else(|| anyhow::anyhow!("'pad' width must be a number"))?;
+            json!(format!(
+                "{:<width$}",
+                to_text(value),
+                width = width as usize
+            ))
+        }
+        "replace" => {
+            let from = to_text(&need(0, "FROM and TO")?);
+            let to = to_text(&need(1, "FROM and TO")?);
+            json!(to_text(value).replace(&from, &to))
+        }
+        "date" => {
+            let format = args
+                .first()
+                .map(to_text)
+                .unwrap_or_else(|| "%Y-%m-%d".to_string());
+            format_date(value, &format)
+        }
+        "priority_name" => match value.as_i64() {
+            Some(p) => json!(crate::priority::priority_name(p)),
+            None => Value::Null,
+        },
+        "default" => {
+            if is_blank(value) {
+                need(0, "a fallback value")?
+            } else {
+                value.clone()
+            }
+        }
+        "join" => {
+            let separator = args
+                .first()
+                .map(to_text)
+                .unwrap_or_else(|| ", ".to_string());
+            match value {
+                Value::Array(items) => json!(items
+                    .iter()
+                    .filter(|v| !v.is_null())
+                    .map(to_text)
+                    .collect::<Vec<_>>()
+                    .join(&separator)),
+                other => other.clone(),
+            }
+        }
+        "length" => json!(match value {
+            Value::Array(items) => items.len(),
+            Value::Object(map) => map.len(),
+            Value::String(s) => s.chars().count(),
+            Value::Null => 0,
+            _ => 1,
+        }),
+        "first" => value
+            .as_array()
+            .and_then(|items| items.first())
+            .cloned()
+            .unwrap_or(Value::Null),
+        "last" => value
+            .as_array()
+            .and_then(|items| items.last())
+            .cloned()
+            .unwrap_or(Value::Null),
+        "json" => json!(serde_json::to_string(value)?),
+        "eq" => json!(compare(value, &arg(0)) == Ordering::Equal),
+        "ne" => json!(compare(value, &arg(0)) != Ordering::Equal),
+        "gt" => json!(compare(value, &arg(0)) == Ordering::Greater),
+        "gte" => json!(compare(value, &arg(0)) != Ordering::Less),
+        "lt" => json!(compare(value, &arg(0)) == Ordering::Less),
+        "lte" => json!(compare(value, &arg(0)) != Ordering::Greater),
+        "contains" => {
+            let needle = arg(0);
+            json!(match value {
+                Value::Array(items) => items
+                    .iter()
+                    .any(|item| compare(item, &needle) == Ordering::Equal),
+                other => to_text(other).contains(&to_text(&needle)),
+            })
+        }
+        "not" => json!(!truthy(value)),
+        "and" => json!(truthy(value) && truthy(&arg(0))),
+        "or" => {
+            if truthy(value) {
+                value.clone()
+            } else {
+                arg(0)
+            }
+        }
+        _ => anyhow::bail!("Unknown helper '{}'", name),
+    })
+}
+
+/// Format an RFC 3339 timestamp (in its own offset) or a YYYY-MM-DD date
+fn format_date(value: &Value, format: &str) -> Value {
+    use std::fmt::Write;
+
+    let Some(text) = value.as_str() else {
+        return value.clone();
+    };
+    let mut out = String::new();
+    let written = if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(text) {
+        write!(out, "{}", dt.format(format))
+    } else if let Ok(date) = chrono::NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        write!(out, "{}", date.format(format))
+    } else {
+        return value.clone();
+    };
+    // An unsupported specifier (e.g. %H on a plain date) leaves the value as-is
+    match written {
+        Ok(()) => json!(out),
+        Err(_) => value.clone(),
+    }
+}
+
+/// Numbers compare numerically, everything else as text
+fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        _ => to_text(a).cmp(&to_text(b)),
+    }
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Handlebars truthiness: null, false, 0, "", [] and {} are false
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(map) => !map.is_empty(),
+    }
+}
+
+/// Strings as-is, null as empty, scalar lists joined with ", ", other values as JSON
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Array(items) if items.iter().all(|v| !v.is_array() && !v.is_object()) => {
+            items.iter().map(to_text).collect::<Vec<_>>().join(", ")
+        }
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &str, value: Value) -> String {
+        Template::parse(template).unwrap().render(&value).unwrap()
+    }
+
+    fn issue() -> Value {
+        json!({
+            "identifier": "ENG-1",
+            "title": "Fix the login redirect loop on Safari",
+            "priority": 2,
+            "dueDate": "2024-03-09",
+            "createdAt": "2024-01-02T15:04:05.000Z",
+            "assignee": null,
+            "state": {"name": "In Progress"},
+            "labels": {"nodes": [{"name": "bug"}, {"name": "ui"}]}
+        })
+    }
+
+    #[test]
+    fn test_paths_and_helpers() {
+        assert_eq!(
+            render("{{identifier}} {{ state.name }}", issue()),
+            "ENG-1 In Progress"
+        );
+        assert_eq!(render("{{title | truncate 12}}", issue()), "Fix the l...");
+        assert_eq!(render("{{upper identifier}}", issue()), "ENG-1");
+        assert_eq!(
+            render("{{state.name | lower | replace \" \" \"-\"}}", issue()),
+            "in-progress"
+        );
+        assert_eq!(render("{{assignee.name | default \"-\"}}", issue()), "-");
+        assert_eq!(render("{{priority | priority_name}}", issue()), "High");
+        assert_eq!(render("{{labels.nodes.name}}", issue()), "bug, ui");
+        assert_eq!(
+            render("{{labels.nodes.name | join \"/\"}}", issue()),
+            "bug/ui"
+        );
+        assert_eq!(render("{{labels.nodes | length}}", issue()), "2");
+        assert_eq!(render("[{{identifier | pad 7}}]", issue()), "[ENG-1  ]");
+    }
+
+    #[test]
+    fn test_triple_braces_keep_outer_braces() {
+        assert_eq!(render("{{{identifier}}}", issue()), "{ENG-1}");
+        assert_eq!(
+            render("key={{{ state.name | lower }}}!", issue()),
+            "key={in progress}!"
+        );
+    }
+
+    #[test]
+    fn test_date_helper() {
+        assert_eq!(render("{{dueDate | date \"%b %d\"}}", issue()), "Mar 09");
+        assert_eq!(render("{{createdAt | date}}", issue()), "2024-01-02");
+        assert_eq!(render("{{createdAt | date \"%H:%M\"}}", issue()), "15:04");
+        assert_eq!(render("{{title | date}}", issue()), issue()["title"]);
+    }
+
+    #[test]
+    fn test_conditionals() {
+        let tpl = "{{#if assignee}}{{assignee.name}}{{else}}unassigned{{/if}}";
+        assert_eq!(render(tpl, issue()), "unassigned");
+        assert_eq!(render(tpl, json!({"assignee": {"name": "Ada"}})), "Ada");
+        assert_eq!(
+            render("{{#unless assignee}}free{{/unless}}", issue()),
+            "free"
+        );
+        let chain =
+            "{{#if eq priority 1}}urgent{{else if priority | lte 2}}soon{{else}}later{{/if}}";
+        assert_eq!(render(chain, json!({"priority": 1})), "urgent");
+        assert_eq!(render(chain, json!({"priority": 2})), "soon");
+        assert_eq!(render(chain, json!({"priority": 4})), "later");
+        assert_eq!(
+            render(
+                "{{#if labels.nodes.name | contains \"bug\"}}bug!{{/if}}",
+                issue()
+            ),
+            "bug!"
+        );
+    }
+
+    #[test]
+    fn test_each_and_with() {
+        assert_eq!(
+            render(
+                "{{#each labels.nodes}}{{@index}}:{{name}}{{#unless @last}}, {{/unless}}{{/each}}",
+                issue()
+            ),
+            "0:bug, 1:ui"
+        );
+        assert_eq!(
+            render(
+                "{{#each labels.nodes}}{{name}}@{{../identifier}} {{/each}}",
+                issue()
+            ),
+            "bug@ENG-1 ui@ENG-1 "
+        );
+        assert_eq!(
+            render("{{#each children}}x{{else}}none{{/each}}", issue()),
+            "none"
+        );
+        assert_eq!(
+            render("{{#each state}}{{@key}}={{this}}{{/each}}", issue()),
+            "name=In Progress"
+        );
+        assert_eq!(
+            render("{{#with state}}{{name}}{{/with}}", issue()),
+            "In Progress"
+        );
+    }
+
+    #[test]
+    fn test_standalone_lines_and_whitespace_control() {
+        let tpl =
+            "# Notes\n{{#each .}}\n{{! one line per issue }}\n- {{identifier}}\n{{/each}}\nend\n";
+        assert_eq!(
+            render(tpl, json!([{"identifier": "A"}, {"identifier": "B"}])),
+            "# Notes\n- A\n- B\nend\n"
+        );
+        assert_eq!(render("a   {{~identifier~}}   b", issue()), "aENG-1b");
+        assert_eq!(render("{{!-- {{ignored}} --}}x", issue()), "x");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Template::parse("{{#if x}}open").is_err());
+        assert!(Template::parse("{{/each}}").is_err());
+        assert!(Template::parse("{{#if x}}a{{/each}}").is_err());
+        assert!(Template::parse("{{#loop x}}{{/loop}}").is_err());
+        assert!(Template::parse("{{name").is_err());
+        let err = Template::parse("{{title | shout}}")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown helper 'shout'"), "{}", err);
+        let err = Template::parse("{{title | truncate}}")
+            .unwrap()
+            .render(&issue())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'truncate' needs a width"), "{}", err);
+    }
+}
@@ -48,6 +48,24 @@ pub fn is_uuid(value: &str) -> bool {
     value.len() == 36 && value.matches("-").count() == 4
 }
 
+/// Generate a random (version 4) UUID, used as a client-chosen entity id
+pub fn new_uuid() -> String {
+    use rand::Rng;
+
+    let mut bytes: [u8; 16] = rand::thread_rng().gen();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
 /// Strip common markdown formatting for terminal display.
 /// Converts headers, bold, italic, links, images, code blocks, etc. to plain text.
 pub fn strip_markdown(input: &str) -> String {
@@ -155,6 +173,15 @@ mod tests {
         assert!(is_uuid("00000000-0000-0000-0000-000000000000"));
     }
 
+    #[test]
+    fn test_new_uuid() {
+        let id = new_uuid();
+        assert!(is_uuid(&id));
+        assert_eq!(&id[14..15], "4");
+        assert!(matches!(&id[19..20], "8" | "9" | "a" | "b"));
+        assert_ne!(id, new_uuid());
+    }
+
     #[test]
     fn test_is_uuid_invalid() {
         assert!(!is_uuid("not-a-uuid"));
@@ -0,0 +1,410 @@
+//! Debug tracing of GraphQL requests (`--debug`, `--trace FILE` / `LINEAR_CLI_TRACE`).
+//!
+//! Every request sent by `LinearClient::query_once` is logged with its operation,
+//! variables, timing, HTTP status and `x-request-id`. `--debug` prints a short
+//! summary to stderr; a trace file receives one HAR-style JSON entry per line.
+//! Authorization headers, API keys, tokens and webhook secrets are redacted
+//! before anything is written.
+
+use regex::Regex;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde_json::{json, Map, Value};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use crate::graphql;
+use crate::rate_limit::RateLimitStatus;
+
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Response headers copied into trace entries
+const TRACED_HEADERS: &[&str] = &[
+    "x-request-id",
+    "content-type",
+    "retry-after",
+    "x-complexity",
+    "x-ratelimit-requests-remaining",
+    "x-ratelimit-complexity-remaining",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Target {
+    Stderr,
+    File(PathBuf),
+}
+
+static TARGETS: OnceLock<Vec<Target>> = OnceLock::new();
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Parse the `--trace` / `LINEAR_CLI_TRACE` value: "1", "true" or "stderr" log
+/// to stderr, "0", "false" or "" disable tracing, anything else is a file path.
+fn parse_target(value: &str) -> Option<Target> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "" | "0" | "false" | "off" => None,
+        "1" | "true" | "on" | "stderr" | "-" => Some(Target::Stderr),
+        _ => Some(Target::File(PathBuf::from(value.trim()))),
+    }
+}
+
+/// Enable tracing for this process
+pub fn configure(debug: bool, trace: Option<&str>) {
+    let mut targets = Vec::new();
+    if debug {
+        targets.push(Target::Stderr);
+    }
+    if let Some(target) = trace.and_then(parse_target) {
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+    }
+    let _ = TARGETS.set(targets);
+}
+
+/// One request/response exchange as seen by the client
+pub struct Exchange<'a> {
+    pub url: &'a str,
+    pub body: &'a Value,
+    pub elapsed: Duration,
+    pub status: Option<StatusCode>,
+    pub headers: Option<&'a HeaderMap>,
+    pub budget: Option<&'a RateLimitStatus>,
+    pub error: Option<&'a anyhow::Error>,
+}
+
+/// Log an exchange to every configured target. Tracing never fails the request.
+pub fn record(exchange: &Exchange) {
+    let Some(targets) = TARGETS.get().filter(|targets| !targets.is_empty()) else {
+        return;
+    };
+    let entry = har_entry(exchange);
+    for target in targets {
+        match target {
+            Target::Stderr => eprintln!("{}", summary(&entry)),
+            Target::File(path) => {
+                if let Err(err) = append(path, &entry) {
+                    eprintln!(
+                        "Warning: could not write trace to {}: {}",
+                        path.display(),
+                        err
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn append(path: &PathBuf, entry: &Value) -> std::io::Result<()> {
+    let _guard = FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut options = std::fs::OpenOptions::new();
+    options.create(true).append(true);
+    // Traces hold query variables and responses, so only the owner may read them
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    writeln!(file, "{}", entry)
+}
+
+fn header_list(headers: &HeaderMap) -> Vec<Value> {
+    TRACED_HEADERS
+        .iter()
+        .filter_map(|name| {
+            let value = headers.get(*name)?.to_str().ok()?;
+            Some(json!({ "name": name, "value": value }))
+        })
+        .collect()
+}
+
+fn error_details(error: &anyhow::Error) -> Value {
+    match error.downcast_ref::<crate::error::CliError>() {
+        Some(err) => json!({
+            "message": redact_text(&err.message),
+            "details": err.details.as_ref().map(redact_value),
+        }),
+        None => json!({ "message": redact_text(&format!("{:#}", error)) }),
+    }
+}
+
+/// Build a HAR-style entry with secrets redacted
+fn har_entry(exchange: &Exchange) -> Value {
+    let query = exchange.body["query"].as_str().unwrap_or_default();
+    let variables = exchange
+        .body
+        .get("variables")
+        .map(redact_value)
+        .unwrap_or(Value::Null);
+    let body = json!({ "query": redact_text(query), "variables": variables });
+    let started =
+        chrono::Utc::now() - chrono::Duration::from_std(exchange.elapsed).unwrap_or_default();
+
+    let mut entry = Map::new();
+    entry.insert("startedDateTime".into(), json!(started.to_rfc3339()));
+    entry.insert(
+        "time".into(),
+        json!(exchange.elapsed.as_secs_f64() * 1000.0),
+    );
+    entry.insert(
+        "request".into(),
+        json!({
+            "method": "POST",
+            "url": exchange.url,
+            "headers": [
+                { "name": "Authorization", "value": REDACTED },
+                { "name": "Content-Type", "value": "application/json" },
+            ],
+            "postData": { "mimeType": "application/json", "text": body.to_string() },
+        }),
+    );
+    entry.insert(
+        "response".into(),
+        json!({
+            "status": exchange.status.map(|s| s.as_u16()).unwrap_or(0),
+            "statusText": exchange.status.and_then(|s| s.canonical_reason()).unwrap_or(""),
+            "headers": exchange.headers.map(header_list).unwrap_or_default(),
+        }),
+    );
+    entry.insert(
+        "_graphql".into(),
+        json!({
+            "operationType": graphql::operation_type(query),
+            "operationName": graphql::operation_name(query),
+            "rootFields": graphql::root_fields(query),
+            "variables": variables,
+        }),
+    );
+    entry.insert(
+        "_requestId".into(),
+        json!(exchange
+            .headers
+            .and_then(|h| h.get("x-request-id"))
+            .and_then(|v| v.to_str().ok())),
+    );
+    if let Some(budget) = exchange.budget {
+        entry.insert("_rateLimit".into(), budget.to_json());
+    }
+    if let Some(error) = exchange.error {
+        entry.insert("_error".into(), error_details(error));
+    }
+    Value::Object(entry)
+}
+
+/// One-line stderr summary of an entry, followed by variables and errors
+fn summary(entry: &Value) -> String {
+    let graphql = &entry["_graphql"];
+    let kind = graphql["operationType"].as_str().unwrap_or("query");
+    let name = graphql["operationName"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            let fields: Vec<&str> = graphql["rootFields"]
+                .as_array()
+                .map(|f| f.iter().filter_map(|v| v.as_str()).collect())
+                .unwrap_or_default();
+            if fields.is_empty() {
+                "(anonymous)".to_string()
+            } else {
+                fields.join(",")
+            }
+        });
+    let status = match entry["response"]["status"].as_u64() {
+        Some(0) | None => "no response".to_string(),
+        Some(code) => code.to_string(),
+    };
+    let mut out = format!(
+        "[debug] {} {} -> {} in {:.0}ms",
+        kind,
+        name,
+        status,
+        entry["time"].as_f64().unwrap_or_default()
+    );
+    if let Some(id) = entry["_requestId"].as_str() {
+        out.push_str(&format!(" (x-request-id: {})", id));
+    }
+    if !graphql["variables"].is_null() {
+        out.push_str(&format!("\n[debug]   variables: {}", graphql["variables"]));
+    }
+    if let Some(error) = entry.get("_error") {
+        out.push_str(&format!("\n[debug]   error: {}", error["message"]));
+        if let Some(details) = error.get("details").filter(|d| !d.is_null()) {
+            out.push_str(&format!("\n[debug]   details: {}", details));
+        }
+    }
+    out
+}
+
+/// Variable and field names whose values are never written to a trace
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase().replace(['_', '-'], "");
+    ["secret", "token", "password", "apikey", "authorization"]
+        .iter()
+        .any(|word| key.contains(word))
+}
+
+fn key_pattern() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"lin_(api|oauth)_[A-Za-z0-9_]+").unwrap())
+}
+
+fn inline_secret_pattern() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r#"(?i)(\w*(?:secret|token|password|api_?key)\w*\s*:\s*)"(?:[^"\\]|\\.)*""#)
+            .unwrap()
+    })
+}
+
+/// Redact API keys and inline secret arguments from query text or messages
+pub fn redact_text(text: &str) -> String {
+    let text = key_pattern().replace_all(text, REDACTED);
+    inline_secret_pattern()
+        .replace_all(&text, format!("${{1}}\"{}\"", REDACTED))
+        .into_owned()
+}
+
+/// Redact secret-named fields and key-shaped strings anywhere in a JSON value
+pub fn redact_value(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = if is_secret_key(key) && !value.is_null() {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact_value(value)
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_value).collect()),
+        Value::String(s) => Value::String(redact_text(s)),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!(parse_target("1"), Some(Target::Stderr));
+        assert_eq!(parse_target("stderr"), Some(Target::Stderr));
+        assert_eq!(parse_target("0"), None);
+        assert_eq!(parse_target(""), None);
+        assert_eq!(
+            parse_target("/tmp/trace.jsonl"),
+            Some(Target::File(PathBuf::from("/tmp/trace.jsonl")))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_trace_file_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let path =
+            std::env::temp_dir().join(format!("linear-cli-trace-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        append(&path, &json!({ "n": 1 })).unwrap();
+        append(&path, &json!({ "n": 2 })).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_redact_value() {
+        let vars = json!({
+            "input": {
+                "url": "https://example.com/hook",
+                "secret": "whsec_123",
+                "label": "key lin_api_abcDEF123 in text",
+            },
+            "apiKey": "anything",
+            "accessToken": null,
+            "ids": ["a", "b"],
+        });
+        assert_eq!(
+            redact_value(&vars),
+            json!({
+                "input": {
+                    "url": "https://example.com/hook",
+                    "secret": REDACTED,
+                    "label": "key [REDACTED] in text",
+                },
+                "apiKey": REDACTED,
+                "accessToken": null,
+                "ids": ["a", "b"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_redact_text_inline_arguments() {
+        let query = r#"mutation { webhookCreate(input: { url: "https://x", secret: "s3cr\"et" }) { success } }"#;
+        assert_eq!(
+            redact_text(query),
+            r#"mutation { webhookCreate(input: { url: "https://x", secret: "[REDACTED]" }) { success } }"#
+        );
+    }
+
+    #[test]
+    fn test_har_entry_and_summary() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-id", HeaderValue::from_static("req-1"));
+        headers.insert("set-cookie", HeaderValue::from_static("session=abc"));
+        let body = json!({
+            "query": "query IssueGet($id: String!) { issue(id: $id) { id } }",
+            "variables": { "id": "ENG-1", "token": "t" },
+        });
+        let error = anyhow::Error::from(
+            crate::error::CliError::general("GraphQL error")
+                .with_details(json!([{ "message": "Entity not found" }])),
+        );
+        let entry = har_entry(&Exchange {
+            url: "https://api.linear.app/graphql",
+            body: &body,
+            elapsed: Duration::from_millis(42),
+            status: Some(StatusCode::OK),
+            headers: Some(&headers),
+            budget: None,
+            error: Some(&error),
+        });
+
+        assert_eq!(entry["response"]["status"], 200);
+        assert_eq!(entry["_requestId"], "req-1");
+        assert_eq!(entry["_graphql"]["operationName"], "IssueGet");
+        assert_eq!(entry["_graphql"]["variables"]["token"], REDACTED);
+        assert_eq!(entry["request"]["headers"][0]["value"], REDACTED);
+        assert!(!entry.to_string().contains("session=abc"));
+
+        let summary = summary(&entry);
+        assert!(summary.starts_with("[debug] query IssueGet -> 200 in 42ms (x-request-id: req-1)"));
+        assert!(summary.contains(r#"variables: {"id":"ENG-1","token":"[REDACTED]"}"#));
+        assert!(summary.contains("Entity not found"));
+
+        let error = anyhow::Error::from(crate::error::CliError::general(
+            "Invalid key lin_api_secret123",
+        ));
+        let entry = har_entry(&Exchange {
+            url: "https://api.linear.app/graphql",
+            body: &body,
+            elapsed: Duration::from_millis(1),
+            status: Some(StatusCode::UNAUTHORIZED),
+            headers: None,
+            budget: None,
+            error: Some(&error),
+        });
+        assert_eq!(
+            entry["_error"]["message"],
+            format!("Invalid key {}", REDACTED)
+        );
+    }
+}
@@ -47,8 +47,8 @@ pub fn git_branch_exists(branch: &str) -> bool {
         .unwrap_or(false)
 }
 
-pub fn generate_branch_name(identifier: &str, title: &str) -> String {
-    // Convert title to kebab-case for branch name
+/// Kebab-case a title for use in a branch name (at most 50 characters)
+pub fn slugify(title: &str) -> String {
     let slug: String = title
         .to_lowercase()
         .chars()
@@ -60,11 +60,13 @@ pub fn generate_branch_name(identifier: &str, title: &str) -> String {
         .join("-");
 
     // Truncate if too long
-    let slug = if slug.len() > 50 {
+    if slug.len() > 50 {
         slug[..50].trim_end_matches('-').to_string()
     } else {
         slug
-    };
+    }
+}
 
-    format!("{}/{}", identifier.to_lowercase(), slug)
+pub fn generate_branch_name(identifier: &str, title: &str) -> String {
+    format!("{}/{}", identifier.to_lowercase(), slugify(title))
 }
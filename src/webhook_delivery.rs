@@ -0,0 +1,352 @@
+//! Persisting and forwarding webhook deliveries received by the local listener.
+//!
+//! Verified events are appended to an NDJSON log (rotated by size) and re-sent
+//! to HTTP endpoints or Unix sockets. `webhooks replay` reads the same log back.
+
+use anyhow::{Context, Result};
+use clap::Args;
+use serde_json::{json, Value};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+const DEFAULT_LOG_MAX_MB: u64 = 10;
+const DEFAULT_LOG_KEEP: usize = 5;
+const FORWARD_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Options for persisting and forwarding received webhook events
+#[derive(Args, Debug, Clone, Default)]
+pub struct DeliveryArgs {
+    /// Append every verified event to this NDJSON log
+    #[arg(long, value_name = "PATH")]
+    pub log: Option<PathBuf>,
+    /// Rotate the log when it grows beyond this many megabytes
+    #[arg(long, value_name = "MB", default_value_t = DEFAULT_LOG_MAX_MB, requires = "log")]
+    pub log_max_size: u64,
+    /// Number of rotated log files to keep
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_LOG_KEEP, requires = "log")]
+    pub log_keep: usize,
+    /// Forward every verified event to an http(s) URL or unix:/path/to.sock (repeatable)
+    #[arg(long, value_name = "TARGET", value_delimiter = ',')]
+    pub forward: Vec<String>,
+}
+
+impl DeliveryArgs {
+    pub fn event_log(&self) -> Option<EventLog> {
+        self.log
+            .as_ref()
+            .map(|path| EventLog::new(path.clone(), self.log_max_size * 1024 * 1024, self.log_keep))
+    }
+
+    pub fn forwarder(&self) -> Result<Option<Forwarder>> {
+        if self.forward.is_empty() {
+            return Ok(None);
+        }
+        let targets = self
+            .forward
+            .iter()
+            .map(|t| t.parse())
+            .collect::<Result<Vec<ForwardTarget>>>()?;
+        Forwarder::new(targets).map(Some)
+    }
+}
+
+/// Append-only NDJSON event log with size-based rotation (`events.log`, `events.log.1`, ...)
+#[derive(Debug, Clone)]
+pub struct EventLog {
+    path: PathBuf,
+    max_bytes: u64,
+    keep: usize,
+}
+
+impl EventLog {
+    pub fn new(path: PathBuf, max_bytes: u64, keep: usize) -> Self {
+        Self {
+            path,
+            max_bytes,
+            keep,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append one record, rotating first if it would push the log past its size limit
+    pub fn append(&self, record: &Value) -> Result<()> {
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let size = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if size > 0 && size + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        let mut options = OpenOptions::new();
+        options.create(true).append(true);
+        // Event payloads can carry issue contents; keep the log private like the mirror
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options
+            .open(&self.path)
+            .with_context(|| format!("Failed to open event log {}", self.path.display()))?;
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    fn rotated(&self, n: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&self) -> Result<()> {
+        if self.keep == 0 {
+            fs::remove_file(&self.path)?;
+            return Ok(());
+        }
+        let _ = fs::remove_file(self.rotated(self.keep));
+        for n in (1..self.keep).rev() {
+            let from = self.rotated(n);
+            if from.exists() {
+                fs::rename(&from, self.rotated(n + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated(1))?;
+        Ok(())
+    }
+}
+
+/// The record written to the event log for one delivery
+pub fn log_record(payload: &Value, signature: Option<&str>) -> Value {
+    json!({
+        "receivedAt": chrono::Utc::now().to_rfc3339(),
+        "type": payload["type"],
+        "action": payload["action"],
+        "signature": signature,
+        "payload": payload,
+    })
+}
+
+/// Read the records of an event log, oldest first
+pub fn read_log(path: &Path) -> Result<Vec<Value>> {
+    let file = fs::File::open(path)
+        .with_context(|| format!("Failed to open event log {}", path.display()))?;
+    let mut records = Vec::new();
+    for (n, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: Value = serde_json::from_str(&line)
+            .with_context(|| format!("{}:{}: invalid JSON", path.display(), n + 1))?;
+        if !record["payload"].is_object() {
+            anyhow::bail!("{}:{}: record has no payload", path.display(), n + 1);
+        }
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Hex HMAC-SHA256 of `body`, as sent in the `Linear-Signature` header
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Where forwarded events are delivered
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForwardTarget {
+    /// POST the event body to an HTTP endpoint
+    Http(String),
+    /// Write the event body as one line to a Unix stream socket
+    Unix(PathBuf),
+}
+
+impl FromStr for ForwardTarget {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.starts_with("http://") || s.starts_with("https://") {
+            return Ok(ForwardTarget::Http(s.to_string()));
+        }
+        if let Some(path) = s.strip_prefix("unix:") {
+            if path.is_empty() {
+                anyhow::bail!("Missing socket path in forward target '{}'", s);
+            }
+            return Ok(ForwardTarget::Unix(PathBuf::from(path)));
+        }
+        anyhow::bail!(
+            "Invalid forward target '{}'. Use an http(s):// URL or unix:/path/to.sock",
+            s
+        )
+    }
+}
+
+impl std::fmt::Display for ForwardTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForwardTarget::Http(url) => write!(f, "{}", url),
+            ForwardTarget::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// Delivers event bodies to a fixed set of targets
+pub struct Forwarder {
+    http: reqwest::Client,
+    targets: Vec<ForwardTarget>,
+}
+
+impl Forwarder {
+    pub fn new(targets: Vec<ForwardTarget>) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(format!("linear-cli/{}", env!("CARGO_PKG_VERSION")))
+            .timeout(FORWARD_TIMEOUT)
+            .build()?;
+        Ok(Self { http, targets })
+    }
+
+    pub fn targets(&self) -> &[ForwardTarget] {
+        &self.targets
+    }
+
+    /// Deliver `body` to every target, returning each target's outcome
+    pub async fn deliver(
+        &self,
+        body: &str,
+        signature: Option<&str>,
+    ) -> Vec<(&ForwardTarget, Result<()>)> {
+        let mut results = Vec::with_capacity(self.targets.len());
+        for target in &self.targets {
+            let result = match target {
+                ForwardTarget::Http(url) => self.deliver_http(url, body, signature).await,
+                ForwardTarget::Unix(path) => deliver_unix(path, body).await,
+            };
+            results.push((target, result));
+        }
+        results
+    }
+
+    async fn deliver_http(&self, url: &str, body: &str, signature: Option<&str>) -> Result<()> {
+        let mut request = self
+            .http
+            .post(url)
+            .header("Content-Type", "application/json")
+            .body(body.to_string());
+        if let Some(signature) = signature {
+            request = request.header("Linear-Signature", signature);
+        }
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            anyhow::bail!("HTTP {}", response.status());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+async fn deliver_unix(path: &Path, body: &str) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut stream = tokio::time::timeout(FORWARD_TIMEOUT, tokio::net::UnixStream::connect(path))
+        .await
+        .context("Connect timeout")??;
+    stream.write_all(body.as_bytes()).await?;
+    stream.write_all(b"\n").await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn deliver_unix(_path: &Path, _body: &str) -> Result<()> {
+    anyhow::bail!("Unix socket targets are not supported on this platform")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_log(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("linear-cli-events-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("events.log")
+    }
+
+    #[test]
+    fn test_event_log_appends_and_rotates() {
+        let path = temp_log("rotate");
+        let log = EventLog::new(path.clone(), 150, 2);
+        for n in 0..6 {
+            log.append(&log_record(&json!({ "type": "Issue", "n": n }), None))
+                .unwrap();
+        }
+        assert!(path.exists());
+        assert!(log.rotated(1).exists());
+        assert!(log.rotated(2).exists());
+        assert!(!log.rotated(3).exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // Newest events stay in the live log, older ones move down the chain
+        let live = read_log(&path).unwrap();
+        let older = read_log(&log.rotated(1)).unwrap();
+        assert!(live.last().unwrap()["payload"]["n"] == 5);
+        assert!(older[0]["payload"]["n"].as_i64() < live[0]["payload"]["n"].as_i64());
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_read_log_reports_bad_lines() {
+        let path = temp_log("bad");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{\"payload\":{\"type\":\"Issue\"}}\n\nnot json\n").unwrap();
+        let err = read_log(&path).unwrap_err().to_string();
+        assert!(err.ends_with(":3: invalid JSON"), "{}", err);
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_forward_target_parse() {
+        assert_eq!(
+            "http://localhost:3000/hook"
+                .parse::<ForwardTarget>()
+                .unwrap(),
+            ForwardTarget::Http("http://localhost:3000/hook".to_string())
+        );
+        assert_eq!(
+            "unix:/tmp/linear.sock".parse::<ForwardTarget>().unwrap(),
+            ForwardTarget::Unix(PathBuf::from("/tmp/linear.sock"))
+        );
+        assert!("localhost:3000".parse::<ForwardTarget>().is_err());
+        assert!("unix:".parse::<ForwardTarget>().is_err());
+    }
+
+    #[test]
+    fn test_sign_matches_known_vector() {
+        assert_eq!(
+            sign("key", b"The quick brown fox jumps over the lazy dog"),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+}
@@ -0,0 +1,902 @@
+//! `--where` expressions for `issues list`, compiled into Linear's `IssueFilter`.
+//!
+//! ```text
+//! priority <= high and label in (bug, regression) and due < +7d and not assignee
+//! state = "In Progress" or (team = ENG and title ~ login)
+//! ```
+//!
+//! Linear's filter has no `not`, so negations are pushed down to the comparisons
+//! (De Morgan for `and`/`or`, inverted operators for leaves).
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDate};
+use serde_json::{json, Value};
+
+use crate::dates::parse_due_date;
+use crate::priority::parse_priority;
+use crate::text::is_uuid;
+
+/// Fields accepted in `--where` expressions (shown in error messages)
+pub const FIELDS: &[&str] = &[
+    "assignee",
+    "completed",
+    "created",
+    "creator",
+    "cycle",
+    "description",
+    "due",
+    "estimate",
+    "label",
+    "parent",
+    "priority",
+    "project",
+    "state",
+    "team",
+    "title",
+    "type",
+    "updated",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Str(String),
+    Op(Op),
+    LParen,
+    RParen,
+    Comma,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Neq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Contains,
+    NotContains,
+}
+
+impl Op {
+    fn invert(self) -> Op {
+        match self {
+            Op::Eq => Op::Neq,
+            Op::Neq => Op::Eq,
+            Op::Lt => Op::Gte,
+            Op::Lte => Op::Gt,
+            Op::Gt => Op::Lte,
+            Op::Gte => Op::Lt,
+            Op::Contains => Op::NotContains,
+            Op::NotContains => Op::Contains,
+        }
+    }
+
+    fn is_negative(self) -> bool {
+        matches!(self, Op::Neq | Op::NotContains)
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Eq => "=",
+            Op::Neq => "!=",
+            Op::Lt => "<",
+            Op::Lte => "<=",
+            Op::Gt => ">",
+            Op::Gte => ">=",
+            Op::Contains => "~",
+            Op::NotContains => "!~",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    /// A bare field name: true when the field is set
+    Present(String),
+    Compare {
+        field: String,
+        op: Op,
+        value: String,
+    },
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '"' | '\'' => {
+                let start = i;
+                i += 1;
+                let mut value = String::new();
+                loop {
+                    match chars.get(i) {
+                        None => {
+                            anyhow::bail!("Unterminated string starting at column {}", start + 1)
+                        }
+                        Some('\\') if chars.get(i + 1).is_some() => {
+                            value.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some(&ch) if ch == c => {
+                            i += 1;
+                            break;
+                        }
+                        Some(&ch) => {
+                            value.push(ch);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            '=' | '!' | '<' | '>' | '~' => {
+                let next = chars.get(i + 1).copied();
+                let (op, len) = match (c, next) {
+                    ('=', Some('=')) => (Op::Eq, 2),
+                    ('=', _) => (Op::Eq, 1),
+                    ('!', Some('=')) => (Op::Neq, 2),
+                    ('!', Some('~')) => (Op::NotContains, 2),
+                    ('<', Some('=')) => (Op::Lte, 2),
+                    ('<', _) => (Op::Lt, 1),
+                    ('>', Some('=')) => (Op::Gte, 2),
+                    ('>', _) => (Op::Gt, 1),
+                    ('~', _) => (Op::Contains, 1),
+                    _ => anyhow::bail!("Unexpected '{}' at column {}", c, i + 1),
+                };
+                tokens.push(Token::Op(op));
+                i += len;
+            }
+            _ if is_word_char(c) => {
+                let start = i;
+                while i < chars.len() && is_word_char(chars[i]) {
+                    i += 1;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            }
+            _ => anyhow::bail!("Unexpected '{}' at column {}", c, i + 1),
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '+' | '.' | '@' | '/' | ':' | '#')
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<()> {
+        match self.next() {
+            Some(ref t) if *t == expected => Ok(()),
+            Some(t) => anyhow::bail!("Expected {} but found {}", what, describe(&t)),
+            None => anyhow::bail!("Expected {} but the expression ended", what),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr> {
+        let mut terms = vec![self.parse_and()?];
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            terms.push(self.parse_and()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Expr::Or(terms)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Expr> {
+        let mut terms = vec![self.parse_unary()?];
+        while self.peek_keyword("and") {
+            self.pos += 1;
+            terms.push(self.parse_unary()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Expr::And(terms)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr> {
+        if self.peek_keyword("not") {
+            self.pos += 1;
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        match self.next() {
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                self.expect(Token::RParen, "')'")?;
+                Ok(expr)
+            }
+            Some(Token::Word(word)) => self.parse_comparison(word),
+            Some(t) => anyhow::bail!("Expected a field name but found {}", describe(&t)),
+            None => anyhow::bail!("Expected a field name but the expression ended"),
+        }
+    }
+
+    fn parse_comparison(&mut self, word: String) -> Result<Expr> {
+        let field = canonical_field(&word)?;
+
+        // `field not in (...)`
+        if self.peek_keyword("not")
+            && matches!(self.tokens.get(self.pos + 1), Some(Token::Word(w)) if w.eq_ignore_ascii_case("in"))
+        {
+            self.pos += 2;
+            let values = self.parse_list()?;
+            return Ok(Expr::Not(Box::new(in_list(field, values))));
+        }
+        if self.peek_keyword("in") {
+            self.pos += 1;
+            let values = self.parse_list()?;
+            return Ok(in_list(field, values));
+        }
+
+        let op = match self.peek() {
+            Some(Token::Op(op)) => *op,
+            _ => return Ok(Expr::Present(field)),
+        };
+        self.pos += 1;
+        let value = self.parse_value(op.symbol())?;
+        Ok(Expr::Compare { field, op, value })
+    }
+
+    fn parse_list(&mut self) -> Result<Vec<String>> {
+        self.expect(Token::LParen, "'(' after 'in'")?;
+        let mut values = vec![self.parse_value("in")?];
+        loop {
+            match self.next() {
+                Some(Token::Comma) => values.push(self.parse_value(",")?),
+                Some(Token::RParen) => return Ok(values),
+                Some(t) => anyhow::bail!("Expected ',' or ')' but found {}", describe(&t)),
+                None => anyhow::bail!("Expected ')' but the expression ended"),
+            }
+        }
+    }
+
+    fn parse_value(&mut self, after: &str) -> Result<String> {
+        match self.next() {
+            Some(Token::Word(w)) | Some(Token::Str(w)) => Ok(w),
+            Some(t) => anyhow::bail!(
+                "Expected a value after '{}' but found {}",
+                after,
+                describe(&t)
+            ),
+            None => anyhow::bail!("Expected a value after '{}'", after),
+        }
+    }
+}
+
+fn in_list(field: String, values: Vec<String>) -> Expr {
+    let mut terms: Vec<Expr> = values
+        .into_iter()
+        .map(|value| Expr::Compare {
+            field: field.clone(),
+            op: Op::Eq,
+            value,
+        })
+        .collect();
+    if terms.len() == 1 {
+        terms.remove(0)
+    } else {
+        Expr::Or(terms)
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(w) => format!("'{}'", w),
+        Token::Str(s) => format!("\"{}\"", s),
+        Token::Op(op) => format!("'{}'", op.symbol()),
+        Token::LParen => "'('".to_string(),
+        Token::RParen => "')'".to_string(),
+        Token::Comma => "','".to_string(),
+    }
+}
+
+fn canonical_field(word: &str) -> Result<String> {
+    let field = match word.to_lowercase().as_str() {
+        "assignee" => "assignee",
+        "completed" | "completedat" => "completed",
+        "created" | "createdat" => "created",
+        "creator" => "creator",
+        "cycle" => "cycle",
+        "description" | "desc" => "description",
+        "due" | "duedate" => "due",
+        "estimate" => "estimate",
+        "label" | "labels" => "label",
+        "parent" => "parent",
+        "priority" => "priority",
+        "project" => "project",
+        "state" | "status" => "state",
+        "team" => "team",
+        "title" => "title",
+        "type" | "statetype" => "type",
+        "updated" | "updatedat" => "updated",
+        _ => anyhow::bail!(
+            "Unknown field '{}'. Available fields: {}",
+            word,
+            FIELDS.join(", ")
+        ),
+    };
+    Ok(field.to_string())
+}
+
+fn parse(input: &str) -> Result<Expr> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        anyhow::bail!("Empty --where expression");
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_or()?;
+    if let Some(t) = parser.peek() {
+        anyhow::bail!("Unexpected {} (missing 'and'/'or'?)", describe(t));
+    }
+    Ok(expr)
+}
+
+/// Compile a `--where` expression into an `IssueFilter` object
+pub fn compile(input: &str) -> Result<Value> {
+    let expr = parse(input).map_err(|e| anyhow::anyhow!("Invalid --where expression: {}", e))?;
+    compile_expr(&expr, false)
+}
+
+fn compile_expr(expr: &Expr, negated: bool) -> Result<Value> {
+    match expr {
+        Expr::And(terms) | Expr::Or(terms) => {
+            let is_and = matches!(expr, Expr::And(_));
+            // De Morgan: not (a and b) == (not a) or (not b)
+            let key = if is_and != negated { "and" } else { "or" };
+            let compiled = terms
+                .iter()
+                .map(|t| compile_expr(t, negated))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(json!({ key: compiled }))
+        }
+        Expr::Not(inner) => compile_expr(inner, !negated),
+        Expr::Present(field) => presence(field, !negated),
+        Expr::Compare { field, op, value } => {
+            let op = if negated { op.invert() } else { *op };
+            if matches!(op, Op::Eq | Op::Neq)
+                && (value.eq_ignore_ascii_case("none") || value.eq_ignore_ascii_case("null"))
+                && is_nullable(field)
+            {
+                return presence(field, op == Op::Neq);
+            }
+            compare(field, op, value, negated)
+        }
+    }
+}
+
+fn is_nullable(field: &str) -> bool {
+    matches!(
+        field,
+        "assignee"
+            | "completed"
+            | "cycle"
+            | "description"
+            | "due"
+            | "estimate"
+            | "label"
+            | "parent"
+            | "project"
+    )
+}
+
+/// Filter for "field is set" (or "field is not set" when `present` is false)
+fn presence(field: &str, present: bool) -> Result<Value> {
+    let null_cmp = json!({ "null": !present });
+    Ok(match field {
+        "assignee" | "cycle" | "parent" | "project" | "estimate" | "description" => {
+            json!({ field: null_cmp })
+        }
+        "due" => json!({ "dueDate": null_cmp }),
+        "completed" => json!({ "completedAt": null_cmp }),
+        "label" => {
+            let length = if present {
+                json!({ "gt": 0 })
+            } else {
+                json!({ "eq": 0 })
+            };
+            json!({ "labels": { "length": length } })
+        }
+        "priority" => {
+            // 0 means "No priority"
+            let cmp = if present {
+                json!({ "neq": 0 })
+            } else {
+                json!({ "eq": 0 })
+            };
+            json!({ "priority": cmp })
+        }
+        _ => anyhow::bail!(
+            "Field '{}' is always set; compare it instead (e.g. {} = value)",
+            field,
+            field
+        ),
+    })
+}
+
+/// Compile one comparison; `negated` is set when `op` was inverted from a `not`
+fn compare(field: &str, op: Op, value: &str, negated: bool) -> Result<Value> {
+    let unsupported = || {
+        anyhow::anyhow!(
+            "Operator '{}' is not supported for '{}'",
+            op.symbol(),
+            field
+        )
+    };
+
+    Ok(match field {
+        "priority" => {
+            let n = parse_priority(value).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid priority '{}'. Use 0-4 or none, urgent, high, normal, low",
+                    value
+                )
+            })?;
+            let mut cmp = number_cmp(op, json!(n)).ok_or_else(unsupported)?;
+            // Lower numbers are more urgent, but 0 is "No priority": `< n` leaves it
+            // out, so `not < n` (compiled as `>= n`) has to let it back in
+            match op {
+                Op::Lt | Op::Lte if !negated => cmp["neq"] = json!(0),
+                Op::Gt | Op::Gte if negated => {
+                    return Ok(json!({ "or": [
+                        { "priority": cmp },
+                        { "priority": { "eq": 0 } },
+                    ] }));
+                }
+                _ => {}
+            }
+            json!({ "priority": cmp })
+        }
+        "estimate" => {
+            let n: f64 = value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid estimate '{}'", value))?;
+            let cmp = json!({ "estimate": number_cmp(op, json!(n)).ok_or_else(unsupported)? });
+            // Unestimated issues fail every range, so `not` has to let them back in
+            if negated {
+                or_null("estimate", cmp)
+            } else {
+                cmp
+            }
+        }
+        "cycle" => {
+            if value.eq_ignore_ascii_case("current") || value.eq_ignore_ascii_case("active") {
+                let active = match op {
+                    Op::Eq => true,
+                    Op::Neq => false,
+                    _ => return Err(unsupported()),
+                };
+                let cmp = json!({ "cycle": { "isActive": { "eq": active } } });
+                return Ok(if active { cmp } else { or_null("cycle", cmp) });
+            }
+            let n: i64 = value.parse().map_err(|_| {
+                anyhow::anyhow!("Invalid cycle '{}'. Use a number or 'current'", value)
+            })?;
+            let cmp =
+                json!({ "cycle": { "number": number_cmp(op, json!(n)).ok_or_else(unsupported)? } });
+            if op.is_negative() || negated {
+                or_null("cycle", cmp)
+            } else {
+                cmp
+            }
+        }
+        "state" => json!({ "state": { "name": string_cmp(op, value).ok_or_else(unsupported)? } }),
+        "type" => {
+            let cmp = match op {
+                Op::Eq => json!({ "eq": value.to_lowercase() }),
+                Op::Neq => json!({ "neq": value.to_lowercase() }),
+                _ => return Err(unsupported()),
+            };
+            json!({ "state": { "type": cmp } })
+        }
+        "title" | "description" => json!({ field: string_cmp(op, value).ok_or_else(unsupported)? }),
+        "team" => match op {
+            Op::Eq => json!({ "team": { "or": [
+                { "key": { "eqIgnoreCase": value } },
+                { "name": { "eqIgnoreCase": value } },
+            ] } }),
+            Op::Neq => json!({ "team": {
+                "key": { "neqIgnoreCase": value },
+                "name": { "neqIgnoreCase": value },
+            } }),
+            _ => json!({ "team": { "name": string_cmp(op, value).ok_or_else(unsupported)? } }),
+        },
+        "assignee" | "creator" => {
+            let cmp = user_cmp(op, value).ok_or_else(unsupported)?;
+            let filter = json!({ field: cmp });
+            // "not assigned to X" includes unassigned issues
+            if field == "assignee" && op.is_negative() {
+                or_null(field, filter)
+            } else {
+                filter
+            }
+        }
+        "project" => {
+            let cmp = if is_uuid(value) {
+                match op {
+                    Op::Eq => json!({ "id": { "eq": value } }),
+                    Op::Neq => json!({ "id": { "neq": value } }),
+                    _ => return Err(unsupported()),
+                }
+            } else {
+                json!({ "name": string_cmp(op, value).ok_or_else(unsupported)? })
+            };
+            let filter = json!({ "project": cmp });
+            if op.is_negative() {
+                or_null("project", filter)
+            } else {
+                filter
+            }
+        }
+        "label" => {
+            let cmp = string_cmp(op, value).ok_or_else(unsupported)?;
+            // "not labeled X" means every label differs (true for unlabeled issues too)
+            let quantifier = if op.is_negative() { "every" } else { "some" };
+            json!({ "labels": { quantifier: { "name": cmp } } })
+        }
+        "due" => {
+            let date = parse_date(value)?;
+            let cmp = json!({ "dueDate": date_cmp(op, &date).ok_or_else(unsupported)? });
+            if negated {
+                or_null("dueDate", cmp)
+            } else {
+                cmp
+            }
+        }
+        "created" | "updated" | "completed" => {
+            let key = format!("{}At", field);
+            let date = NaiveDate::parse_from_str(&parse_date(value)?, "%Y-%m-%d")
+                .map_err(|_| anyhow::anyhow!("Invalid date '{}'", value))?;
+            let cmp = timestamp_cmp(&key, op, date).ok_or_else(unsupported)?;
+            // Only completedAt can be unset
+            if negated && field == "completed" {
+                or_null(&key, cmp)
+            } else {
+                cmp
+            }
+        }
+        "parent" => {
+            anyhow::bail!("'parent' can only be tested for presence (e.g. parent, not parent)")
+        }
+        _ => anyhow::bail!("Unknown field '{}'", field),
+    })
+}
+
+/// `{"or": [{field: {"null": true}}, filter]}`
+fn or_null(field: &str, filter: Value) -> Value {
+    json!({ "or": [{ field: { "null": true } }, filter] })
+}
+
+fn number_cmp(op: Op, n: Value) -> Option<Value> {
+    let key = match op {
+        Op::Eq => "eq",
+        Op::Neq => "neq",
+        Op::Lt => "lt",
+        Op::Lte => "lte",
+        Op::Gt => "gt",
+        Op::Gte => "gte",
+        Op::Contains | Op::NotContains => return None,
+    };
+    Some(json!({ key: n }))
+}
+
+fn string_cmp(op: Op, value: &str) -> Option<Value> {
+    let key = match op {
+        Op::Eq => "eqIgnoreCase",
+        Op::Neq => "neqIgnoreCase",
+        Op::Contains => "containsIgnoreCase",
+        Op::NotContains => "notContainsIgnoreCase",
+        _ => return None,
+    };
+    Some(json!({ key: value }))
+}
+
+fn user_cmp(op: Op, value: &str) -> Option<Value> {
+    if value.eq_ignore_ascii_case("me") {
+        return match op {
+            Op::Eq => Some(json!({ "isMe": { "eq": true } })),
+            Op::Neq => Some(json!({ "isMe": { "eq": false } })),
+            _ => None,
+        };
+    }
+    if is_uuid(value) {
+        return match op {
+            Op::Eq => Some(json!({ "id": { "eq": value } })),
+            Op::Neq => Some(json!({ "id": { "neq": value } })),
+            _ => None,
+        };
+    }
+    let key = if value.contains('@') { "email" } else { "name" };
+    Some(json!({ key: string_cmp(op, value)? }))
+}
+
+fn parse_date(value: &str) -> Result<String> {
+    parse_due_date(value).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid date '{}'. Use today, +7d, -2w, 2024-01-15, etc.",
+            value
+        )
+    })
+}
+
+fn date_cmp(op: Op, date: &str) -> Option<Value> {
+    let key = match op {
+        Op::Eq => "eq",
+        Op::Neq => "neq",
+        Op::Lt => "lt",
+        Op::Lte => "lte",
+        Op::Gt => "gt",
+        Op::Gte => "gte",
+        Op::Contains | Op::NotContains => return None,
+    };
+    Some(json!({ key: date }))
+}
+
+/// Compare a timestamp field against a whole day (local date, UTC midnight bounds)
+fn timestamp_cmp(key: &str, op: Op, date: NaiveDate) -> Option<Value> {
+    let start = format!("{}T00:00:00.000Z", date.format("%Y-%m-%d"));
+    let end = format!(
+        "{}T00:00:00.000Z",
+        (date + Duration::days(1)).format("%Y-%m-%d")
+    );
+    Some(match op {
+        Op::Eq => json!({ key: { "gte": start, "lt": end } }),
+        Op::Neq => json!({ "or": [{ key: { "lt": start } }, { key: { "gte": end } }] }),
+        Op::Lt => json!({ key: { "lt": start } }),
+        Op::Lte => json!({ key: { "lt": end } }),
+        Op::Gt => json!({ key: { "gte": end } }),
+        Op::Gte => json!({ key: { "gte": start } }),
+        Op::Contains | Op::NotContains => return None,
+    })
+}
+
+/// Add a compiled `--where` filter to an existing `IssueFilter` (AND semantics)
+pub fn merge_into(filter: &mut Value, compiled: Value) {
+    let Some(obj) = filter.as_object_mut() else {
+        *filter = compiled;
+        return;
+    };
+    if obj.is_empty() {
+        *filter = compiled;
+        return;
+    }
+    match obj.get_mut("and").and_then(|a| a.as_array_mut()) {
+        Some(and) => and.push(compiled),
+        None => {
+            obj.insert("and".to_string(), json!([compiled]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_simple_comparisons() {
+        assert_eq!(
+            compile("state = 'In Progress'").unwrap(),
+            json!({ "state": { "name": { "eqIgnoreCase": "In Progress" } } })
+        );
+        assert_eq!(
+            compile("title ~ login").unwrap(),
+            json!({ "title": { "containsIgnoreCase": "login" } })
+        );
+        assert_eq!(
+            compile("assignee = me").unwrap(),
+            json!({ "assignee": { "isMe": { "eq": true } } })
+        );
+    }
+
+    #[test]
+    fn test_priority_names_and_no_priority() {
+        assert_eq!(
+            compile("priority <= high").unwrap(),
+            json!({ "priority": { "lte": 2, "neq": 0 } })
+        );
+        assert_eq!(
+            compile("priority = urgent").unwrap(),
+            json!({ "priority": { "eq": 1 } })
+        );
+        assert!(compile("priority = soon").is_err());
+    }
+
+    #[test]
+    fn test_negated_priority_range_includes_no_priority() {
+        assert_eq!(
+            compile("not priority <= high").unwrap(),
+            json!({ "or": [{ "priority": { "gt": 2 } }, { "priority": { "eq": 0 } }] })
+        );
+        assert_eq!(
+            compile("not priority < high").unwrap(),
+            json!({ "or": [{ "priority": { "gte": 2 } }, { "priority": { "eq": 0 } }] })
+        );
+        assert_eq!(
+            compile("not priority > high").unwrap(),
+            json!({ "priority": { "lte": 2 } })
+        );
+    }
+
+    #[test]
+    fn test_in_list_and_boolean_structure() {
+        assert_eq!(
+            compile("label in (bug, regression) and not assignee").unwrap(),
+            json!({ "and": [
+                { "or": [
+                    { "labels": { "some": { "name": { "eqIgnoreCase": "bug" } } } },
+                    { "labels": { "some": { "name": { "eqIgnoreCase": "regression" } } } },
+                ] },
+                { "assignee": { "null": true } },
+            ] })
+        );
+    }
+
+    #[test]
+    fn test_negation_is_pushed_down() {
+        // not (a or b) == not a and not b
+        assert_eq!(
+            compile("not (state = Done or label = bug)").unwrap(),
+            json!({ "and": [
+                { "state": { "name": { "neqIgnoreCase": "Done" } } },
+                { "labels": { "every": { "name": { "neqIgnoreCase": "bug" } } } },
+            ] })
+        );
+        assert_eq!(
+            compile("label not in (bug)").unwrap(),
+            json!({ "labels": { "every": { "name": { "neqIgnoreCase": "bug" } } } })
+        );
+    }
+
+    #[test]
+    fn test_negated_relations_include_unset() {
+        assert_eq!(
+            compile("assignee != me").unwrap(),
+            json!({ "or": [
+                { "assignee": { "null": true } },
+                { "assignee": { "isMe": { "eq": false } } },
+            ] })
+        );
+        assert_eq!(
+            compile("project = none").unwrap(),
+            json!({ "project": { "null": true } })
+        );
+    }
+
+    #[test]
+    fn test_dates_use_date_shorthand() {
+        let week = parse_due_date("+7d").unwrap();
+        assert_eq!(
+            compile("due < +7d").unwrap(),
+            json!({ "dueDate": { "lt": week } })
+        );
+        assert_eq!(
+            compile("created >= 2024-01-15").unwrap(),
+            json!({ "createdAt": { "gte": "2024-01-15T00:00:00.000Z" } })
+        );
+        assert_eq!(
+            compile("updated = 2024-01-15").unwrap(),
+            json!({ "updatedAt": {
+                "gte": "2024-01-15T00:00:00.000Z",
+                "lt": "2024-01-16T00:00:00.000Z",
+            } })
+        );
+    }
+
+    #[test]
+    fn test_errors_are_descriptive() {
+        let err = compile("colour = red").unwrap_err().to_string();
+        assert!(err.contains("Unknown field 'colour'"), "{}", err);
+        let err = compile("state =").unwrap_err().to_string();
+        assert!(err.contains("Expected a value after '='"), "{}", err);
+        let err = compile("state = Done label = bug").unwrap_err().to_string();
+        assert!(err.contains("missing 'and'/'or'"), "{}", err);
+        let err = compile("state < Done").unwrap_err().to_string();
+        assert!(err.contains("not supported"), "{}", err);
+        assert!(compile("(state = Done").is_err());
+        assert!(compile("title = 'open").is_err());
+    }
+
+    #[test]
+    fn test_merge_into_existing_filter() {
+        let mut filter = json!({});
+        merge_into(&mut filter, json!({ "priority": { "eq": 1 } }));
+        assert_eq!(filter, json!({ "priority": { "eq": 1 } }));
+
+        let mut filter = json!({ "team": { "id": { "eq": "t" } } });
+        merge_into(&mut filter, json!({ "priority": { "eq": 1 } }));
+        assert_eq!(
+            filter,
+            json!({ "team": { "id": { "eq": "t" } }, "and": [{ "priority": { "eq": 1 } }] })
+        );
+    }
+
+    #[test]
+    fn test_negated_estimate_range_includes_unset() {
+        assert_eq!(
+            compile("not estimate > 3").unwrap(),
+            json!({ "or": [{ "estimate": { "null": true } }, { "estimate": { "lte": 3.0 } }] })
+        );
+    }
+
+    #[test]
+    fn test_negated_cycle_range_includes_unset() {
+        assert_eq!(
+            compile("not cycle < 5").unwrap(),
+            json!({ "or": [
+                { "cycle": { "null": true } },
+                { "cycle": { "number": { "gte": 5 } } },
+            ] })
+        );
+    }
+
+    #[test]
+    fn test_negated_due_range_includes_unset() {
+        assert_eq!(
+            compile("not due < 2024-01-15").unwrap(),
+            json!({ "or": [
+                { "dueDate": { "null": true } },
+                { "dueDate": { "gte": "2024-01-15" } },
+            ] })
+        );
+    }
+
+    #[test]
+    fn test_negated_completed_range_includes_unset() {
+        assert_eq!(
+            compile("not completed < 2024-01-15").unwrap(),
+            json!({ "or": [
+                { "completedAt": { "null": true } },
+                { "completedAt": { "gte": "2024-01-15T00:00:00.000Z" } },
+            ] })
+        );
+        assert_eq!(
+            compile("not created < 2024-01-15").unwrap(),
+            json!({ "createdAt": { "gte": "2024-01-15T00:00:00.000Z" } })
+        );
+    }
+}
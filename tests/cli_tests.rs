@@ -511,6 +511,50 @@ fn test_watch_issue_help() {
     let (code, stdout, _stderr) = run_cli(&["watch", "issue", "--help"]);
     assert_eq!(code, 0);
     assert!(stdout.contains("--interval"));
+    assert!(stdout.contains("--listen"));
+    assert!(stdout.contains("--relay"));
+}
+
+#[test]
+fn test_watch_rejects_unknown_fields() {
+    let (code, _stdout, stderr) =
+        run_cli(&["watch", "issue", "LIN-1", "--only-fields", "state,bogus"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Unknown watch field 'bogus'"));
+}
+
+#[test]
+fn test_watch_on_requires_exec_and_valid_condition() {
+    let (code, _stdout, stderr) = run_cli(&["watch", "issue", "LIN-1", "--on", "state=Done"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--exec"));
+
+    let (code, _stdout, stderr) = run_cli(&[
+        "watch",
+        "issue",
+        "LIN-1",
+        "--on",
+        "colour=red",
+        "--exec",
+        "true",
+    ]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Unknown field in --on condition"));
+}
+
+#[test]
+fn test_watch_listen_and_relay_conflict() {
+    let (code, _stdout, stderr) = run_cli(&[
+        "watch",
+        "issue",
+        "LIN-1",
+        "--listen",
+        "9000",
+        "--relay",
+        "http://localhost:1/events",
+    ]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("cannot be used with"));
 }
 
 #[test]
@@ -2076,3 +2120,1495 @@ fn test_yes_flag_works_with_subcommand() {
         "--yes flag should not interfere with subcommand parsing"
     );
 }
+
+// === Offline integration tests against the bundled mock server ===
+
+/// A `linear-cli dev mock-server` child serving tests/fixtures on a free port
+struct MockServer {
+    child: std::process::Child,
+    // Held open so the server's stdout writes never hit a closed pipe
+    _stdout: std::io::BufReader<std::process::ChildStdout>,
+    url: String,
+    home: std::path::PathBuf,
+}
+
+impl MockServer {
+    fn start(name: &str) -> Self {
+        use std::io::{BufRead, BufReader};
+        use std::process::Stdio;
+
+        let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+        let mut child = Command::new(env!("CARGO_BIN_EXE_linear-cli"))
+            .args(["dev", "mock-server", "--fixtures", fixtures, "--port", "0"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to start mock server");
+
+        let mut stdout = BufReader::new(child.stdout.take().expect("mock server stdout"));
+        let mut line = String::new();
+        stdout
+            .read_line(&mut line)
+            .expect("Failed to read mock server address");
+        let url = line
+            .split_whitespace()
+            .find(|part| part.starts_with("http://"))
+            .expect("mock server should print its URL")
+            .to_string();
+
+        let home =
+            std::env::temp_dir().join(format!("linear-cli-test-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&home).expect("Failed to create test home");
+
+        MockServer {
+            child,
+            _stdout: stdout,
+            url,
+            home,
+        }
+    }
+
+    /// A CLI command pointed at this server with an isolated config directory
+    fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_linear-cli"));
+        command
+            .args(args)
+            .env("LINEAR_API_URL", &self.url)
+            .env("LINEAR_API_KEY", "lin_api_mock")
+            .env("LINEAR_CLI_NO_CACHE", "true")
+            .env("HOME", &self.home)
+            .env("XDG_CONFIG_HOME", &self.home)
+            .env_remove("LINEAR_CLI_PROFILE")
+            .env_remove("LINEAR_CLI_OUTPUT")
+            .env_remove("LINEAR_WEBHOOK_SECRET")
+            .env_remove("LINEAR_CLI_TRACE");
+        command
+    }
+
+    /// Run the CLI against this server with an isolated config directory
+    fn run(&self, args: &[&str]) -> (i32, String, String) {
+        let output = self
+            .command(args)
+            .output()
+            .expect("Failed to execute command");
+
+        (
+            output.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&output.stdout).to_string(),
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        )
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.home);
+    }
+}
+
+#[test]
+fn test_dev_mock_server_help() {
+    let (code, stdout, _stderr) = run_cli(&["dev", "mock-server", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--fixtures"));
+    assert!(stdout.contains("--port"));
+}
+
+#[test]
+fn test_mock_users_me_json() {
+    let server = MockServer::start("users-me");
+    let (code, stdout, stderr) = server.run(&["users", "me", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(json["name"], "Mock User");
+}
+
+#[test]
+fn test_mock_issues_get_json() {
+    let server = MockServer::start("issues-get");
+    let (code, stdout, stderr) = server.run(&["issues", "get", "ENG-1", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(json["identifier"], "ENG-1");
+    assert_eq!(json["state"]["name"], "In Progress");
+}
+
+#[test]
+fn test_mock_issues_get_not_found() {
+    let server = MockServer::start("issues-missing");
+    let (code, _stdout, stderr) = server.run(&["issues", "get", "ENG-404"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("not found"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_mock_issues_list_json() {
+    let server = MockServer::start("issues-list");
+    let (code, stdout, stderr) = server.run(&["issues", "list", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(json.as_array().map(|a| a.len()), Some(2));
+}
+
+#[test]
+fn test_mock_teams_list_json() {
+    let server = MockServer::start("teams-list");
+    let (code, stdout, stderr) = server.run(&["teams", "list", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("\"ENG\""));
+}
+
+#[test]
+fn test_api_url_rejects_plain_http_remote_host() {
+    let output = Command::new(env!("CARGO_BIN_EXE_linear-cli"))
+        .args(["users", "me"])
+        .env("LINEAR_API_URL", "http://api.example.com/graphql")
+        .env("LINEAR_API_KEY", "lin_api_mock")
+        .output()
+        .expect("Failed to execute command");
+    assert_ne!(output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("plain http"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_mirror_pull_then_offline_reads() {
+    let server = MockServer::start("mirror");
+    let (code, stdout, stderr) = server.run(&["mirror", "pull", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let summary: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(summary["issues"]["total"], 2);
+    assert_eq!(summary["comments"]["total"], 1);
+
+    let (code, stdout, stderr) = server.run(&["--offline", "issues", "list", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let issues: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(issues.as_array().map(|a| a.len()), Some(2));
+
+    let (code, stdout, stderr) = server.run(&[
+        "--offline",
+        "issues",
+        "get",
+        "ENG-1",
+        "--comments",
+        "--output",
+        "json",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let issue: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(
+        issue["comments"]["nodes"][0]["body"],
+        "Reproduced on Safari."
+    );
+
+    let (code, stdout, stderr) =
+        server.run(&["--offline", "search", "issues", "dark", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("ENG-2") && !stdout.contains("ENG-1"));
+}
+
+#[test]
+fn test_offline_rejects_network_commands() {
+    let server = MockServer::start("offline-reject");
+    let (code, _stdout, stderr) = server.run(&["--offline", "teams", "list"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--offline"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_offline_mutations_queue_and_push_with_conflicts() {
+    let server = MockServer::start("outbox");
+    let (code, _stdout, stderr) = server.run(&["mirror", "pull"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+
+    for args in [
+        &["--offline", "issues", "update", "ENG-1", "--priority", "1"][..],
+        &[
+            "--offline",
+            "issues",
+            "comment",
+            "ENG-1",
+            "-b",
+            "Queued comment",
+        ][..],
+        &["--offline", "issues", "update", "ENG-2", "--priority", "1"][..],
+    ] {
+        let (code, stdout, stderr) = server.run(args);
+        assert_eq!(code, 0, "stderr: {}", stderr);
+        assert!(stdout.contains("Queued"), "stdout: {}", stdout);
+    }
+
+    let (code, stdout, stderr) = server.run(&["outbox", "list", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let entries: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(entries.as_array().map(|a| a.len()), Some(3));
+    assert_eq!(entries[0]["base_updated_at"], "2024-01-03T00:00:00.000Z");
+    assert_eq!(entries[1]["type"], "comment");
+    assert!(entries[1]["comment_id"].is_string(), "entries: {}", entries);
+
+    // ENG-2 changed on the server after the mirror pull, so it is held back
+    let (code, stdout, stderr) = server.run(&["outbox", "push", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let pushed: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    let statuses: Vec<&str> = pushed["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(statuses, ["pushed", "pushed", "conflict"]);
+    assert_eq!(
+        pushed["results"][2]["detail"]["serverUpdatedAt"],
+        "2024-01-06T00:00:00.000Z"
+    );
+    assert_eq!(pushed["remaining"], 1);
+
+    let (code, stdout, stderr) = server.run(&["outbox", "push", "--force", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let pushed: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(pushed["results"][0]["status"], "pushed");
+    assert_eq!(pushed["remaining"], 0);
+}
+
+#[test]
+fn test_update_queues_when_api_unreachable() {
+    let server = MockServer::start("outbox-unreachable");
+    // Nothing listens on port 1, so the connection is refused before anything is sent
+    let output = Command::new(env!("CARGO_BIN_EXE_linear-cli"))
+        .args([
+            "issues",
+            "update",
+            "ENG-1",
+            "--priority",
+            "2",
+            "--output",
+            "json",
+        ])
+        .env("LINEAR_API_URL", "http://127.0.0.1:1/graphql")
+        .env("LINEAR_API_KEY", "lin_api_mock")
+        .env("HOME", &server.home)
+        .env("XDG_CONFIG_HOME", &server.home)
+        .env_remove("LINEAR_CLI_PROFILE")
+        .env_remove("LINEAR_CLI_OUTPUT")
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let queued: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(queued["queued"], true);
+    assert_eq!(queued["reason"], "network unavailable");
+
+    let (code, stdout, _stderr) = server.run(&["outbox", "list", "--output", "json"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("ENG-1"));
+
+    // Nothing recorded the version the change was based on, so it is held back
+    let (code, stdout, stderr) = server.run(&["outbox", "push", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let pushed: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(pushed["results"][0]["status"], "conflict");
+    assert!(pushed["results"][0]["detail"]["baseUpdatedAt"].is_null());
+    assert_eq!(pushed["remaining"], 1);
+}
+
+#[test]
+fn test_issues_list_where_compiles_server_side_filter() {
+    let server = MockServer::start("where");
+    let (code, stdout, stderr) = server.run(&[
+        "issues",
+        "list",
+        "--where",
+        "priority <= high and label = bug",
+        "--output",
+        "json",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let issues: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(issues.as_array().map(|a| a.len()), Some(1));
+    assert_eq!(issues[0]["identifier"], "ENG-1");
+}
+
+#[test]
+fn test_issues_list_where_reports_syntax_errors() {
+    let (code, _stdout, stderr) = run_cli(&["issues", "list", "--where", "priority <<= 2"]);
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("Invalid --where expression"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+/// Accept `count` HTTP requests on a free port and return their raw text
+fn capture_http_requests(count: usize) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind capture server");
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for stream in listener.incoming().take(count) {
+            let mut stream = stream.expect("accept");
+            let mut data = Vec::new();
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = stream.read(&mut buf).expect("read request");
+                data.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&data).to_string();
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let length = text[..end]
+                        .lines()
+                        .find_map(|l| {
+                            l.to_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|v| v.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if data.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+                if n == 0 {
+                    break;
+                }
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .expect("write response");
+            requests.push(String::from_utf8_lossy(&data).to_string());
+        }
+        requests
+    });
+    (url, handle)
+}
+
+#[test]
+fn test_webhooks_replay_redelivers_logged_events() {
+    let dir = std::env::temp_dir().join(format!("linear-cli-replay-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let log = dir.join("events.log");
+    std::fs::write(
+        &log,
+        concat!(
+            r#"{"receivedAt":"2024-01-01T00:00:00Z","payload":{"action":"update","type":"Issue","data":{"id":"issue-1"}}}"#,
+            "\n",
+            r#"{"receivedAt":"2024-01-01T00:00:01Z","payload":{"action":"create","type":"Comment","data":{"id":"c1"}}}"#,
+            "\n"
+        ),
+    )
+    .unwrap();
+
+    let (url, capture) = capture_http_requests(1);
+    let (code, stdout, stderr) = run_cli(&[
+        "webhooks",
+        "replay",
+        log.to_str().unwrap(),
+        "--to",
+        &url,
+        "--events",
+        "Issue",
+        "--secret",
+        "s3cret",
+        "--output",
+        "json",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let summary: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(summary["events"], 1);
+    assert_eq!(summary["results"][0]["status"], "sent");
+
+    let requests = capture.join().unwrap();
+    let request = requests[0].to_lowercase();
+    assert!(request.starts_with("post /hook"));
+    assert!(request.contains("linear-signature: "));
+    assert!(request.contains(r#""type":"issue""#));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_webhooks_replay_dry_run_and_bad_target() {
+    let dir = std::env::temp_dir().join(format!("linear-cli-replay-dry-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let log = dir.join("events.log");
+    std::fs::write(
+        &log,
+        r#"{"payload":{"action":"update","type":"Issue","data":{"id":"issue-1"}}}"#,
+    )
+    .unwrap();
+
+    let (code, stdout, stderr) = run_cli(&[
+        "webhooks",
+        "replay",
+        log.to_str().unwrap(),
+        "--to",
+        "http://127.0.0.1:1/hook",
+        "--dry-run",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("1 event(s) replayed"));
+
+    let (code, _stdout, stderr) = run_cli(&[
+        "webhooks",
+        "replay",
+        log.to_str().unwrap(),
+        "--to",
+        "localhost:3000",
+    ]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Invalid forward target"));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_webhooks_serve_help() {
+    let (code, stdout, _stderr) = run_cli(&["webhooks", "serve", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--check-interval"));
+    assert!(stdout.contains("--no-reenable"));
+    assert!(stdout.contains("GET  /health"));
+}
+
+#[test]
+fn test_webhooks_serve_requires_stored_secret() {
+    let server = MockServer::start("serve-no-secret");
+    let (code, _stdout, stderr) = server.run(&["webhooks", "serve", "webhook-1", "--port", "0"]);
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("linear webhooks store-secret webhook-1"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+/// Send one HTTP/1.1 request and return the full response text
+fn http_request(addr: &str, method: &str, path: &str, headers: &str, body: &str) -> String {
+    use std::io::{Read, Write};
+
+    let mut stream = std::net::TcpStream::connect(addr).expect("connect");
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n{}Content-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        addr,
+        headers,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[cfg(unix)]
+#[test]
+fn test_webhooks_serve_health_delivery_and_graceful_shutdown() {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::process::Stdio;
+
+    let server = MockServer::start("serve");
+
+    let mut store = server
+        .command(&["webhooks", "store-secret", "webhook-1"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .expect("spawn store-secret");
+    store.stdin.take().unwrap().write_all(b"s3cret\n").unwrap();
+    assert!(store.wait().unwrap().success());
+
+    let mut child = server
+        .command(&["webhooks", "serve", "webhook-1", "--port", "0", "--json"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn serve");
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut addr = String::new();
+    let mut line = String::new();
+    while stdout.read_line(&mut line).unwrap() > 0 {
+        if let Some(rest) = line.trim().strip_prefix("Health: http://") {
+            addr = rest.trim_end_matches("/health").to_string();
+            break;
+        }
+        line.clear();
+    }
+    assert!(!addr.is_empty(), "serve should print its health URL");
+
+    let health = http_request(&addr, "GET", "/health", "", "");
+    assert!(health.starts_with("HTTP/1.1 200"), "{}", health);
+    assert!(health.contains(r#""eventsReceived":0"#));
+    assert!(health.contains(r#""webhookId":"webhook-1""#));
+
+    let body = r#"{"action":"update","type":"Issue","data":{"id":"issue-1"}}"#;
+    let unsigned = http_request(&addr, "POST", "/webhook", "", body);
+    assert!(unsigned.starts_with("HTTP/1.1 401"), "{}", unsigned);
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cret").unwrap();
+    mac.update(body.as_bytes());
+    let signature = hex::encode(mac.finalize().into_bytes());
+    let signed = http_request(
+        &addr,
+        "POST",
+        "/webhook",
+        &format!("Linear-Signature: {}\r\n", signature),
+        body,
+    );
+    assert!(signed.starts_with("HTTP/1.1 200"), "{}", signed);
+
+    let health = http_request(&addr, "GET", "/health", "", "");
+    assert!(health.contains(r#""eventsReceived":1"#), "{}", health);
+
+    let status = Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    assert!(child.wait().unwrap().success());
+    let mut rest = String::new();
+    stdout.read_to_string(&mut rest).unwrap();
+    assert!(rest.contains(r#""type":"Issue""#), "stdout: {}", rest);
+}
+
+#[test]
+fn test_config_profile_defaults_apply_to_commands() {
+    let server = MockServer::start("profile-defaults");
+
+    let (code, _stdout, stderr) = server.run(&["statuses", "list"]);
+    assert_eq!(code, 2, "missing --team should be a usage error");
+    assert!(stderr.contains("--team"), "stderr: {}", stderr);
+
+    for (key, value) in [("default-team", "ENG"), ("default-output", "json")] {
+        let (code, stdout, stderr) = server.run(&["config", "set", key, value]);
+        assert_eq!(code, 0, "stderr: {}", stderr);
+        assert!(stdout.contains(&format!("{} for 'default' set to", key)));
+    }
+    let (code, stdout, _stderr) = server.run(&["config", "get", "default-team"]);
+    assert_eq!(code, 0);
+    assert_eq!(stdout.trim(), "ENG");
+    let (_code, stdout, _stderr) = server.run(&["config", "show"]);
+    assert!(
+        stdout.contains("default-output: json"),
+        "stdout: {}",
+        stdout
+    );
+
+    // The default team satisfies the required flag; output defaults to JSON
+    let (code, _stdout, stderr) = server.run(&["statuses", "list"]);
+    assert_ne!(code, 2, "stderr: {}", stderr);
+    let (code, stdout, stderr) = server.run(&["issues", "list"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_ok());
+
+    // Explicit flags and other profiles are unaffected
+    let (code, stdout, _stderr) = server.run(&["issues", "list", "--output", "table"]);
+    assert_eq!(code, 0);
+    assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_err());
+    let (code, _stdout, _stderr) = server.run(&["statuses", "list", "--profile", "other"]);
+    assert_eq!(code, 2);
+
+    // Defaults feed the flags directly and are not exported to child processes
+    let (code, _stdout, stderr) = server.run(&[
+        "config",
+        "set",
+        "api-key-command",
+        r#"test -z "$LINEAR_CLI_TEAM$LINEAR_CLI_OUTPUT" && echo lin_api_mock"#,
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let output = server
+        .command(&["issues", "list"])
+        .env_remove("LINEAR_API_KEY")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let (code, _stdout, stderr) = server.run(&["config", "set", "default-output", "yaml"]);
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("Invalid output format"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_repo_config_pins_team_project_and_labels() {
+    let server = MockServer::start("repo-config");
+    let repo = server.home.join("monorepo");
+    let nested = repo.join("apps/mobile");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(
+        repo.join(".linear.toml"),
+        "team = \"ENG\"\nproject = \"Mobile App\"\nlabels = [\"mobile\"]\npr_base = \"develop\"\n",
+    )
+    .unwrap();
+
+    let output = server
+        .command(&[
+            "issues",
+            "create",
+            "Crash on launch",
+            "-l",
+            "bug",
+            "--dry-run",
+            "--output",
+            "json",
+        ])
+        .current_dir(&nested)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {}", stderr);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid JSON");
+    let issue = &json["would_create"];
+    assert_eq!(issue["team"], "ENG");
+    assert_eq!(issue["project"], "Mobile App");
+    assert_eq!(issue["labels"], serde_json::json!(["mobile", "bug"]));
+
+    let output = server
+        .command(&["config", "show"])
+        .current_dir(&nested)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Repository config:"), "stdout: {}", stdout);
+    assert!(stdout.contains("pr_base: develop"));
+
+    // A typo is a warning, not an error, so unrelated commands keep working
+    std::fs::write(repo.join(".linear.toml"), "teams = \"ENG\"\n").unwrap();
+    let output = server
+        .command(&["issues", "list"])
+        .current_dir(&nested)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {}", stderr);
+    assert!(
+        stderr.contains("Ignoring unknown key 'teams'"),
+        "stderr: {}",
+        stderr
+    );
+
+    std::fs::write(repo.join(".linear.toml"), "team = [\"ENG\"\n").unwrap();
+    let output = server
+        .command(&["--help"])
+        .current_dir(&nested)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(".linear.toml"));
+
+    // A pinned profile that differs from the active one is announced
+    std::fs::write(repo.join(".linear.toml"), "profile = \"other\"\n").unwrap();
+    let output = server
+        .command(&["config", "get", "profile"])
+        .current_dir(&nested)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "other");
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("Using profile 'other' from"),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_aliases_and_macros_expand_before_parsing() {
+    let server = MockServer::start("aliases");
+
+    let (code, stdout, stderr) = server.run(&["config", "alias", "peek", "i get {1}"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("Alias 'peek' set to: i get {1}"));
+
+    // Global flags before the alias and extra arguments after it are kept
+    let (code, stdout, stderr) = server.run(&["--output", "json", "peek", "ENG-1"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(json["identifier"], "ENG-1");
+
+    let (code, _stdout, stderr) = server.run(&["peek"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("needs argument {1}"), "stderr: {}", stderr);
+
+    // Built-in commands cannot be shadowed
+    server.run(&["config", "alias", "issues", "peek ENG-2"]);
+    let (code, stdout, _stderr) = server.run(&["issues", "get", "ENG-1", "--output", "json"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("ENG-1"));
+
+    let config_path = server.home.join("linear-cli").join("config.toml");
+    let mut config = std::fs::read_to_string(&config_path).expect("config written");
+    config.push_str("\n[macros]\ncheck = [\"peek {1}\", \"i get ENG-404\", \"peek ENG-2\"]\n");
+    std::fs::write(&config_path, config).unwrap();
+
+    let (code, stdout, stderr) = server.run(&["--output", "json", "check", "ENG-1"]);
+    assert_ne!(code, 0, "the second step should fail");
+    assert!(
+        stdout.contains("ENG-1"),
+        "stdout: {} stderr: {}",
+        stdout,
+        stderr
+    );
+    assert!(
+        !stdout.contains("ENG-2"),
+        "macro should stop at the failing step"
+    );
+    assert!(
+        stderr.contains("Macro 'check' stopped at step 2"),
+        "stderr: {}",
+        stderr
+    );
+
+    let (code, stdout, _stderr) = server.run(&["config", "aliases"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("peek = i get {1}"));
+    assert!(stdout.contains("check:"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_api_key_command_supplies_key() {
+    let server = MockServer::start("api-key-command");
+    let (code, stdout, stderr) = server.run(&[
+        "config",
+        "set",
+        "api-key-command",
+        "printf 'lin_api_from_helper\\n'",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("API key command for 'default' set to"));
+
+    let output = server
+        .command(&["config", "get", "api-key"])
+        .env_remove("LINEAR_API_KEY")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "lin_***lper"
+    );
+
+    let output = server
+        .command(&["auth", "status", "--output", "json"])
+        .env_remove("LINEAR_API_KEY")
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid JSON");
+    assert_eq!(json["configured"], true);
+    assert_eq!(json["api_key_command_ok"], true);
+    assert_eq!(json["api_key_command_key"], "lin_***lper");
+
+    let output = server
+        .command(&["issues", "list", "--output", "json"])
+        .env_remove("LINEAR_API_KEY")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    server.run(&["config", "set", "api-key-command", "exit 1"]);
+    let output = server
+        .command(&["doctor"])
+        .env_remove("LINEAR_API_KEY")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("API key command: exit 1 (failed:"),
+        "stdout: {}",
+        stdout
+    );
+    let output = server
+        .command(&["issues", "list"])
+        .env_remove("LINEAR_API_KEY")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("api_key_command"));
+}
+
+#[test]
+fn test_profile_list_fans_out_read_commands() {
+    let server = MockServer::start("fanout");
+    let config_dir = server.home.join("linear-cli");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.toml"),
+        format!(
+            "current = \"work\"\n\n[workspaces.work]\napi_key = \"lin_api_work\"\napi_url = \"{url}\"\n\n[workspaces.work.defaults]\noutput = \"json\"\n\n[workspaces.oss]\napi_key = \"lin_api_oss\"\napi_url = \"{url}\"\n",
+            url = server.url
+        ),
+    )
+    .unwrap();
+
+    let (code, stdout, stderr) =
+        server.run(&["users", "me", "--profile", "work,oss", "-o", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    let workspaces: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["workspace"].as_str().unwrap())
+        .collect();
+    assert_eq!(workspaces, vec!["work", "oss"]);
+
+    let (code, stdout, stderr) = server.run(&["issues", "list", "--all-profiles", "-o", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    let issues = json.as_array().unwrap();
+    assert!(!issues.is_empty());
+    assert!(issues.iter().all(|i| i["workspace"].is_string()));
+    assert_eq!(issues.first().unwrap()["workspace"], "oss");
+
+    // The current profile's defaults (output = json) are not applied to a fan-out
+    let (code, stdout, stderr) = server.run(&["issues", "list", "--profile", "work,oss"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("Workspace"), "stdout: {}", stdout);
+    let (code, stdout, stderr) = server.run(&["issues", "list", "--all-profiles"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("Workspace"), "stdout: {}", stdout);
+
+    let (code, _stdout, stderr) = server.run(&["teams", "list", "--profile", "work,oss"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("only supported by"), "stderr: {}", stderr);
+
+    let (code, _stdout, stderr) = server.run(&["users", "me", "--profile", "work,missing"]);
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("Workspace 'missing' not found"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_api_rate_limit_reports_budget_and_errors() {
+    let server = MockServer::start("rate-limit");
+
+    let (code, stdout, stderr) = server.run(&["api", "rate-limit", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(json["requests_remaining"], 1487);
+    assert_eq!(json["complexity_limit"], 250000);
+
+    let (code, stdout, _stderr) = server.run(&["api", "rate-limit"]);
+    assert_eq!(code, 0);
+    assert!(
+        stdout.contains("Requests: 1487 / 1500 remaining"),
+        "stdout: {}",
+        stdout
+    );
+
+    // An exhausted budget with a distant reset fails fast with exit code 4
+    let (code, _stdout, stderr) = server.run(&[
+        "api",
+        "query",
+        "query RateLimitedProbe { viewer { id } }",
+        "--output",
+        "json",
+    ]);
+    assert_eq!(code, 4, "stderr: {}", stderr);
+    let err: serde_json::Value = serde_json::from_str(stderr.trim()).expect("JSON error");
+    assert_eq!(err["rate_limit"]["requests_remaining"], 0);
+    assert!(err["retry_after"].as_u64().unwrap() > 3600);
+}
+
+#[test]
+fn test_retried_create_recovers_entity_created_by_lost_attempt() {
+    let server = MockServer::start("idempotency");
+    let trace_file = server.home.join("trace.jsonl");
+
+    // First attempt gets a 503, the retry is rejected as a duplicate of the
+    // client-generated id, so the comment is fetched instead of failing.
+    let output = server
+        .command(&[
+            "comments",
+            "create",
+            "ENG-1",
+            "--body",
+            "Flaky comment",
+            "--retry",
+            "1",
+        ])
+        .env("LINEAR_CLI_TRACE", &trace_file)
+        .output()
+        .expect("run comments create");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {}", stderr);
+    assert!(stdout.contains("ID: comment-9"), "stdout: {}", stdout);
+    assert!(stderr.contains("already created by an earlier attempt"));
+
+    // Both attempts sent the same client-generated id
+    let trace = std::fs::read_to_string(&trace_file).expect("trace file written");
+    let ids: Vec<String> = trace
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|entry| entry["_graphql"]["rootFields"][0] == "commentCreate")
+        .map(|entry| entry["_graphql"]["variables"]["input"]["id"].to_string())
+        .collect();
+    assert_eq!(ids.len(), 2, "trace: {}", trace);
+    assert_eq!(ids[0], ids[1]);
+
+    // Without --retry the transient failure is reported as-is
+    let server = MockServer::start("idempotency");
+    let (code, _stdout, stderr) =
+        server.run(&["comments", "create", "ENG-1", "--body", "Flaky comment"]);
+    assert_eq!(code, 1);
+    assert!(stderr.contains("503"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_retried_create_survives_dropped_response_and_refused_connection() {
+    let server = MockServer::start("idempotency-dropped");
+
+    // The first response never arrives; the retry recovers the created comment
+    let (code, stdout, stderr) = server.run(&[
+        "comments",
+        "create",
+        "ENG-1",
+        "--body",
+        "Lost comment",
+        "--retry",
+        "1",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stderr.contains("Attempt 1 failed"), "stderr: {}", stderr);
+    assert!(stdout.contains("ID: comment-9"), "stdout: {}", stdout);
+
+    // Nothing listens on the port: every attempt is made before giving up
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+    let output = server
+        .command(&[
+            "comments",
+            "create",
+            "ENG-1",
+            "--body",
+            "Lost comment",
+            "--retry",
+            "1",
+        ])
+        .env("LINEAR_API_URL", &url)
+        .output()
+        .expect("run comments create");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Attempt 1 failed"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_api_schema_pull_validates_and_coerces_variables() {
+    let server = MockServer::start("schema");
+
+    let (code, stdout, stderr) = server.run(&["api", "schema", "pull"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("1 mutations"), "stdout: {}", stdout);
+
+    let (code, stdout, _stderr) = server.run(&["api", "schema", "show", "Issue"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("title: String!"), "stdout: {}", stdout);
+
+    // Unknown fields are rejected before anything is sent
+    let (code, _stdout, stderr) =
+        server.run(&["api", "query", r#"query { issue(id: "ENG-1") { titel } }"#]);
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("Unknown field 'titel' on type 'Issue' (did you mean 'title'?)"),
+        "stderr: {}",
+        stderr
+    );
+
+    // -v values follow the declared types: Int stays a number, enums are checked
+    let query = "query SchemaCoerce($first: Int, $orderBy: PaginationOrderBy) { issues(first: $first, orderBy: $orderBy) { nodes { id title } } }";
+    let (code, stdout, stderr) = server.run(&[
+        "api",
+        "query",
+        "-v",
+        "first=5",
+        "-v",
+        "orderBy=updatedAt",
+        query,
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("Coerced"), "stdout: {}", stdout);
+    let (code, _stdout, stderr) = server.run(&["api", "query", "-v", "first=five", query]);
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("Variable '$first' expects Int, got 'five'"),
+        "stderr: {}",
+        stderr
+    );
+    let (code, _stdout, stderr) = server.run(&["api", "query", "-v", "frist=5", query]);
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("Variable 'frist' is not declared"),
+        "stderr: {}",
+        stderr
+    );
+
+    let (code, stdout, _stderr) =
+        server.run(&["_complete", "--type", "schema", "--prefix", "Issue.ti"]);
+    assert_eq!(code, 0);
+    assert_eq!(stdout.trim(), "Issue.title\tString!");
+}
+
+#[test]
+fn test_api_runs_saved_operation_from_file_with_json_variables() {
+    let server = MockServer::start("operations");
+    let ops = server.home.join("ops.graphql");
+    std::fs::write(
+        &ops,
+        r#"# Saved team operations
+fragment IssueBits on Issue { id identifier title }
+
+query SavedTeamIssues($teamKey: String!, $first: Int, $after: String) {
+  issues(first: $first, after: $after, filter: { team: { key: { eq: $teamKey } } }) {
+    nodes { ...IssueBits }
+    pageInfo { hasNextPage endCursor }
+  }
+}
+
+mutation SavedClose($id: String!) {
+  issueUpdate(id: $id, input: { stateId: "done" }) { success }
+}
+"#,
+    )
+    .unwrap();
+    let vars = server.home.join("vars.json");
+    std::fs::write(&vars, r#"{"teamKey": "ENG"}"#).unwrap();
+    let ops = ops.to_str().unwrap();
+
+    let (code, _stdout, stderr) = server.run(&["api", "query", "-f", ops]);
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("Document defines 2 operations (SavedTeamIssues, SavedClose)"),
+        "stderr: {}",
+        stderr
+    );
+
+    let (code, stdout, stderr) = server.run(&[
+        "api",
+        "query",
+        "-f",
+        ops,
+        "--operation",
+        "SavedTeamIssues",
+        "--variables",
+        vars.to_str().unwrap(),
+        "-V",
+        "first:=2",
+        "--paginate",
+        "--nodes-path",
+        "data.issues.nodes",
+        "--page-info-path",
+        "data.issues.pageInfo",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let nodes: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let ids: Vec<&str> = nodes
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["identifier"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["ENG-1", "ENG-2", "ENG-3"]);
+
+    let (code, _stdout, stderr) = server.run(&[
+        "api",
+        "query",
+        "-f",
+        ops,
+        "--operation",
+        "SavedTeamIssues",
+        "-V",
+        "first:=two",
+    ]);
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("Variable 'first' is not valid JSON"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_issue_lookups_are_cached_until_a_mutation_touches_the_issue() {
+    let server = MockServer::start("issue_cache");
+    let trace_file = server.home.join("trace.jsonl");
+    let trace = trace_file.to_str().unwrap();
+    let issue_lookups = || {
+        std::fs::read_to_string(&trace_file)
+            .unwrap_or_default()
+            .lines()
+            .filter(|line| line.contains(r#""rootFields":["issue"]"#))
+            .count()
+    };
+    let link = |url: &str| {
+        let output = server
+            .command(&["--trace", trace, "attachments", "link-url", "ENG-1", url])
+            .env("LINEAR_CLI_NO_CACHE", "false")
+            .output()
+            .expect("run attachments link-url");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(0), "stderr: {}", stderr);
+    };
+
+    link("https://example.com/a");
+    link("https://example.com/b");
+    assert_eq!(
+        issue_lookups(),
+        1,
+        "second link should reuse the cached UUID"
+    );
+
+    let (code, stdout, _stderr) = server.run(&["cache", "status"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Issues"), "stdout: {}", stdout);
+
+    // Updating the issue drops its cached identifier
+    let (code, _stdout, stderr) = server.run(&[
+        "api",
+        "mutate",
+        "-v",
+        "id=ENG-1",
+        r#"mutation($id: String!) { issueUpdate(id: $id, input: { title: "Renamed" }) { success } }"#,
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    link("https://example.com/c");
+    assert_eq!(issue_lookups(), 2);
+}
+
+#[test]
+fn test_cache_warm_and_stale_while_revalidate() {
+    let server = MockServer::start("cache_warm");
+
+    let (code, stdout, stderr) = server.run(&["cache", "warm", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let warmed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let items = |cache: &str| {
+        warmed
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|e| e["cache"] == cache)
+            .unwrap_or_else(|| panic!("{} missing from {}", cache, stdout))["items"]
+            .clone()
+    };
+    assert_eq!(items("Teams"), 2);
+    assert_eq!(items("Labels"), 1);
+    assert_eq!(items("Statuses (ENG)"), 2);
+    assert_eq!(items("Statuses (DES)"), 2);
+
+    // An expired teams list that still knows a team the API no longer returns
+    let teams_cache = server.home.join("linear-cli/cache/default/teams.json");
+    assert!(teams_cache.exists(), "warm should write {:?}", teams_cache);
+    let write_stale = || {
+        std::fs::write(
+            &teams_cache,
+            r#"{"timestamp": 1, "ttl_seconds": 3600, "data": [{"id": "team-old", "key": "OLD", "name": "Old"}]}"#,
+        )
+        .unwrap();
+    };
+    let list_statuses = |extra: &[&str]| {
+        let mut args = vec!["statuses", "list", "--team", "OLD", "--output", "json"];
+        args.extend_from_slice(extra);
+        server
+            .command(&args)
+            .env("LINEAR_CLI_NO_CACHE", "false")
+            .output()
+            .expect("run statuses list")
+    };
+
+    write_stale();
+    let output = list_statuses(&[]);
+    assert_ne!(
+        output.status.code(),
+        Some(0),
+        "expired entries are not served"
+    );
+
+    write_stale();
+    let output = list_statuses(&["--stale-while-revalidate"]);
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let refreshed = std::fs::read_to_string(&teams_cache).unwrap();
+    assert!(
+        refreshed.contains("team-eng") && !refreshed.contains("team-old"),
+        "background refresh should replace the stale list: {}",
+        refreshed
+    );
+
+    // Per-team statuses are served stale and refreshed the same way
+    let statuses_cache = server.home.join("linear-cli/cache/default/statuses.json");
+    std::fs::write(
+        &statuses_cache,
+        r#"{"timestamp": 1, "ttl_seconds": 3600, "data": {"team-eng": {"timestamp": 1, "data": {"team_name": "Engineering", "states": [{"id": "state-old", "name": "Retired", "type": "started"}]}}}}"#,
+    )
+    .unwrap();
+    let output = server
+        .command(&[
+            "statuses",
+            "list",
+            "--team",
+            "ENG",
+            "--stale-while-revalidate",
+            "--output",
+            "json",
+        ])
+        .env("LINEAR_CLI_NO_CACHE", "false")
+        .output()
+        .expect("run statuses list");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Retired"), "stdout: {}", stdout);
+    let refreshed = std::fs::read_to_string(&statuses_cache).unwrap();
+    assert!(
+        !refreshed.contains("state-old"),
+        "background refresh should replace the stale statuses: {}",
+        refreshed
+    );
+}
+
+#[test]
+fn test_debug_trace_logs_requests_with_secrets_redacted() {
+    let server = MockServer::start("trace");
+    let trace_file = server.home.join("trace.jsonl");
+
+    let output = server
+        .command(&[
+            "api",
+            "mutate",
+            "-v",
+            "secret=whsec_topsecret",
+            r#"mutation TraceProbe($secret: String!) { webhookCreate(input: { url: "https://x", secret: $secret }) { success } }"#,
+        ])
+        .env("LINEAR_CLI_TRACE", &trace_file)
+        .output()
+        .expect("run api mutate");
+    let code = output.status.code().unwrap_or(-1);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let trace = std::fs::read_to_string(&trace_file).expect("trace file written");
+    assert!(!trace.contains("whsec_topsecret"), "trace: {}", trace);
+    assert!(!trace.contains("lin_api_mock"), "trace: {}", trace);
+    let entry: serde_json::Value =
+        serde_json::from_str(trace.lines().next().unwrap()).expect("JSON entry per line");
+    assert_eq!(entry["request"]["method"], "POST");
+    assert_eq!(entry["response"]["status"], 200);
+    assert_eq!(entry["_requestId"], "req-trace-1");
+    assert_eq!(entry["_graphql"]["operationType"], "mutation");
+    assert_eq!(entry["_graphql"]["variables"]["secret"], "[REDACTED]");
+
+    let (code, _stdout, stderr) = server.run(&[
+        "--debug",
+        "api",
+        "query",
+        "query TraceFailure { viewer { id } }",
+    ]);
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("[debug] query TraceFailure -> 200")
+            && stderr.contains("(x-request-id: req-trace-2)")
+            && stderr.contains("Entity not found: viewer"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_filter_operators_and_filter_any() {
+    let server = MockServer::start("filter-operators");
+    let identifiers = |args: &[&str]| -> Vec<String> {
+        let mut full = vec!["issues", "list", "--output", "json"];
+        full.extend_from_slice(args);
+        let (code, stdout, stderr) = server.run(&full);
+        assert_eq!(code, 0, "stderr: {}", stderr);
+        let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+        json.as_array()
+            .unwrap()
+            .iter()
+            .map(|i| i["identifier"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        identifiers(&["--filter", "labels.nodes.name=bug"]),
+        ["ENG-1"]
+    );
+    assert_eq!(identifiers(&["--filter", "priority>2"]), ["ENG-2"]);
+    assert_eq!(
+        identifiers(&["--filter", "createdAt>=2024-01-04"]),
+        ["ENG-2"]
+    );
+    assert_eq!(identifiers(&["--filter", "assignee not exists"]), ["ENG-2"]);
+    assert_eq!(identifiers(&["--filter", "title=~^fix"]), ["ENG-1"]);
+    assert_eq!(
+        identifiers(&[
+            "--filter-any",
+            "state.name in (Done, Todo)",
+            "--filter-any",
+            "labels.nodes.name=bug",
+        ]),
+        ["ENG-1", "ENG-2"]
+    );
+    assert_eq!(
+        identifiers(&[
+            "--filter",
+            "priority<=2",
+            "--filter-any",
+            "state.name=Todo",
+            "--filter-any",
+            "title~=login",
+        ]),
+        ["ENG-1"]
+    );
+
+    let (code, _stdout, stderr) =
+        server.run(&["issues", "list", "--output", "json", "--filter", "title=~("]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Invalid regex"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_jq_transforms_json_output() {
+    let server = MockServer::start("jq");
+
+    // --jq implies JSON output and runs after --filter
+    let (code, stdout, stderr) = server.run(&[
+        "issues",
+        "list",
+        "--filter",
+        "priority>=2",
+        "--jq",
+        "map({id: .identifier, state: .state.name})",
+        "--compact",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert_eq!(
+        stdout.trim(),
+        r#"[{"id":"ENG-1","state":"In Progress"},{"id":"ENG-2","state":"Todo"}]"#
+    );
+
+    // Strings print raw, one per line
+    let (code, stdout, stderr) = server.run(&["issues", "list", "--jq", ".[].identifier"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert_eq!(stdout, "ENG-1\nENG-2\n");
+
+    let (code, stdout, stderr) = server.run(&[
+        "issues",
+        "list",
+        "--jq",
+        "group_by(.labels.nodes | length) | map({labels: (.[0].labels.nodes | length), count: length})",
+        "--compact",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert_eq!(
+        stdout.trim(),
+        r#"[{"count":1,"labels":0},{"count":1,"labels":1}]"#
+    );
+
+    // Parse errors are reported as JSON, like errors while running the expression
+    let (code, _stdout, stderr) = server.run(&["issues", "list", "--jq", "map(.identifier"]);
+    assert_ne!(code, 0);
+    let error: serde_json::Value = serde_json::from_str(stderr.trim()).unwrap();
+    assert_eq!(error["error"], true);
+    assert!(
+        error["message"]
+            .as_str()
+            .unwrap()
+            .contains("Invalid --jq expression"),
+        "stderr: {}",
+        stderr
+    );
+    let (code, _stdout, stderr) = server.run(&["issues", "list", "--jq", ".[] | .identifier - 1"]);
+    assert_ne!(code, 0);
+    let error: serde_json::Value = serde_json::from_str(stderr.trim()).unwrap();
+    assert!(error["message"].as_str().unwrap().starts_with("--jq:"));
+
+    let (code, _stdout, stderr) =
+        server.run(&["issues", "list", "--jq", ".[].identifier", "-o", "table"]);
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("cannot be combined with --output table"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_format_templates_with_blocks_helpers_and_files() {
+    let server = MockServer::start("format-templates");
+
+    let (code, stdout, stderr) = server.run(&[
+        "issues",
+        "list",
+        "--format",
+        "{{identifier}} {{title | truncate 14}} [{{priority | priority_name}}] {{#if assignee}}@{{assignee.name}}{{else}}unassigned{{/if}}{{#each labels.nodes}} #{{name}}{{/each}}",
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert_eq!(
+        stdout,
+        "ENG-1 Fix login r... [High] @Mock User #bug\nENG-2 Add dark mode [Normal] unassigned\n"
+    );
+
+    let template = server.home.join("standup.tpl");
+    std::fs::write(
+        &template,
+        "# Standup\n{{#each .}}\n- {{identifier}} {{title}} ({{state.name | lower}}, {{createdAt | date \"%b %d\"}})\n{{/each}}\n",
+    )
+    .unwrap();
+    let (code, stdout, stderr) = server.run(&[
+        "issues",
+        "list",
+        "--format-file",
+        template.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    assert_eq!(
+        stdout,
+        "# Standup\n- ENG-1 Fix login redirect (in progress, Jan 02)\n- ENG-2 Add dark mode (todo, Jan 04)\n"
+    );
+
+    let (code, _stdout, stderr) =
+        server.run(&["issues", "list", "--format", "{{#if assignee}}open"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Invalid template"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_mirror_incremental_pull_dedupes_boundary_records() {
+    let server = MockServer::start("mirror");
+    let (code, _stdout, stderr) = server.run(&["mirror", "pull"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+
+    // The incremental pull re-fetches records at the cursor; they replace the stored ones
+    let (code, stdout, stderr) = server.run(&["mirror", "pull", "--output", "json"]);
+    assert_eq!(code, 0, "stderr: {}", stderr);
+    let summary: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert_eq!(summary["issues"]["total"], 2);
+    assert_eq!(summary["comments"]["total"], 1);
+}